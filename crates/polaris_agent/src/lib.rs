@@ -46,6 +46,7 @@
 //! ```
 
 use polaris_graph::graph::Graph;
+use polaris_graph::node::SubgraphOptions;
 use polaris_system::param::SystemContext;
 
 /// Error returned by [`Agent::setup`].
@@ -146,9 +147,59 @@ pub trait Agent: Send + Sync + 'static {
     }
 }
 
+/// Extension methods for embedding agents in a [`Graph`].
+pub trait GraphAgentExt {
+    /// Adds `agent`'s graph as a subgraph node named after the agent.
+    ///
+    /// The agent runs as a single step in its own context. Only the values
+    /// declared in `options` cross the boundary, and [`Agent::setup`] is not
+    /// called, so any resources the agent needs must be bound with
+    /// [`SubgraphOptions::resource`] or registered globally.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polaris_agent::{Agent, GraphAgentExt};
+    /// use polaris_graph::Graph;
+    /// use polaris_graph::node::SubgraphOptions;
+    ///
+    /// #[derive(Clone)]
+    /// struct Task(String);
+    /// struct Answer(String);
+    ///
+    /// # async fn plan() -> Task { Task("summarize".into()) }
+    /// # async fn solve() -> Answer { Answer("done".into()) }
+    /// struct Worker;
+    ///
+    /// impl Agent for Worker {
+    ///     fn build(&self, graph: &mut Graph) {
+    ///         graph.add_system(solve);
+    ///     }
+    ///
+    ///     fn name(&self) -> &'static str {
+    ///         "Worker"
+    ///     }
+    /// }
+    ///
+    /// let mut graph = Graph::new();
+    /// graph.add_system(plan).add_agent(
+    ///     &Worker,
+    ///     SubgraphOptions::new().input::<Task>().output::<Answer>(),
+    /// );
+    /// ```
+    fn add_agent(&mut self, agent: &dyn Agent, options: SubgraphOptions) -> &mut Self;
+}
+
+impl GraphAgentExt for Graph {
+    fn add_agent(&mut self, agent: &dyn Agent, options: SubgraphOptions) -> &mut Self {
+        self.add_subgraph(agent.name(), agent.to_graph(), options)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use polaris_graph::node::Node;
 
     // Test system functions
     async fn step_one() -> i32 {
@@ -187,6 +238,26 @@ mod tests {
         assert!(graph.entry().is_some());
     }
 
+    #[test]
+    fn add_agent_adds_subgraph_node() {
+        let mut graph = Graph::new();
+        graph
+            .add_system(step_one)
+            .add_agent(&ThreeStepAgent, SubgraphOptions::new());
+
+        assert_eq!(graph.node_count(), 2);
+        let subgraph = graph
+            .nodes()
+            .iter()
+            .find_map(|node| match node {
+                Node::Subgraph(sub) => Some(sub),
+                _ => None,
+            })
+            .expect("expected a subgraph node");
+        assert_eq!(&*subgraph.name, "ThreeStepAgent");
+        assert_eq!(subgraph.graph.node_count(), 3);
+    }
+
     #[test]
     fn agent_name() {
         let agent = ThreeStepAgent;
@@ -24,7 +24,7 @@
 //! ```
 //! # use std::sync::Arc;
 //! # use polaris_system::server::Server;
-//! # use polaris_system::plugin::{Plugin, PluginId, Version};
+//! # use polaris_system::plugin::{Dependency, Plugin, Version};
 //! # use polaris_core_plugins::{ServerInfoPlugin, IOPlugin, IOProvider, IOMessage, IOError, UserIO};
 //!
 //! struct TerminalProvider;
@@ -52,7 +52,7 @@
 //! ```
 
 use crate::ServerInfoPlugin;
-use polaris_system::plugin::{Plugin, PluginId, Version};
+use polaris_system::plugin::{Dependency, Plugin, Version};
 use polaris_system::resource::LocalResource;
 use polaris_system::server::Server;
 use std::collections::HashMap;
@@ -535,8 +535,8 @@ impl Plugin for IOPlugin {
         server.register_local(OutputBuffer::new);
     }
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<ServerInfoPlugin>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ServerInfoPlugin>()]
     }
 }
 
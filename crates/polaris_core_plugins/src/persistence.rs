@@ -177,7 +177,7 @@ impl PersistenceAPI {
     ///
     /// ```
     /// use polaris_system::resource::LocalResource;
-    /// use polaris_system::plugin::{Plugin, PluginId, Version};
+    /// use polaris_system::plugin::{Dependency, Plugin, Version};
     /// use polaris_system::server::Server;
     /// use serde::{Serialize, Deserialize};
     /// use polaris_core_plugins::persistence::{Storable, PersistenceAPI, PersistencePlugin};
@@ -196,8 +196,8 @@ impl PersistenceAPI {
     ///     const ID: &'static str = "my_plugin";
     ///     const VERSION: Version = Version::new(1, 0, 0);
     ///
-    ///     fn dependencies(&self) -> Vec<PluginId> {
-    ///         vec![PluginId::of::<PersistencePlugin>()]
+    ///     fn dependencies(&self) -> Vec<Dependency> {
+    ///         vec![Dependency::of::<PersistencePlugin>()]
     ///     }
     ///
     ///     fn build(&self, server: &mut Server) {
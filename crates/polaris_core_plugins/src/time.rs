@@ -36,7 +36,7 @@
 //! ```
 
 use crate::ServerInfoPlugin;
-use polaris_system::plugin::{Plugin, PluginId, Version};
+use polaris_system::plugin::{Dependency, Plugin, Version};
 use polaris_system::resource::{GlobalResource, LocalResource};
 use polaris_system::server::Server;
 use std::sync::Arc;
@@ -369,8 +369,8 @@ impl Plugin for TimePlugin {
         server.register_local(Stopwatch::new);
     }
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<ServerInfoPlugin>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ServerInfoPlugin>()]
     }
 }
 
@@ -43,7 +43,7 @@
 //! ```
 
 use crate::ServerInfoPlugin;
-use polaris_system::plugin::{Plugin, PluginId, Version};
+use polaris_system::plugin::{Dependency, Plugin, Version};
 use polaris_system::resource::GlobalResource;
 use polaris_system::server::Server;
 use tracing::Level;
@@ -331,8 +331,8 @@ impl Plugin for TracingPlugin {
         tracing::info!("TracingPlugin shutting down");
     }
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<ServerInfoPlugin>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ServerInfoPlugin>()]
     }
 }
 
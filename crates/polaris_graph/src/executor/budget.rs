@@ -0,0 +1,133 @@
+//! Run-wide execution limits.
+
+use super::error::ExecutionError;
+use polaris_system::resource::LocalResource;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Remaining time and node budget for the current graph run.
+///
+/// The executor inserts an `ExecutionBudget` into the context before the
+/// first node runs, so systems can read it via `Res<ExecutionBudget>` and
+/// adapt (e.g. skip optional work when little time is left). It is removed
+/// when the run ends, so a context reused for several runs never holds a
+/// stale budget. The node counter is shared across nested loops and
+/// parallel branches, so every clone observes the same run.
+///
+/// # Example
+///
+/// ```
+/// use polaris_graph::ExecutionBudget;
+/// use polaris_system::param::Res;
+/// use polaris_system::system;
+/// use std::time::Duration;
+///
+/// #[system]
+/// async fn plan(budget: Res<ExecutionBudget>) -> bool {
+///     // Only take the slow path when at least 10 seconds remain.
+///     budget
+///         .remaining_time()
+///         .is_none_or(|left| left >= Duration::from_secs(10))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExecutionBudget {
+    /// When the run started.
+    started: Instant,
+    /// Wall-clock limit for the whole run.
+    deadline: Option<Duration>,
+    /// Maximum number of node executions for the whole run.
+    max_nodes: Option<usize>,
+    /// Node executions so far, shared by all branches of the run.
+    nodes_executed: Arc<AtomicUsize>,
+}
+
+impl LocalResource for ExecutionBudget {}
+
+impl ExecutionBudget {
+    /// Creates a budget for a run starting now.
+    pub(crate) fn new(deadline: Option<Duration>, max_nodes: Option<usize>) -> Self {
+        Self {
+            started: Instant::now(),
+            deadline,
+            max_nodes,
+            nodes_executed: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns the wall-clock limit for the run, if any.
+    #[must_use]
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Returns the time elapsed since the run started.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Returns the time left before the deadline, or `None` if the run has
+    /// no deadline.
+    ///
+    /// Returns [`Duration::ZERO`] once the deadline has passed.
+    #[must_use]
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_sub(self.elapsed()))
+    }
+
+    /// Returns the maximum number of node executions for the run, if any.
+    #[must_use]
+    pub fn max_nodes(&self) -> Option<usize> {
+        self.max_nodes
+    }
+
+    /// Returns the number of nodes executed so far, including the current one.
+    #[must_use]
+    pub fn nodes_executed(&self) -> usize {
+        self.nodes_executed.load(Ordering::Acquire)
+    }
+
+    /// Returns how many more nodes may execute, or `None` if the run has no
+    /// node budget.
+    #[must_use]
+    pub fn remaining_nodes(&self) -> Option<usize> {
+        self.max_nodes
+            .map(|max| max.saturating_sub(self.nodes_executed()))
+    }
+
+    /// Returns the instant at which the deadline expires, if any.
+    pub(crate) fn expires_at(&self) -> Option<Instant> {
+        self.deadline.map(|deadline| self.started + deadline)
+    }
+
+    /// Returns [`ExecutionError::DeadlineExceeded`] if the deadline has passed.
+    pub(crate) fn check_deadline(&self) -> Result<(), ExecutionError> {
+        match self.deadline {
+            Some(deadline) if self.elapsed() >= deadline => Err(self.deadline_error()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Builds the error reported when the deadline expires.
+    pub(crate) fn deadline_error(&self) -> ExecutionError {
+        ExecutionError::DeadlineExceeded {
+            deadline: self.deadline.unwrap_or_default(),
+            elapsed: self.elapsed(),
+        }
+    }
+
+    /// Counts one node execution against the budget.
+    ///
+    /// Returns [`ExecutionError::NodeBudgetExceeded`] if the node would
+    /// exceed the budget; the node must not run in that case.
+    pub(crate) fn record_node(&self) -> Result<(), ExecutionError> {
+        let executed = self.nodes_executed.fetch_add(1, Ordering::AcqRel) + 1;
+        match self.max_nodes {
+            Some(max) if executed > max => Err(ExecutionError::NodeBudgetExceeded { max }),
+            _ => Ok(()),
+        }
+    }
+}
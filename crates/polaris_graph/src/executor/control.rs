@@ -0,0 +1,256 @@
+//! Per-run execution controls.
+
+use super::budget::ExecutionBudget;
+use super::cursor::{CheckpointSink, CursorFrame, ExecutionCursor, FrameState, SavedOutput, Scope};
+use super::error::{ExecutionError, SystemOutcome};
+use crate::graph::Graph;
+use crate::node::{NodeId, SystemNode};
+use crate::trace::{OutputCodecs, ReplayState, TraceEvent, TraceRecorder};
+use parking_lot::Mutex;
+use polaris_system::param::SystemContext;
+use polaris_system::resource::Outputs;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio_util::sync::CancellationToken;
+
+/// State shared by every node of a single graph run.
+///
+/// Created once per [`GraphExecutor::execute`](super::GraphExecutor::execute)
+/// call and threaded through all nested control flow, including parallel
+/// branches.
+pub(crate) struct RunControl {
+    /// Token checked between nodes and raced against system futures.
+    pub(crate) cancellation: CancellationToken,
+    /// Wall-clock deadline and node budget for the run.
+    pub(crate) budget: ExecutionBudget,
+    /// Destination for cursors recorded after each completed node.
+    pub(crate) checkpoints: Option<Arc<dyn CheckpointSink>>,
+    /// Output types saved in cursors and restored on resume.
+    outputs: Arc<OutputCodecs>,
+    /// Recorder capturing the run's trace.
+    recorder: Option<TraceRecorder>,
+    /// Recorded trace the run replays.
+    replay: Option<Arc<ReplayState>>,
+    /// Response for the interrupt node the run resumes at, until taken.
+    interrupt_response: Mutex<Option<serde_json::Value>>,
+    /// Nodes entered so far, in the order they started.
+    path: Mutex<Vec<NodeId>>,
+    /// Edges followed so far, in the order they were taken.
+    edges: Mutex<Vec<(NodeId, NodeId)>>,
+    /// Node caps of the enclosing subgraphs, counted on top of the run budget.
+    limits: Vec<Arc<NodeLimit>>,
+    /// This subgraph's own node cap, also the last entry of `limits`.
+    own_limit: Option<Arc<NodeLimit>>,
+}
+
+/// Maximum node count for one subgraph execution.
+struct NodeLimit {
+    max: usize,
+    executed: AtomicUsize,
+}
+
+impl RunControl {
+    /// Creates controls for a run.
+    pub(crate) fn new(
+        cancellation: CancellationToken,
+        budget: ExecutionBudget,
+        checkpoints: Option<Arc<dyn CheckpointSink>>,
+        outputs: OutputCodecs,
+        interrupt_response: Option<serde_json::Value>,
+        recorder: Option<TraceRecorder>,
+        replay: Option<Arc<ReplayState>>,
+    ) -> Self {
+        Self {
+            cancellation,
+            budget,
+            checkpoints,
+            outputs: Arc::new(outputs),
+            recorder,
+            replay,
+            interrupt_response: Mutex::new(interrupt_response),
+            path: Mutex::new(Vec::new()),
+            edges: Mutex::new(Vec::new()),
+            limits: Vec::new(),
+            own_limit: None,
+        }
+    }
+
+    /// Creates controls for a subgraph executed within this run.
+    ///
+    /// The subgraph shares the run's cancellation token and budget but
+    /// records no checkpoints or node trace events, substitutes no systems,
+    /// takes no interrupt response, keeps its own path and edges, and may cap
+    /// its own node count with `max_nodes`. External calls made inside it
+    /// are still recorded and replayed through the run's
+    /// [`ActiveRun`](crate::trace::ActiveRun), which stays set on the task.
+    pub(crate) fn nested(&self, max_nodes: Option<usize>) -> Self {
+        let own_limit = max_nodes.map(|max| {
+            Arc::new(NodeLimit {
+                max,
+                executed: AtomicUsize::new(0),
+            })
+        });
+        let mut limits = self.limits.clone();
+        limits.extend(own_limit.clone());
+
+        Self {
+            cancellation: self.cancellation.clone(),
+            budget: self.budget.clone(),
+            checkpoints: None,
+            outputs: Arc::clone(&self.outputs),
+            recorder: None,
+            replay: None,
+            interrupt_response: Mutex::new(None),
+            path: Mutex::new(Vec::new()),
+            edges: Mutex::new(Vec::new()),
+            limits,
+            own_limit,
+        }
+    }
+
+    /// Returns `true` if the subgraph node cap from [`nested`](Self::nested)
+    /// was exceeded.
+    pub(crate) fn limit_exceeded(&self) -> bool {
+        self.own_limit
+            .as_ref()
+            .is_some_and(|limit| limit.executed.load(Ordering::Acquire) > limit.max)
+    }
+
+    /// Returns an error if the run was cancelled or its deadline has passed.
+    pub(crate) fn check(&self) -> Result<(), ExecutionError> {
+        if self.cancellation.is_cancelled() {
+            return Err(ExecutionError::Cancelled);
+        }
+        self.budget.check_deadline()
+    }
+
+    /// Checks the run is still live, counts one node against the budget and
+    /// appends it to the recorded path.
+    pub(crate) fn enter_node(&self, node: &NodeId) -> Result<(), ExecutionError> {
+        self.check()?;
+        self.budget.record_node()?;
+        for limit in &self.limits {
+            if limit.executed.fetch_add(1, Ordering::AcqRel) >= limit.max {
+                return Err(ExecutionError::NodeBudgetExceeded { max: limit.max });
+            }
+        }
+        self.path.lock().push(node.clone());
+        Ok(())
+    }
+
+    /// Takes the interrupt response provided for the run, if not yet taken.
+    pub(crate) fn take_interrupt_response(&self) -> Option<serde_json::Value> {
+        self.interrupt_response.lock().take()
+    }
+
+    /// Records the event built by `event` when the run records a trace.
+    pub(crate) fn record(&self, event: impl FnOnce(&TraceRecorder) -> Option<TraceEvent>) {
+        if let Some(recorder) = &self.recorder
+            && let Some(event) = event(recorder)
+        {
+            recorder.record(event);
+        }
+    }
+
+    /// Returns the recorded outcome of `sys` when the run replays a trace
+    /// that substitutes it.
+    pub(crate) fn replayed_outcome(
+        &self,
+        graph: &Graph,
+        id: &NodeId,
+        sys: &SystemNode,
+    ) -> Option<Result<SystemOutcome, ExecutionError>> {
+        self.replay.as_ref()?.system_outcome(graph, id, sys)
+    }
+
+    /// Takes the recorded path, leaving it empty.
+    pub(crate) fn take_path(&self) -> Vec<NodeId> {
+        std::mem::take(&mut *self.path.lock())
+    }
+
+    /// Records that execution moved from `from` to `to`.
+    pub(crate) fn follow(&self, from: &NodeId, to: &NodeId) {
+        self.edges.lock().push((from.clone(), to.clone()));
+    }
+
+    /// Takes the recorded edges, leaving them empty.
+    pub(crate) fn take_edges(&self) -> Vec<(NodeId, NodeId)> {
+        std::mem::take(&mut *self.edges.lock())
+    }
+
+    /// Runs `future` to completion unless the run is cancelled or its
+    /// deadline expires first.
+    ///
+    /// On cancellation or deadline expiry, `future` is dropped and the
+    /// corresponding error is returned.
+    pub(crate) async fn race<F: Future>(&self, future: F) -> Result<F::Output, ExecutionError> {
+        let deadline = async {
+            match self.budget.expires_at() {
+                Some(at) => tokio::time::sleep_until(at.into()).await,
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            biased;
+            () = self.cancellation.cancelled() => Err(ExecutionError::Cancelled),
+            () = deadline => Err(self.budget.deadline_error()),
+            output = future => Ok(output),
+        }
+    }
+
+    /// Records a checkpoint for `node` at the level described by `scope`.
+    ///
+    /// Does nothing when no sink is configured or when `scope` is inside a
+    /// parallel branch.
+    pub(crate) async fn checkpoint(
+        &self,
+        graph: &Graph,
+        scope: &Scope<'_>,
+        node: &NodeId,
+        state: FrameState,
+        ctx: &SystemContext<'_>,
+    ) -> Result<(), ExecutionError> {
+        let Some(sink) = &self.checkpoints else {
+            return Ok(());
+        };
+        if scope.detached {
+            return Ok(());
+        }
+
+        let cursor = self.cursor(scope, CursorFrame::at(graph, node, state)?, ctx);
+        sink.save(&cursor, ctx).await
+    }
+
+    /// Builds the cursor for `frame` at the level described by `scope`,
+    /// saving the registered outputs of `ctx`.
+    pub(crate) fn cursor(
+        &self,
+        scope: &Scope<'_>,
+        frame: CursorFrame,
+        ctx: &SystemContext<'_>,
+    ) -> ExecutionCursor {
+        scope.cursor(frame, self.outputs.save(ctx.outputs()))
+    }
+
+    /// Serializes the registered outputs of a completed parallel branch.
+    ///
+    /// Returns nothing when no sink is configured, since the outputs would
+    /// never be saved.
+    pub(crate) fn save_branch_outputs(&self, outputs: &Outputs) -> Vec<SavedOutput> {
+        if self.checkpoints.is_none() {
+            return Vec::new();
+        }
+        self.outputs.save(outputs)
+    }
+
+    /// Deserializes outputs saved in a cursor.
+    ///
+    /// Returns [`ExecutionError::InvalidCursor`] if an output's type is not
+    /// registered or its value does not deserialize.
+    pub(crate) fn restore_outputs(&self, saved: &[SavedOutput]) -> Result<Outputs, ExecutionError> {
+        self.outputs
+            .restore(saved)
+            .map_err(ExecutionError::InvalidCursor)
+    }
+}
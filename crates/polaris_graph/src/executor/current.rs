@@ -0,0 +1,38 @@
+//! The system node executing on the current task.
+
+use crate::node::NodeId;
+use std::future::Future;
+
+tokio::task_local! {
+    /// System node whose future is being polled on the current task.
+    static CURRENT: RunningSystem;
+}
+
+/// A system node being executed.
+///
+/// Returned by [`current_system`] to code called from inside a system, such
+/// as a model handle attributing usage to the node that made the request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunningSystem {
+    /// The node's ID.
+    pub node_id: NodeId,
+    /// The system's name.
+    pub name: &'static str,
+}
+
+/// Returns the system node being executed on the current task, if any.
+///
+/// Each attempt of a system runs with its node set, including systems in
+/// parallel branches and subgraphs. Outside a graph run, and in tasks
+/// spawned by a system, this returns `None`.
+#[must_use]
+pub fn current_system() -> Option<RunningSystem> {
+    CURRENT.try_with(Clone::clone).ok()
+}
+
+impl RunningSystem {
+    /// Runs `future` with this system set as the current one.
+    pub(crate) async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT.scope(self, future).await
+    }
+}
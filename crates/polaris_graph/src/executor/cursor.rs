@@ -0,0 +1,262 @@
+//! Execution cursors for checkpointing and resuming a graph run.
+
+use super::error::ExecutionError;
+use crate::graph::Graph;
+use crate::node::NodeId;
+use polaris_system::param::SystemContext;
+use polaris_system::system::BoxFuture;
+use serde::{Deserialize, Serialize};
+
+/// Position within a graph run from which execution can be resumed.
+///
+/// A cursor is a stack of [`CursorFrame`]s, one per level of nested control
+/// flow. The outermost frame refers to a node of the top-level sequence; each
+/// following frame refers to a node inside the loop body, decision branch, or
+/// switch case entered by the frame before it.
+///
+/// Nodes are addressed by their position in [`Graph::nodes`] rather than by
+/// [`NodeId`], because node IDs are regenerated each time a graph is built.
+/// The node name is recorded alongside so that resuming against a graph with
+/// a different shape fails with [`ExecutionError::InvalidCursor`] instead of
+/// running the wrong node.
+///
+/// # Outputs
+///
+/// System outputs (`Out<T>`) produced before the checkpoint are saved in the
+/// cursor for every type registered with
+/// [`RunOptions::with_checkpoint_output`](super::RunOptions::with_checkpoint_output),
+/// and restored into the context when the run resumes. Outputs of other
+/// types are not saved, so a node after the cursor that reads one fails with
+/// a missing output. Progress inside parallel branches is tracked per
+/// branch: a resumed parallel node restores the outputs of completed
+/// branches and re-runs pending ones from their start.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionCursor {
+    frames: Vec<CursorFrame>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    outputs: Vec<SavedOutput>,
+}
+
+impl ExecutionCursor {
+    /// Returns the frames of the cursor, outermost first.
+    #[must_use]
+    pub fn frames(&self) -> &[CursorFrame] {
+        &self.frames
+    }
+
+    /// Returns the innermost frame, which identifies the node execution will
+    /// resume at.
+    #[must_use]
+    pub fn innermost(&self) -> Option<&CursorFrame> {
+        self.frames.last()
+    }
+
+    /// Returns the outputs saved with the cursor, ordered by type name.
+    #[must_use]
+    pub fn outputs(&self) -> &[SavedOutput] {
+        &self.outputs
+    }
+}
+
+/// A system output saved in an [`ExecutionCursor`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedOutput {
+    /// Type name of the output, as reported by [`std::any::type_name`].
+    pub output_type: String,
+    /// The serialized output.
+    pub value: serde_json::Value,
+}
+
+/// A single level of an [`ExecutionCursor`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CursorFrame {
+    /// Position of the node in [`Graph::nodes`].
+    pub node_index: usize,
+    /// Name of the node, used to detect graph changes on resume.
+    pub node_name: String,
+    /// Progress made at this node.
+    pub state: FrameState,
+}
+
+impl CursorFrame {
+    /// Creates a frame for the node with the given ID.
+    pub(crate) fn at(
+        graph: &Graph,
+        id: &NodeId,
+        state: FrameState,
+    ) -> Result<Self, ExecutionError> {
+        let (node_index, node) = graph
+            .nodes()
+            .iter()
+            .enumerate()
+            .find(|(_, node)| node.id() == *id)
+            .ok_or_else(|| ExecutionError::NodeNotFound(id.clone()))?;
+
+        Ok(Self {
+            node_index,
+            node_name: node.name().to_owned(),
+            state,
+        })
+    }
+
+    /// Resolves the frame against `graph`, returning the node's ID.
+    pub(crate) fn resolve(&self, graph: &Graph) -> Result<NodeId, ExecutionError> {
+        let node = graph.nodes().get(self.node_index).ok_or_else(|| {
+            ExecutionError::InvalidCursor(format!(
+                "node index {} is out of range for a graph with {} nodes",
+                self.node_index,
+                graph.node_count()
+            ))
+        })?;
+
+        if node.name() != self.node_name {
+            return Err(ExecutionError::InvalidCursor(format!(
+                "expected node '{}' at index {}, found '{}'",
+                self.node_name,
+                self.node_index,
+                node.name()
+            )));
+        }
+
+        Ok(node.id())
+    }
+
+    /// Builds the error reported when a frame's state does not fit its node.
+    pub(crate) fn mismatch(&self) -> ExecutionError {
+        ExecutionError::InvalidCursor(format!(
+            "cannot resume node '{}' at index {} from {:?}",
+            self.node_name, self.node_index, self.state
+        ))
+    }
+}
+
+/// Progress recorded for the node referenced by a [`CursorFrame`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FrameState {
+    /// The node has not started; execution resumes by running it.
+    Next,
+    /// A decision node is executing the selected branch.
+    Decision {
+        /// Which branch was selected.
+        branch: bool,
+    },
+    /// A switch node is executing the selected case.
+    Switch {
+        /// The selected case key, or the default case name.
+        case: String,
+        /// Whether the default case was selected.
+        used_default: bool,
+    },
+    /// A loop node is executing its body.
+    Loop {
+        /// Number of iterations completed before the current one.
+        iteration: usize,
+    },
+    /// A parallel node is waiting on its branches.
+    Parallel {
+        /// Indices of branches that have completed, in ascending order.
+        completed: Vec<usize>,
+        /// Saved outputs of each completed branch, in the order of
+        /// `completed`.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        outputs: Vec<Vec<SavedOutput>>,
+    },
+    /// An interrupt node is waiting for its response.
+    Interrupted,
+    /// The node has completed and was the last of its level; execution
+    /// resumes after the level.
+    Done,
+}
+
+/// Destination for execution cursors recorded during a run.
+///
+/// When a sink is set through
+/// [`RunOptions::with_checkpoints`](super::RunOptions::with_checkpoints), the
+/// executor calls [`save`](Self::save) after each completed node with the
+/// cursor to resume from and the context at that point, including after the
+/// last node of a loop body, decision branch or switch case. Checkpoints are
+/// not taken inside parallel branches; instead, one is taken as each branch
+/// completes.
+///
+/// An error returned by the sink fails the run with
+/// [`ExecutionError::CheckpointFailed`].
+pub trait CheckpointSink: Send + Sync {
+    /// Persists `cursor` together with any state it needs from `ctx`.
+    fn save<'a>(
+        &'a self,
+        cursor: &'a ExecutionCursor,
+        ctx: &'a SystemContext<'_>,
+    ) -> BoxFuture<'a, Result<(), ExecutionError>>;
+}
+
+/// Cursor bookkeeping for one level of nested control flow.
+///
+/// Carries the frames of the enclosing levels (used to build checkpoints)
+/// and the frames still to be resumed into at or below this level.
+#[derive(Clone)]
+pub(crate) struct Scope<'a> {
+    /// Frames of the enclosing levels, outermost first.
+    pub(crate) path: Vec<CursorFrame>,
+    /// Frames to resume into, starting with this level's frame.
+    pub(crate) resume: &'a [CursorFrame],
+    /// Whether checkpoints are suppressed (inside parallel branches).
+    pub(crate) detached: bool,
+}
+
+impl<'a> Scope<'a> {
+    /// Creates the top-level scope, optionally resuming from `cursor`.
+    pub(crate) fn root(cursor: Option<&'a ExecutionCursor>) -> Self {
+        Self {
+            path: Vec::new(),
+            resume: cursor.map(ExecutionCursor::frames).unwrap_or_default(),
+            detached: false,
+        }
+    }
+
+    /// Creates the scope for a level nested under `frame`.
+    pub(crate) fn enter(&self, frame: CursorFrame, resume: &'a [CursorFrame]) -> Self {
+        let mut path = self.path.clone();
+        path.push(frame);
+        Self {
+            path,
+            resume,
+            detached: self.detached,
+        }
+    }
+
+    /// Creates the scope for a parallel branch, where checkpoints are suppressed.
+    pub(crate) fn detached() -> Scope<'static> {
+        Scope {
+            path: Vec::new(),
+            resume: &[],
+            detached: true,
+        }
+    }
+
+    /// Builds the cursor for `frame` at this level, saving `outputs`.
+    pub(crate) fn cursor(&self, frame: CursorFrame, outputs: Vec<SavedOutput>) -> ExecutionCursor {
+        let mut frames = self.path.clone();
+        frames.push(frame);
+        ExecutionCursor { frames, outputs }
+    }
+}
+
+/// Filters the resume frames for a node down to recorded progress.
+///
+/// Returns `None` if the node starts fresh (no resume, or a [`FrameState::Next`]
+/// frame with nothing below it), otherwise the frame and the frames below it.
+pub(crate) fn resumed<'a>(
+    resuming: Option<(&'a CursorFrame, &'a [CursorFrame])>,
+) -> Result<Option<(&'a CursorFrame, &'a [CursorFrame])>, ExecutionError> {
+    match resuming {
+        Some((frame, rest)) if frame.state == FrameState::Next => {
+            if rest.is_empty() {
+                Ok(None)
+            } else {
+                Err(frame.mismatch())
+            }
+        }
+        other => Ok(other),
+    }
+}
@@ -1,10 +1,14 @@
 //! Error types for graph execution.
 
+use super::parallel::BranchFailure;
+use crate::interrupt::Interrupt;
 use crate::node::NodeId;
 use crate::predicate::PredicateError;
 use polaris_system::param::{AccessMode, ErrorContext};
+use serde::{Deserialize, Serialize};
 use std::any::TypeId;
 use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Errors that can occur during graph execution.
@@ -63,6 +67,64 @@ pub enum ExecutionError {
         /// The discriminator value that didn't match any case.
         key: &'static str,
     },
+    /// Execution was cancelled through its cancellation token.
+    Cancelled,
+    /// The run did not finish within the executor's wall-clock deadline.
+    DeadlineExceeded {
+        /// The configured deadline for the whole run.
+        deadline: Duration,
+        /// Time elapsed when the deadline was detected.
+        elapsed: Duration,
+    },
+    /// The run exceeded the executor's total node-execution budget.
+    NodeBudgetExceeded {
+        /// The maximum number of node executions allowed.
+        max: usize,
+    },
+    /// A resume cursor does not match the graph being executed.
+    InvalidCursor(String),
+    /// A checkpoint sink failed to record the execution cursor.
+    CheckpointFailed(String),
+    /// Too many branches of a parallel node failed for its policy to be met.
+    ParallelFailed {
+        /// The parallel node ID.
+        node: NodeId,
+        /// Number of successful branches the policy requires.
+        required: usize,
+        /// Number of branches that succeeded.
+        succeeded: usize,
+        /// The branches that failed, in ascending branch order.
+        failures: Vec<BranchFailure>,
+    },
+    /// A subgraph node failed, or exceeded its own node limit.
+    ///
+    /// Cancellation and run-wide deadline or budget errors raised inside a
+    /// subgraph are returned unwrapped.
+    SubgraphFailed {
+        /// The subgraph node ID.
+        node: NodeId,
+        /// The subgraph node name.
+        name: Arc<str>,
+        /// The error raised inside the subgraph.
+        error: Box<ExecutionError>,
+    },
+    /// The run stopped at an interrupt node to wait for a response.
+    ///
+    /// Resume it from the carried cursor with
+    /// [`RunOptions::with_interrupt_response`](super::RunOptions::with_interrupt_response).
+    Interrupted(Box<Interrupt>),
+    /// An interrupt node could not send its request or accept its response.
+    InterruptFailed {
+        /// The interrupt node ID.
+        node: NodeId,
+        /// The interrupt node name.
+        name: Arc<str>,
+        /// Why the request or response was rejected.
+        message: String,
+    },
+    /// A replayed run did something its trace did not record, such as
+    /// running a substituted system more often than recorded.
+    ReplayFailed(String),
 }
 
 impl fmt::Display for ExecutionError {
@@ -103,14 +165,72 @@ impl fmt::Display for ExecutionError {
             ExecutionError::NoMatchingCase { node, key } => {
                 write!(f, "no matching case for key '{key}' on switch node: {node}")
             }
+            ExecutionError::Cancelled => write!(f, "graph execution was cancelled"),
+            ExecutionError::DeadlineExceeded { deadline, elapsed } => {
+                write!(
+                    f,
+                    "graph deadline of {deadline:?} exceeded after {elapsed:?}"
+                )
+            }
+            ExecutionError::NodeBudgetExceeded { max } => {
+                write!(f, "node execution budget ({max}) exceeded")
+            }
+            ExecutionError::InvalidCursor(msg) => write!(f, "invalid resume cursor: {msg}"),
+            ExecutionError::CheckpointFailed(msg) => write!(f, "checkpoint failed: {msg}"),
+            ExecutionError::ParallelFailed {
+                node,
+                required,
+                succeeded,
+                failures,
+            } => {
+                write!(
+                    f,
+                    "parallel node {node} needed {required} successful branches but {succeeded} succeeded and {} failed",
+                    failures.len()
+                )?;
+                if let Some(first) = failures.first() {
+                    write!(f, " ({first})")?;
+                }
+                Ok(())
+            }
+            ExecutionError::SubgraphFailed { node, name, error } => {
+                write!(f, "subgraph '{name}' ({node}) failed: {error}")
+            }
+            ExecutionError::Interrupted(interrupt) => write!(
+                f,
+                "graph interrupted at '{}' awaiting {}",
+                interrupt.request.node_name, interrupt.request.response_type
+            ),
+            ExecutionError::InterruptFailed {
+                node,
+                name,
+                message,
+            } => write!(f, "interrupt '{name}' ({node}) failed: {message}"),
+            ExecutionError::ReplayFailed(message) => {
+                write!(f, "replay diverged from trace: {message}")
+            }
         }
     }
 }
 
+impl ExecutionError {
+    /// Returns `true` for errors that end the whole run rather than a single
+    /// branch: cancellation, the deadline, and the node budget.
+    pub(crate) fn is_run_abort(&self) -> bool {
+        matches!(
+            self,
+            ExecutionError::Cancelled
+                | ExecutionError::DeadlineExceeded { .. }
+                | ExecutionError::NodeBudgetExceeded { .. }
+        )
+    }
+}
+
 impl std::error::Error for ExecutionError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             ExecutionError::PredicateError(err) => Some(err),
+            ExecutionError::SubgraphFailed { error, .. } => Some(error.as_ref()),
             _ => None,
         }
     }
@@ -188,7 +308,8 @@ impl std::error::Error for ResourceValidationError {}
 ///
 /// Used in [`CaughtError`] to distinguish error sources without parsing
 /// message strings.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ErrorKind {
     /// System returned `Err(SystemError::ExecutionError(...))`.
     Execution,
@@ -268,7 +389,15 @@ pub(crate) enum SystemOutcome {
     /// System completed successfully.
     Ok(Box<dyn core::any::Any + Send + Sync>),
     /// System failed with an error after all retry attempts.
-    Err(polaris_system::system::SystemError),
+    Err {
+        /// Where the error came from.
+        kind: ErrorKind,
+        /// The error message.
+        message: String,
+    },
     /// System timed out after all retry attempts.
     Timeout,
+    /// The run was cancelled or hit its deadline while the system was running
+    /// or waiting to retry.
+    Aborted(ExecutionError),
 }
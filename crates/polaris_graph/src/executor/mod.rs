@@ -24,21 +24,42 @@
 //! # }
 //! ```
 
+mod budget;
+mod control;
+mod current;
+mod cursor;
 mod error;
+mod parallel;
 mod run;
 
+pub use budget::ExecutionBudget;
+pub use current::{RunningSystem, current_system};
+pub use cursor::{CheckpointSink, CursorFrame, ExecutionCursor, FrameState, SavedOutput};
+pub(crate) use error::SystemOutcome;
 pub use error::{CaughtError, ErrorKind, ExecutionError, ResourceValidationError};
+pub use parallel::{BranchFailure, ParallelOutcome};
 pub use run::DEFAULT_SWITCH_CASE;
+pub use tokio_util::sync::CancellationToken;
 
 use crate::graph::Graph;
 use crate::hooks::HooksAPI;
 use crate::hooks::events::GraphEvent;
-use crate::hooks::schedule::{OnGraphComplete, OnGraphFailure, OnGraphStart, OnSystemStart};
-use crate::node::Node;
+use crate::hooks::schedule::{
+    OnGraphCancelled, OnGraphComplete, OnGraphFailure, OnGraphInterrupted, OnGraphStart,
+    OnSystemStart,
+};
+use crate::node::{Node, NodeId};
+use crate::trace::{ActiveRun, OutputCodecs, Replay, ReplayState, TraceEvent, TraceRecorder};
+use control::RunControl;
+use cursor::Scope;
 use hashbrown::HashSet;
 use polaris_system::param::{AccessMode, SystemContext};
 use polaris_system::plugin::{Schedule, ScheduleId};
+use polaris_system::resource::Output;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::any::TypeId;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Result of executing a graph.
@@ -48,6 +69,142 @@ pub struct ExecutionResult {
     pub nodes_executed: usize,
     /// Total execution duration.
     pub duration: Duration,
+    /// Nodes in the order they were entered.
+    ///
+    /// Nodes inside parallel branches are interleaved in the order they
+    /// started. Pass this to [`GraphDescription::with_path`] to overlay the
+    /// run on an exported diagram.
+    ///
+    /// [`GraphDescription::with_path`]: crate::graph::GraphDescription::with_path
+    pub path: Vec<NodeId>,
+    /// Edges followed, as `(from, to)` pairs in the order they were taken.
+    ///
+    /// Includes sequential edges, the branch, case or loop body entered by a
+    /// control-flow node, and error or timeout routes. Pass this to
+    /// [`GraphDescription::with_edges`] to overlay the run on an exported
+    /// diagram.
+    ///
+    /// [`GraphDescription::with_edges`]: crate::graph::GraphDescription::with_edges
+    pub edges: Vec<(NodeId, NodeId)>,
+}
+
+/// Per-run options for [`GraphExecutor::execute_with`].
+///
+/// # Example
+///
+/// ```
+/// use polaris_graph::{CancellationToken, RunOptions};
+///
+/// let token = CancellationToken::new();
+/// let options = RunOptions::new().with_cancellation(token.clone());
+/// ```
+#[derive(Clone, Default)]
+pub struct RunOptions {
+    /// Token that stops the run when cancelled.
+    cancellation: Option<CancellationToken>,
+    /// Cursor to resume the run from.
+    resume_from: Option<ExecutionCursor>,
+    /// Destination for cursors recorded after each completed node.
+    checkpoints: Option<Arc<dyn CheckpointSink>>,
+    /// Output types saved in cursors and restored on resume.
+    checkpoint_outputs: OutputCodecs,
+    /// Response for the interrupt node the run resumes at.
+    interrupt_response: Option<serde_json::Value>,
+    /// Recorder capturing the run's trace.
+    recorder: Option<TraceRecorder>,
+    /// Recorded trace the run replays.
+    replay: Option<Replay>,
+}
+
+impl RunOptions {
+    /// Creates options for a plain run from the graph entry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops the run when `token` is cancelled.
+    ///
+    /// See [`GraphExecutor::execute_with_cancellation`].
+    #[must_use]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Resumes the run from `cursor` instead of the graph entry.
+    ///
+    /// Nodes before the cursor are not re-executed. Decisions and switches
+    /// on the cursor path reuse their recorded selection instead of
+    /// re-evaluating predicates. Outputs saved in the cursor are restored
+    /// into the context before the run continues; their types must be
+    /// registered with [`with_checkpoint_output`](Self::with_checkpoint_output).
+    #[must_use]
+    pub fn resume_from(mut self, cursor: ExecutionCursor) -> Self {
+        self.resume_from = Some(cursor);
+        self
+    }
+
+    /// Records an [`ExecutionCursor`] through `sink` after each completed node.
+    #[must_use]
+    pub fn with_checkpoints(mut self, sink: Arc<dyn CheckpointSink>) -> Self {
+        self.checkpoints = Some(sink);
+        self
+    }
+
+    /// Saves outputs of type `T` in recorded cursors and restores them when
+    /// the run resumes.
+    ///
+    /// Outputs of unregistered types are not saved, so nodes after a resumed
+    /// cursor cannot read them.
+    #[must_use]
+    pub fn with_checkpoint_output<T: Output + Serialize + DeserializeOwned>(mut self) -> Self {
+        self.checkpoint_outputs.register::<T>();
+        self
+    }
+
+    /// Provides the response for the interrupt node the run resumes at.
+    ///
+    /// Use together with [`resume_from`](Self::resume_from) and the cursor
+    /// of an [`Interrupt`](crate::interrupt::Interrupt). The response is
+    /// deserialized into the node's response type and stored as an output.
+    #[must_use]
+    pub fn with_interrupt_response(mut self, response: serde_json::Value) -> Self {
+        self.interrupt_response = Some(response);
+        self
+    }
+
+    /// Records an [`ExecutionTrace`](crate::trace::ExecutionTrace) of the
+    /// run through `recorder`.
+    #[must_use]
+    pub fn with_recorder(mut self, recorder: TraceRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Replays a recorded trace: recorded calls are served back instead of
+    /// being made, and substituted systems return their recorded outcome.
+    ///
+    /// See [`Replay`].
+    #[must_use]
+    pub fn with_replay(mut self, replay: Replay) -> Self {
+        self.replay = Some(replay);
+        self
+    }
+}
+
+impl std::fmt::Debug for RunOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RunOptions")
+            .field("cancellation", &self.cancellation)
+            .field("resume_from", &self.resume_from)
+            .field("checkpoints", &self.checkpoints.is_some())
+            .field("checkpoint_outputs", &self.checkpoint_outputs.type_names())
+            .field("interrupt_response", &self.interrupt_response)
+            .field("recorder", &self.recorder)
+            .field("replay", &self.replay)
+            .finish()
+    }
 }
 
 /// Graph execution engine.
@@ -60,6 +217,10 @@ pub struct GraphExecutor {
     pub(crate) default_max_iterations: Option<usize>,
     /// Maximum recursion depth for nested control flow (safety default).
     pub(crate) max_recursion_depth: usize,
+    /// Wall-clock limit for a whole run.
+    pub(crate) deadline: Option<Duration>,
+    /// Maximum number of node executions for a whole run.
+    pub(crate) max_nodes: Option<usize>,
 }
 
 impl Default for GraphExecutor {
@@ -78,6 +239,8 @@ impl GraphExecutor {
         Self {
             default_max_iterations: Some(1000),
             max_recursion_depth: Self::DEFAULT_MAX_RECURSION_DEPTH,
+            deadline: None,
+            max_nodes: None,
         }
     }
 
@@ -92,6 +255,8 @@ impl GraphExecutor {
         Self {
             default_max_iterations: None,
             max_recursion_depth: Self::DEFAULT_MAX_RECURSION_DEPTH,
+            deadline: None,
+            max_nodes: None,
         }
     }
 
@@ -109,6 +274,29 @@ impl GraphExecutor {
         self
     }
 
+    /// Sets a wall-clock deadline for each run.
+    ///
+    /// The deadline covers the whole graph, including nested loops and
+    /// parallel branches. It is checked between nodes and raced against
+    /// running systems; when it expires the run fails with
+    /// [`ExecutionError::DeadlineExceeded`].
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets the maximum number of node executions for each run.
+    ///
+    /// Every node visit counts, including control-flow nodes and each loop
+    /// iteration's body, across all parallel branches. Exceeding the budget
+    /// fails the run with [`ExecutionError::NodeBudgetExceeded`].
+    #[must_use]
+    pub fn with_node_budget(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
     /// Validates that all resources required by systems in the graph
     /// are available in the context.
     ///
@@ -121,8 +309,15 @@ impl GraphExecutor {
     ///   resources (local scope, parent chain, and globals).
     /// - **Hook-provided resources**: Resources provided by hooks on `OnGraphStart`
     ///   and `OnSystemStart` are considered available.
-    /// - **Outputs** (`Out<T>`): Currently not validated, as outputs are
-    ///   produced dynamically during execution.
+    /// - **Optional resources**: `Option<Res<T>>`, `Option<ResMut<T>>` and
+    ///   `ResOrDefault<T>` tolerate the resource being absent and are never
+    ///   reported.
+    /// - **Executor-provided resources**: [`ExecutionBudget`] is inserted by the
+    ///   executor itself and is always considered available.
+    /// - **Outputs** (`Out<T>`): Not checked here, as they do not depend on
+    ///   the context. [`Graph::validate`] warns about outputs read without
+    ///   being produced upstream; [`Graph::validate_outputs`] reports them as
+    ///   errors given the outputs the context holds before the run.
     ///
     /// # Returns
     ///
@@ -136,7 +331,7 @@ impl GraphExecutor {
     ) -> Result<(), Vec<ResourceValidationError>> {
         let mut errors = Vec::new();
 
-        let hook_provided: HashSet<TypeId> = hooks
+        let mut provided: HashSet<TypeId> = hooks
             .map(|h| {
                 let mut resources = HashSet::new();
                 resources.extend(h.provided_resources_for(OnGraphStart::schedule_id()));
@@ -144,6 +339,7 @@ impl GraphExecutor {
                 resources
             })
             .unwrap_or_default();
+        provided.insert(TypeId::of::<ExecutionBudget>());
 
         for node in graph.nodes() {
             if let Node::System(sys) = node {
@@ -153,7 +349,7 @@ impl GraphExecutor {
                     sys.system.name(),
                     &access,
                     ctx,
-                    &hook_provided,
+                    &provided,
                     &mut errors,
                 );
             }
@@ -169,15 +365,15 @@ impl GraphExecutor {
     /// Validates a single system's access requirements against the context.
     fn validate_system_access(
         &self,
-        node_id: &crate::node::NodeId,
+        node_id: &NodeId,
         system_name: &'static str,
         access: &polaris_system::param::SystemAccess,
         ctx: &SystemContext<'_>,
-        hook_provided: &HashSet<TypeId>,
+        provided: &HashSet<TypeId>,
         errors: &mut Vec<ResourceValidationError>,
     ) {
         for res_access in &access.resources {
-            if hook_provided.contains(&res_access.type_id) {
+            if res_access.optional || provided.contains(&res_access.type_id) {
                 continue;
             }
 
@@ -202,17 +398,21 @@ impl GraphExecutor {
     ///
     /// System outputs are stored in the context after each system executes,
     /// making them available to subsequent systems via `Out<T>` parameters
-    /// and predicates.
+    /// and predicates. An [`ExecutionBudget`] describing the run's remaining
+    /// time and node budget is inserted into the context before the first node
+    /// and removed when the run ends.
     ///
     /// # Hooks
     ///
     /// If `hooks` is provided, lifecycle hooks are invoked at key execution points:
-    /// - `OnGraphStart` / `OnGraphComplete` / `OnGraphFailure` - Graph-level events
+    /// - `OnGraphStart` / `OnGraphComplete` / `OnGraphFailure` / `OnGraphCancelled` /
+    ///   `OnGraphInterrupted` - Graph-level events
     /// - `OnSystemStart` / `OnSystemComplete` / `OnSystemError` - System events
     /// - `OnDecisionStart` / `OnDecisionComplete` - Decision node events
     /// - `OnSwitchStart` / `OnSwitchComplete` - Switch node events
     /// - `OnLoopStart` / `OnLoopEnd` - Loop iteration events
     /// - `OnParallelStart` / `OnParallelComplete` - Parallel execution events
+    /// - `OnSubgraphStart` / `OnSubgraphComplete` / `OnSubgraphFailure` - Subgraph events
     ///
     /// For more, see the [`hooks` module](crate::hooks).
     ///
@@ -224,15 +424,110 @@ impl GraphExecutor {
     /// - A system execution fails
     /// - A predicate evaluation fails
     /// - A loop exceeds its maximum iterations
+    /// - The run exceeds its [deadline](Self::with_deadline) or
+    ///   [node budget](Self::with_node_budget)
+    ///
+    /// A run that reaches an interrupt node stops with
+    /// [`ExecutionError::Interrupted`] and fires `OnGraphInterrupted` hooks in
+    /// place of `OnGraphFailure`.
     pub async fn execute(
         &self,
         graph: &Graph,
         ctx: &mut SystemContext<'_>,
         hooks: Option<&HooksAPI>,
+    ) -> Result<ExecutionResult, ExecutionError> {
+        self.execute_with(graph, ctx, hooks, RunOptions::new())
+            .await
+    }
+
+    /// Executes a graph that can be stopped through a [`CancellationToken`].
+    ///
+    /// Behaves like [`execute`](Self::execute), but checks `cancel` before each
+    /// node and loop iteration, and races it against running systems and retry
+    /// delays. When cancellation is observed, the in-flight system future is
+    /// dropped and `OnGraphCancelled` hooks fire in place of `OnGraphFailure`,
+    /// so cleanup and persistence hooks still run.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecutionError::Cancelled`] if `cancel` is triggered before the
+    /// graph completes, or any error documented on [`execute`](Self::execute).
+    pub async fn execute_with_cancellation(
+        &self,
+        graph: &Graph,
+        ctx: &mut SystemContext<'_>,
+        hooks: Option<&HooksAPI>,
+        cancel: &CancellationToken,
+    ) -> Result<ExecutionResult, ExecutionError> {
+        self.execute_with(
+            graph,
+            ctx,
+            hooks,
+            RunOptions::new().with_cancellation(cancel.clone()),
+        )
+        .await
+    }
+
+    /// Executes a graph with per-run [`RunOptions`].
+    ///
+    /// Options control cancellation, resuming from a previously recorded
+    /// [`ExecutionCursor`], and recording cursors through a
+    /// [`CheckpointSink`] after each completed node.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecutionError::InvalidCursor`] if the resume cursor does
+    /// not match `graph`, [`ExecutionError::CheckpointFailed`] if the sink
+    /// fails, or any error documented on [`execute`](Self::execute) and
+    /// [`execute_with_cancellation`](Self::execute_with_cancellation).
+    pub async fn execute_with(
+        &self,
+        graph: &Graph,
+        ctx: &mut SystemContext<'_>,
+        hooks: Option<&HooksAPI>,
+        options: RunOptions,
+    ) -> Result<ExecutionResult, ExecutionError> {
+        // The budget belongs to this run only: it must not outlive the run in
+        // a context that is reused, such as a session's context across turns.
+        let previous = ctx.remove_resource::<ExecutionBudget>();
+        let result = self.run_graph(graph, ctx, hooks, options).await;
+        ctx.remove_resource::<ExecutionBudget>();
+        if let Some(previous) = previous {
+            ctx.insert(previous);
+        }
+        result
+    }
+
+    /// Runs `graph` with a fresh [`ExecutionBudget`] in `ctx`.
+    async fn run_graph(
+        &self,
+        graph: &Graph,
+        ctx: &mut SystemContext<'_>,
+        hooks: Option<&HooksAPI>,
+        options: RunOptions,
     ) -> Result<ExecutionResult, ExecutionError> {
         let start = std::time::Instant::now();
         let entry = graph.entry().ok_or(ExecutionError::EmptyGraph)?;
 
+        let budget = ExecutionBudget::new(self.deadline, self.max_nodes);
+        let replay = options
+            .replay
+            .map(|replay| Arc::new(ReplayState::new(replay)));
+        let run = RunControl::new(
+            options.cancellation.unwrap_or_default(),
+            budget.clone(),
+            options.checkpoints,
+            options.checkpoint_outputs,
+            options.interrupt_response,
+            options.recorder.clone(),
+            replay.clone(),
+        );
+        if let Some(cursor) = &options.resume_from {
+            let restored = run.restore_outputs(cursor.outputs())?;
+            ctx.outputs_mut().merge_from(restored);
+        }
+        ctx.insert(budget);
+
         // Invoke OnGraphStart hook
         Self::invoke_hook::<OnGraphStart>(
             hooks,
@@ -242,13 +537,31 @@ impl GraphExecutor {
                 node_map: graph
                     .nodes()
                     .iter()
-                    .map(|node| (node.id(), node.name()))
+                    .map(|node| (node.id(), node.name().into()))
                     .collect(),
             },
         );
 
         // Execute the graph
-        let result = self.execute_from(graph, ctx, entry, 0, hooks).await;
+        let scope = Scope::root(options.resume_from.as_ref());
+        let execution = self.execute_from(graph, ctx, entry, 0, hooks, &run, scope);
+        let result = if options.recorder.is_some() || replay.is_some() {
+            let active = ActiveRun {
+                recorder: options.recorder,
+                replay,
+            };
+            active.scope(execution).await
+        } else {
+            execution.await
+        };
+        run.record(|_| match &result {
+            Ok(nodes_executed) => Some(TraceEvent::RunCompleted {
+                nodes_executed: *nodes_executed,
+            }),
+            Err(err) => Some(TraceEvent::RunFailed {
+                error: err.to_string(),
+            }),
+        });
 
         // Invoke OnGraphComplete hook
         let duration = start.elapsed();
@@ -265,8 +578,33 @@ impl GraphExecutor {
                 Ok(ExecutionResult {
                     nodes_executed,
                     duration,
+                    path: run.take_path(),
+                    edges: run.take_edges(),
                 })
             }
+            Err(ExecutionError::Cancelled) => {
+                Self::invoke_hook::<OnGraphCancelled>(
+                    hooks,
+                    ctx,
+                    &GraphEvent::GraphCancelled { duration },
+                );
+                Err(ExecutionError::Cancelled)
+            }
+            Err(ExecutionError::Interrupted(interrupt)) => {
+                Self::invoke_hook::<OnGraphInterrupted>(
+                    hooks,
+                    ctx,
+                    &GraphEvent::GraphInterrupted {
+                        node_id: interrupt.node.clone(),
+                        node_name: graph
+                            .get_node(interrupt.node.clone())
+                            .map_or("", Node::name)
+                            .into(),
+                        duration,
+                    },
+                );
+                Err(ExecutionError::Interrupted(interrupt))
+            }
             Err(err) => {
                 Self::invoke_hook::<OnGraphFailure>(
                     hooks,
@@ -318,6 +656,8 @@ mod tests {
         let executor = GraphExecutor::new();
         assert_eq!(executor.default_max_iterations, Some(1000));
         assert_eq!(executor.max_recursion_depth, 64);
+        assert_eq!(executor.deadline, None);
+        assert_eq!(executor.max_nodes, None);
     }
 
     #[test]
@@ -337,4 +677,13 @@ mod tests {
         let executor = GraphExecutor::new().with_max_recursion_depth(128);
         assert_eq!(executor.max_recursion_depth, 128);
     }
+
+    #[test]
+    fn executor_with_deadline_and_node_budget() {
+        let executor = GraphExecutor::new()
+            .with_deadline(Duration::from_secs(60))
+            .with_node_budget(500);
+        assert_eq!(executor.deadline, Some(Duration::from_secs(60)));
+        assert_eq!(executor.max_nodes, Some(500));
+    }
 }
@@ -0,0 +1,68 @@
+//! Outcome reporting for parallel nodes.
+
+use super::error::ExecutionError;
+use crate::node::NodeId;
+use std::fmt;
+use std::sync::Arc;
+
+/// A parallel branch that failed.
+#[derive(Debug, Clone)]
+pub struct BranchFailure {
+    /// Index of the branch, in declaration order.
+    pub branch: usize,
+    /// The error the branch failed with.
+    pub error: ExecutionError,
+}
+
+impl fmt::Display for BranchFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "branch {} failed: {}", self.branch, self.error)
+    }
+}
+
+/// Summary of a completed parallel node, stored as an output.
+///
+/// The executor inserts a `ParallelOutcome` after every parallel node that
+/// completes, so downstream systems can read it via `Out<ParallelOutcome>`
+/// and react to branches that failed under a tolerant
+/// [`ParallelPolicy`](crate::node::ParallelPolicy). Outputs of failed and
+/// abandoned branches are discarded; only successful branches contribute
+/// outputs.
+///
+/// # Example
+///
+/// ```
+/// use polaris_graph::ParallelOutcome;
+/// use polaris_system::param::Out;
+/// use polaris_system::system;
+///
+/// #[system]
+/// async fn summarize(outcome: Out<ParallelOutcome>) -> usize {
+///     for failure in &outcome.failed {
+///         tracing::warn!("{}: {failure}", outcome.node_name);
+///     }
+///     outcome.succeeded.len()
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParallelOutcome {
+    /// The parallel node's ID.
+    pub node_id: NodeId,
+    /// The parallel node's name.
+    pub node_name: Arc<str>,
+    /// Indices of branches that succeeded, in ascending order.
+    pub succeeded: Vec<usize>,
+    /// Branches that failed, in ascending branch order.
+    pub failed: Vec<BranchFailure>,
+    /// Indices of branches dropped or never started because the policy was
+    /// already satisfied, in ascending order.
+    pub abandoned: Vec<usize>,
+}
+
+impl ParallelOutcome {
+    /// Returns `true` if every branch ran and succeeded.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty() && self.abandoned.is_empty()
+    }
+}
@@ -1,18 +1,31 @@
 //! Core graph execution engine — node dispatch and control flow.
 
 use super::GraphExecutor;
+use super::control::RunControl;
+use super::current::RunningSystem;
+use super::cursor::{self, CursorFrame, FrameState, SavedOutput, Scope};
 use super::error::{CaughtError, ErrorKind, ExecutionError, SystemOutcome};
+use super::parallel::{BranchFailure, ParallelOutcome};
 use crate::edge::Edge;
 use crate::graph::Graph;
 use crate::hooks::HooksAPI;
 use crate::hooks::events::GraphEvent;
 use crate::hooks::schedule::{
     OnDecisionComplete, OnDecisionStart, OnLoopEnd, OnLoopIteration, OnLoopStart,
-    OnParallelComplete, OnParallelStart, OnSwitchComplete, OnSwitchStart, OnSystemComplete,
-    OnSystemError, OnSystemStart,
+    OnParallelComplete, OnParallelStart, OnSubgraphComplete, OnSubgraphFailure, OnSubgraphStart,
+    OnSwitchComplete, OnSwitchStart, OnSystemComplete, OnSystemError, OnSystemStart,
 };
-use crate::node::{LoopNode, Node, NodeId, ParallelNode, SwitchNode, SystemNode};
+use crate::interrupt::Interrupt;
+use crate::node::{
+    InterruptNode, LoopNode, Node, NodeId, ParallelNode, ParallelPolicy, SubgraphNode, SwitchNode,
+    SystemNode,
+};
+use crate::subgraph::BindingKind;
+use crate::trace::{TraceEvent, TraceNode};
 use polaris_system::param::SystemContext;
+use polaris_system::system::SystemError;
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
 /// Default case name for switch nodes when no match is found.
 pub const DEFAULT_SWITCH_CASE: &str = "default";
@@ -34,6 +47,36 @@ impl GraphExecutor {
         Err(ExecutionError::NoNextNode(from.clone()))
     }
 
+    /// Moves on from the completed node `current`, returning the next
+    /// sequential node, or `None` if `current` ends its level.
+    ///
+    /// Records a checkpoint at the next node, or a [`FrameState::Done`]
+    /// checkpoint at `current` when it ends its level, so that the last node
+    /// of a nested body is not re-run on resume.
+    pub(crate) async fn advance(
+        &self,
+        graph: &Graph,
+        run: &RunControl,
+        scope: &Scope<'_>,
+        current: &NodeId,
+        ctx: &SystemContext<'_>,
+    ) -> Result<Option<NodeId>, ExecutionError> {
+        match self.find_next_sequential(graph, current) {
+            Ok(next) => {
+                run.follow(current, &next);
+                run.checkpoint(graph, scope, &next, FrameState::Next, ctx)
+                    .await?;
+                Ok(Some(next))
+            }
+            Err(ExecutionError::NoNextNode(_)) => {
+                run.checkpoint(graph, scope, current, FrameState::Done, ctx)
+                    .await?;
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     /// Finds an error handler edge from the given node.
     ///
     /// Returns the target node ID if an error edge exists from `from`.
@@ -66,9 +109,13 @@ impl GraphExecutor {
     ///
     /// Each retry attempt gets a fresh timeout window. After all retries
     /// are exhausted, returns the final outcome (error or timeout).
+    /// Cancellation and the run deadline are raced against each attempt and
+    /// each retry delay.
     pub(crate) async fn run_with_retry(
+        graph: &Graph,
         sys: &SystemNode,
         ctx: &mut SystemContext<'_>,
+        run: &RunControl,
     ) -> SystemOutcome {
         let total_attempts = sys
             .retry_policy
@@ -76,27 +123,54 @@ impl GraphExecutor {
             .map(|p| p.max_retries() + 1)
             .unwrap_or(1);
 
+        let running = RunningSystem {
+            node_id: sys.id.clone(),
+            name: sys.name(),
+        };
         let mut last_was_timeout = false;
-        let mut last_err = None;
+        let mut last_err: Option<SystemError> = None;
 
         for attempt in 0..total_attempts {
             if attempt > 0
                 && let Some(policy) = &sys.retry_policy
             {
+                run.record(|_| {
+                    Some(TraceEvent::SystemRetried {
+                        node: TraceNode::of(graph, &sys.id)?,
+                        attempt,
+                        error: match &last_err {
+                            Some(err) if !last_was_timeout => err.to_string(),
+                            _ => "timed out".to_owned(),
+                        },
+                    })
+                });
                 let delay = policy.delay_for_attempt(attempt - 1);
-                tokio::time::sleep(delay).await;
+                if let Err(err) = run.race(tokio::time::sleep(delay)).await {
+                    return SystemOutcome::Aborted(err);
+                }
             }
 
             let result = if let Some(timeout_duration) = sys.timeout {
-                match tokio::time::timeout(timeout_duration, sys.system.run_erased(ctx)).await {
-                    Ok(inner) => inner,
-                    Err(_elapsed) => {
+                let attempt = run.race(tokio::time::timeout(
+                    timeout_duration,
+                    running.clone().scope(sys.system.run_erased(ctx)),
+                ));
+                match attempt.await {
+                    Err(err) => return SystemOutcome::Aborted(err),
+                    Ok(Ok(inner)) => inner,
+                    Ok(Err(_elapsed)) => {
                         last_was_timeout = true;
                         continue;
                     }
                 }
             } else {
-                sys.system.run_erased(ctx).await
+                match run
+                    .race(running.clone().scope(sys.system.run_erased(ctx)))
+                    .await
+                {
+                    Ok(inner) => inner,
+                    Err(err) => return SystemOutcome::Aborted(err),
+                }
             };
 
             match result {
@@ -111,13 +185,28 @@ impl GraphExecutor {
         if last_was_timeout {
             SystemOutcome::Timeout
         } else {
-            SystemOutcome::Err(last_err.expect("at least one attempt was made"))
+            let err = last_err.expect("at least one attempt was made");
+            let kind = match &err {
+                SystemError::ParamError(_) => ErrorKind::ParamResolution,
+                SystemError::ExecutionError(_) => ErrorKind::Execution,
+            };
+            SystemOutcome::Err {
+                kind,
+                message: err.to_string(),
+            }
         }
     }
 
     /// Executes a loop node, returning the number of nodes executed in the loop body.
     ///
+    /// `resume` carries the recorded iteration and the body frames to resume
+    /// into, if the run is resuming inside this loop.
+    ///
     /// Returns a boxed future to support recursion with nested control flow.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "internal recursion threads per-run state explicitly"
+    )]
     pub(crate) fn execute_loop<'a>(
         &'a self,
         graph: &'a Graph,
@@ -125,6 +214,9 @@ impl GraphExecutor {
         loop_node: &'a LoopNode,
         depth: usize,
         hooks: Option<&'a HooksAPI>,
+        run: &'a RunControl,
+        scope: &'a Scope<'a>,
+        resume: Option<(usize, &'a [CursorFrame])>,
     ) -> futures::future::BoxFuture<'a, Result<usize, ExecutionError>> {
         Box::pin(async move {
             let max_iterations = loop_node
@@ -132,7 +224,7 @@ impl GraphExecutor {
                 .or(self.default_max_iterations)
                 .ok_or_else(|| ExecutionError::NoTerminationCondition(loop_node.id.clone()))?;
 
-            let mut iterations = 0;
+            let (mut iterations, mut body_resume) = resume.unwrap_or((0, &[]));
             let mut nodes_executed = 0;
 
             // Invoke OnLoopStart hook
@@ -141,7 +233,7 @@ impl GraphExecutor {
                 ctx,
                 &GraphEvent::LoopStart {
                     node_id: loop_node.id.clone(),
-                    loop_name: loop_node.name,
+                    loop_name: loop_node.name.clone(),
                     max_iterations: Some(max_iterations),
                 },
             );
@@ -149,42 +241,82 @@ impl GraphExecutor {
             let loop_start = std::time::Instant::now();
 
             loop {
-                // Check termination predicate first
-                if let Some(term) = &loop_node.termination
-                    && term.evaluate(ctx).map_err(ExecutionError::PredicateError)?
-                {
-                    break;
-                }
+                run.check()?;
 
-                if iterations >= max_iterations {
-                    if loop_node.termination.is_some() {
-                        return Err(ExecutionError::MaxIterationsExceeded {
-                            node: loop_node.id.clone(),
-                            max: max_iterations,
-                        });
+                // A run resumed mid-iteration continues the body without
+                // re-checking termination.
+                if body_resume.is_empty() {
+                    // Check termination predicate first
+                    if let Some(term) = &loop_node.termination
+                        && term.evaluate(ctx).map_err(ExecutionError::PredicateError)?
+                    {
+                        break;
+                    }
+
+                    if iterations >= max_iterations {
+                        if loop_node.termination.is_some() {
+                            return Err(ExecutionError::MaxIterationsExceeded {
+                                node: loop_node.id.clone(),
+                                max: max_iterations,
+                            });
+                        }
+                        break;
                     }
-                    break;
                 }
 
+                run.record(|_| {
+                    Some(TraceEvent::LoopIteration {
+                        node: TraceNode::of(graph, &loop_node.id)?,
+                        iteration: iterations,
+                    })
+                });
+
                 // Invoke OnLoopIteration hook
                 Self::invoke_hook::<OnLoopIteration>(
                     hooks,
                     ctx,
                     &GraphEvent::LoopIteration {
                         node_id: loop_node.id.clone(),
-                        loop_name: loop_node.name,
+                        loop_name: loop_node.name.clone(),
                         iteration: iterations,
                     },
                 );
 
+                let body_resume = std::mem::take(&mut body_resume);
                 if let Some(body) = &loop_node.body_entry {
+                    run.follow(&loop_node.id, body);
+                    let frame = CursorFrame::at(
+                        graph,
+                        &loop_node.id,
+                        FrameState::Loop {
+                            iteration: iterations,
+                        },
+                    )?;
                     let count = self
-                        .execute_from(graph, ctx, body.clone(), depth, hooks)
+                        .execute_from(
+                            graph,
+                            ctx,
+                            body.clone(),
+                            depth,
+                            hooks,
+                            run,
+                            scope.enter(frame, body_resume),
+                        )
                         .await?;
                     nodes_executed += count;
                 }
 
                 iterations += 1;
+                run.checkpoint(
+                    graph,
+                    scope,
+                    &loop_node.id,
+                    FrameState::Loop {
+                        iteration: iterations,
+                    },
+                    ctx,
+                )
+                .await?;
             }
 
             // Invoke OnLoopEnd hook
@@ -193,7 +325,7 @@ impl GraphExecutor {
                 ctx,
                 &GraphEvent::LoopEnd {
                     node_id: loop_node.id.clone(),
-                    loop_name: loop_node.name,
+                    loop_name: loop_node.name.clone(),
                     iterations,
                     nodes_executed,
                     duration: loop_start.elapsed(),
@@ -207,7 +339,20 @@ impl GraphExecutor {
     /// Executes parallel branches concurrently, returning the total nodes executed.
     ///
     /// Each branch runs in its own child context, providing isolation between
-    /// parallel execution paths.
+    /// parallel execution paths. At most `max_concurrency` branches run at
+    /// once, and the node's [`ParallelPolicy`] decides when enough branches
+    /// have succeeded or too many have failed. Branches listed in `completed`
+    /// (from a resume cursor) count as succeeded and are skipped, their saved
+    /// outputs taking the place of a run, and a checkpoint is recorded as
+    /// each branch succeeds.
+    ///
+    /// On success, outputs of the succeeded branches are merged in branch
+    /// order, except for types with a registered gather, which are reduced
+    /// across branches. A [`ParallelOutcome`] is then stored as an output.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "internal recursion threads per-run state explicitly"
+    )]
     pub(crate) fn execute_parallel<'a>(
         &'a self,
         graph: &'a Graph,
@@ -215,9 +360,13 @@ impl GraphExecutor {
         par: &'a ParallelNode,
         depth: usize,
         hooks: Option<&'a HooksAPI>,
+        run: &'a RunControl,
+        scope: &'a Scope<'a>,
+        completed: Vec<(usize, Vec<SavedOutput>)>,
     ) -> futures::future::BoxFuture<'a, Result<usize, ExecutionError>> {
         Box::pin(async move {
-            use futures::future::try_join_all;
+            use futures::StreamExt;
+            use futures::stream::FuturesUnordered;
 
             let branch_count = par.branches.len();
 
@@ -227,45 +376,157 @@ impl GraphExecutor {
                 ctx,
                 &GraphEvent::ParallelStart {
                     node_id: par.id.clone(),
-                    node_name: par.name,
+                    node_name: par.name.clone(),
                     branch_count,
                 },
             );
 
             let start = std::time::Instant::now();
+            let policy = par.options.policy;
+            let required = policy.required_successes(branch_count);
+
+            let mut restored = Vec::with_capacity(completed.len());
+            for (index, saved) in &completed {
+                restored.push((*index, run.restore_outputs(saved)?));
+            }
+            // Saved outputs of the succeeded branches, recorded in checkpoints.
+            let mut saved: BTreeMap<usize, Vec<SavedOutput>> = completed.into_iter().collect();
+            let pending: Vec<usize> = (0..branch_count)
+                .filter(|index| !saved.contains_key(index))
+                .collect();
+            let limit = par.options.max_concurrency.unwrap_or(pending.len()).max(1);
 
+            let parent: &SystemContext<'_> = ctx;
             let mut child_contexts: Vec<SystemContext<'_>> =
-                par.branches.iter().map(|_| ctx.child()).collect();
+                pending.iter().map(|_| parent.child()).collect();
 
-            let futures =
-                par.branches
+            let mut succeeded: Vec<usize> = saved.keys().copied().collect();
+            let mut failed: Vec<BranchFailure> = Vec::new();
+            // Positions in `pending` whose outputs are merged back.
+            let mut merge_slots: Vec<usize> = Vec::new();
+            let mut total_nodes = 0;
+            {
+                // Branch futures are created lazily so that at most `limit`
+                // of them exist at once.
+                let mut queue = pending
                     .iter()
                     .zip(child_contexts.iter_mut())
-                    .map(|(branch, child_ctx)| {
-                        self.execute_from(graph, child_ctx, branch.clone(), depth, hooks)
+                    .enumerate()
+                    .map(|(slot, (&index, child_ctx))| async move {
+                        run.follow(&par.id, &par.branches[index]);
+                        let result = self
+                            .execute_from(
+                                graph,
+                                child_ctx,
+                                par.branches[index].clone(),
+                                depth,
+                                hooks,
+                                run,
+                                Scope::detached(),
+                            )
+                            .await
+                            .map(|nodes| (nodes, run.save_branch_outputs(child_ctx.outputs())));
+                        (slot, index, result)
                     });
+                let mut running: FuturesUnordered<_> = queue.by_ref().take(limit).collect();
 
-            let results = try_join_all(futures).await?;
-            let total_nodes = results.iter().sum();
+                loop {
+                    if let Some(required) = required {
+                        if succeeded.len() >= required {
+                            break;
+                        }
+                        let reachable = succeeded.len() + running.len() + queue.len();
+                        if reachable < required {
+                            failed.sort_by_key(|failure| failure.branch);
+                            return Err(ExecutionError::ParallelFailed {
+                                node: par.id.clone(),
+                                required,
+                                succeeded: succeeded.len(),
+                                failures: failed,
+                            });
+                        }
+                    }
+
+                    let Some((slot, index, result)) = running.next().await else {
+                        break;
+                    };
+
+                    match result {
+                        Ok((nodes, outputs)) => {
+                            total_nodes += nodes;
+                            merge_slots.push(slot);
+                            succeeded.push(index);
+                            succeeded.sort_unstable();
+                            saved.insert(index, outputs);
+                            run.checkpoint(
+                                graph,
+                                scope,
+                                &par.id,
+                                FrameState::Parallel {
+                                    completed: succeeded.clone(),
+                                    outputs: saved.values().cloned().collect(),
+                                },
+                                parent,
+                            )
+                            .await?;
+                        }
+                        // Cancellation and run-wide limits end the run
+                        // whatever the policy.
+                        Err(err) if policy == ParallelPolicy::FailFast || err.is_run_abort() => {
+                            return Err(err);
+                        }
+                        Err(err) => failed.push(BranchFailure {
+                            branch: index,
+                            error: err,
+                        }),
+                    }
+
+                    running.extend(queue.next());
+                }
+            }
 
-            // Merge outputs from child contexts back to parent (branch-order deterministic).
-            // Extract outputs first, then drop children to release borrow on ctx.
-            let child_outputs: Vec<_> = child_contexts
-                .iter_mut()
-                .map(SystemContext::take_outputs)
+            // Merge outputs from succeeded branches back to parent (branch-order
+            // deterministic), including those restored from the cursor.
+            // Extract outputs first, then drop children to release the borrow
+            // on ctx.
+            let mut child_outputs: Vec<_> = merge_slots
+                .iter()
+                .map(|&slot| (pending[slot], child_contexts[slot].take_outputs()))
+                .chain(restored)
                 .collect();
+            child_outputs.sort_unstable_by_key(|(index, _)| *index);
             drop(child_contexts);
-            for outputs in child_outputs {
+            // Gathered types are removed from the branch outputs so that the
+            // plain merge below cannot overwrite the reduced value.
+            for gather in &par.options.gathers {
+                gather.gather(&mut child_outputs, ctx.outputs_mut());
+            }
+            for (_, outputs) in child_outputs {
                 ctx.outputs_mut().merge_from(outputs);
             }
 
+            failed.sort_by_key(|failure| failure.branch);
+            let abandoned = (0..branch_count)
+                .filter(|index| {
+                    !succeeded.contains(index)
+                        && !failed.iter().any(|failure| failure.branch == *index)
+                })
+                .collect();
+            ctx.outputs_mut().insert(ParallelOutcome {
+                node_id: par.id.clone(),
+                node_name: par.name.clone(),
+                succeeded,
+                failed,
+                abandoned,
+            });
+
             // Invoke OnParallelComplete hook
             Self::invoke_hook::<OnParallelComplete>(
                 hooks,
                 ctx,
                 &GraphEvent::ParallelComplete {
                     node_id: par.id.clone(),
-                    node_name: par.name,
+                    node_name: par.name.clone(),
                     branch_count,
                     total_nodes_executed: total_nodes,
                     duration: start.elapsed(),
@@ -276,10 +537,14 @@ impl GraphExecutor {
         })
     }
 
-    /// Executes a switch node, returning the nodes executed and optional next node.
+    /// Executes a switch node, returning the nodes executed in the selected case.
     ///
-    /// Returns a tuple of `(nodes_executed, next_node)` where `next_node` is the
-    /// sequential continuation after the switch, if any.
+    /// When resuming, the recorded case from `resume` is used instead of the
+    /// discriminator.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "internal recursion threads per-run state explicitly"
+    )]
     pub(crate) fn execute_switch<'a>(
         &'a self,
         graph: &'a Graph,
@@ -287,7 +552,10 @@ impl GraphExecutor {
         switch_node: &'a SwitchNode,
         depth: usize,
         hooks: Option<&'a HooksAPI>,
-    ) -> futures::future::BoxFuture<'a, Result<(usize, Option<NodeId>), ExecutionError>> {
+        run: &'a RunControl,
+        scope: &'a Scope<'a>,
+        resume: Option<(&'a CursorFrame, &'a [CursorFrame])>,
+    ) -> futures::future::BoxFuture<'a, Result<usize, ExecutionError>> {
         Box::pin(async move {
             // Invoke OnSwitchStart hook
             Self::invoke_hook::<OnSwitchStart>(
@@ -295,33 +563,87 @@ impl GraphExecutor {
                 ctx,
                 &GraphEvent::SwitchStart {
                     node_id: switch_node.id.clone(),
-                    node_name: switch_node.name,
+                    node_name: switch_node.name.clone(),
                     case_count: switch_node.cases.len(),
                     has_default: switch_node.default.is_some(),
                 },
             );
 
-            let discriminator = switch_node
-                .discriminator
-                .as_ref()
-                .ok_or_else(|| ExecutionError::MissingDiscriminator(switch_node.id.clone()))?;
+            let (target, selected_case, used_default, rest) = match resume {
+                Some((frame, rest)) => {
+                    let FrameState::Switch { case, used_default } = &frame.state else {
+                        return Err(frame.mismatch());
+                    };
+                    let selected = if *used_default {
+                        switch_node
+                            .default
+                            .clone()
+                            .map(|target| (target, Arc::from(DEFAULT_SWITCH_CASE)))
+                    } else {
+                        switch_node
+                            .cases
+                            .iter()
+                            .find(|(case_key, _)| **case_key == **case)
+                            .map(|(case_key, target)| (target.clone(), case_key.clone()))
+                    };
+                    let (target, selected_case) = selected.ok_or_else(|| frame.mismatch())?;
+                    (target, selected_case, *used_default, rest)
+                }
+                None => {
+                    let discriminator = switch_node.discriminator.as_ref().ok_or_else(|| {
+                        ExecutionError::MissingDiscriminator(switch_node.id.clone())
+                    })?;
 
-            let key = discriminator
-                .discriminate(ctx)
-                .map_err(ExecutionError::PredicateError)?;
+                    let key = discriminator
+                        .discriminate(ctx)
+                        .map_err(ExecutionError::PredicateError)?;
 
-            let (target, used_default) = switch_node
-                .cases
-                .iter()
-                .find(|(case_key, _)| *case_key == key)
-                .map(|(_, node_id)| (node_id.clone(), false))
-                .or_else(|| switch_node.default.as_ref().map(|d| (d.clone(), true)))
-                .ok_or_else(|| ExecutionError::NoMatchingCase {
-                    node: switch_node.id.clone(),
-                    key,
-                })?;
+                    let (target, selected_case, used_default) = switch_node
+                        .cases
+                        .iter()
+                        .find(|(case_key, _)| **case_key == *key)
+                        .map(|(case_key, node_id)| (node_id.clone(), case_key.clone(), false))
+                        .or_else(|| {
+                            switch_node
+                                .default
+                                .as_ref()
+                                .map(|d| (d.clone(), Arc::from(DEFAULT_SWITCH_CASE), true))
+                        })
+                        .ok_or_else(|| ExecutionError::NoMatchingCase {
+                            node: switch_node.id.clone(),
+                            key,
+                        })?;
+                    (target, selected_case, used_default, &[][..])
+                }
+            };
+
+            run.record(|_| {
+                Some(TraceEvent::BranchSelected {
+                    node: TraceNode::of(graph, &switch_node.id)?,
+                    branch: selected_case.to_string(),
+                })
+            });
 
-            let nodes_executed = self.execute_from(graph, ctx, target, depth, hooks).await?;
+            run.follow(&switch_node.id, &target);
+            let frame = CursorFrame::at(
+                graph,
+                &switch_node.id,
+                FrameState::Switch {
+                    case: selected_case.to_string(),
+                    used_default,
+                },
+            )?;
+            let nodes_executed = self
+                .execute_from(
+                    graph,
+                    ctx,
+                    target,
+                    depth,
+                    hooks,
+                    run,
+                    scope.enter(frame, rest),
+                )
+                .await?;
 
             // Invoke OnSwitchComplete hook
             Self::invoke_hook::<OnSwitchComplete>(
@@ -329,18 +651,112 @@ impl GraphExecutor {
                 ctx,
                 &GraphEvent::SwitchComplete {
                     node_id: switch_node.id.clone(),
-                    node_name: switch_node.name,
-                    selected_case: if used_default {
-                        DEFAULT_SWITCH_CASE
-                    } else {
-                        key
-                    },
+                    node_name: switch_node.name.clone(),
+                    selected_case,
                     used_default,
                 },
             );
 
-            let next = self.find_next_sequential(graph, &switch_node.id).ok();
-            Ok((nodes_executed, next))
+            Ok(nodes_executed)
+        })
+    }
+
+    /// Executes a subgraph node's graph in an isolated context, returning the
+    /// nodes executed inside it.
+    ///
+    /// The child context shares only globals with `ctx`; input and resource
+    /// bindings are applied before the run and output bindings after it
+    /// succeeds. Lent resources are returned whether or not it succeeds. The
+    /// subgraph hooks all run against the child context, with
+    /// `OnSubgraphComplete` or `OnSubgraphFailure` fired before bindings are
+    /// returned. The subgraph shares the run's cancellation token and budget
+    /// but records no checkpoints, so it is resumed from its start and cannot
+    /// be interrupted.
+    pub(crate) fn execute_subgraph<'a>(
+        &'a self,
+        ctx: &'a mut SystemContext<'_>,
+        sub: &'a SubgraphNode,
+        depth: usize,
+        hooks: Option<&'a HooksAPI>,
+        run: &'a RunControl,
+    ) -> futures::future::BoxFuture<'a, Result<usize, ExecutionError>> {
+        Box::pin(async move {
+            let entry = sub
+                .graph
+                .entry()
+                .ok_or_else(|| subgraph_failed(sub, ExecutionError::EmptyGraph))?;
+
+            let mut child = ctx.isolated();
+            child.insert(run.budget.clone());
+            for binding in &sub.options.bindings {
+                binding.enter(ctx, &mut child);
+            }
+
+            // Invoke OnSubgraphStart hook on the subgraph's context
+            Self::invoke_hook::<OnSubgraphStart>(
+                hooks,
+                &mut child,
+                &GraphEvent::SubgraphStart {
+                    node_id: sub.id.clone(),
+                    node_name: sub.name.clone(),
+                    node_count: sub.graph.node_count(),
+                },
+            );
+
+            let subgraph_start = std::time::Instant::now();
+            let executor = GraphExecutor {
+                default_max_iterations: sub.options.max_iterations.or(self.default_max_iterations),
+                ..self.clone()
+            };
+            let sub_run = run.nested(sub.options.max_nodes);
+            let result = executor
+                .execute_from(
+                    &sub.graph,
+                    &mut child,
+                    entry,
+                    depth,
+                    hooks,
+                    &sub_run,
+                    Scope::detached(),
+                )
+                .await;
+
+            // Invoke OnSubgraphComplete or OnSubgraphFailure hook on the
+            // subgraph's context
+            match &result {
+                Ok(nodes_executed) => Self::invoke_hook::<OnSubgraphComplete>(
+                    hooks,
+                    &mut child,
+                    &GraphEvent::SubgraphComplete {
+                        node_id: sub.id.clone(),
+                        node_name: sub.name.clone(),
+                        nodes_executed: *nodes_executed,
+                        duration: subgraph_start.elapsed(),
+                    },
+                ),
+                Err(err) => Self::invoke_hook::<OnSubgraphFailure>(
+                    hooks,
+                    &mut child,
+                    &GraphEvent::SubgraphFailure {
+                        node_id: sub.id.clone(),
+                        node_name: sub.name.clone(),
+                        error: err.to_string(),
+                        duration: subgraph_start.elapsed(),
+                    },
+                ),
+            }
+
+            for binding in &sub.options.bindings {
+                if result.is_ok() || binding.kind() == BindingKind::Resource {
+                    binding.exit(&mut child, ctx);
+                }
+            }
+
+            match result {
+                Ok(count) => Ok(count),
+                Err(err) if err.is_run_abort() && !sub_run.limit_exceeded() => Err(err),
+                Err(err) => Err(subgraph_failed(sub, err)),
+            }
         })
     }
 
@@ -360,10 +776,16 @@ impl GraphExecutor {
     /// * `start` - The node ID to begin execution from
     /// * `depth` - Current recursion depth for nested control flow (safety limit)
     /// * `hooks` - Optional hooks API for lifecycle callbacks
+    /// * `run` - Per-run controls such as the cancellation token
+    /// * `scope` - Cursor frames of the enclosing levels and frames to resume into
     ///
     /// # Returns
     ///
     /// The number of nodes executed, or an error if execution fails.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "internal recursion threads per-run state explicitly"
+    )]
     pub(crate) fn execute_from<'a>(
         &'a self,
         graph: &'a Graph,
@@ -371,6 +793,8 @@ impl GraphExecutor {
         start: NodeId,
         depth: usize,
         hooks: Option<&'a HooksAPI>,
+        run: &'a RunControl,
+        scope: Scope<'a>,
     ) -> futures::future::BoxFuture<'a, Result<usize, ExecutionError>> {
         Box::pin(async move {
             if depth >= self.max_recursion_depth {
@@ -380,18 +804,47 @@ impl GraphExecutor {
                 });
             }
 
-            let mut current = start;
+            // A resumed level starts at the node recorded in its frame.
+            let mut resume = scope.resume.split_first();
+            let mut current = match resume {
+                Some((frame, _)) => frame.resolve(graph)?,
+                None => start,
+            };
+            // A level recorded as done has nothing left to run.
+            if let Some((frame, rest)) = resume
+                && frame.state == FrameState::Done
+            {
+                return if rest.is_empty() {
+                    Ok(0)
+                } else {
+                    Err(frame.mismatch())
+                };
+            }
             let mut nodes_executed = 0;
 
             loop {
+                run.enter_node(&current)?;
+
                 let node = graph
                     .get_node(current.clone())
                     .ok_or_else(|| ExecutionError::NodeNotFound(current.clone()))?;
+                run.record(|_| {
+                    Some(TraceEvent::NodeEntered {
+                        node: TraceNode::of(graph, &current)?,
+                    })
+                });
 
                 nodes_executed += 1;
 
+                // Only the first node of a resumed level carries recorded progress.
+                let resumed = cursor::resumed(resume.take())?;
+
                 match node {
                     Node::System(sys) => {
+                        if let Some((frame, _)) = resumed {
+                            return Err(frame.mismatch());
+                        }
+
                         // Invoke OnSystemStart hook
                         let start_event = GraphEvent::SystemStart {
                             node_id: current.clone(),
@@ -402,8 +855,20 @@ impl GraphExecutor {
 
                         let system_start = std::time::Instant::now();
 
-                        match Self::run_with_retry(sys, ctx).await {
+                        let outcome = match run.replayed_outcome(graph, &current, sys) {
+                            Some(outcome) => outcome?,
+                            None => Self::run_with_retry(graph, sys, ctx, run).await,
+                        };
+                        match outcome {
                             SystemOutcome::Ok(output) => {
+                                run.record(|recorder| {
+                                    Some(TraceEvent::SystemCompleted {
+                                        node: TraceNode::of(graph, &current)?,
+                                        output_type: sys.output_type_name().to_owned(),
+                                        output: recorder
+                                            .encode_output(sys.output_type_id(), &*output),
+                                    })
+                                });
                                 ctx.insert_output_boxed(sys.output_type_id(), output);
 
                                 // Invoke OnSystemComplete hook
@@ -420,14 +885,20 @@ impl GraphExecutor {
                                     &complete_event,
                                 );
 
-                                match self.find_next_sequential(graph, &current) {
-                                    Ok(next) => current = next,
-                                    Err(ExecutionError::NoNextNode(_)) => break,
-                                    Err(err) => return Err(err),
+                                match self.advance(graph, run, &scope, &current, ctx).await? {
+                                    Some(next) => current = next,
+                                    None => break,
                                 }
                             }
+                            SystemOutcome::Aborted(err) => return Err(err),
                             SystemOutcome::Timeout => {
+                                run.record(|_| {
+                                    Some(TraceEvent::SystemTimedOut {
+                                        node: TraceNode::of(graph, &current)?,
+                                    })
+                                });
                                 if let Some(handler) = self.find_timeout_edge(graph, &current) {
+                                    record_error_routed(graph, run, &current, &handler);
                                     current = handler;
                                 } else {
                                     return Err(ExecutionError::Timeout {
@@ -436,16 +907,17 @@ impl GraphExecutor {
                                     });
                                 }
                             }
-                            SystemOutcome::Err(err) => {
-                                let kind = match &err {
-                                    polaris_system::system::SystemError::ParamError(_) => {
-                                        ErrorKind::ParamResolution
-                                    }
-                                    polaris_system::system::SystemError::ExecutionError(_) => {
-                                        ErrorKind::Execution
-                                    }
-                                };
-                                let error_string = err.to_string();
+                            SystemOutcome::Err {
+                                kind,
+                                message: error_string,
+                            } => {
+                                run.record(|_| {
+                                    Some(TraceEvent::SystemFailed {
+                                        node: TraceNode::of(graph, &current)?,
+                                        kind,
+                                        error: error_string.clone(),
+                                    })
+                                });
 
                                 // Invoke OnSystemError hook
                                 let error_event = GraphEvent::SystemError {
@@ -471,6 +943,7 @@ impl GraphExecutor {
                                         duration: system_start.elapsed(),
                                         kind,
                                     });
+                                    record_error_routed(graph, run, &current, &handler);
                                     current = handler;
                                 } else {
                                     return Err(ExecutionError::SystemError(error_string));
@@ -487,18 +960,33 @@ impl GraphExecutor {
                             ctx,
                             &GraphEvent::DecisionStart {
                                 node_id: current.clone(),
-                                node_name: dec.name,
+                                node_name: dec.name.clone(),
                             },
                         );
 
-                        let predicate = dec
-                            .predicate
-                            .as_ref()
-                            .ok_or_else(|| ExecutionError::MissingPredicate(current.clone()))?;
+                        let (result, rest) = match resumed {
+                            Some((frame, rest)) => match frame.state {
+                                FrameState::Decision { branch } => (branch, rest),
+                                _ => return Err(frame.mismatch()),
+                            },
+                            None => {
+                                let predicate = dec.predicate.as_ref().ok_or_else(|| {
+                                    ExecutionError::MissingPredicate(current.clone())
+                                })?;
+
+                                let result = predicate
+                                    .evaluate(ctx)
+                                    .map_err(ExecutionError::PredicateError)?;
+                                (result, &[][..])
+                            }
+                        };
 
-                        let result = predicate
-                            .evaluate(ctx)
-                            .map_err(ExecutionError::PredicateError)?;
+                        run.record(|_| {
+                            Some(TraceEvent::BranchSelected {
+                                node: TraceNode::of(graph, &decision_id)?,
+                                branch: result.to_string(),
+                            })
+                        });
 
                         let (branch_entry, selected_branch) = if result {
                             (
@@ -523,8 +1011,22 @@ impl GraphExecutor {
                         };
 
                         // Execute branch as subgraph (with increased depth)
+                        run.follow(&decision_id, &branch_entry);
+                        let frame = CursorFrame::at(
+                            graph,
+                            &decision_id,
+                            FrameState::Decision { branch: result },
+                        )?;
                         let branch_count = self
-                            .execute_from(graph, ctx, branch_entry, depth + 1, hooks)
+                            .execute_from(
+                                graph,
+                                ctx,
+                                branch_entry,
+                                depth + 1,
+                                hooks,
+                                run,
+                                scope.enter(frame, rest),
+                            )
                             .await?;
                         nodes_executed += branch_count;
 
@@ -534,48 +1036,147 @@ impl GraphExecutor {
                             ctx,
                             &GraphEvent::DecisionComplete {
                                 node_id: decision_id.clone(),
-                                node_name: dec.name,
+                                node_name: dec.name.clone(),
                                 selected_branch,
                             },
                         );
 
-                        match self.find_next_sequential(graph, &decision_id) {
-                            Ok(next) => current = next,
-                            Err(ExecutionError::NoNextNode(_)) => break,
-                            Err(err) => return Err(err),
+                        match self.advance(graph, run, &scope, &decision_id, ctx).await? {
+                            Some(next) => current = next,
+                            None => break,
                         }
                     }
                     Node::Loop(loop_node) => {
+                        let resume_loop = match resumed {
+                            Some((frame, rest)) => match frame.state {
+                                FrameState::Loop { iteration } => Some((iteration, rest)),
+                                _ => return Err(frame.mismatch()),
+                            },
+                            None => None,
+                        };
                         let loop_count = self
-                            .execute_loop(graph, ctx, loop_node, depth + 1, hooks)
+                            .execute_loop(
+                                graph,
+                                ctx,
+                                loop_node,
+                                depth + 1,
+                                hooks,
+                                run,
+                                &scope,
+                                resume_loop,
+                            )
                             .await?;
                         nodes_executed += loop_count;
 
-                        match self.find_next_sequential(graph, &current) {
-                            Ok(next) => current = next,
-                            Err(ExecutionError::NoNextNode(_)) => break,
-                            Err(err) => return Err(err),
+                        match self.advance(graph, run, &scope, &current, ctx).await? {
+                            Some(next) => current = next,
+                            None => break,
                         }
                     }
                     Node::Parallel(par) => {
+                        let completed = match resumed {
+                            Some((frame, [])) => match &frame.state {
+                                // Cursors saved without outputs restore none.
+                                FrameState::Parallel { completed, outputs } => completed
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(i, &index)| {
+                                        (index, outputs.get(i).cloned().unwrap_or_default())
+                                    })
+                                    .collect(),
+                                _ => return Err(frame.mismatch()),
+                            },
+                            Some((frame, _)) => return Err(frame.mismatch()),
+                            None => Vec::new(),
+                        };
                         let parallel_count = self
-                            .execute_parallel(graph, ctx, par, depth + 1, hooks)
+                            .execute_parallel(
+                                graph,
+                                ctx,
+                                par,
+                                depth + 1,
+                                hooks,
+                                run,
+                                &scope,
+                                completed,
+                            )
                             .await?;
                         nodes_executed += parallel_count;
 
-                        match self.find_next_sequential(graph, &current) {
-                            Ok(next) => current = next,
-                            Err(ExecutionError::NoNextNode(_)) => break,
-                            Err(err) => return Err(err),
+                        match self.advance(graph, run, &scope, &current, ctx).await? {
+                            Some(next) => current = next,
+                            None => break,
+                        }
+                    }
+                    Node::Subgraph(sub) => {
+                        if let Some((frame, _)) = resumed {
+                            return Err(frame.mismatch());
+                        }
+
+                        let subgraph_count = self
+                            .execute_subgraph(ctx, sub, depth + 1, hooks, run)
+                            .await?;
+                        nodes_executed += subgraph_count;
+
+                        match self.advance(graph, run, &scope, &current, ctx).await? {
+                            Some(next) => current = next,
+                            None => break,
+                        }
+                    }
+                    Node::Interrupt(interrupt) => {
+                        match resumed {
+                            Some((frame, [])) if frame.state == FrameState::Interrupted => {
+                                let response = run.take_interrupt_response().ok_or_else(|| {
+                                    interrupt_failed(interrupt, "no response was provided")
+                                })?;
+                                interrupt
+                                    .respond(response, ctx)
+                                    .map_err(|message| interrupt_failed(interrupt, message))?;
+                            }
+                            Some((frame, _)) => return Err(frame.mismatch()),
+                            None => {
+                                // Rejected by `Graph::validate`.
+                                if scope.detached {
+                                    return Err(interrupt_failed(
+                                        interrupt,
+                                        "interrupts cannot run in parallel branches or subgraphs",
+                                    ));
+                                }
+                                let request = interrupt
+                                    .request(ctx)
+                                    .map_err(|message| interrupt_failed(interrupt, message))?;
+                                let frame =
+                                    CursorFrame::at(graph, &current, FrameState::Interrupted)?;
+                                return Err(ExecutionError::Interrupted(Box::new(Interrupt {
+                                    node: current,
+                                    request,
+                                    cursor: run.cursor(&scope, frame, ctx),
+                                })));
+                            }
+                        }
+
+                        match self.advance(graph, run, &scope, &current, ctx).await? {
+                            Some(next) => current = next,
+                            None => break,
                         }
                     }
                     Node::Switch(switch_node) => {
-                        let (switch_count, next) = self
-                            .execute_switch(graph, ctx, switch_node, depth + 1, hooks)
+                        let switch_count = self
+                            .execute_switch(
+                                graph,
+                                ctx,
+                                switch_node,
+                                depth + 1,
+                                hooks,
+                                run,
+                                &scope,
+                                resumed,
+                            )
                             .await?;
                         nodes_executed += switch_count;
-                        match next {
-                            Some(n) => current = n,
+
+                        match self.advance(graph, run, &scope, &current, ctx).await? {
+                            Some(next) => current = next,
                             None => break,
                         }
                     }
@@ -586,3 +1187,32 @@ impl GraphExecutor {
         })
     }
 }
+
+/// Builds the error for an interrupt node whose request or response failed.
+fn interrupt_failed(interrupt: &InterruptNode, message: impl Into<String>) -> ExecutionError {
+    ExecutionError::InterruptFailed {
+        node: interrupt.id.clone(),
+        name: interrupt.name.clone(),
+        message: message.into(),
+    }
+}
+
+/// Wraps an error raised inside a subgraph with the subgraph node's identity.
+fn subgraph_failed(sub: &SubgraphNode, error: ExecutionError) -> ExecutionError {
+    ExecutionError::SubgraphFailed {
+        node: sub.id.clone(),
+        name: sub.name.clone(),
+        error: Box::new(error),
+    }
+}
+
+/// Records that the failed or timed-out system `from` was routed to `handler`.
+fn record_error_routed(graph: &Graph, run: &RunControl, from: &NodeId, handler: &NodeId) {
+    run.follow(from, handler);
+    run.record(|_| {
+        Some(TraceEvent::ErrorRouted {
+            node: TraceNode::of(graph, from)?,
+            handler: TraceNode::of(graph, handler)?,
+        })
+    });
+}
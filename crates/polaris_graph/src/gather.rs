@@ -0,0 +1,260 @@
+//! Typed merging of parallel branch outputs.
+//!
+//! By default, outputs of parallel branches are merged in branch order, so
+//! when several branches produce the same output type only the last one
+//! survives. Registering a [`Reducer`] for a type on
+//! [`ParallelOptions`](crate::node::ParallelOptions) gathers every branch's
+//! value of that type instead.
+//!
+//! # Architecture
+//!
+//! Gathers follow the same type erasure pattern as predicates:
+//!
+//! - [`Gather<T>`] - Typed gather that reduces branch values of `T`
+//! - [`ErasedGather`] - Object-safe trait for type-erased storage
+//! - [`BoxedGather`] - Type alias for boxed gathers
+//!
+//! # Example
+//!
+//! ```
+//! use polaris_graph::Graph;
+//! use polaris_graph::gather::{Branches, Reducer};
+//! use polaris_graph::node::ParallelOptions;
+//! use polaris_system::param::Out;
+//! use polaris_system::system;
+//!
+//! struct Finding(String);
+//! struct Report(Vec<String>);
+//!
+//! async fn search_web() -> Finding { Finding("web".into()) }
+//! async fn search_docs() -> Finding { Finding("docs".into()) }
+//!
+//! #[system]
+//! async fn report(findings: Out<Branches<Finding>>) -> Report {
+//!     Report(findings.values().map(|finding| finding.0.clone()).collect())
+//! }
+//!
+//! let mut graph = Graph::new();
+//! graph
+//!     .add_parallel_with(
+//!         "search",
+//!         ParallelOptions::new().gather::<Finding>(Reducer::Collect),
+//!         [
+//!             |g: &mut Graph| { g.add_system(search_web); },
+//!             |g: &mut Graph| { g.add_system(search_docs); },
+//!         ],
+//!     )
+//!     .add_system(report);
+//! ```
+
+use polaris_system::resource::{Output, Outputs};
+use std::any::{TypeId, type_name};
+use std::fmt;
+
+/// Every parallel branch's value of an output type, in branch order.
+///
+/// Produced by [`Reducer::Collect`] and passed to [`Reducer::reduce`]
+/// functions. Branches that did not produce a `T` are absent, so
+/// [`branch_indices`](Self::branch_indices) may skip indices.
+pub struct Branches<T> {
+    entries: Vec<(usize, T)>,
+}
+
+impl<T> Branches<T> {
+    /// Returns the number of branches that produced a value.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no branch produced a value.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the value produced by the branch at `index`, if any.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.entries
+            .iter()
+            .find(|(branch, _)| *branch == index)
+            .map(|(_, value)| value)
+    }
+
+    /// Iterates over `(branch index, value)` pairs in branch order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.entries.iter().map(|(branch, value)| (*branch, value))
+    }
+
+    /// Iterates over the values in branch order.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// Iterates over the indices of branches that produced a value.
+    pub fn branch_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.entries.iter().map(|(branch, _)| *branch)
+    }
+
+    /// Consumes the collection, returning the values in branch order.
+    #[must_use]
+    pub fn into_values(self) -> Vec<T> {
+        self.entries.into_iter().map(|(_, value)| value).collect()
+    }
+}
+
+impl<T> IntoIterator for Branches<T> {
+    type Item = (usize, T);
+    type IntoIter = std::vec::IntoIter<(usize, T)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Branches<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// How branch values of one output type are combined after a parallel node.
+pub enum Reducer<T> {
+    /// Stores every branch's value as a [`Branches<T>`] output.
+    ///
+    /// The output is stored even when no branch produced a value.
+    Collect,
+    /// Keeps the value from the last branch in declaration order, as an
+    /// unconfigured parallel node does.
+    LastWins,
+    /// Combines the branch values into a single `T` with a custom function.
+    ///
+    /// The function is not called when no branch produced a value.
+    Reduce(Box<dyn Fn(Branches<T>) -> T + Send + Sync>),
+}
+
+impl<T> Reducer<T> {
+    /// Creates a [`Reducer::Reduce`] from a merge function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polaris_graph::gather::{Branches, Reducer};
+    ///
+    /// struct Score(u32);
+    ///
+    /// let total = Reducer::reduce(|scores: Branches<Score>| {
+    ///     Score(scores.values().map(|score| score.0).sum())
+    /// });
+    /// ```
+    pub fn reduce<F>(merge: F) -> Self
+    where
+        F: Fn(Branches<T>) -> T + Send + Sync + 'static,
+    {
+        Reducer::Reduce(Box::new(merge))
+    }
+}
+
+impl<T> fmt::Debug for Reducer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reducer::Collect => write!(f, "Collect"),
+            Reducer::LastWins => write!(f, "LastWins"),
+            Reducer::Reduce(_) => write!(f, "Reduce(..)"),
+        }
+    }
+}
+
+/// Object-safe trait for type-erased gathers.
+///
+/// This trait enables storing gathers for heterogeneous output types in a
+/// parallel node.
+pub trait ErasedGather: Send + Sync {
+    /// Removes this gather's output type from each branch's outputs and
+    /// stores the reduced result in `target`.
+    ///
+    /// `branches` holds `(branch index, outputs)` pairs in branch order.
+    fn gather(&self, branches: &mut [(usize, Outputs)], target: &mut Outputs);
+
+    /// Returns the [`TypeId`] of the output type this gather reads.
+    fn output_type_id(&self) -> TypeId;
+
+    /// Returns the name of the output type for error messages.
+    fn output_type_name(&self) -> &'static str;
+
+    /// Returns the [`TypeId`] of the output this gather stores even when no
+    /// branch produced a value, if any.
+    ///
+    /// Used by graph validation to track which outputs follow a parallel
+    /// node. Defaults to `None`.
+    fn guaranteed_type_id(&self) -> Option<TypeId> {
+        None
+    }
+}
+
+impl fmt::Debug for dyn ErasedGather {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErasedGather")
+            .field("output_type", &self.output_type_name())
+            .finish()
+    }
+}
+
+/// Type alias for boxed gathers stored in parallel nodes.
+pub type BoxedGather = Box<dyn ErasedGather>;
+
+/// A typed gather that combines branch values of `T` with a [`Reducer`].
+pub struct Gather<T> {
+    reducer: Reducer<T>,
+}
+
+impl<T: Output> Gather<T> {
+    /// Creates a gather for `T` using the given reducer.
+    #[must_use]
+    pub fn new(reducer: Reducer<T>) -> Self {
+        Self { reducer }
+    }
+}
+
+impl<T: Output> ErasedGather for Gather<T> {
+    fn gather(&self, branches: &mut [(usize, Outputs)], target: &mut Outputs) {
+        let values = Branches {
+            entries: branches
+                .iter_mut()
+                .filter_map(|(branch, outputs)| outputs.remove::<T>().map(|value| (*branch, value)))
+                .collect(),
+        };
+
+        match &self.reducer {
+            Reducer::Collect => {
+                target.insert(values);
+            }
+            Reducer::LastWins => {
+                if let Some(value) = values.into_values().pop() {
+                    target.insert(value);
+                }
+            }
+            Reducer::Reduce(merge) => {
+                if !values.is_empty() {
+                    target.insert(merge(values));
+                }
+            }
+        }
+    }
+
+    fn output_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn output_type_name(&self) -> &'static str {
+        type_name::<T>()
+    }
+
+    fn guaranteed_type_id(&self) -> Option<TypeId> {
+        match self.reducer {
+            Reducer::Collect => Some(TypeId::of::<Branches<T>>()),
+            Reducer::LastWins | Reducer::Reduce(_) => None,
+        }
+    }
+}
@@ -3,13 +3,16 @@
 use super::{Graph, MergeError};
 use crate::edge::{Edge, ErrorEdge, TimeoutEdge};
 use crate::node::{
-    DecisionNode, IntoSystemNode, LoopNode, Node, NodeId, ParallelNode, RetryPolicy, SwitchNode,
-    SystemNode,
+    DecisionNode, InterruptNode, IntoSystemNode, LoopNode, Node, NodeId, ParallelNode,
+    ParallelOptions, RetryPolicy, SubgraphNode, SubgraphOptions, SwitchNode, SystemNode,
 };
-use crate::predicate::Predicate;
+use crate::predicate::{BoxedDiscriminator, BoxedPredicate, Discriminator, Predicate};
 use hashbrown::HashSet;
 use polaris_system::resource::Output;
 use polaris_system::system::BoxedSystem;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
 use std::time::Duration;
 
 impl Graph {
@@ -333,7 +336,7 @@ impl Graph {
     /// ```
     pub fn add_conditional_branch<T, P, F1, F2>(
         &mut self,
-        name: &'static str,
+        name: impl Into<Arc<str>>,
         predicate: P,
         true_path: F1,
         false_path: F2,
@@ -345,7 +348,25 @@ impl Graph {
         F2: FnOnce(&mut Graph),
     {
         let boxed_predicate = Box::new(Predicate::<T, P>::new(predicate));
-        let mut decision = DecisionNode::with_predicate(name, boxed_predicate);
+        self.add_decision_node(name, boxed_predicate, true_path, false_path)
+    }
+
+    /// Adds a decision node with a type-erased predicate.
+    ///
+    /// Shared by [`add_conditional_branch`](Self::add_conditional_branch)
+    /// and graphs built from a [`GraphSpec`](crate::spec::GraphSpec).
+    pub(crate) fn add_decision_node<F1, F2>(
+        &mut self,
+        name: impl Into<Arc<str>>,
+        predicate: BoxedPredicate,
+        true_path: F1,
+        false_path: F2,
+    ) -> &mut Self
+    where
+        F1: FnOnce(&mut Graph),
+        F2: FnOnce(&mut Graph),
+    {
+        let mut decision = DecisionNode::with_predicate(name, predicate);
         let decision_id = decision.id.clone();
 
         // Connect to previous node if exists
@@ -391,16 +412,63 @@ impl Graph {
 
     /// Adds a parallel execution node.
     ///
+    /// All branches start at once and the first failing branch fails the
+    /// node. Use [`add_parallel_with`](Self::add_parallel_with) to limit
+    /// concurrency or tolerate failures.
+    ///
     /// # Arguments
     ///
     /// * `name` - Human-readable name for the parallel node.
     /// * `branches` - Builder functions for each parallel branch.
-    pub fn add_parallel<I, F>(&mut self, name: &'static str, branches: I) -> &mut Self
+    pub fn add_parallel<I, F>(&mut self, name: impl Into<Arc<str>>, branches: I) -> &mut Self
     where
         I: IntoIterator<Item = F>,
         F: FnOnce(&mut Graph),
     {
-        let mut parallel = ParallelNode::new(name);
+        self.add_parallel_with(name, ParallelOptions::default(), branches)
+    }
+
+    /// Adds a parallel execution node with a concurrency limit and failure
+    /// policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Human-readable name for the parallel node.
+    /// * `options` - Concurrency limit and [`ParallelPolicy`](crate::node::ParallelPolicy).
+    /// * `branches` - Builder functions for each parallel branch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polaris_graph::Graph;
+    /// use polaris_graph::node::{ParallelOptions, ParallelPolicy};
+    /// # async fn search_web() -> i32 { 1 }
+    /// # async fn search_docs() -> i32 { 2 }
+    /// # async fn search_code() -> i32 { 3 }
+    /// # let mut graph = Graph::new();
+    /// graph.add_parallel_with(
+    ///     "research",
+    ///     ParallelOptions::new()
+    ///         .with_max_concurrency(2)
+    ///         .with_policy(ParallelPolicy::CollectErrors),
+    ///     [
+    ///         |g: &mut Graph| { g.add_system(search_web); },
+    ///         |g: &mut Graph| { g.add_system(search_docs); },
+    ///         |g: &mut Graph| { g.add_system(search_code); },
+    ///     ],
+    /// );
+    /// ```
+    pub fn add_parallel_with<I, F>(
+        &mut self,
+        name: impl Into<Arc<str>>,
+        options: ParallelOptions,
+        branches: I,
+    ) -> &mut Self
+    where
+        I: IntoIterator<Item = F>,
+        F: FnOnce(&mut Graph),
+    {
+        let mut parallel = ParallelNode::with_options(name, options);
         let parallel_id = parallel.id.clone();
 
         // Connect to previous node if exists
@@ -471,14 +539,62 @@ impl Graph {
     ///     },
     /// );
     /// ```
-    pub fn add_loop<T, P, F>(&mut self, name: &'static str, termination: P, body: F) -> &mut Self
+    pub fn add_loop<T, P, F>(
+        &mut self,
+        name: impl Into<Arc<str>>,
+        termination: P,
+        body: F,
+    ) -> &mut Self
     where
         T: Output,
         P: Fn(&T) -> bool + Send + Sync + 'static,
         F: FnOnce(&mut Graph),
     {
         let boxed_termination = Box::new(Predicate::<T, P>::new(termination));
-        let mut loop_node = LoopNode::with_termination(name, boxed_termination);
+        self.add_loop_node(LoopNode::with_termination(name, boxed_termination), body)
+    }
+
+    /// Adds a loop node with a maximum iteration count.
+    ///
+    /// The loop body executes up to `max_iterations` times. Use this
+    /// when you want a simple bounded loop without a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Human-readable name for the loop node
+    /// * `max_iterations` - Maximum number of iterations
+    /// * `body` - Builder function for the loop body
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polaris_graph::Graph;
+    /// # async fn attempt_operation() -> i32 { 1 }
+    /// # let mut graph = Graph::new();
+    /// graph.add_loop_n("retry_loop", 3, |g| {
+    ///     g.add_system(attempt_operation);
+    /// });
+    /// ```
+    pub fn add_loop_n<F>(
+        &mut self,
+        name: impl Into<Arc<str>>,
+        max_iterations: usize,
+        body: F,
+    ) -> &mut Self
+    where
+        F: FnOnce(&mut Graph),
+    {
+        self.add_loop_node(LoopNode::with_max_iterations(name, max_iterations), body)
+    }
+
+    /// Adds a prepared loop node and builds its body.
+    ///
+    /// Shared by [`add_loop`](Self::add_loop), [`add_loop_n`](Self::add_loop_n)
+    /// and graphs built from a [`GraphSpec`](crate::spec::GraphSpec).
+    pub(crate) fn add_loop_node<F>(&mut self, mut loop_node: LoopNode, body: F) -> &mut Self
+    where
+        F: FnOnce(&mut Graph),
+    {
         let loop_id = loop_node.id.clone();
 
         // Connect to previous node if exists
@@ -512,61 +628,112 @@ impl Graph {
         self
     }
 
-    /// Adds a loop node with a maximum iteration count.
+    /// Adds a node that runs `subgraph` as a single step.
     ///
-    /// The loop body executes up to `max_iterations` times. Use this
-    /// when you want a simple bounded loop without a predicate.
+    /// Unlike [`append`](Self::append), the subgraph's nodes are not merged
+    /// into this graph: it runs in an isolated context with its own outputs,
+    /// and only the bindings in `options` cross the boundary. Use this to
+    /// delegate to another agent's graph, such as a worker called by a
+    /// supervisor.
     ///
     /// # Arguments
     ///
-    /// * `name` - Human-readable name for the loop node
-    /// * `max_iterations` - Maximum number of iterations
-    /// * `body` - Builder function for the loop body
+    /// * `name` - Human-readable name for the subgraph node
+    /// * `subgraph` - The graph to run
+    /// * `options` - Bindings and limits for the subgraph
     ///
     /// # Example
     ///
     /// ```
     /// # use polaris_graph::Graph;
-    /// # async fn attempt_operation() -> i32 { 1 }
-    /// # let mut graph = Graph::new();
-    /// graph.add_loop_n("retry_loop", 3, |g| {
-    ///     g.add_system(attempt_operation);
-    /// });
+    /// use polaris_graph::node::SubgraphOptions;
+    /// # #[derive(Clone)] struct Query;
+    /// # struct Findings;
+    /// # async fn plan() -> Query { Query }
+    /// # async fn research() -> Findings { Findings }
+    /// # async fn respond() {}
+    /// let mut researcher = Graph::new();
+    /// researcher.add_system(research);
+    ///
+    /// let mut graph = Graph::new();
+    /// graph
+    ///     .add_system(plan)
+    ///     .add_subgraph(
+    ///         "researcher",
+    ///         researcher,
+    ///         SubgraphOptions::new().input::<Query>().output::<Findings>(),
+    ///     )
+    ///     .add_system(respond);
     /// ```
-    pub fn add_loop_n<F>(&mut self, name: &'static str, max_iterations: usize, body: F) -> &mut Self
-    where
-        F: FnOnce(&mut Graph),
-    {
-        let mut loop_node = LoopNode::with_max_iterations(name, max_iterations);
-        let loop_id = loop_node.id.clone();
+    pub fn add_subgraph(
+        &mut self,
+        name: impl Into<Arc<str>>,
+        subgraph: Graph,
+        options: SubgraphOptions,
+    ) -> &mut Self {
+        let node = SubgraphNode::new(name, subgraph, options);
+        let id = node.id.clone();
 
         // Connect to previous node if exists
         if let Some(prev_id) = self.last_node.clone() {
-            self.add_sequential_edge(prev_id, loop_id.clone());
+            self.add_sequential_edge(prev_id, id.clone());
         }
 
         // Set as entry if first node
         if self.entry.is_none() {
-            self.entry = Some(loop_id.clone());
+            self.entry = Some(id.clone());
         }
 
-        // Build loop body
-        let mut body_graph = Graph::new();
-        body(&mut body_graph);
+        self.nodes.push(Node::Subgraph(node));
+        self.last_node = Some(id);
 
-        if let Some(entry) = body_graph.entry {
-            loop_node.body_entry = Some(entry);
-        }
+        self
+    }
 
-        // Merge body graph
-        self.nodes.extend(body_graph.nodes);
-        self.edges.extend(body_graph.edges);
+    /// Adds a node that suspends the run until a response is provided.
+    ///
+    /// The node reads the `Req` output of an earlier system and stops the run
+    /// with [`ExecutionError::Interrupted`], carrying the serialized request
+    /// and a cursor to resume from. When the run is resumed with a response,
+    /// it is stored as a `Resp` output for the following systems. See the
+    /// [`interrupt`](crate::interrupt) module.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polaris_graph::Graph;
+    /// # #[derive(serde::Serialize)] struct Approval;
+    /// # #[derive(serde::Deserialize)] struct Decision;
+    /// # async fn propose() -> Approval { Approval }
+    /// # async fn act() {}
+    /// let mut graph = Graph::new();
+    /// graph
+    ///     .add_system(propose)
+    ///     .add_interrupt::<Approval, Decision>("approve")
+    ///     .add_system(act);
+    /// ```
+    ///
+    /// [`ExecutionError::Interrupted`]: crate::ExecutionError::Interrupted
+    pub fn add_interrupt<Req, Resp>(&mut self, name: impl Into<Arc<str>>) -> &mut Self
+    where
+        Req: Output + Serialize,
+        Resp: Output + DeserializeOwned,
+    {
+        let node = InterruptNode::new::<Req, Resp>(name);
+        let id = node.id.clone();
 
-        // Add loop node
-        self.nodes.push(Node::Loop(loop_node));
+        // Connect to previous node if exists
+        if let Some(prev_id) = self.last_node.clone() {
+            self.add_sequential_edge(prev_id, id.clone());
+        }
 
-        // Loop node becomes the last node
-        self.last_node = Some(loop_id);
+        // Set as entry if first node
+        if self.entry.is_none() {
+            self.entry = Some(id.clone());
+        }
+
+        self.nodes.push(Node::Interrupt(node));
+        self.last_node = Some(id);
 
         self
     }
@@ -877,7 +1044,7 @@ impl Graph {
     /// ```
     pub fn add_switch<T, D, C, F>(
         &mut self,
-        name: &'static str,
+        name: impl Into<Arc<str>>,
         discriminator: D,
         cases: C,
         default: Option<F>,
@@ -888,14 +1055,28 @@ impl Graph {
         C: IntoIterator<Item = (&'static str, F)>,
         F: FnOnce(&mut Graph),
     {
-        use crate::predicate::Discriminator;
-
-        // Create the discriminator
-        let boxed_discriminator: crate::predicate::BoxedDiscriminator =
-            Box::new(Discriminator::<T, D>::new(discriminator));
+        let boxed_discriminator = Box::new(Discriminator::<T, D>::new(discriminator));
+        self.add_switch_node(name, boxed_discriminator, cases, default)
+    }
 
+    /// Adds a switch node with a type-erased discriminator.
+    ///
+    /// Shared by [`add_switch`](Self::add_switch) and graphs built from a
+    /// [`GraphSpec`](crate::spec::GraphSpec).
+    pub(crate) fn add_switch_node<C, K, F>(
+        &mut self,
+        name: impl Into<Arc<str>>,
+        discriminator: BoxedDiscriminator,
+        cases: C,
+        default: Option<F>,
+    ) -> &mut Self
+    where
+        C: IntoIterator<Item = (K, F)>,
+        K: Into<Arc<str>>,
+        F: FnOnce(&mut Graph),
+    {
         // Create switch node
-        let mut switch_node = SwitchNode::with_discriminator(name, boxed_discriminator);
+        let mut switch_node = SwitchNode::with_discriminator(name, discriminator);
         let switch_id = switch_node.id.clone();
 
         // Build each case subgraph
@@ -904,7 +1085,7 @@ impl Graph {
             handler(&mut case_graph);
 
             if let Some(case_entry) = case_graph.entry {
-                switch_node.cases.push((key, case_entry));
+                switch_node.cases.push((key.into(), case_entry));
 
                 // Merge case graph into main graph
                 self.nodes.extend(case_graph.nodes);
@@ -0,0 +1,388 @@
+//! Static analysis of the outputs available to each node.
+//!
+//! Outputs only flow forward along the path the executor takes: parallel
+//! branches start with no outputs, subgraphs only see their input bindings,
+//! and a decision's branches each see what came before them. A node reading
+//! an output that some path into it never produces fails at runtime with
+//! `OutputNotFound`. The analysis walks the graph the way the executor
+//! does, tracking the outputs guaranteed at each node, and reports every
+//! such read together with a path that lacks the producer.
+
+use super::{Graph, ValidationError};
+use crate::edge::Edge;
+use crate::executor::{CaughtError, ParallelOutcome};
+use crate::node::{Node, NodeId};
+use crate::subgraph::{Binding, BindingKind};
+use hashbrown::{HashMap, HashSet};
+use polaris_system::param::AccessMode;
+use std::any::TypeId;
+
+impl Graph {
+    /// Checks that every output read in the graph is produced on all paths
+    /// leading to the reader.
+    ///
+    /// Reads are the non-optional output accesses of systems (`Out<T>`,
+    /// `ErrOut<T>`) and the inputs of decision predicates, switch
+    /// discriminators, loop termination predicates and interrupt requests.
+    /// `Option<Out<T>>` parameters are ignored. Subgraphs are checked with
+    /// the outputs their input bindings provide.
+    ///
+    /// `available` lists the output types already present in the context
+    /// before the run starts. [`Graph::validate`] runs this check with none
+    /// and reports its findings as
+    /// [`ValidationWarning::OutputNotProduced`](super::ValidationWarning::OutputNotProduced),
+    /// since it cannot know what the context will hold.
+    ///
+    /// Returns one [`ValidationError::OutputNotProduced`] per node and
+    /// missing output type.
+    #[must_use]
+    pub fn validate_outputs(
+        &self,
+        available: impl IntoIterator<Item = TypeId>,
+    ) -> Vec<ValidationError> {
+        let mut dataflow = Dataflow::default();
+        if let Some(entry) = &self.entry {
+            let flow = Flow {
+                available: available.into_iter().collect(),
+                ..Flow::default()
+            };
+            dataflow.chain(self, entry, flow);
+        }
+        dataflow.errors
+    }
+}
+
+/// Outputs guaranteed at a point of the walk, and how it got there.
+#[derive(Debug, Clone, Default)]
+struct Flow {
+    /// Output types present on every path to this point.
+    available: HashSet<TypeId>,
+    /// Steps of one path to this point.
+    ///
+    /// The path lacks every unavailable output type not listed in `gaps`.
+    path: Vec<String>,
+    /// Paths to this point that lack an unavailable output type other
+    /// paths produce.
+    gaps: HashMap<TypeId, Vec<String>>,
+}
+
+impl Flow {
+    /// Appends a step to the path and every gap.
+    fn step(&mut self, step: &str) {
+        self.path.push(step.to_owned());
+        for gap in self.gaps.values_mut() {
+            gap.push(step.to_owned());
+        }
+    }
+
+    /// Returns a copy whose last step is labelled with the branch taken.
+    fn enter(&self, label: &str) -> Flow {
+        let mut flow = self.clone();
+        for steps in std::iter::once(&mut flow.path).chain(flow.gaps.values_mut()) {
+            if let Some(step) = steps.last_mut() {
+                *step = format!("{step} ({label})");
+            }
+        }
+        flow
+    }
+
+    /// Records that `type_id` is present from here on.
+    fn produce(&mut self, type_id: TypeId) {
+        self.available.insert(type_id);
+        self.gaps.remove(&type_id);
+    }
+
+    /// Returns a path lacking `type_id`, or `None` if it is available.
+    fn missing(&self, type_id: TypeId) -> Option<&[String]> {
+        if self.available.contains(&type_id) {
+            return None;
+        }
+        Some(self.gaps.get(&type_id).unwrap_or(&self.path))
+    }
+
+    /// Combines two alternative flows reaching the same point, where `None`
+    /// is an alternative that never gets there.
+    fn meet(left: Option<Flow>, right: Option<Flow>) -> Option<Flow> {
+        let (mut left, right) = match (left, right) {
+            (Some(left), Some(right)) => (left, right),
+            (left, right) => return left.or(right),
+        };
+        let lost: Vec<TypeId> = left
+            .available
+            .difference(&right.available)
+            .copied()
+            .collect();
+        for type_id in lost {
+            left.available.remove(&type_id);
+            let gap = right.missing(type_id).unwrap_or_default().to_vec();
+            left.gaps.insert(type_id, gap);
+        }
+        Some(left)
+    }
+}
+
+/// State of a dataflow walk.
+#[derive(Default)]
+struct Dataflow {
+    errors: Vec<ValidationError>,
+    /// `(node, output type)` pairs already reported.
+    reported: HashSet<(NodeId, TypeId)>,
+    /// Nodes on the current path, to stop at cycles in malformed graphs.
+    active: Vec<NodeId>,
+}
+
+impl Dataflow {
+    /// Walks the chain of nodes starting at `start`, following sequential
+    /// edges as the executor does.
+    ///
+    /// Returns the flow when the chain completes, or `None` if no path
+    /// through it completes.
+    fn chain(&mut self, graph: &Graph, start: &NodeId, flow: Flow) -> Option<Flow> {
+        let active_len = self.active.len();
+        let mut flow = Some(flow);
+        // Error and timeout handlers replace the rest of the chain, so the
+        // chain also completes wherever they do.
+        let mut handled = None;
+        let mut current = Some(start.clone());
+
+        while let Some(id) = current.take() {
+            let Some(mut state) = flow.take() else {
+                break;
+            };
+            let node = match graph.get_node(id.clone()) {
+                Some(node) if !self.active.contains(&id) => node,
+                _ => {
+                    flow = Some(state);
+                    break;
+                }
+            };
+            self.active.push(id.clone());
+            state.step(node.name());
+
+            flow = self.node(graph, node, state, &mut handled);
+            current = graph.edges.iter().find_map(|edge| match edge {
+                Edge::Sequential(seq) if seq.from == id => Some(seq.to.clone()),
+                _ => None,
+            });
+        }
+
+        self.active.truncate(active_len);
+        Flow::meet(flow, handled)
+    }
+
+    /// Checks the reads of `node` and returns the flow after it completes.
+    fn node(
+        &mut self,
+        graph: &Graph,
+        node: &Node,
+        mut flow: Flow,
+        handled: &mut Option<Flow>,
+    ) -> Option<Flow> {
+        match node {
+            Node::System(sys) => {
+                let access = sys.system.access();
+                for output in &access.outputs {
+                    if output.mode == AccessMode::Read && !output.optional {
+                        self.require(&sys.id, sys.name(), output.type_id, output.type_name, &flow);
+                    }
+                }
+                for edge in &graph.edges {
+                    let end = match edge {
+                        Edge::Error(err) if err.from == sys.id => {
+                            let mut caught = flow.enter("error");
+                            caught.produce(TypeId::of::<CaughtError>());
+                            self.chain(graph, &err.to, caught)
+                        }
+                        Edge::Timeout(timeout) if timeout.from == sys.id => {
+                            self.chain(graph, &timeout.to, flow.enter("timeout"))
+                        }
+                        _ => continue,
+                    };
+                    *handled = Flow::meet(handled.take(), end);
+                }
+                flow.produce(sys.output_type_id());
+                Some(flow)
+            }
+
+            Node::Decision(dec) => {
+                if let Some(predicate) = &dec.predicate {
+                    let (type_id, type_name) =
+                        (predicate.input_type_id(), predicate.input_type_name());
+                    self.require(&dec.id, &dec.name, type_id, type_name, &flow);
+                }
+                let branches = [("true", &dec.true_branch), ("false", &dec.false_branch)];
+                let mut end = None;
+                for (label, target) in branches {
+                    if let Some(target) = target {
+                        let branch = self.chain(graph, target, flow.enter(label));
+                        end = Flow::meet(end, branch);
+                    }
+                }
+                end
+            }
+
+            Node::Switch(sw) => {
+                if let Some(discriminator) = &sw.discriminator {
+                    let (type_id, type_name) = (
+                        discriminator.input_type_id(),
+                        discriminator.input_type_name(),
+                    );
+                    self.require(&sw.id, &sw.name, type_id, type_name, &flow);
+                }
+                let default = sw.default.iter().map(|target| ("default", target));
+                let mut end = None;
+                for (label, target) in sw
+                    .cases
+                    .iter()
+                    .map(|(case, target)| (&**case, target))
+                    .chain(default)
+                {
+                    let branch = self.chain(graph, target, flow.enter(label));
+                    end = Flow::meet(end, branch);
+                }
+                end
+            }
+
+            Node::Loop(lp) => {
+                // The termination predicate runs before the first iteration.
+                if let Some(termination) = &lp.termination {
+                    let (type_id, type_name) =
+                        (termination.input_type_id(), termination.input_type_name());
+                    self.require(&lp.id, &lp.name, type_id, type_name, &flow);
+                }
+                // Later iterations only add outputs, so walking the first
+                // one covers them.
+                let body = lp
+                    .body_entry
+                    .as_ref()
+                    .map(|entry| self.chain(graph, entry, flow.enter("body")));
+                // Without a predicate the body runs at least once, unless
+                // the limit is zero.
+                let runs_body = lp.termination.is_none() && lp.max_iterations != Some(0);
+                match body {
+                    Some(end) if runs_body => end,
+                    _ => Some(flow),
+                }
+            }
+
+            Node::Parallel(par) => {
+                let ends: Vec<Option<Flow>> = par
+                    .branches
+                    .iter()
+                    .enumerate()
+                    .map(|(index, entry)| {
+                        let branch = Flow {
+                            path: flow.enter(&format!("branch {index}")).path,
+                            ..Flow::default()
+                        };
+                        self.chain(graph, entry, branch)
+                    })
+                    .collect();
+                let required = par
+                    .options
+                    .policy
+                    .required_successes(par.branches.len())
+                    .unwrap_or(0);
+                if ends.iter().flatten().count() < required {
+                    return None;
+                }
+
+                let mut collected = HashSet::new();
+                for gather in &par.options.gathers {
+                    if let Some(type_id) = gather.guaranteed_type_id() {
+                        collected.insert(gather.output_type_id());
+                        flow.produce(type_id);
+                    }
+                }
+                // A merged type is guaranteed if every choice of `required`
+                // succeeding branches includes one that produces it.
+                let produced: HashSet<TypeId> = ends
+                    .iter()
+                    .flatten()
+                    .flat_map(|end| end.available.iter().copied())
+                    .filter(|type_id| !collected.contains(type_id))
+                    .collect();
+                for type_id in produced {
+                    let missing = ends.iter().filter(|end| {
+                        end.as_ref()
+                            .is_none_or(|end| !end.available.contains(&type_id))
+                    });
+                    if missing.clone().count() < required {
+                        flow.produce(type_id);
+                    } else if !flow.available.contains(&type_id)
+                        && let Some(gap) = missing.flatten().find_map(|end| end.missing(type_id))
+                    {
+                        flow.gaps.insert(type_id, gap.to_vec());
+                    }
+                }
+                flow.produce(TypeId::of::<ParallelOutcome>());
+                Some(flow)
+            }
+
+            Node::Subgraph(sub) => {
+                let bindings = |kind| {
+                    sub.options
+                        .bindings
+                        .iter()
+                        .filter(move |binding| binding.kind() == kind)
+                        .map(Binding::type_id)
+                };
+                let entered = flow.enter("subgraph");
+                let inputs: HashSet<TypeId> = bindings(BindingKind::Input).collect();
+                let child = Flow {
+                    available: entered.available.intersection(&inputs).copied().collect(),
+                    gaps: entered
+                        .gaps
+                        .into_iter()
+                        .filter(|(type_id, _)| inputs.contains(type_id))
+                        .collect(),
+                    path: entered.path,
+                };
+                let end = self.chain(&sub.graph, sub.graph.entry.as_ref()?, child)?;
+                for type_id in bindings(BindingKind::Output) {
+                    match end.missing(type_id) {
+                        None => flow.produce(type_id),
+                        Some(gap) if !flow.available.contains(&type_id) => {
+                            flow.gaps.insert(type_id, gap.to_vec());
+                        }
+                        Some(_) => {}
+                    }
+                }
+                Some(flow)
+            }
+
+            Node::Interrupt(interrupt) => {
+                self.require(
+                    &interrupt.id,
+                    &interrupt.name,
+                    interrupt.request_type_id(),
+                    interrupt.request_type_name(),
+                    &flow,
+                );
+                flow.produce(interrupt.response_type_id());
+                Some(flow)
+            }
+        }
+    }
+
+    /// Reports `output_type` as missing at `node` unless `flow` has it.
+    fn require(
+        &mut self,
+        node: &NodeId,
+        name: &str,
+        output_type: TypeId,
+        type_name: &'static str,
+        flow: &Flow,
+    ) {
+        if let Some(path) = flow.missing(output_type)
+            && self.reported.insert((node.clone(), output_type))
+        {
+            self.errors.push(ValidationError::OutputNotProduced {
+                node: node.clone(),
+                name: name.into(),
+                output_type: type_name,
+                path: path.to_vec(),
+            });
+        }
+    }
+}
@@ -0,0 +1,672 @@
+//! Topology export to Mermaid, Graphviz DOT and serde-serializable data.
+
+use super::Graph;
+use crate::edge::Edge;
+use crate::node::{Node, NodeId, ParallelPolicy, RetryPolicy, SystemNode};
+use crate::subgraph::BindingKind;
+use hashbrown::{HashMap, HashSet};
+use polaris_system::param::AccessMode;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::time::Duration;
+
+/// Serializable description of a graph's topology.
+///
+/// Produced by [`Graph::describe`]. Every node and edge is listed, including
+/// the branch, case and loop-body links that control-flow nodes hold
+/// directly rather than as [`Edge`]s. Serialize it with any serde format for
+/// tooling, or render it with [`to_mermaid`](Self::to_mermaid) and
+/// [`to_dot`](Self::to_dot).
+///
+/// Use [`with_path`](Self::with_path) and [`with_edges`](Self::with_edges)
+/// to highlight the nodes visited and edges followed during a run, from
+/// [`ExecutionResult::path`](crate::ExecutionResult::path) and
+/// [`ExecutionResult::edges`](crate::ExecutionResult::edges).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphDescription {
+    /// ID of the entry node, if any.
+    pub entry: Option<String>,
+    /// Nodes in graph order.
+    pub nodes: Vec<NodeDescription>,
+    /// Edges, grouped by source node in graph order.
+    pub edges: Vec<EdgeDescription>,
+}
+
+/// A node in a [`GraphDescription`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeDescription {
+    /// The node's ID.
+    pub id: String,
+    /// The node's name.
+    pub name: String,
+    /// Node type and type-specific details.
+    #[serde(flatten)]
+    pub kind: NodeKind,
+    /// Whether the node was visited in the overlaid run.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub visited: bool,
+}
+
+/// Type-specific details of a [`NodeDescription`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NodeKind {
+    /// A system node.
+    System {
+        /// Output types the system reads via `Out<T>` or `ErrOut<T>`.
+        inputs: Vec<String>,
+        /// Resources the system accesses.
+        resources: Vec<ResourceDescription>,
+        /// The system's output type.
+        output: String,
+        /// Execution timeout in milliseconds.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timeout_ms: Option<u64>,
+        /// Retry policy for transient failures.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        retry: Option<RetryDescription>,
+    },
+    /// A binary decision node.
+    Decision {
+        /// Output type read by the predicate.
+        predicate_input: Option<String>,
+    },
+    /// A multi-way switch node.
+    Switch {
+        /// Output type read by the discriminator.
+        discriminator_input: Option<String>,
+    },
+    /// A parallel fan-out node.
+    Parallel {
+        /// Maximum number of branches running at once.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_concurrency: Option<usize>,
+        /// Failure policy, e.g. `fail_fast` or `quorum(2)`.
+        policy: String,
+        /// Output types gathered across branches.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        gathered: Vec<String>,
+    },
+    /// A loop node.
+    Loop {
+        /// Output type read by the termination predicate.
+        termination_input: Option<String>,
+        /// Maximum number of iterations.
+        max_iterations: Option<usize>,
+    },
+    /// A subgraph node.
+    Subgraph {
+        /// Values that cross the subgraph boundary.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        bindings: Vec<BindingDescription>,
+        /// Iteration limit for loops in the subgraph without explicit limits.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_iterations: Option<usize>,
+        /// Maximum number of node executions inside the subgraph.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_nodes: Option<usize>,
+        /// The subgraph's own topology.
+        graph: GraphDescription,
+    },
+    /// An interrupt node.
+    Interrupt {
+        /// Output type sent as the request.
+        request: String,
+        /// Output type stored from the response.
+        response: String,
+    },
+}
+
+/// A value crossing a subgraph boundary, in a [`NodeKind::Subgraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BindingDescription {
+    /// Direction and storage of the binding.
+    pub kind: BindingKind,
+    /// The bound type name.
+    pub type_name: String,
+}
+
+/// A resource accessed by a system, in a [`NodeKind::System`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceDescription {
+    /// The resource type name.
+    pub type_name: String,
+    /// Whether the system writes the resource.
+    pub write: bool,
+    /// Whether the resource is global.
+    pub global: bool,
+}
+
+/// A retry policy, in a [`NodeKind::System`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+pub enum RetryDescription {
+    /// Fixed delay between retries.
+    Fixed {
+        /// Maximum number of retries.
+        max_retries: usize,
+        /// Delay between attempts in milliseconds.
+        delay_ms: u64,
+    },
+    /// Exponential backoff between retries.
+    Exponential {
+        /// Maximum number of retries.
+        max_retries: usize,
+        /// Delay before the first retry in milliseconds.
+        initial_delay_ms: u64,
+        /// Cap on the delay in milliseconds.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_delay_ms: Option<u64>,
+    },
+}
+
+/// A directed link between two nodes in a [`GraphDescription`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EdgeDescription {
+    /// Source node ID.
+    pub from: String,
+    /// Target node ID.
+    pub to: String,
+    /// What the link represents.
+    pub kind: EdgeKind,
+    /// Branch or case label, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Whether the overlaid run followed this edge.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub taken: bool,
+}
+
+/// Kind of an [`EdgeDescription`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeKind {
+    /// An [`Edge::Sequential`] edge.
+    Sequential,
+    /// One target of an [`Edge::Conditional`] edge.
+    Conditional,
+    /// One target of an [`Edge::Parallel`] edge.
+    Parallel,
+    /// An [`Edge::LoopBack`] edge.
+    LoopBack,
+    /// An [`Edge::Error`] edge.
+    Error,
+    /// An [`Edge::Timeout`] edge.
+    Timeout,
+    /// A decision branch, switch case, or parallel branch held by a node.
+    Branch,
+    /// The body entry of a loop node.
+    LoopBody,
+}
+
+impl Graph {
+    /// Builds a serializable description of this graph's topology.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polaris_graph::Graph;
+    /// # async fn reason() -> i32 { 1 }
+    /// # async fn respond() -> String { String::new() }
+    /// let mut graph = Graph::new();
+    /// graph.add_system(reason).add_system(respond);
+    ///
+    /// let description = graph.describe();
+    /// assert_eq!(description.nodes.len(), 2);
+    /// assert_eq!(description.edges.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn describe(&self) -> GraphDescription {
+        let mut edges = Vec::new();
+        for node in &self.nodes {
+            let from = node.id();
+            let mut branch = |to: &NodeId, kind: EdgeKind, label: Option<String>| {
+                edges.push(EdgeDescription {
+                    from: from.as_str().to_owned(),
+                    to: to.as_str().to_owned(),
+                    kind,
+                    label,
+                    taken: false,
+                });
+            };
+
+            match node {
+                Node::System(_) | Node::Subgraph(_) | Node::Interrupt(_) => {}
+                Node::Decision(decision) => {
+                    if let Some(target) = &decision.true_branch {
+                        branch(target, EdgeKind::Branch, Some("true".to_owned()));
+                    }
+                    if let Some(target) = &decision.false_branch {
+                        branch(target, EdgeKind::Branch, Some("false".to_owned()));
+                    }
+                }
+                Node::Switch(switch) => {
+                    for (case, target) in &switch.cases {
+                        branch(target, EdgeKind::Branch, Some(case.to_string()));
+                    }
+                    if let Some(target) = &switch.default {
+                        branch(
+                            target,
+                            EdgeKind::Branch,
+                            Some(crate::executor::DEFAULT_SWITCH_CASE.to_owned()),
+                        );
+                    }
+                }
+                Node::Parallel(parallel) => {
+                    for (index, target) in parallel.branches.iter().enumerate() {
+                        branch(target, EdgeKind::Branch, Some(format!("branch {index}")));
+                    }
+                }
+                Node::Loop(lp) => {
+                    if let Some(target) = &lp.body_entry {
+                        branch(target, EdgeKind::LoopBody, None);
+                    }
+                }
+            }
+
+            for edge in self.edges.iter().filter(|edge| edge.from() == from) {
+                match edge {
+                    Edge::Sequential(seq) => branch(&seq.to, EdgeKind::Sequential, None),
+                    Edge::Conditional(cond) => {
+                        branch(
+                            &cond.true_target,
+                            EdgeKind::Conditional,
+                            Some("true".to_owned()),
+                        );
+                        branch(
+                            &cond.false_target,
+                            EdgeKind::Conditional,
+                            Some("false".to_owned()),
+                        );
+                    }
+                    Edge::Parallel(par) => {
+                        for target in &par.targets {
+                            branch(target, EdgeKind::Parallel, None);
+                        }
+                    }
+                    Edge::LoopBack(back) => branch(&back.to, EdgeKind::LoopBack, None),
+                    Edge::Error(err) => branch(&err.to, EdgeKind::Error, None),
+                    Edge::Timeout(timeout) => branch(&timeout.to, EdgeKind::Timeout, None),
+                }
+            }
+        }
+
+        GraphDescription {
+            entry: self.entry.as_ref().map(|id| id.as_str().to_owned()),
+            nodes: self.nodes.iter().map(describe_node).collect(),
+            edges,
+        }
+    }
+
+    /// Renders this graph as a Mermaid flowchart.
+    ///
+    /// Shorthand for `self.describe().to_mermaid()`.
+    #[must_use]
+    pub fn to_mermaid(&self) -> String {
+        self.describe().to_mermaid()
+    }
+
+    /// Renders this graph in Graphviz DOT format.
+    ///
+    /// Shorthand for `self.describe().to_dot()`.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        self.describe().to_dot()
+    }
+}
+
+impl GraphDescription {
+    /// Marks the nodes in `path` as visited.
+    ///
+    /// IDs not present in the description are ignored.
+    #[must_use]
+    pub fn with_path<'a>(mut self, path: impl IntoIterator<Item = &'a NodeId>) -> Self {
+        let visited: HashSet<&str> = path.into_iter().map(NodeId::as_str).collect();
+        for node in &mut self.nodes {
+            node.visited = visited.contains(node.id.as_str());
+        }
+        self
+    }
+
+    /// Marks the edges followed in a run as taken, given as `(from, to)`
+    /// pairs such as [`ExecutionResult::edges`](crate::ExecutionResult::edges).
+    ///
+    /// Pairs not present in the description are ignored.
+    #[must_use]
+    pub fn with_edges<'a>(mut self, edges: impl IntoIterator<Item = &'a (NodeId, NodeId)>) -> Self {
+        let followed: HashSet<(&str, &str)> = edges
+            .into_iter()
+            .map(|(from, to)| (from.as_str(), to.as_str()))
+            .collect();
+        for edge in &mut self.edges {
+            edge.taken = followed.contains(&(edge.from.as_str(), edge.to.as_str()));
+        }
+        self
+    }
+
+    /// Renders the description as a Mermaid flowchart.
+    ///
+    /// Node shapes follow the node kind: rectangles for systems, rhombi for
+    /// decisions, hexagons for switches, subroutines for parallel nodes,
+    /// stadiums for loops, parallelograms for subgraphs and flags for
+    /// interrupts. Error and timeout edges are dotted. Visited nodes from
+    /// [`with_path`](Self::with_path) and taken edges from
+    /// [`with_edges`](Self::with_edges) are highlighted.
+    #[must_use]
+    pub fn to_mermaid(&self) -> String {
+        let aliases = self.aliases();
+        let mut out = String::from("flowchart TD\n");
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let label = mermaid_escape(&node.label());
+            let (open, close) = match node.kind {
+                NodeKind::System { .. } => ("[\"", "\"]"),
+                NodeKind::Decision { .. } => ("{\"", "\"}"),
+                NodeKind::Switch { .. } => ("{{\"", "\"}}"),
+                NodeKind::Parallel { .. } => ("[[\"", "\"]]"),
+                NodeKind::Loop { .. } => ("([\"", "\"])"),
+                NodeKind::Subgraph { .. } => ("[/\"", "\"/]"),
+                NodeKind::Interrupt { .. } => (">\"", "\"]"),
+            };
+            let _ = writeln!(out, "    n{index}{open}{label}{close}");
+        }
+
+        for (_, edge) in self.rendered_edges(&aliases) {
+            let from = &aliases[edge.from.as_str()];
+            let to = &aliases[edge.to.as_str()];
+            let label = edge.display_label().map(|label| mermaid_escape(&label));
+            let arrow = match (edge.kind, label) {
+                (EdgeKind::Error | EdgeKind::Timeout, Some(label)) => format!("-. {label} .->"),
+                (EdgeKind::Error | EdgeKind::Timeout, None) => "-.->".to_owned(),
+                (_, Some(label)) => format!("-- {label} -->"),
+                (_, None) => "-->".to_owned(),
+            };
+            let _ = writeln!(out, "    {from} {arrow} {to}");
+        }
+
+        let visited: Vec<_> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.visited)
+            .map(|(index, _)| format!("n{index}"))
+            .collect();
+        if !visited.is_empty() {
+            out.push_str("    classDef visited fill:#d4edda,stroke:#28a745,stroke-width:2px\n");
+            let _ = writeln!(out, "    class {} visited", visited.join(","));
+        }
+
+        let taken: Vec<_> = self
+            .rendered_edges(&aliases)
+            .filter(|(_, edge)| edge.taken)
+            .map(|(position, _)| position.to_string())
+            .collect();
+        if !taken.is_empty() {
+            let _ = writeln!(
+                out,
+                "    linkStyle {} stroke:#28a745,stroke-width:3px",
+                taken.join(",")
+            );
+        }
+
+        out
+    }
+
+    /// Renders the description in Graphviz DOT format.
+    ///
+    /// Visited nodes from [`with_path`](Self::with_path) are filled and taken
+    /// edges from [`with_edges`](Self::with_edges) are drawn bold.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let aliases = self.aliases();
+        let mut out = String::from("digraph G {\n    rankdir=TB;\n");
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let shape = match node.kind {
+                NodeKind::System { .. } => "box",
+                NodeKind::Decision { .. } => "diamond",
+                NodeKind::Switch { .. } => "hexagon",
+                NodeKind::Parallel { .. } => "parallelogram",
+                NodeKind::Loop { .. } => "ellipse",
+                NodeKind::Subgraph { .. } => "component",
+                NodeKind::Interrupt { .. } => "octagon",
+            };
+            let _ = write!(
+                out,
+                "    n{index} [label=\"{}\", shape={shape}",
+                dot_escape(&node.label())
+            );
+            if node.visited {
+                out.push_str(", style=filled, fillcolor=\"#d4edda\"");
+            }
+            out.push_str("];\n");
+        }
+
+        for (_, edge) in self.rendered_edges(&aliases) {
+            let from = &aliases[edge.from.as_str()];
+            let to = &aliases[edge.to.as_str()];
+            let mut attrs = Vec::new();
+            if let Some(label) = edge.display_label() {
+                attrs.push(format!("label=\"{}\"", dot_escape(&label)));
+            }
+            match edge.kind {
+                EdgeKind::Error => attrs.push("style=dashed, color=red".to_owned()),
+                EdgeKind::Timeout => attrs.push("style=dashed, color=orange".to_owned()),
+                EdgeKind::LoopBack => attrs.push("style=dotted".to_owned()),
+                _ => {}
+            }
+            if edge.taken {
+                attrs.push("penwidth=3, color=\"#28a745\"".to_owned());
+            }
+            if attrs.is_empty() {
+                let _ = writeln!(out, "    {from} -> {to};");
+            } else {
+                let _ = writeln!(out, "    {from} -> {to} [{}];", attrs.join(", "));
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Maps node IDs to diagram identifiers (`n{index}`).
+    fn aliases(&self) -> HashMap<&str, String> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.id.as_str(), format!("n{index}")))
+            .collect()
+    }
+
+    /// Iterates over edges whose ends are both known nodes, with their
+    /// position among rendered edges.
+    fn rendered_edges<'a>(
+        &'a self,
+        aliases: &'a HashMap<&str, String>,
+    ) -> impl Iterator<Item = (usize, &'a EdgeDescription)> {
+        self.edges
+            .iter()
+            .filter(|edge| {
+                aliases.contains_key(edge.from.as_str()) && aliases.contains_key(edge.to.as_str())
+            })
+            .enumerate()
+    }
+}
+
+impl NodeDescription {
+    /// Builds the diagram label: the name plus any timeout or retry policy.
+    fn label(&self) -> String {
+        let mut label = self.name.clone();
+        match &self.kind {
+            NodeKind::System {
+                timeout_ms, retry, ..
+            } => {
+                if let Some(timeout) = timeout_ms {
+                    let _ = write!(label, "\ntimeout {timeout}ms");
+                }
+                match retry {
+                    Some(RetryDescription::Fixed { max_retries, .. }) => {
+                        let _ = write!(label, "\nretry {max_retries}x fixed");
+                    }
+                    Some(RetryDescription::Exponential { max_retries, .. }) => {
+                        let _ = write!(label, "\nretry {max_retries}x exponential");
+                    }
+                    None => {}
+                }
+            }
+            NodeKind::Parallel {
+                max_concurrency,
+                policy,
+                ..
+            } => {
+                let _ = write!(label, "\n{policy}");
+                if let Some(max) = max_concurrency {
+                    let _ = write!(label, ", max {max}");
+                }
+            }
+            NodeKind::Loop {
+                max_iterations: Some(max),
+                ..
+            } => {
+                let _ = write!(label, "\nmax {max} iterations");
+            }
+            NodeKind::Subgraph { graph, .. } => {
+                let _ = write!(label, "\nsubgraph, {} nodes", graph.nodes.len());
+            }
+            _ => {}
+        }
+        label
+    }
+}
+
+impl EdgeDescription {
+    /// Returns the label drawn on the edge.
+    fn display_label(&self) -> Option<String> {
+        match self.kind {
+            EdgeKind::Error => Some("error".to_owned()),
+            EdgeKind::Timeout => Some("timeout".to_owned()),
+            EdgeKind::LoopBody => Some("body".to_owned()),
+            EdgeKind::LoopBack => Some("loop".to_owned()),
+            _ => self.label.clone(),
+        }
+    }
+}
+
+fn describe_node(node: &Node) -> NodeDescription {
+    let kind = match node {
+        Node::System(sys) => describe_system(sys),
+        Node::Decision(decision) => NodeKind::Decision {
+            predicate_input: decision
+                .predicate
+                .as_ref()
+                .map(|predicate| predicate.input_type_name().to_owned()),
+        },
+        Node::Switch(switch) => NodeKind::Switch {
+            discriminator_input: switch
+                .discriminator
+                .as_ref()
+                .map(|discriminator| discriminator.input_type_name().to_owned()),
+        },
+        Node::Parallel(parallel) => NodeKind::Parallel {
+            max_concurrency: parallel.options.max_concurrency,
+            policy: match parallel.options.policy {
+                ParallelPolicy::FailFast => "fail_fast".to_owned(),
+                ParallelPolicy::CollectErrors => "collect_errors".to_owned(),
+                ParallelPolicy::FirstSuccess => "first_success".to_owned(),
+                ParallelPolicy::Quorum(n) => format!("quorum({n})"),
+            },
+            gathered: parallel
+                .options
+                .gathers
+                .iter()
+                .map(|gather| gather.output_type_name().to_owned())
+                .collect(),
+        },
+        Node::Loop(lp) => NodeKind::Loop {
+            termination_input: lp
+                .termination
+                .as_ref()
+                .map(|predicate| predicate.input_type_name().to_owned()),
+            max_iterations: lp.max_iterations,
+        },
+        Node::Subgraph(sub) => NodeKind::Subgraph {
+            bindings: sub
+                .options
+                .bindings
+                .iter()
+                .map(|binding| BindingDescription {
+                    kind: binding.kind(),
+                    type_name: binding.type_name().to_owned(),
+                })
+                .collect(),
+            max_iterations: sub.options.max_iterations,
+            max_nodes: sub.options.max_nodes,
+            graph: sub.graph.describe(),
+        },
+        Node::Interrupt(interrupt) => NodeKind::Interrupt {
+            request: interrupt.request_type_name().to_owned(),
+            response: interrupt.response_type_name().to_owned(),
+        },
+    };
+
+    NodeDescription {
+        id: node.id().as_str().to_owned(),
+        name: node.name().to_owned(),
+        kind,
+        visited: false,
+    }
+}
+
+fn describe_system(sys: &SystemNode) -> NodeKind {
+    let access = sys.system.access();
+    NodeKind::System {
+        inputs: access
+            .outputs
+            .iter()
+            .map(|output| output.type_name.to_owned())
+            .collect(),
+        resources: access
+            .resources
+            .iter()
+            .map(|resource| ResourceDescription {
+                type_name: resource.type_name.to_owned(),
+                write: resource.mode == AccessMode::Write,
+                global: resource.is_global,
+            })
+            .collect(),
+        output: sys.output_type_name().to_owned(),
+        timeout_ms: sys.timeout.map(millis),
+        retry: sys.retry_policy.as_ref().map(|policy| match policy {
+            RetryPolicy::Fixed { max_retries, delay } => RetryDescription::Fixed {
+                max_retries: *max_retries,
+                delay_ms: millis(*delay),
+            },
+            RetryPolicy::Exponential {
+                max_retries,
+                initial_delay,
+                max_delay,
+            } => RetryDescription::Exponential {
+                max_retries: *max_retries,
+                initial_delay_ms: millis(*initial_delay),
+                max_delay_ms: max_delay.map(millis),
+            },
+        }),
+    }
+}
+
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Escapes a label for use inside a quoted Mermaid node or edge label.
+fn mermaid_escape(label: &str) -> String {
+    label.replace('"', "#quot;").replace('\n', "<br/>")
+}
+
+/// Escapes a label for use inside a quoted DOT string.
+fn dot_escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
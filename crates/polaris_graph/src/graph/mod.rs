@@ -4,13 +4,20 @@
 //! as a directed graph of systems and control flow constructs.
 
 mod builder;
+mod dataflow;
+mod export;
 mod validation;
 
 pub use builder::SystemNodeBuilder;
+pub use export::{
+    BindingDescription, EdgeDescription, EdgeKind, GraphDescription, NodeDescription, NodeKind,
+    ResourceDescription, RetryDescription,
+};
 pub use validation::{MergeError, ValidationError, ValidationResult, ValidationWarning};
 
 use crate::edge::{Edge, EdgeId, SequentialEdge};
 use crate::node::{Node, NodeId};
+use crate::subgraph::BindingKind;
 use hashbrown::HashSet;
 use std::any::TypeId;
 
@@ -208,7 +215,7 @@ impl Graph {
                         stack.push(branch.clone());
                     }
                 }
-                Node::System(_) => {}
+                Node::System(_) | Node::Subgraph(_) | Node::Interrupt(_) => {}
             }
 
             // Follow sequential edges from this node
@@ -226,17 +233,30 @@ impl Graph {
 
     /// Collects output types produced by all system nodes reachable from `entry`.
     ///
-    /// Returns `(TypeId, type_name)` pairs for each system node in the subgraph.
+    /// Returns `(TypeId, type_name)` pairs for each system node in the
+    /// subgraph, plus the output bindings of any subgraph nodes and the
+    /// responses of any interrupt nodes.
     pub(crate) fn collect_branch_output_types(
         &self,
         entry: &NodeId,
     ) -> Vec<(TypeId, &'static str)> {
-        self.reachable_nodes(entry)
-            .into_iter()
-            .filter_map(|node| match node {
-                Node::System(sys) => Some((sys.output_type_id(), sys.output_type_name())),
-                _ => None,
-            })
-            .collect()
+        let mut types = Vec::new();
+        for node in self.reachable_nodes(entry) {
+            match node {
+                Node::System(sys) => types.push((sys.output_type_id(), sys.output_type_name())),
+                Node::Subgraph(sub) => types.extend(
+                    sub.options
+                        .bindings
+                        .iter()
+                        .filter(|binding| binding.kind() == BindingKind::Output)
+                        .map(|binding| (binding.type_id(), binding.type_name())),
+                ),
+                Node::Interrupt(interrupt) => {
+                    types.push((interrupt.response_type_id(), interrupt.response_type_name()));
+                }
+                _ => {}
+            }
+        }
+        types
     }
 }
@@ -2,11 +2,12 @@
 
 use super::Graph;
 use crate::edge::{Edge, EdgeId};
-use crate::node::{Node, NodeId};
+use crate::node::{Node, NodeId, ParallelPolicy};
 use hashbrown::{HashMap, HashSet};
 use polaris_system::param::ERROR_CONTEXT;
 use std::any::TypeId;
 use std::fmt;
+use std::sync::Arc;
 
 /// Context tag for timeout path validation.
 ///
@@ -31,6 +32,9 @@ impl Graph {
     /// - Warns if parallel branches produce overlapping output types
     /// - Errors if a loop predicate reads an output type no body system produces
     /// - Ensures edge requirements are met (e.g. error edges target nodes that can fail)
+    /// - Warns if an output is read on a path where nothing produces it,
+    ///   since it may be inserted into the context before the run (see
+    ///   [`Graph::validate_outputs`] for the strict check)
     ///
     /// # Returns
     ///
@@ -57,6 +61,33 @@ impl Graph {
     /// }
     /// ```
     pub fn validate(&self) -> ValidationResult {
+        let mut result = self.validate_structure();
+        result.warnings.extend(
+            self.validate_outputs(std::iter::empty())
+                .into_iter()
+                .filter_map(|err| match err {
+                    ValidationError::OutputNotProduced {
+                        node,
+                        name,
+                        output_type,
+                        path,
+                    } => Some(ValidationWarning::OutputNotProduced {
+                        node,
+                        name,
+                        output_type,
+                        path,
+                    }),
+                    _ => None,
+                }),
+        );
+        result
+    }
+
+    /// Runs every check of [`Graph::validate`] except output dataflow.
+    ///
+    /// Subgraphs are checked this way, since their outputs depend on the
+    /// bindings of the node running them.
+    fn validate_structure(&self) -> ValidationResult {
         let mut errors = Vec::new();
         let mut warnings = Vec::new();
 
@@ -106,9 +137,45 @@ impl Graph {
             );
         }
 
+        self.validate_interrupt_placement(&mut errors);
+
         ValidationResult { errors, warnings }
     }
 
+    /// Rejects interrupt nodes inside parallel branches and subgraphs.
+    ///
+    /// An interrupt suspends the whole run with a cursor into the
+    /// top-level path, which cannot describe a point inside concurrent
+    /// branches or an isolated subgraph context. Interrupts in nested
+    /// subgraphs are reported through [`ValidationError::InvalidSubgraph`].
+    fn validate_interrupt_placement(&self, errors: &mut Vec<ValidationError>) {
+        let mut reported: HashSet<NodeId> = HashSet::new();
+        for node in &self.nodes {
+            let (parent, nested): (&Arc<str>, Vec<&Node>) = match node {
+                Node::Parallel(par) => (
+                    &par.name,
+                    par.branches
+                        .iter()
+                        .flat_map(|branch| self.reachable_nodes(branch))
+                        .collect(),
+                ),
+                Node::Subgraph(sub) => (&sub.name, sub.graph.nodes.iter().collect()),
+                _ => continue,
+            };
+            for nested in nested {
+                if let Node::Interrupt(interrupt) = nested
+                    && reported.insert(interrupt.id.clone())
+                {
+                    errors.push(ValidationError::NestedInterrupt {
+                        node: interrupt.id.clone(),
+                        name: interrupt.name.clone(),
+                        parent: parent.clone(),
+                    });
+                }
+            }
+        }
+    }
+
     /// Validates a single edge.
     ///
     /// # Edge Type Validation
@@ -274,11 +341,17 @@ impl Graph {
     /// ## `ParallelNode`
     /// - Must have at least one branch
     /// - All branch targets must reference existing nodes
+    /// - `max_concurrency`, if set, must be at least 1
+    /// - A [`ParallelPolicy::Quorum`] must be between 1 and the branch count
     ///
     /// ## `LoopNode`
     /// - Must have either a termination predicate or `max_iterations`
     /// - Must have a body entry point
     /// - Body entry must reference an existing node
+    ///
+    /// ## `SubgraphNode`
+    /// - The subgraph must itself be structurally valid; its warnings are
+    ///   reported as-is. Its outputs are checked by the dataflow pass.
     fn validate_node(
         &self,
         node: &Node,
@@ -301,7 +374,7 @@ impl Graph {
                     if !satisfied {
                         errors.push(ValidationError::MissingEdgeRequirement {
                             node: sys.id.clone(),
-                            name: sys.system.name(),
+                            name: sys.system.name().into(),
                             requirement: tag,
                         });
                     }
@@ -313,13 +386,13 @@ impl Graph {
                 if dec.predicate.is_none() {
                     errors.push(ValidationError::MissingPredicate {
                         node: dec.id.clone(),
-                        name: dec.name,
+                        name: dec.name.clone(),
                     });
                 }
                 if dec.true_branch.is_none() {
                     errors.push(ValidationError::MissingBranch {
                         node: dec.id.clone(),
-                        name: dec.name,
+                        name: dec.name.clone(),
                         branch: "true",
                     });
                 } else if let Some(target) = &dec.true_branch
@@ -334,7 +407,7 @@ impl Graph {
                 if dec.false_branch.is_none() {
                     errors.push(ValidationError::MissingBranch {
                         node: dec.id.clone(),
-                        name: dec.name,
+                        name: dec.name.clone(),
                         branch: "false",
                     });
                 } else if let Some(target) = &dec.false_branch
@@ -353,20 +426,20 @@ impl Graph {
                 if sw.discriminator.is_none() {
                     errors.push(ValidationError::MissingDiscriminator {
                         node: sw.id.clone(),
-                        name: sw.name,
+                        name: sw.name.clone(),
                     });
                 }
                 if sw.cases.is_empty() && sw.default.is_none() {
                     errors.push(ValidationError::EmptySwitch {
                         node: sw.id.clone(),
-                        name: sw.name,
+                        name: sw.name.clone(),
                     });
                 }
                 for (case_name, target) in &sw.cases {
                     if !valid_nodes.contains(target) {
                         errors.push(ValidationError::InvalidCaseTarget {
                             node: sw.id.clone(),
-                            case: case_name,
+                            case: case_name.clone(),
                             target: target.clone(),
                         });
                     }
@@ -386,7 +459,7 @@ impl Graph {
                 if par.branches.is_empty() {
                     errors.push(ValidationError::EmptyParallel {
                         node: par.id.clone(),
-                        name: par.name,
+                        name: par.name.clone(),
                     });
                 }
                 for branch in &par.branches {
@@ -398,15 +471,39 @@ impl Graph {
                         });
                     }
                 }
+                if par.options.max_concurrency == Some(0) {
+                    errors.push(ValidationError::ZeroParallelConcurrency {
+                        node: par.id.clone(),
+                        name: par.name.clone(),
+                    });
+                }
+                if let ParallelPolicy::Quorum(quorum) = par.options.policy
+                    && (quorum == 0 || quorum > par.branches.len())
+                {
+                    errors.push(ValidationError::InvalidQuorum {
+                        node: par.id.clone(),
+                        name: par.name.clone(),
+                        quorum,
+                        branches: par.branches.len(),
+                    });
+                }
 
                 // Check for overlapping output types across parallel branches.
                 // If 2+ branches produce the same type, the last branch in
-                // declaration order silently wins at merge time.
+                // declaration order silently wins at merge time, unless the
+                // type is gathered.
+                let gathered: HashSet<TypeId> = par
+                    .options
+                    .gathers
+                    .iter()
+                    .map(|gather| gather.output_type_id())
+                    .collect();
                 let mut type_counts: HashMap<TypeId, (usize, &'static str)> = HashMap::new();
                 for branch in &par.branches {
                     let branch_types: HashSet<_> = self
                         .collect_branch_output_types(branch)
                         .into_iter()
+                        .filter(|(type_id, _)| !gathered.contains(type_id))
                         .collect();
                     for (type_id, type_name) in branch_types {
                         type_counts
@@ -419,7 +516,7 @@ impl Graph {
                     if count > 1 {
                         warnings.push(ValidationWarning::ConflictingParallelOutputs {
                             node: par.id.clone(),
-                            name: par.name,
+                            name: par.name.clone(),
                             output_type: type_name,
                         });
                     }
@@ -432,13 +529,13 @@ impl Graph {
                 if lp.termination.is_none() && lp.max_iterations.is_none() {
                     errors.push(ValidationError::NoTerminationCondition {
                         node: lp.id.clone(),
-                        name: lp.name,
+                        name: lp.name.clone(),
                     });
                 }
                 if lp.body_entry.is_none() {
                     errors.push(ValidationError::EmptyLoopBody {
                         node: lp.id.clone(),
-                        name: lp.name,
+                        name: lp.name.clone(),
                     });
                 } else if let Some(body) = &lp.body_entry
                     && !valid_nodes.contains(body)
@@ -465,12 +562,28 @@ impl Graph {
                     if !body_output_types.contains(&predicate_input) {
                         errors.push(ValidationError::LoopPredicateOutputNotProduced {
                             node: lp.id.clone(),
-                            name: lp.name,
+                            name: lp.name.clone(),
                             expected_output: term.input_type_name(),
                         });
                     }
                 }
             }
+
+            // Subgraph nodes need a valid subgraph
+            Node::Subgraph(sub) => {
+                let result = sub.graph.validate_structure();
+                if !result.errors.is_empty() {
+                    errors.push(ValidationError::InvalidSubgraph {
+                        node: sub.id.clone(),
+                        name: sub.name.clone(),
+                        errors: result.errors,
+                    });
+                }
+                warnings.extend(result.warnings);
+            }
+
+            // Interrupt placement is checked by `validate_interrupt_placement`
+            Node::Interrupt(_) => {}
         }
     }
 }
@@ -551,14 +664,37 @@ impl fmt::Display for ValidationResult {
 pub enum ValidationWarning {
     /// Two or more parallel branches produce the same output type.
     /// The last branch in declaration order will win at merge time.
+    ///
+    /// Register a reducer with
+    /// [`ParallelOptions::gather`](crate::node::ParallelOptions::gather) to
+    /// combine the values instead.
     ConflictingParallelOutputs {
         /// The parallel node ID.
         node: NodeId,
         /// The parallel node name.
-        name: &'static str,
+        name: Arc<str>,
         /// The conflicting output type name.
         output_type: &'static str,
     },
+    /// A node reads an output type that the graph does not produce on every
+    /// path leading to it.
+    ///
+    /// The run still succeeds if the output is in the context before it
+    /// starts, for example from agent setup or an earlier session turn.
+    /// [`Graph::validate_outputs`] reports the same finding as a
+    /// [`ValidationError::OutputNotProduced`] given the outputs known to be
+    /// available.
+    OutputNotProduced {
+        /// The reading node ID.
+        node: NodeId,
+        /// The reading node name.
+        name: Arc<str>,
+        /// The output type read.
+        output_type: &'static str,
+        /// Names of the nodes on a path to the reader that never produces
+        /// the output, ending with the reader.
+        path: Vec<String>,
+    },
 }
 
 impl fmt::Display for ValidationWarning {
@@ -571,7 +707,19 @@ impl fmt::Display for ValidationWarning {
             } => {
                 write!(
                     f,
-                    "parallel node '{name}' ({node}) has multiple branches producing output type '{output_type}'; last branch wins"
+                    "parallel node '{name}' ({node}) has multiple branches producing output type '{output_type}'; last branch wins (use ParallelOptions::gather to combine them)"
+                )
+            }
+            ValidationWarning::OutputNotProduced {
+                node,
+                name,
+                output_type,
+                path,
+            } => {
+                write!(
+                    f,
+                    "node '{name}' ({node}) reads output type '{output_type}' that is not produced on path: {}; it must be in the context before the run",
+                    path.join(" -> ")
                 )
             }
         }
@@ -609,14 +757,14 @@ pub enum ValidationError {
         /// The node ID.
         node: NodeId,
         /// The node name.
-        name: &'static str,
+        name: Arc<str>,
     },
     /// A decision node is missing a branch target.
     MissingBranch {
         /// The node ID.
         node: NodeId,
         /// The node name.
-        name: &'static str,
+        name: Arc<str>,
         /// Which branch is missing ("true" or "false").
         branch: &'static str,
     },
@@ -634,21 +782,21 @@ pub enum ValidationError {
         /// The node ID.
         node: NodeId,
         /// The node name.
-        name: &'static str,
+        name: Arc<str>,
     },
     /// A switch node has no cases and no default.
     EmptySwitch {
         /// The node ID.
         node: NodeId,
         /// The node name.
-        name: &'static str,
+        name: Arc<str>,
     },
     /// A switch case target references an invalid node.
     InvalidCaseTarget {
         /// The node ID.
         node: NodeId,
         /// The case name.
-        case: &'static str,
+        case: Arc<str>,
         /// The invalid target node ID.
         target: NodeId,
     },
@@ -664,21 +812,39 @@ pub enum ValidationError {
         /// The node ID.
         node: NodeId,
         /// The node name.
-        name: &'static str,
+        name: Arc<str>,
+    },
+    /// A parallel node has a concurrency limit of zero.
+    ZeroParallelConcurrency {
+        /// The node ID.
+        node: NodeId,
+        /// The node name.
+        name: Arc<str>,
+    },
+    /// A parallel node's quorum is zero or exceeds its branch count.
+    InvalidQuorum {
+        /// The node ID.
+        node: NodeId,
+        /// The node name.
+        name: Arc<str>,
+        /// The required number of successful branches.
+        quorum: usize,
+        /// The number of branches.
+        branches: usize,
     },
     /// A loop node has no termination condition.
     NoTerminationCondition {
         /// The node ID.
         node: NodeId,
         /// The node name.
-        name: &'static str,
+        name: Arc<str>,
     },
     /// A loop node has no body.
     EmptyLoopBody {
         /// The node ID.
         node: NodeId,
         /// The node name.
-        name: &'static str,
+        name: Arc<str>,
     },
     /// A loop body entry references an invalid node.
     InvalidLoopBody {
@@ -693,7 +859,7 @@ pub enum ValidationError {
         /// The loop node ID.
         node: NodeId,
         /// The loop node name.
-        name: &'static str,
+        name: Arc<str>,
         /// The output type the predicate expects.
         expected_output: &'static str,
     },
@@ -705,10 +871,46 @@ pub enum ValidationError {
         /// The node ID.
         node: NodeId,
         /// The system name.
-        name: &'static str,
+        name: Arc<str>,
         /// A human-readable description of the required edge type.
         requirement: &'static str,
     },
+    /// A node reads an output type that is not produced on every path
+    /// leading to it.
+    ///
+    /// Reading it fails at runtime with an `OutputNotFound` error whenever
+    /// `path` is taken.
+    OutputNotProduced {
+        /// The reading node ID.
+        node: NodeId,
+        /// The reading node name.
+        name: Arc<str>,
+        /// The output type read.
+        output_type: &'static str,
+        /// Names of the nodes on a path to the reader that never produces
+        /// the output, ending with the reader. Steps into a branch are
+        /// suffixed with the branch taken, e.g. `route (billing)`.
+        path: Vec<String>,
+    },
+    /// A subgraph node's graph failed validation.
+    InvalidSubgraph {
+        /// The subgraph node ID.
+        node: NodeId,
+        /// The subgraph node name.
+        name: Arc<str>,
+        /// The errors found in the subgraph.
+        errors: Vec<ValidationError>,
+    },
+    /// An interrupt node is inside a parallel branch or a subgraph, where
+    /// the run cannot be suspended.
+    NestedInterrupt {
+        /// The interrupt node ID.
+        node: NodeId,
+        /// The interrupt node name.
+        name: Arc<str>,
+        /// The name of the parallel or subgraph node containing it.
+        parent: Arc<str>,
+    },
 }
 
 impl fmt::Display for ValidationError {
@@ -767,6 +969,23 @@ impl fmt::Display for ValidationError {
             ValidationError::EmptyParallel { node, name } => {
                 write!(f, "parallel node '{name}' ({node}) has no branches")
             }
+            ValidationError::ZeroParallelConcurrency { node, name } => {
+                write!(
+                    f,
+                    "parallel node '{name}' ({node}) has a max concurrency of 0"
+                )
+            }
+            ValidationError::InvalidQuorum {
+                node,
+                name,
+                quorum,
+                branches,
+            } => {
+                write!(
+                    f,
+                    "parallel node '{name}' ({node}) requires a quorum of {quorum} but has {branches} branches"
+                )
+            }
             ValidationError::NoTerminationCondition { node, name } => {
                 write!(
                     f,
@@ -802,6 +1021,36 @@ impl fmt::Display for ValidationError {
                     "system '{name}' ({node}) requires {requirement} edge context but is not reachable via a matching edge"
                 )
             }
+            ValidationError::OutputNotProduced {
+                node,
+                name,
+                output_type,
+                path,
+            } => {
+                write!(
+                    f,
+                    "node '{name}' ({node}) reads output type '{output_type}' that is not produced on path: {}",
+                    path.join(" -> ")
+                )
+            }
+            ValidationError::InvalidSubgraph { node, name, errors } => {
+                write!(
+                    f,
+                    "subgraph '{name}' ({node}) has {} validation error(s)",
+                    errors.len()
+                )?;
+                if let Some(first) = errors.first() {
+                    write!(f, ": {first}")?;
+                }
+                Ok(())
+            }
+            ValidationError::NestedInterrupt { node, name, parent } => {
+                write!(
+                    f,
+                    "interrupt node '{name}' ({node}) is inside '{parent}'; interrupts \
+                     cannot run in parallel branches or subgraphs"
+                )
+            }
         }
     }
 }
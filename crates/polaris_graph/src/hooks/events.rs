@@ -21,6 +21,7 @@
 //! ```
 
 use crate::{ExecutionError, node::NodeId};
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Unified event enum for all graph execution hooks.
@@ -39,7 +40,7 @@ pub enum GraphEvent {
         /// Number of nodes in the graph.
         node_count: usize,
         /// Node ID to name mapping for all nodes in the graph.
-        node_map: Vec<(NodeId, &'static str)>,
+        node_map: Vec<(NodeId, Arc<str>)>,
     },
 
     /// Event fired after graph execution completes.
@@ -56,6 +57,22 @@ pub enum GraphEvent {
         error: ExecutionError,
     },
 
+    /// Event fired when graph execution is cancelled.
+    GraphCancelled {
+        /// Execution duration until cancellation was observed.
+        duration: Duration,
+    },
+
+    /// Event fired when graph execution stops at an interrupt node.
+    GraphInterrupted {
+        /// The interrupt node ID.
+        node_id: NodeId,
+        /// The interrupt node's name.
+        node_name: Arc<str>,
+        /// Execution duration until the interrupt was reached.
+        duration: Duration,
+    },
+
     // ─────────────────────────────────────────────────────────────────────────
     // System Events
     // ─────────────────────────────────────────────────────────────────────────
@@ -95,7 +112,7 @@ pub enum GraphEvent {
         /// The node ID of the decision node.
         node_id: NodeId,
         /// The decision node's name.
-        node_name: &'static str,
+        node_name: Arc<str>,
     },
 
     /// Event emitted after a decision branch is selected and executed.
@@ -103,7 +120,7 @@ pub enum GraphEvent {
         /// The node ID of the decision node.
         node_id: NodeId,
         /// The decision node's name.
-        node_name: &'static str,
+        node_name: Arc<str>,
         /// The branch that was selected ("true" or "false").
         selected_branch: &'static str,
     },
@@ -116,7 +133,7 @@ pub enum GraphEvent {
         /// The node ID of the switch node.
         node_id: NodeId,
         /// The switch node's name.
-        node_name: &'static str,
+        node_name: Arc<str>,
         /// Number of cases in the switch.
         case_count: usize,
         /// Whether a default case exists.
@@ -128,9 +145,9 @@ pub enum GraphEvent {
         /// The node ID of the switch node.
         node_id: NodeId,
         /// The switch node's name.
-        node_name: &'static str,
+        node_name: Arc<str>,
         /// The case key that was selected.
-        selected_case: &'static str,
+        selected_case: Arc<str>,
         /// Whether the default case was used.
         used_default: bool,
     },
@@ -143,7 +160,7 @@ pub enum GraphEvent {
         /// The node ID of the loop node.
         node_id: NodeId,
         /// The loop's name.
-        loop_name: &'static str,
+        loop_name: Arc<str>,
         /// The maximum iterations allowed, if set.
         max_iterations: Option<usize>,
     },
@@ -153,7 +170,7 @@ pub enum GraphEvent {
         /// The node ID of the loop node.
         node_id: NodeId,
         /// The loop's name.
-        loop_name: &'static str,
+        loop_name: Arc<str>,
         /// The current iteration number (0-indexed).
         iteration: usize,
     },
@@ -163,7 +180,7 @@ pub enum GraphEvent {
         /// The node ID of the loop node.
         node_id: NodeId,
         /// The loop's name.
-        loop_name: &'static str,
+        loop_name: Arc<str>,
         /// The total number of iterations executed.
         iterations: usize,
         /// Total nodes executed across all iterations.
@@ -180,7 +197,7 @@ pub enum GraphEvent {
         /// The node ID of the parallel node.
         node_id: NodeId,
         /// The parallel node's name.
-        node_name: &'static str,
+        node_name: Arc<str>,
         /// The number of parallel branches.
         branch_count: usize,
     },
@@ -190,7 +207,7 @@ pub enum GraphEvent {
         /// The node ID of the parallel node.
         node_id: NodeId,
         /// The parallel node's name.
-        node_name: &'static str,
+        node_name: Arc<str>,
         /// The number of parallel branches.
         branch_count: usize,
         /// Total nodes executed across all branches.
@@ -198,6 +215,43 @@ pub enum GraphEvent {
         /// Total duration for parallel execution.
         duration: Duration,
     },
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Subgraph Events
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Event emitted before a subgraph starts execution.
+    SubgraphStart {
+        /// The node ID of the subgraph node.
+        node_id: NodeId,
+        /// The subgraph node's name.
+        node_name: Arc<str>,
+        /// The number of nodes in the subgraph.
+        node_count: usize,
+    },
+
+    /// Event emitted after a subgraph completes successfully.
+    SubgraphComplete {
+        /// The node ID of the subgraph node.
+        node_id: NodeId,
+        /// The subgraph node's name.
+        node_name: Arc<str>,
+        /// Total nodes executed inside the subgraph.
+        nodes_executed: usize,
+        /// Total duration for the subgraph.
+        duration: Duration,
+    },
+
+    /// Event emitted when a subgraph fails.
+    SubgraphFailure {
+        /// The node ID of the subgraph node.
+        node_id: NodeId,
+        /// The subgraph node's name.
+        node_name: Arc<str>,
+        /// The error that ended the subgraph.
+        error: String,
+        /// Time spent in the subgraph before it failed.
+        duration: Duration,
+    },
 }
 
 impl GraphEvent {
@@ -210,6 +264,8 @@ impl GraphEvent {
             GraphEvent::GraphStart { .. } => "OnGraphStart",
             GraphEvent::GraphComplete { .. } => "OnGraphComplete",
             GraphEvent::GraphFailure { .. } => "OnGraphFailure",
+            GraphEvent::GraphCancelled { .. } => "OnGraphCancelled",
+            GraphEvent::GraphInterrupted { .. } => "OnGraphInterrupted",
             GraphEvent::SystemStart { .. } => "OnSystemStart",
             GraphEvent::SystemComplete { .. } => "OnSystemComplete",
             GraphEvent::SystemError { .. } => "OnSystemError",
@@ -222,20 +278,26 @@ impl GraphEvent {
             GraphEvent::LoopEnd { .. } => "OnLoopEnd",
             GraphEvent::ParallelStart { .. } => "OnParallelStart",
             GraphEvent::ParallelComplete { .. } => "OnParallelComplete",
+            GraphEvent::SubgraphStart { .. } => "OnSubgraphStart",
+            GraphEvent::SubgraphComplete { .. } => "OnSubgraphComplete",
+            GraphEvent::SubgraphFailure { .. } => "OnSubgraphFailure",
         }
     }
 
     /// Returns the node ID if this is a node-level event.
     ///
-    /// Graph-level events (like `GraphStart`, `GraphComplete`) return `None`.
+    /// Graph-level events (like `GraphStart`, `GraphComplete`) return `None`,
+    /// except `GraphInterrupted`, which names the interrupt node.
     /// Node-specific events return `Some(node_id)`.
     #[must_use]
     pub fn node_id(&self) -> Option<NodeId> {
         match self {
             GraphEvent::GraphStart { .. }
             | GraphEvent::GraphComplete { .. }
-            | GraphEvent::GraphFailure { .. } => None,
-            GraphEvent::SystemStart { node_id, .. }
+            | GraphEvent::GraphFailure { .. }
+            | GraphEvent::GraphCancelled { .. } => None,
+            GraphEvent::GraphInterrupted { node_id, .. }
+            | GraphEvent::SystemStart { node_id, .. }
             | GraphEvent::SystemComplete { node_id, .. }
             | GraphEvent::SystemError { node_id, .. }
             | GraphEvent::DecisionStart { node_id, .. }
@@ -246,7 +308,10 @@ impl GraphEvent {
             | GraphEvent::LoopIteration { node_id, .. }
             | GraphEvent::LoopEnd { node_id, .. }
             | GraphEvent::ParallelStart { node_id, .. }
-            | GraphEvent::ParallelComplete { node_id, .. } => Some(node_id.clone()),
+            | GraphEvent::ParallelComplete { node_id, .. }
+            | GraphEvent::SubgraphStart { node_id, .. }
+            | GraphEvent::SubgraphComplete { node_id, .. }
+            | GraphEvent::SubgraphFailure { node_id, .. } => Some(node_id.clone()),
         }
     }
 }
@@ -280,6 +345,20 @@ impl std::fmt::Display for GraphEvent {
             GraphEvent::GraphFailure { error } => {
                 write!(f, "GraphFailure(error: {})", error)
             }
+            GraphEvent::GraphCancelled { duration } => {
+                write!(f, "GraphCancelled(duration: {:?})", duration)
+            }
+            GraphEvent::GraphInterrupted {
+                node_id,
+                node_name,
+                duration,
+            } => {
+                write!(
+                    f,
+                    "GraphInterrupted({} @ {:?}, duration: {:?})",
+                    node_name, node_id, duration
+                )
+            }
             GraphEvent::SystemStart {
                 node_id,
                 system_name,
@@ -405,6 +484,41 @@ impl std::fmt::Display for GraphEvent {
                     node_name, node_id, branch_count, total_nodes_executed, duration
                 )
             }
+            GraphEvent::SubgraphStart {
+                node_id,
+                node_name,
+                node_count,
+            } => {
+                write!(
+                    f,
+                    "SubgraphStart({} @ {:?}, nodes: {})",
+                    node_name, node_id, node_count
+                )
+            }
+            GraphEvent::SubgraphComplete {
+                node_id,
+                node_name,
+                nodes_executed,
+                duration,
+            } => {
+                write!(
+                    f,
+                    "SubgraphComplete({} @ {:?}, executed: {}, duration: {:?})",
+                    node_name, node_id, nodes_executed, duration
+                )
+            }
+            GraphEvent::SubgraphFailure {
+                node_id,
+                node_name,
+                error,
+                duration,
+            } => {
+                write!(
+                    f,
+                    "SubgraphFailure({} @ {:?}, error: {}, duration: {:?})",
+                    node_name, node_id, error, duration
+                )
+            }
         }
     }
 }
@@ -170,6 +170,53 @@ impl Schedule for OnParallelStart {}
 pub struct OnParallelComplete;
 impl Schedule for OnParallelComplete {}
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Subgraph Schedules
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Marker type for hooks called before a subgraph starts execution.
+///
+/// Hooks run against the subgraph's isolated context after input bindings
+/// are applied, so resources provided here are visible only to the subgraph.
+///
+/// # Validation
+///
+/// Resources provided by `OnSubgraphStart` hooks are **not** considered during
+/// validation.
+///
+/// Event data: [`GraphEvent::SubgraphStart`](super::events::GraphEvent::SubgraphStart)
+pub struct OnSubgraphStart;
+impl Schedule for OnSubgraphStart {}
+
+/// Marker type for hooks called after a subgraph completes successfully.
+///
+/// Hooks run against the subgraph's isolated context, like `OnSubgraphStart`,
+/// before output and resource bindings are returned to the parent.
+///
+/// # Validation
+///
+/// Resources provided by `OnSubgraphComplete` hooks are **not** considered during
+/// validation.
+///
+/// Event data: [`GraphEvent::SubgraphComplete`](super::events::GraphEvent::SubgraphComplete)
+pub struct OnSubgraphComplete;
+impl Schedule for OnSubgraphComplete {}
+
+/// Marker type for hooks called when a subgraph fails, is cancelled, or
+/// exceeds a limit.
+///
+/// Fires in place of `OnSubgraphComplete`, against the subgraph's isolated
+/// context before lent resources are returned to the parent.
+///
+/// # Validation
+///
+/// Resources provided by `OnSubgraphFailure` hooks are **not** considered during
+/// validation.
+///
+/// Event data: [`GraphEvent::SubgraphFailure`](super::events::GraphEvent::SubgraphFailure)
+pub struct OnSubgraphFailure;
+impl Schedule for OnSubgraphFailure {}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Graph-Level Schedules
 // ─────────────────────────────────────────────────────────────────────────────
@@ -212,6 +259,26 @@ impl Schedule for OnGraphComplete {}
 pub struct OnGraphFailure;
 impl Schedule for OnGraphFailure {}
 
+/// Marker type for hooks called when graph execution is cancelled.
+///
+/// This hook fires once, in place of `OnGraphFailure`, when execution stops
+/// because its cancellation token was triggered. Use this for cleanup or to
+/// persist partial progress.
+///
+/// Event data: [`GraphEvent::GraphCancelled`](super::events::GraphEvent::GraphCancelled)
+pub struct OnGraphCancelled;
+impl Schedule for OnGraphCancelled {}
+
+/// Marker type for hooks called when graph execution stops at an interrupt node.
+///
+/// This hook fires once, in place of `OnGraphFailure`, when the run is
+/// suspended to wait for a response. Use this to notify whoever must answer
+/// the request or to persist the suspended run.
+///
+/// Event data: [`GraphEvent::GraphInterrupted`](super::events::GraphEvent::GraphInterrupted)
+pub struct OnGraphInterrupted;
+impl Schedule for OnGraphInterrupted {}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Composite Schedule Type
 // ─────────────────────────────────────────────────────────────────────────────
@@ -242,6 +309,8 @@ pub type AllGraphSchedules = (
     OnGraphStart,
     OnGraphComplete,
     OnGraphFailure,
+    OnGraphCancelled,
+    OnGraphInterrupted,
     OnSystemStart,
     OnSystemComplete,
     OnSystemError,
@@ -254,4 +323,7 @@ pub type AllGraphSchedules = (
     OnLoopEnd,
     OnParallelStart,
     OnParallelComplete,
+    OnSubgraphStart,
+    OnSubgraphComplete,
+    OnSubgraphFailure,
 );
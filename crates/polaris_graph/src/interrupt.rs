@@ -0,0 +1,142 @@
+//! Suspending a run to wait for input from outside the graph.
+//!
+//! An interrupt node, added with [`Graph::add_interrupt`], pauses a run until
+//! an answer arrives from outside the graph, such as a human approval. When
+//! the executor reaches the node, it serializes the request output produced
+//! by an earlier system and stops with [`ExecutionError::Interrupted`]. The
+//! error carries an [`Interrupt`] holding the [`InterruptRequest`] and an
+//! [`ExecutionCursor`] positioned at the node.
+//!
+//! To continue, run the graph again with [`RunOptions::resume_from`] and the
+//! answer in [`RunOptions::with_interrupt_response`]. The answer is
+//! deserialized into the node's response type and stored as an output, and
+//! execution continues with the node after the interrupt. Nothing is held
+//! open while the run is suspended, so the request and cursor can be
+//! persisted and the run resumed in another process.
+//!
+//! Interrupts are not supported inside parallel branches or subgraphs, which
+//! record no cursor. [`Graph::validate`](crate::Graph::validate) rejects them
+//! there with [`ValidationError::NestedInterrupt`](crate::graph::ValidationError::NestedInterrupt).
+//!
+//! # Example
+//!
+//! ```
+//! # async fn example_fn() -> Result<(), Box<dyn std::error::Error>> {
+//! use polaris_graph::{ExecutionError, Graph, GraphExecutor, RunOptions};
+//! use polaris_system::param::{Out, SystemContext};
+//! use polaris_system::system;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize)]
+//! struct Approval { action: String }
+//!
+//! #[derive(Deserialize)]
+//! struct Decision { approved: bool }
+//!
+//! #[system]
+//! async fn propose() -> Approval { Approval { action: "refund".into() } }
+//!
+//! #[system]
+//! async fn act(decision: Out<Decision>) -> bool { decision.approved }
+//!
+//! let mut graph = Graph::new();
+//! graph
+//!     .add_system(propose)
+//!     .add_interrupt::<Approval, Decision>("approve")
+//!     .add_system(act);
+//!
+//! let executor = GraphExecutor::new();
+//! let mut ctx = SystemContext::new();
+//! let Err(ExecutionError::Interrupted(interrupt)) =
+//!     executor.execute(&graph, &mut ctx, None).await
+//! else {
+//!     panic!("expected the run to stop at the interrupt");
+//! };
+//! assert_eq!(interrupt.request.payload["action"], "refund");
+//!
+//! let options = RunOptions::new()
+//!     .resume_from(interrupt.cursor)
+//!     .with_interrupt_response(serde_json::json!({ "approved": true }));
+//! executor.execute_with(&graph, &mut ctx, None, options).await?;
+//! assert!(*ctx.get_output::<bool>()?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Graph::add_interrupt`]: crate::Graph::add_interrupt
+//! [`ExecutionError::Interrupted`]: crate::ExecutionError::Interrupted
+//! [`ExecutionCursor`]: crate::ExecutionCursor
+//! [`RunOptions::resume_from`]: crate::RunOptions::resume_from
+//! [`RunOptions::with_interrupt_response`]: crate::RunOptions::with_interrupt_response
+
+use crate::executor::ExecutionCursor;
+use crate::node::NodeId;
+use polaris_system::param::SystemContext;
+use polaris_system::resource::Output;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Serializes the request output read by an interrupt node.
+pub(crate) type EncodeRequest = fn(&SystemContext<'_>) -> Result<serde_json::Value, String>;
+
+/// Deserializes a response and stores it as an output.
+pub(crate) type DecodeResponse =
+    fn(serde_json::Value, &mut SystemContext<'_>) -> Result<(), String>;
+
+/// A request for input raised by an interrupt node.
+///
+/// Serializable, so it can be persisted alongside the cursor of the
+/// suspended run and shown to whoever answers it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InterruptRequest {
+    /// Name of the interrupt node.
+    pub node_name: String,
+    /// Type name of the request payload.
+    pub request_type: String,
+    /// Type name of the expected response.
+    pub response_type: String,
+    /// The serialized request output.
+    pub payload: serde_json::Value,
+}
+
+impl InterruptRequest {
+    /// Deserializes the payload back into its request type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload does not match `T`.
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(&self.payload)
+    }
+}
+
+/// A run suspended at an interrupt node.
+///
+/// Returned in [`ExecutionError::Interrupted`](crate::ExecutionError::Interrupted).
+#[derive(Debug, Clone)]
+pub struct Interrupt {
+    /// The interrupt node.
+    pub node: NodeId,
+    /// The request waiting for a response.
+    pub request: InterruptRequest,
+    /// Cursor to resume the run from once the response is available.
+    pub cursor: ExecutionCursor,
+}
+
+/// Serializes the `Req` output from `ctx`.
+pub(crate) fn encode_request<Req: Output + Serialize>(
+    ctx: &SystemContext<'_>,
+) -> Result<serde_json::Value, String> {
+    let request = ctx.get_output::<Req>().map_err(|err| err.to_string())?;
+    serde_json::to_value(&*request).map_err(|err| err.to_string())
+}
+
+/// Deserializes `value` as `Resp` and stores it as an output in `ctx`.
+pub(crate) fn decode_response<Resp: Output + DeserializeOwned>(
+    value: serde_json::Value,
+    ctx: &mut SystemContext<'_>,
+) -> Result<(), String> {
+    let response: Resp = serde_json::from_value(value).map_err(|err| err.to_string())?;
+    ctx.insert_output(response);
+    Ok(())
+}
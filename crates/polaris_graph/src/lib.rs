@@ -52,15 +52,30 @@ pub mod edge;
 /// Graph execution engine.
 pub mod executor;
 
+/// Typed merging of parallel branch outputs.
+pub mod gather;
+
 /// Graph structure and builder API.
 pub mod graph;
 
+/// Suspending a run to wait for input from outside the graph.
+pub mod interrupt;
+
 /// Node types for graph vertices.
 pub mod node;
 
 /// Type-safe predicates for control flow decisions.
 pub mod predicate;
 
+/// Declarative graph definitions loaded from JSON or YAML.
+pub mod spec;
+
+/// State bindings between a subgraph node and its parent graph.
+pub mod subgraph;
+
+/// Recording graph runs and replaying them deterministically.
+pub mod trace;
+
 /// Lifecycle hooks for graph execution.
 pub mod hooks;
 
@@ -74,28 +89,49 @@ pub mod prelude {
         TimeoutEdge,
     };
     pub use crate::executor::{
-        CaughtError, ErrorKind, ExecutionError, ExecutionResult, GraphExecutor,
-        ResourceValidationError,
+        BranchFailure, CancellationToken, CaughtError, CheckpointSink, CursorFrame, ErrorKind,
+        ExecutionBudget, ExecutionCursor, ExecutionError, ExecutionResult, FrameState,
+        GraphExecutor, ParallelOutcome, ResourceValidationError, RunOptions, RunningSystem,
+        current_system,
     };
+    pub use crate::gather::{BoxedGather, Branches, ErasedGather, Gather, Reducer};
     pub use crate::graph::{
-        Graph, MergeError, SystemNodeBuilder, ValidationError, ValidationResult, ValidationWarning,
+        BindingDescription, EdgeDescription, EdgeKind, Graph, GraphDescription, MergeError,
+        NodeDescription, NodeKind, ResourceDescription, RetryDescription, SystemNodeBuilder,
+        ValidationError, ValidationResult, ValidationWarning,
     };
+    pub use crate::interrupt::{Interrupt, InterruptRequest};
     pub use crate::node::{
-        DecisionNode, IntoSystemNode, JoinNode, LoopNode, Node, NodeId, NodeMarker, ParallelNode,
-        RetryPolicy, ScheduledNodeMarker, SwitchNode, SystemNode,
+        DecisionNode, InterruptNode, IntoSystemNode, JoinNode, LoopNode, Node, NodeId, NodeMarker,
+        ParallelNode, ParallelOptions, ParallelPolicy, RetryPolicy, ScheduledNodeMarker,
+        SubgraphNode, SubgraphOptions, SwitchNode, SystemNode,
     };
     pub use crate::predicate::{
         BoxedDiscriminator, BoxedPredicate, Discriminator, ErasedDiscriminator, ErasedPredicate,
         Predicate, PredicateError,
     };
+    pub use crate::spec::{CaseSpec, GraphSpec, SpecError, SpecRegistry, StepSpec};
+    pub use crate::subgraph::{Binding, BindingKind};
+    pub use crate::trace::{
+        ExecutionTrace, RecordedCall, Replay, TraceAPI, TraceError, TraceEvent, TraceNode,
+        TracePlugin, TraceRecorder,
+    };
 }
 
 // Re-export key types at crate root for convenience
 pub use dev::{DevToolsPlugin, SystemInfo};
 pub use executor::{
-    CaughtError, ErrorKind, ExecutionError, ExecutionResult, GraphExecutor, ResourceValidationError,
+    BranchFailure, CancellationToken, CaughtError, CheckpointSink, CursorFrame, ErrorKind,
+    ExecutionBudget, ExecutionCursor, ExecutionError, ExecutionResult, FrameState, GraphExecutor,
+    ParallelOutcome, ResourceValidationError, RunOptions, RunningSystem, SavedOutput,
+    current_system,
 };
+pub use gather::{Branches, Reducer};
 pub use graph::{
-    Graph, MergeError, SystemNodeBuilder, ValidationError, ValidationResult, ValidationWarning,
+    Graph, GraphDescription, MergeError, SystemNodeBuilder, ValidationError, ValidationResult,
+    ValidationWarning,
 };
-pub use node::{NodeId, RetryPolicy};
+pub use interrupt::{Interrupt, InterruptRequest};
+pub use node::{NodeId, ParallelOptions, ParallelPolicy, RetryPolicy, SubgraphOptions};
+pub use spec::{GraphSpec, SpecError, SpecRegistry};
+pub use trace::{ExecutionTrace, Replay, TracePlugin, TraceRecorder};
@@ -3,10 +3,19 @@
 //! Nodes are the vertices in a graph, representing units of computation
 //! or control flow decisions.
 
+use crate::gather::{BoxedGather, Gather, Reducer};
+use crate::graph::Graph;
+use crate::interrupt::{
+    DecodeResponse, EncodeRequest, InterruptRequest, decode_response, encode_request,
+};
 use crate::predicate::BoxedPredicate;
+use crate::subgraph::Binding;
+use polaris_system::param::SystemContext;
 use polaris_system::plugin::{IntoScheduleIds, ScheduleId};
-use polaris_system::resource::LocalResource;
+use polaris_system::resource::{LocalResource, Output};
 use polaris_system::system::{BoxedSystem, ErasedSystem, IntoSystem};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::any::TypeId;
 use std::fmt;
 use std::marker::PhantomData;
@@ -86,6 +95,10 @@ pub enum Node {
     Parallel(ParallelNode),
     /// Repeats subgraph until termination condition.
     Loop(LoopNode),
+    /// Runs another graph as a single step in an isolated context.
+    Subgraph(SubgraphNode),
+    /// Suspends the run until a response is provided from outside the graph.
+    Interrupt(InterruptNode),
 }
 
 impl Node {
@@ -98,18 +111,22 @@ impl Node {
             Node::Switch(n) => n.id.clone(),
             Node::Parallel(n) => n.id.clone(),
             Node::Loop(n) => n.id.clone(),
+            Node::Subgraph(n) => n.id.clone(),
+            Node::Interrupt(n) => n.id.clone(),
         }
     }
 
     /// Returns the node's name.
     #[must_use]
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &str {
         match self {
             Node::System(n) => n.name(),
-            Node::Decision(n) => n.name,
-            Node::Switch(n) => n.name,
-            Node::Parallel(n) => n.name,
-            Node::Loop(n) => n.name,
+            Node::Decision(n) => &n.name,
+            Node::Switch(n) => &n.name,
+            Node::Parallel(n) => &n.name,
+            Node::Loop(n) => &n.name,
+            Node::Subgraph(n) => &n.name,
+            Node::Interrupt(n) => &n.name,
         }
     }
 }
@@ -305,7 +322,7 @@ pub struct DecisionNode {
     /// Unique identifier for this node.
     pub id: NodeId,
     /// Human-readable name for debugging and tracing.
-    pub name: &'static str,
+    pub name: Arc<str>,
     /// The predicate that determines which branch to take.
     pub predicate: Option<BoxedPredicate>,
     /// Node ID for the true branch.
@@ -317,10 +334,10 @@ pub struct DecisionNode {
 impl DecisionNode {
     /// Creates a new decision node.
     #[must_use]
-    pub fn new(name: &'static str) -> Self {
+    pub fn new(name: impl Into<Arc<str>>) -> Self {
         Self {
             id: NodeId::new(),
-            name,
+            name: name.into(),
             predicate: None,
             true_branch: None,
             false_branch: None,
@@ -329,10 +346,10 @@ impl DecisionNode {
 
     /// Creates a new decision node with a predicate.
     #[must_use]
-    pub fn with_predicate(name: &'static str, predicate: BoxedPredicate) -> Self {
+    pub fn with_predicate(name: impl Into<Arc<str>>, predicate: BoxedPredicate) -> Self {
         Self {
             id: NodeId::new(),
-            name,
+            name: name.into(),
             predicate: Some(predicate),
             true_branch: None,
             false_branch: None,
@@ -360,11 +377,11 @@ pub struct SwitchNode {
     /// Unique identifier for this node.
     pub id: NodeId,
     /// Human-readable name for debugging and tracing.
-    pub name: &'static str,
+    pub name: Arc<str>,
     /// The discriminator that determines which case to take.
     pub discriminator: Option<crate::predicate::BoxedDiscriminator>,
     /// Node IDs for each case, keyed by case name.
-    pub cases: Vec<(&'static str, NodeId)>,
+    pub cases: Vec<(Arc<str>, NodeId)>,
     /// Default case if no match.
     pub default: Option<NodeId>,
 }
@@ -372,10 +389,10 @@ pub struct SwitchNode {
 impl SwitchNode {
     /// Creates a new switch node.
     #[must_use]
-    pub fn new(name: &'static str) -> Self {
+    pub fn new(name: impl Into<Arc<str>>) -> Self {
         Self {
             id: NodeId::new(),
-            name,
+            name: name.into(),
             discriminator: None,
             cases: Vec::new(),
             default: None,
@@ -385,12 +402,12 @@ impl SwitchNode {
     /// Creates a new switch node with a discriminator.
     #[must_use]
     pub fn with_discriminator(
-        name: &'static str,
+        name: impl Into<Arc<str>>,
         discriminator: crate::predicate::BoxedDiscriminator,
     ) -> Self {
         Self {
             id: NodeId::new(),
-            name,
+            name: name.into(),
             discriminator: Some(discriminator),
             cases: Vec::new(),
             default: None,
@@ -410,29 +427,140 @@ impl fmt::Debug for SwitchNode {
     }
 }
 
+/// How a [`ParallelNode`] reacts to failing branches.
+///
+/// Whatever the policy, a node that completes successfully stores a
+/// [`ParallelOutcome`](crate::executor::ParallelOutcome) output describing
+/// which branches succeeded, failed, or were abandoned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParallelPolicy {
+    /// Fail with the first branch error, dropping branches still running.
+    #[default]
+    FailFast,
+    /// Run every branch to completion and report failures through
+    /// [`ParallelOutcome`](crate::executor::ParallelOutcome) instead of
+    /// failing the node.
+    CollectErrors,
+    /// Complete as soon as one branch succeeds, dropping the rest.
+    ///
+    /// Fails only if every branch fails.
+    FirstSuccess,
+    /// Complete as soon as the given number of branches succeed, dropping
+    /// the rest.
+    ///
+    /// Fails as soon as the quorum can no longer be reached.
+    Quorum(usize),
+}
+
+impl ParallelPolicy {
+    /// Returns how many of `branch_count` branches must succeed, or `None`
+    /// if branch failures do not fail the node.
+    pub(crate) fn required_successes(self, branch_count: usize) -> Option<usize> {
+        match self {
+            ParallelPolicy::FailFast => Some(branch_count),
+            ParallelPolicy::CollectErrors => None,
+            ParallelPolicy::FirstSuccess => Some(1),
+            ParallelPolicy::Quorum(n) => Some(n),
+        }
+    }
+}
+
+/// Execution options for a [`ParallelNode`].
+///
+/// # Example
+///
+/// ```
+/// use polaris_graph::node::{ParallelOptions, ParallelPolicy};
+///
+/// // At most three branches in flight; done once two have succeeded.
+/// let options = ParallelOptions::new()
+///     .with_max_concurrency(3)
+///     .with_policy(ParallelPolicy::Quorum(2));
+/// ```
+#[derive(Debug, Default)]
+pub struct ParallelOptions {
+    /// Maximum number of branches running at once (`None` = unbounded).
+    pub max_concurrency: Option<usize>,
+    /// How branch failures are handled.
+    pub policy: ParallelPolicy,
+    /// Output types whose branch values are gathered rather than
+    /// overwritten when outputs are merged.
+    pub gathers: Vec<BoxedGather>,
+}
+
+impl ParallelOptions {
+    /// Creates options with unbounded concurrency and [`ParallelPolicy::FailFast`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits how many branches run at once.
+    ///
+    /// Remaining branches start, in declaration order, as running ones finish.
+    #[must_use]
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+
+    /// Sets how branch failures are handled.
+    #[must_use]
+    pub fn with_policy(mut self, policy: ParallelPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Combines every branch's `T` output with `reducer` instead of keeping
+    /// only the last one.
+    ///
+    /// With [`Reducer::Collect`], downstream systems read the values as
+    /// `Out<Branches<T>>`. Registering `T` again replaces its reducer.
+    ///
+    /// [`Reducer::Collect`]: crate::gather::Reducer::Collect
+    #[must_use]
+    pub fn gather<T: Output>(mut self, reducer: Reducer<T>) -> Self {
+        self.gathers
+            .retain(|gather| gather.output_type_id() != TypeId::of::<T>());
+        self.gathers.push(Box::new(Gather::new(reducer)));
+        self
+    }
+}
+
 /// A node that executes multiple paths concurrently.
 ///
 /// Parallel nodes fork execution into multiple branches that run
-/// simultaneously. After all branches complete, outputs are merged
+/// simultaneously. Once the branches required by the node's
+/// [`ParallelPolicy`] complete, outputs of the successful branches are merged
 /// and execution continues from the parallel node's outgoing edge.
 #[derive(Debug)]
 pub struct ParallelNode {
     /// Unique identifier for this node.
     pub id: NodeId,
     /// Human-readable name for debugging and tracing.
-    pub name: &'static str,
+    pub name: Arc<str>,
     /// Node IDs for each parallel branch entry point.
     pub branches: Vec<NodeId>,
+    /// Concurrency limit and failure policy.
+    pub options: ParallelOptions,
 }
 
 impl ParallelNode {
-    /// Creates a new parallel node.
+    /// Creates a new parallel node with default options.
+    #[must_use]
+    pub fn new(name: impl Into<Arc<str>>) -> Self {
+        Self::with_options(name, ParallelOptions::default())
+    }
+
+    /// Creates a new parallel node with the given options.
     #[must_use]
-    pub fn new(name: &'static str) -> Self {
+    pub fn with_options(name: impl Into<Arc<str>>, options: ParallelOptions) -> Self {
         Self {
             id: NodeId::new(),
-            name,
+            name: name.into(),
             branches: Vec::new(),
+            options,
         }
     }
 }
@@ -446,7 +574,7 @@ pub struct LoopNode {
     /// Unique identifier for this node.
     pub id: NodeId,
     /// Human-readable name for debugging and tracing.
-    pub name: &'static str,
+    pub name: Arc<str>,
     /// The termination predicate (loop exits when this returns true).
     pub termination: Option<BoxedPredicate>,
     /// Maximum number of iterations (safety limit).
@@ -458,10 +586,10 @@ pub struct LoopNode {
 impl LoopNode {
     /// Creates a new loop node.
     #[must_use]
-    pub fn new(name: &'static str) -> Self {
+    pub fn new(name: impl Into<Arc<str>>) -> Self {
         Self {
             id: NodeId::new(),
-            name,
+            name: name.into(),
             termination: None,
             max_iterations: None,
             body_entry: None,
@@ -470,10 +598,10 @@ impl LoopNode {
 
     /// Creates a new loop node with a termination predicate.
     #[must_use]
-    pub fn with_termination(name: &'static str, termination: BoxedPredicate) -> Self {
+    pub fn with_termination(name: impl Into<Arc<str>>, termination: BoxedPredicate) -> Self {
         Self {
             id: NodeId::new(),
-            name,
+            name: name.into(),
             termination: Some(termination),
             max_iterations: None,
             body_entry: None,
@@ -482,10 +610,10 @@ impl LoopNode {
 
     /// Creates a new loop node with a maximum iteration count.
     #[must_use]
-    pub fn with_max_iterations(name: &'static str, max_iterations: usize) -> Self {
+    pub fn with_max_iterations(name: impl Into<Arc<str>>, max_iterations: usize) -> Self {
         Self {
             id: NodeId::new(),
-            name,
+            name: name.into(),
             termination: None,
             max_iterations: Some(max_iterations),
             body_entry: None,
@@ -505,6 +633,218 @@ impl fmt::Debug for LoopNode {
     }
 }
 
+/// Execution options for a [`SubgraphNode`].
+///
+/// The subgraph runs in a context that shares only the server's global
+/// resources with the parent. Outputs and local resources must be bound
+/// explicitly to cross the boundary; see [`crate::subgraph`].
+///
+/// # Example
+///
+/// ```
+/// use polaris_graph::node::SubgraphOptions;
+/// # #[derive(Clone)] struct Task;
+/// # struct Answer;
+/// # struct Memory;
+/// # impl polaris_system::resource::LocalResource for Memory {}
+///
+/// let options = SubgraphOptions::new()
+///     .input::<Task>()
+///     .output::<Answer>()
+///     .resource::<Memory>()
+///     .with_max_nodes(50);
+/// ```
+#[derive(Debug, Default)]
+pub struct SubgraphOptions {
+    /// Values that cross the subgraph boundary.
+    pub bindings: Vec<Binding>,
+    /// Iteration limit for loops in the subgraph without explicit limits.
+    ///
+    /// Defaults to the executor's limit.
+    pub max_iterations: Option<usize>,
+    /// Maximum number of node executions inside the subgraph per run.
+    ///
+    /// Nodes in the subgraph also count against the run-wide node budget.
+    pub max_nodes: Option<usize>,
+}
+
+impl SubgraphOptions {
+    /// Creates options with no bindings and no subgraph-specific limits.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copies the parent's `T` output into the subgraph before it runs.
+    ///
+    /// See [`Binding::input`].
+    #[must_use]
+    pub fn input<T: Output + Clone>(self) -> Self {
+        self.bind(Binding::input::<T>())
+    }
+
+    /// Moves the subgraph's `T` output into the parent after it completes.
+    ///
+    /// See [`Binding::output`].
+    #[must_use]
+    pub fn output<T: Output>(self) -> Self {
+        self.bind(Binding::output::<T>())
+    }
+
+    /// Lends the parent's local `R` resource to the subgraph while it runs.
+    ///
+    /// See [`Binding::resource`].
+    #[must_use]
+    pub fn resource<R: LocalResource>(self) -> Self {
+        self.bind(Binding::resource::<R>())
+    }
+
+    /// Adds a binding.
+    #[must_use]
+    pub fn bind(mut self, binding: Binding) -> Self {
+        self.bindings.push(binding);
+        self
+    }
+
+    /// Sets the iteration limit for loops in the subgraph without explicit
+    /// limits.
+    #[must_use]
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Limits the number of node executions inside the subgraph per run.
+    ///
+    /// Exceeding the limit fails the subgraph node with
+    /// [`ExecutionError::SubgraphFailed`](crate::ExecutionError::SubgraphFailed).
+    #[must_use]
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+}
+
+/// A node that runs another graph as a single step.
+///
+/// The subgraph keeps its own node IDs and runs in an isolated
+/// [`SystemContext`](polaris_system::param::SystemContext) with its own
+/// outputs, so it can be built and tested on its own, for example from an
+/// agent's graph. State crosses the boundary only through the bindings in
+/// [`SubgraphOptions`].
+#[derive(Debug)]
+pub struct SubgraphNode {
+    /// Unique identifier for this node.
+    pub id: NodeId,
+    /// Human-readable name for debugging and tracing.
+    pub name: Arc<str>,
+    /// The graph to run.
+    pub graph: Graph,
+    /// Bindings and limits.
+    pub options: SubgraphOptions,
+}
+
+impl SubgraphNode {
+    /// Creates a new subgraph node.
+    #[must_use]
+    pub fn new(name: impl Into<Arc<str>>, graph: Graph, options: SubgraphOptions) -> Self {
+        Self {
+            id: NodeId::new(),
+            name: name.into(),
+            graph,
+            options,
+        }
+    }
+}
+
+/// A node that suspends the run until a response is provided.
+///
+/// On reaching the node, the executor serializes the `Req` output of an
+/// earlier system into an [`InterruptRequest`] and stops the run with
+/// [`ExecutionError::Interrupted`]. Resuming from the returned cursor with a
+/// response stores it as a `Resp` output and continues with the next node.
+/// See the [`interrupt`](crate::interrupt) module.
+///
+/// [`ExecutionError::Interrupted`]: crate::ExecutionError::Interrupted
+#[derive(Debug)]
+pub struct InterruptNode {
+    /// Unique identifier for this node.
+    pub id: NodeId,
+    /// Human-readable name for debugging and tracing.
+    pub name: Arc<str>,
+    request_type_id: TypeId,
+    request_type_name: &'static str,
+    response_type_id: TypeId,
+    response_type_name: &'static str,
+    encode: EncodeRequest,
+    decode: DecodeResponse,
+}
+
+impl InterruptNode {
+    /// Creates an interrupt node that sends the `Req` output and waits for a
+    /// `Resp` response.
+    #[must_use]
+    pub fn new<Req, Resp>(name: impl Into<Arc<str>>) -> Self
+    where
+        Req: Output + Serialize,
+        Resp: Output + DeserializeOwned,
+    {
+        Self {
+            id: NodeId::new(),
+            name: name.into(),
+            request_type_id: TypeId::of::<Req>(),
+            request_type_name: std::any::type_name::<Req>(),
+            response_type_id: TypeId::of::<Resp>(),
+            response_type_name: std::any::type_name::<Resp>(),
+            encode: encode_request::<Req>,
+            decode: decode_response::<Resp>,
+        }
+    }
+
+    /// Returns the `TypeId` of the request output.
+    #[must_use]
+    pub fn request_type_id(&self) -> TypeId {
+        self.request_type_id
+    }
+
+    /// Returns the type name of the request output.
+    #[must_use]
+    pub fn request_type_name(&self) -> &'static str {
+        self.request_type_name
+    }
+
+    /// Returns the `TypeId` of the response output.
+    #[must_use]
+    pub fn response_type_id(&self) -> TypeId {
+        self.response_type_id
+    }
+
+    /// Returns the type name of the response output.
+    #[must_use]
+    pub fn response_type_name(&self) -> &'static str {
+        self.response_type_name
+    }
+
+    /// Serializes the request output from `ctx`.
+    pub(crate) fn request(&self, ctx: &SystemContext<'_>) -> Result<InterruptRequest, String> {
+        Ok(InterruptRequest {
+            node_name: self.name.to_string(),
+            request_type: self.request_type_name.to_owned(),
+            response_type: self.response_type_name.to_owned(),
+            payload: (self.encode)(ctx)?,
+        })
+    }
+
+    /// Deserializes `response` and stores it as an output in `ctx`.
+    pub(crate) fn respond(
+        &self,
+        response: serde_json::Value,
+        ctx: &mut SystemContext<'_>,
+    ) -> Result<(), String> {
+        (self.decode)(response, ctx)
+    }
+}
+
 /// A node that aggregates results from parallel paths.
 ///
 /// Join nodes are the counterpart to Parallel nodes, collecting
@@ -514,7 +854,7 @@ pub struct JoinNode {
     /// Unique identifier for this node.
     pub id: NodeId,
     /// Human-readable name for debugging and tracing.
-    pub name: &'static str,
+    pub name: Arc<str>,
     /// Node IDs of the parallel branches being joined.
     pub sources: Vec<NodeId>,
 }
@@ -522,10 +862,10 @@ pub struct JoinNode {
 impl JoinNode {
     /// Creates a new join node.
     #[must_use]
-    pub fn new(name: &'static str) -> Self {
+    pub fn new(name: impl Into<Arc<str>>) -> Self {
         Self {
             id: NodeId::new(),
-            name,
+            name: name.into(),
             sources: Vec::new(),
         }
     }
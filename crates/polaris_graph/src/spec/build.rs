@@ -0,0 +1,205 @@
+//! Building graphs from specs.
+
+use super::{GraphSpec, SpecError, SpecRegistry, StepSpec};
+use crate::graph::{Graph, RetryDescription};
+use crate::node::{LoopNode, ParallelOptions, RetryPolicy};
+use std::cell::RefCell;
+use std::time::Duration;
+
+impl GraphSpec {
+    /// Builds a new graph from the spec and validates it.
+    ///
+    /// Validation warnings are logged but do not fail the build.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecError`] if the spec references an unregistered name or
+    /// the resulting graph fails [`Graph::validate`].
+    pub fn build(&self, registry: &SpecRegistry) -> Result<Graph, SpecError> {
+        let mut graph = Graph::new();
+        self.build_into(&mut graph, registry)?;
+        Ok(graph)
+    }
+
+    /// Appends the spec's steps to `graph` and validates the result.
+    ///
+    /// This is the form to call from `Agent::build`, which receives the
+    /// graph to populate. A top-level `on_error` handler also applies to
+    /// fallible systems `graph` already contained. On error, `graph` may be
+    /// left partially built.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecError`] if the spec references an unregistered name or
+    /// the resulting graph fails [`Graph::validate`].
+    pub fn build_into(&self, graph: &mut Graph, registry: &SpecRegistry) -> Result<(), SpecError> {
+        add_steps(graph, &self.steps, registry)?;
+
+        if !self.on_error.is_empty() {
+            let errors = FirstError::default();
+            graph.add_error_handler(errors.branch(&self.on_error, registry));
+            errors.check()?;
+        }
+
+        let result = graph.validate();
+        if result.is_err() {
+            return Err(SpecError::Invalid(result));
+        }
+        if result.has_warnings() {
+            tracing::warn!("{}", result);
+        }
+        Ok(())
+    }
+}
+
+/// Adds `steps` to `graph` in order.
+fn add_steps(
+    graph: &mut Graph,
+    steps: &[StepSpec],
+    registry: &SpecRegistry,
+) -> Result<(), SpecError> {
+    for step in steps {
+        add_step(graph, step, registry)?;
+    }
+    Ok(())
+}
+
+/// Adds a single step, and any branches it contains, to `graph`.
+fn add_step(graph: &mut Graph, step: &StepSpec, registry: &SpecRegistry) -> Result<(), SpecError> {
+    let errors = FirstError::default();
+
+    match step {
+        StepSpec::System {
+            system,
+            timeout_ms,
+            retry,
+            on_error,
+            on_timeout,
+        } => {
+            let id = registry.add_system(graph, system)?;
+            if let Some(timeout_ms) = timeout_ms {
+                graph.set_timeout(id.clone(), Duration::from_millis(*timeout_ms));
+            }
+            if let Some(retry) = retry {
+                graph.set_retry_policy(id.clone(), retry_policy(retry));
+            }
+            if !on_error.is_empty() {
+                graph.add_error_handler_for(id.clone(), errors.branch(on_error, registry));
+            }
+            if !on_timeout.is_empty() {
+                graph.add_timeout_handler(id, errors.branch(on_timeout, registry));
+            }
+        }
+        StepSpec::Conditional {
+            name,
+            predicate,
+            then,
+            otherwise,
+        } => {
+            graph.add_decision_node(
+                name.as_str(),
+                registry.predicate(predicate)?,
+                errors.branch(then, registry),
+                errors.branch(otherwise, registry),
+            );
+        }
+        StepSpec::Switch {
+            name,
+            discriminator,
+            cases,
+            default,
+        } => {
+            graph.add_switch_node(
+                name.as_str(),
+                registry.discriminator(discriminator)?,
+                cases
+                    .iter()
+                    .map(|case| (case.key.as_str(), errors.branch(&case.steps, registry))),
+                default
+                    .as_deref()
+                    .map(|steps| errors.branch(steps, registry)),
+            );
+        }
+        StepSpec::Parallel {
+            name,
+            branches,
+            max_concurrency,
+            policy,
+        } => {
+            let mut options = ParallelOptions::new().with_policy(*policy);
+            if let Some(max_concurrency) = max_concurrency {
+                options = options.with_max_concurrency(*max_concurrency);
+            }
+            graph.add_parallel_with(
+                name.as_str(),
+                options,
+                branches.iter().map(|steps| errors.branch(steps, registry)),
+            );
+        }
+        StepSpec::Loop {
+            name,
+            until,
+            max_iterations,
+            body,
+        } => {
+            let mut loop_node = match until {
+                Some(predicate) => {
+                    LoopNode::with_termination(name.as_str(), registry.predicate(predicate)?)
+                }
+                None => LoopNode::new(name.as_str()),
+            };
+            loop_node.max_iterations = *max_iterations;
+            graph.add_loop_node(loop_node, errors.branch(body, registry));
+        }
+    }
+
+    errors.check()
+}
+
+/// First error raised while building nested branches.
+///
+/// Branches are built inside builder closures, which cannot return errors,
+/// so failures are recorded here and checked once the builder returns.
+#[derive(Default)]
+struct FirstError(RefCell<Option<SpecError>>);
+
+impl FirstError {
+    /// Returns a builder closure that adds `steps` to a branch graph.
+    fn branch<'a>(
+        &'a self,
+        steps: &'a [StepSpec],
+        registry: &'a SpecRegistry,
+    ) -> impl FnOnce(&mut Graph) + 'a {
+        move |graph| {
+            if let Err(err) = add_steps(graph, steps, registry) {
+                self.0.borrow_mut().get_or_insert(err);
+            }
+        }
+    }
+
+    /// Returns the first recorded error, if any.
+    fn check(self) -> Result<(), SpecError> {
+        self.0.into_inner().map_or(Ok(()), Err)
+    }
+}
+
+fn retry_policy(retry: &RetryDescription) -> RetryPolicy {
+    match retry {
+        RetryDescription::Fixed {
+            max_retries,
+            delay_ms,
+        } => RetryPolicy::fixed(*max_retries, Duration::from_millis(*delay_ms)),
+        RetryDescription::Exponential {
+            max_retries,
+            initial_delay_ms,
+            max_delay_ms,
+        } => {
+            let policy =
+                RetryPolicy::exponential(*max_retries, Duration::from_millis(*initial_delay_ms));
+            match max_delay_ms {
+                Some(max_delay_ms) => policy.with_max_delay(Duration::from_millis(*max_delay_ms)),
+                None => policy,
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Declarative graph definitions.
+//!
+//! A [`GraphSpec`] describes a graph's topology as data, so it can be loaded
+//! from JSON or YAML and rearranged without recompiling. Systems, predicates
+//! and discriminators are referenced by name and resolved against a
+//! [`SpecRegistry`] populated by the host.
+//!
+//! # Format
+//!
+//! A spec is a list of steps, each tagged with a `kind`:
+//!
+//! | Kind | Fields |
+//! |------|--------|
+//! | `system` | `system`, `timeout_ms`, `retry`, `on_error`, `on_timeout` |
+//! | `conditional` | `name`, `predicate`, `then`, `otherwise` |
+//! | `switch` | `name`, `discriminator`, `cases`, `default` |
+//! | `parallel` | `name`, `branches`, `max_concurrency`, `policy` |
+//! | `loop` | `name`, `until`, `max_iterations`, `body` |
+//!
+//! A top-level `on_error` list is attached to every fallible system that
+//! has no handler of its own, as [`Graph::add_error_handler`] does.
+//!
+//! # Example
+//!
+//! ```
+//! use polaris_graph::spec::{GraphSpec, SpecRegistry};
+//!
+//! struct Reasoning { needs_tool: bool }
+//!
+//! async fn reason() -> Reasoning { Reasoning { needs_tool: true } }
+//! async fn use_tool() {}
+//! async fn respond() {}
+//!
+//! let mut registry = SpecRegistry::new();
+//! registry
+//!     .register_system("reason", reason)
+//!     .register_system("use_tool", use_tool)
+//!     .register_system("respond", respond)
+//!     .register_predicate("needs_tool", |out: &Reasoning| out.needs_tool);
+//!
+//! let spec = GraphSpec::from_json(r#"{
+//!     "steps": [
+//!         { "kind": "system", "system": "reason" },
+//!         {
+//!             "kind": "conditional",
+//!             "name": "needs_tool",
+//!             "predicate": "needs_tool",
+//!             "then": [{ "kind": "system", "system": "use_tool" }],
+//!             "otherwise": [{ "kind": "system", "system": "respond" }]
+//!         }
+//!     ]
+//! }"#)?;
+//!
+//! let graph = spec.build(&registry)?;
+//! assert_eq!(graph.node_count(), 4);
+//! # Ok::<(), polaris_graph::spec::SpecError>(())
+//! ```
+
+mod build;
+mod registry;
+
+pub use registry::SpecRegistry;
+
+use crate::graph::{RetryDescription, ValidationResult};
+use crate::node::ParallelPolicy;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A serializable description of a graph's topology.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphSpec {
+    /// Steps executed in order.
+    pub steps: Vec<StepSpec>,
+    /// Handler for every fallible system without its own `on_error`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_error: Vec<StepSpec>,
+}
+
+/// One step of a [`GraphSpec`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StepSpec {
+    /// A registered system.
+    System {
+        /// Registry name of the system.
+        system: String,
+        /// Execution time limit in milliseconds.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timeout_ms: Option<u64>,
+        /// Retry policy applied before error handlers.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        retry: Option<RetryDescription>,
+        /// Steps run when the system fails.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        on_error: Vec<StepSpec>,
+        /// Steps run when the system times out.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        on_timeout: Vec<StepSpec>,
+    },
+    /// A binary branch on a registered predicate.
+    Conditional {
+        /// Node name.
+        name: String,
+        /// Registry name of the predicate.
+        predicate: String,
+        /// Steps run when the predicate returns `true`.
+        #[serde(default)]
+        then: Vec<StepSpec>,
+        /// Steps run when the predicate returns `false`.
+        #[serde(default)]
+        otherwise: Vec<StepSpec>,
+    },
+    /// A multi-way branch on a registered discriminator.
+    Switch {
+        /// Node name.
+        name: String,
+        /// Registry name of the discriminator.
+        discriminator: String,
+        /// Case branches, matched by key.
+        cases: Vec<CaseSpec>,
+        /// Steps run when no case matches.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        default: Option<Vec<StepSpec>>,
+    },
+    /// Branches run concurrently.
+    Parallel {
+        /// Node name.
+        name: String,
+        /// Steps of each branch.
+        branches: Vec<Vec<StepSpec>>,
+        /// Maximum number of branches running at once.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_concurrency: Option<usize>,
+        /// How failing branches are handled.
+        #[serde(default)]
+        policy: ParallelPolicy,
+    },
+    /// A repeated body.
+    Loop {
+        /// Node name.
+        name: String,
+        /// Registry name of the termination predicate.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        until: Option<String>,
+        /// Maximum number of iterations.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_iterations: Option<usize>,
+        /// Steps of the loop body.
+        body: Vec<StepSpec>,
+    },
+}
+
+/// One case of a [`StepSpec::Switch`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaseSpec {
+    /// Discriminator value selecting this case.
+    pub key: String,
+    /// Steps run for this case.
+    pub steps: Vec<StepSpec>,
+}
+
+impl GraphSpec {
+    /// Parses a spec from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecError::Parse`] if the input is not a valid spec.
+    pub fn from_json(input: &str) -> Result<Self, SpecError> {
+        serde_json::from_str(input).map_err(|err| SpecError::Parse(err.to_string()))
+    }
+
+    /// Parses a spec from YAML.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecError::Parse`] if the input is not a valid spec.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(input: &str) -> Result<Self, SpecError> {
+        serde_norway::from_str(input).map_err(|err| SpecError::Parse(err.to_string()))
+    }
+}
+
+/// Errors that can occur when loading or building a [`GraphSpec`].
+#[derive(Debug)]
+pub enum SpecError {
+    /// The input could not be parsed as a spec.
+    Parse(String),
+    /// A step references a system that is not registered.
+    UnknownSystem(String),
+    /// A step references a predicate that is not registered.
+    UnknownPredicate(String),
+    /// A step references a discriminator that is not registered.
+    UnknownDiscriminator(String),
+    /// The built graph failed validation.
+    Invalid(ValidationResult),
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpecError::Parse(message) => write!(f, "failed to parse graph spec: {message}"),
+            SpecError::UnknownSystem(name) => write!(f, "unknown system '{name}' in graph spec"),
+            SpecError::UnknownPredicate(name) => {
+                write!(f, "unknown predicate '{name}' in graph spec")
+            }
+            SpecError::UnknownDiscriminator(name) => {
+                write!(f, "unknown discriminator '{name}' in graph spec")
+            }
+            SpecError::Invalid(result) => write!(f, "graph spec is invalid: {result}"),
+        }
+    }
+}
+
+impl std::error::Error for SpecError {}
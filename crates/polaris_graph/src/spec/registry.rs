@@ -0,0 +1,170 @@
+//! Named systems, predicates and discriminators for building specs.
+
+use super::SpecError;
+use crate::graph::Graph;
+use crate::node::{IntoSystemNode, NodeId};
+use crate::predicate::{BoxedDiscriminator, BoxedPredicate, Discriminator, Predicate};
+use hashbrown::HashMap;
+use polaris_system::resource::Output;
+use polaris_system::system::BoxedSystem;
+use std::fmt;
+use std::sync::Arc;
+
+/// Adds a fresh instance of a registered system to a graph.
+type SystemFactory = Box<dyn Fn(&mut Graph) -> NodeId + Send + Sync>;
+
+/// Creates a fresh instance of a registered predicate.
+type PredicateFactory = Box<dyn Fn() -> BoxedPredicate + Send + Sync>;
+
+/// Creates a fresh instance of a registered discriminator.
+type DiscriminatorFactory = Box<dyn Fn() -> BoxedDiscriminator + Send + Sync>;
+
+/// Host-provided lookup table that resolves the names used in a
+/// [`GraphSpec`](super::GraphSpec).
+///
+/// A spec may reference the same name several times, so each entry creates
+/// a new instance whenever it is used.
+#[derive(Default)]
+pub struct SpecRegistry {
+    systems: HashMap<String, SystemFactory>,
+    predicates: HashMap<String, PredicateFactory>,
+    discriminators: HashMap<String, DiscriminatorFactory>,
+}
+
+impl SpecRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a system under `name`.
+    ///
+    /// Accepts anything [`Graph::add_system`] does, including
+    /// `(custom_schedules, system)` tuples, as long as it can be cloned.
+    /// Registering a name again replaces the earlier entry.
+    pub fn register_system<S, M>(&mut self, name: impl Into<String>, system: S) -> &mut Self
+    where
+        S: IntoSystemNode<M> + Clone + Send + Sync + 'static,
+    {
+        self.systems.insert(
+            name.into(),
+            Box::new(move |graph| graph.add_system_node(system.clone())),
+        );
+        self
+    }
+
+    /// Registers a factory for systems that cannot be cloned.
+    ///
+    /// Registering a name again replaces the earlier entry.
+    pub fn register_boxed_system<F>(&mut self, name: impl Into<String>, factory: F) -> &mut Self
+    where
+        F: Fn() -> BoxedSystem + Send + Sync + 'static,
+    {
+        self.systems.insert(
+            name.into(),
+            Box::new(move |graph| graph.add_boxed_system(factory())),
+        );
+        self
+    }
+
+    /// Registers a predicate over the output `T` under `name`.
+    ///
+    /// Used by `conditional` steps and as the `until` condition of `loop`
+    /// steps. Registering a name again replaces the earlier entry.
+    pub fn register_predicate<T, P>(&mut self, name: impl Into<String>, predicate: P) -> &mut Self
+    where
+        T: Output,
+        P: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        let predicate = Arc::new(predicate);
+        self.predicates.insert(
+            name.into(),
+            Box::new(move || {
+                let predicate = Arc::clone(&predicate);
+                Box::new(Predicate::new(move |value: &T| predicate(value)))
+            }),
+        );
+        self
+    }
+
+    /// Registers a discriminator over the output `T` under `name`.
+    ///
+    /// Used by `switch` steps. Registering a name again replaces the earlier
+    /// entry.
+    pub fn register_discriminator<T, D>(
+        &mut self,
+        name: impl Into<String>,
+        discriminator: D,
+    ) -> &mut Self
+    where
+        T: Output,
+        D: Fn(&T) -> &'static str + Send + Sync + 'static,
+    {
+        let discriminator = Arc::new(discriminator);
+        self.discriminators.insert(
+            name.into(),
+            Box::new(move || {
+                let discriminator = Arc::clone(&discriminator);
+                Box::new(Discriminator::new(move |value: &T| discriminator(value)))
+            }),
+        );
+        self
+    }
+
+    /// Returns `true` if a system is registered under `name`.
+    #[must_use]
+    pub fn contains_system(&self, name: &str) -> bool {
+        self.systems.contains_key(name)
+    }
+
+    /// Returns `true` if a predicate is registered under `name`.
+    #[must_use]
+    pub fn contains_predicate(&self, name: &str) -> bool {
+        self.predicates.contains_key(name)
+    }
+
+    /// Returns `true` if a discriminator is registered under `name`.
+    #[must_use]
+    pub fn contains_discriminator(&self, name: &str) -> bool {
+        self.discriminators.contains_key(name)
+    }
+
+    /// Adds a new instance of the system registered under `name` to `graph`.
+    pub(crate) fn add_system(&self, graph: &mut Graph, name: &str) -> Result<NodeId, SpecError> {
+        let factory = self
+            .systems
+            .get(name)
+            .ok_or_else(|| SpecError::UnknownSystem(name.to_owned()))?;
+        Ok(factory(graph))
+    }
+
+    /// Creates a new instance of the predicate registered under `name`.
+    pub(crate) fn predicate(&self, name: &str) -> Result<BoxedPredicate, SpecError> {
+        self.predicates
+            .get(name)
+            .map(|factory| factory())
+            .ok_or_else(|| SpecError::UnknownPredicate(name.to_owned()))
+    }
+
+    /// Creates a new instance of the discriminator registered under `name`.
+    pub(crate) fn discriminator(&self, name: &str) -> Result<BoxedDiscriminator, SpecError> {
+        self.discriminators
+            .get(name)
+            .map(|factory| factory())
+            .ok_or_else(|| SpecError::UnknownDiscriminator(name.to_owned()))
+    }
+}
+
+impl fmt::Debug for SpecRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpecRegistry")
+            .field("systems", &self.systems.keys().collect::<Vec<_>>())
+            .field("predicates", &self.predicates.keys().collect::<Vec<_>>())
+            .field(
+                "discriminators",
+                &self.discriminators.keys().collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
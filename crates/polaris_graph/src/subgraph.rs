@@ -0,0 +1,171 @@
+//! State bindings between a subgraph node and its parent graph.
+//!
+//! A subgraph node runs another graph in an isolated [`SystemContext`] that
+//! shares only the server's global resources. Everything else that crosses
+//! the boundary is declared as a [`Binding`] on
+//! [`SubgraphOptions`](crate::node::SubgraphOptions):
+//!
+//! - [`Binding::input`] - Copies a parent output into the subgraph before it runs
+//! - [`Binding::output`] - Moves a subgraph output into the parent after it completes
+//! - [`Binding::resource`] - Lends a parent local resource to the subgraph for
+//!   the duration of the run, including any changes the subgraph makes to it
+//!
+//! # Example
+//!
+//! ```
+//! use polaris_graph::Graph;
+//! use polaris_graph::node::SubgraphOptions;
+//!
+//! #[derive(Clone)]
+//! struct Task(String);
+//! struct Answer(String);
+//!
+//! async fn plan() -> Task { Task("summarize".into()) }
+//! async fn solve() -> Answer { Answer("done".into()) }
+//!
+//! let mut worker = Graph::new();
+//! worker.add_system(solve);
+//!
+//! let mut supervisor = Graph::new();
+//! supervisor.add_system(plan).add_subgraph(
+//!     "worker",
+//!     worker,
+//!     SubgraphOptions::new().input::<Task>().output::<Answer>(),
+//! );
+//! ```
+
+use polaris_system::param::SystemContext;
+use polaris_system::resource::{LocalResource, Output};
+use serde::{Deserialize, Serialize};
+use std::any::{TypeId, type_name};
+use std::fmt;
+
+/// Function that moves or copies one value between two contexts.
+type Transfer =
+    Box<dyn for<'a, 'b> Fn(&mut SystemContext<'a>, &mut SystemContext<'b>) + Send + Sync>;
+
+/// Direction and storage of a [`Binding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BindingKind {
+    /// A parent output copied into the subgraph.
+    Input,
+    /// A subgraph output moved into the parent.
+    Output,
+    /// A parent local resource lent to the subgraph and returned afterwards.
+    Resource,
+}
+
+/// One value that crosses a subgraph boundary.
+pub struct Binding {
+    kind: BindingKind,
+    type_id: TypeId,
+    type_name: &'static str,
+    /// Applied before the subgraph runs, as `(parent, child)`.
+    enter: Option<Transfer>,
+    /// Applied after the subgraph finishes, as `(child, parent)`.
+    exit: Option<Transfer>,
+}
+
+impl Binding {
+    /// Copies the parent's `T` output into the subgraph before it runs.
+    ///
+    /// Nothing is copied if the parent has no `T` output.
+    #[must_use]
+    pub fn input<T: Output + Clone>() -> Self {
+        Self {
+            kind: BindingKind::Input,
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            enter: Some(Box::new(|parent, child| {
+                if let Ok(value) = parent.get_output::<T>() {
+                    child.insert_output(T::clone(&value));
+                }
+            })),
+            exit: None,
+        }
+    }
+
+    /// Moves the subgraph's `T` output into the parent after it completes.
+    ///
+    /// Nothing is moved if the subgraph produced no `T` output.
+    #[must_use]
+    pub fn output<T: Output>() -> Self {
+        Self {
+            kind: BindingKind::Output,
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            enter: None,
+            exit: Some(Box::new(|child, parent| {
+                if let Some(value) = child.outputs_mut().remove::<T>() {
+                    parent.insert_output(value);
+                }
+            })),
+        }
+    }
+
+    /// Moves the parent's local `R` resource into the subgraph before it runs
+    /// and back once it finishes, whether or not it succeeded.
+    ///
+    /// Only resources in the parent's own scope are lent; resources visible
+    /// through ancestors or globals are not.
+    #[must_use]
+    pub fn resource<R: LocalResource>() -> Self {
+        Self {
+            kind: BindingKind::Resource,
+            type_id: TypeId::of::<R>(),
+            type_name: type_name::<R>(),
+            enter: Some(Box::new(|parent, child| {
+                if let Some(resource) = parent.remove_resource::<R>() {
+                    child.insert(resource);
+                }
+            })),
+            exit: Some(Box::new(|child, parent| {
+                if let Some(resource) = child.remove_resource::<R>() {
+                    parent.insert(resource);
+                }
+            })),
+        }
+    }
+
+    /// Returns the direction and storage of the binding.
+    #[must_use]
+    pub fn kind(&self) -> BindingKind {
+        self.kind
+    }
+
+    /// Returns the [`TypeId`] of the bound type.
+    #[must_use]
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns the name of the bound type.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Passes the bound value from `parent` into `child`, if it flows in.
+    pub(crate) fn enter(&self, parent: &mut SystemContext<'_>, child: &mut SystemContext<'_>) {
+        if let Some(transfer) = &self.enter {
+            transfer(parent, child);
+        }
+    }
+
+    /// Passes the bound value from `child` back to `parent`, if it flows out.
+    pub(crate) fn exit(&self, child: &mut SystemContext<'_>, parent: &mut SystemContext<'_>) {
+        if let Some(transfer) = &self.exit {
+            transfer(child, parent);
+        }
+    }
+}
+
+impl fmt::Debug for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Binding")
+            .field("kind", &self.kind)
+            .field("type_name", &self.type_name)
+            .finish()
+    }
+}
@@ -0,0 +1,378 @@
+//! Recording graph runs and replaying them deterministically.
+//!
+//! A [`TraceRecorder`] passed through [`RunOptions::with_recorder`] captures
+//! a complete [`ExecutionTrace`] of a run: every node entered, each branch
+//! and case selected, loop iterations, retries, error and timeout routing,
+//! and the serialized output of every system whose output type was
+//! registered with [`TraceRecorder::with_output`]. Calls to external
+//! services made while the run executes, such as LLM requests made through
+//! `polaris_models`, are recorded alongside as [`RecordedCall`]s.
+//!
+//! A trace is plain data. It can be saved to a file with
+//! [`ExecutionTrace::save`] and loaded later to reproduce the run offline.
+//! [`RunOptions::with_replay`] re-runs a graph against a [`Replay`] of the
+//! trace: recorded calls are served back to the calls made with the same
+//! request instead of reaching the service, and systems selected with [`Replay::substitute`] return their
+//! recorded outcome instead of running.
+//!
+//! [`TracePlugin`] records every session turn to a trace file.
+//!
+//! # Limitations
+//!
+//! Nodes are addressed by their position in [`Graph::nodes`], as with
+//! [`ExecutionCursor`](crate::ExecutionCursor), so a trace only replays
+//! against a graph built the same way. Nodes inside a subgraph node are
+//! not recorded individually, and cannot be substituted, but calls made
+//! inside them are.
+//!
+//! # Example
+//!
+//! ```
+//! # async fn example_fn() -> Result<(), Box<dyn std::error::Error>> {
+//! use polaris_graph::trace::{Replay, TraceRecorder};
+//! use polaris_graph::{Graph, GraphExecutor, RunOptions};
+//! use polaris_system::param::SystemContext;
+//!
+//! async fn classify() -> String { "billing".to_string() }
+//! async fn respond() {}
+//!
+//! let mut graph = Graph::new();
+//! graph.add_system(classify).add_system(respond);
+//! let executor = GraphExecutor::new();
+//!
+//! let recorder = TraceRecorder::new().with_output::<String>();
+//! let mut ctx = SystemContext::new();
+//! let options = RunOptions::new().with_recorder(recorder.clone());
+//! executor.execute_with(&graph, &mut ctx, None, options).await?;
+//! let trace = recorder.take();
+//!
+//! // Re-run with `classify` returning its recorded output.
+//! let replay = Replay::new(trace)
+//!     .with_output::<String>()
+//!     .substitute("classify");
+//! let mut ctx = SystemContext::new();
+//! let options = RunOptions::new().with_replay(replay);
+//! executor.execute_with(&graph, &mut ctx, None, options).await?;
+//! assert_eq!(*ctx.get_output::<String>()?, "billing");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`RunOptions::with_recorder`]: crate::RunOptions::with_recorder
+//! [`RunOptions::with_replay`]: crate::RunOptions::with_replay
+//! [`Graph::nodes`]: crate::Graph::nodes
+
+mod plugin;
+mod recorder;
+mod replay;
+
+pub use plugin::{TraceAPI, TracePlugin};
+pub use recorder::TraceRecorder;
+pub use replay::Replay;
+
+pub(crate) use recorder::OutputCodecs;
+pub(crate) use replay::ReplayState;
+
+use crate::executor::ErrorKind;
+use crate::graph::Graph;
+use crate::node::NodeId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+tokio::task_local! {
+    /// Recorder and replay of the run executing on the current task.
+    static ACTIVE: ActiveRun;
+}
+
+/// A recorded graph run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionTrace {
+    /// Events in the order they occurred.
+    pub events: Vec<TraceEvent>,
+}
+
+impl ExecutionTrace {
+    /// Returns the recorded calls of `kind`, in order.
+    pub fn calls<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a RecordedCall> + 'a {
+        self.events.iter().filter_map(move |event| match event {
+            TraceEvent::Call(call) if call.kind == kind => Some(call),
+            _ => None,
+        })
+    }
+
+    /// Returns the names of the nodes entered, in order.
+    pub fn path(&self) -> impl Iterator<Item = &str> {
+        self.events.iter().filter_map(|event| match event {
+            TraceEvent::NodeEntered { node } => Some(node.name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Parses a trace from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TraceError::Json`] if the input is not a valid trace.
+    pub fn from_json(input: &str) -> Result<Self, TraceError> {
+        serde_json::from_str(input).map_err(TraceError::Json)
+    }
+
+    /// Serializes the trace to pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TraceError::Json`] if a recorded value cannot be serialized.
+    pub fn to_json(&self) -> Result<String, TraceError> {
+        serde_json::to_string_pretty(self).map_err(TraceError::Json)
+    }
+
+    /// Writes the trace to a JSON file at `path`, replacing any existing file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TraceError`] if the trace cannot be serialized or written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), TraceError> {
+        std::fs::write(path, self.to_json()?).map_err(TraceError::Io)
+    }
+
+    /// Reads a trace from the JSON file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TraceError`] if the file cannot be read or parsed.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TraceError> {
+        Self::from_json(&std::fs::read_to_string(path).map_err(TraceError::Io)?)
+    }
+}
+
+/// One event of an [`ExecutionTrace`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    /// Execution entered a node.
+    NodeEntered {
+        /// The node entered.
+        node: TraceNode,
+    },
+    /// A decision selected a branch or a switch selected a case.
+    BranchSelected {
+        /// The decision or switch node.
+        node: TraceNode,
+        /// `"true"` or `"false"` for decisions, the case key for switches.
+        branch: String,
+    },
+    /// A loop started an iteration.
+    LoopIteration {
+        /// The loop node.
+        node: TraceNode,
+        /// The iteration number (0-indexed).
+        iteration: usize,
+    },
+    /// A system failed or timed out and is about to be retried.
+    SystemRetried {
+        /// The system node.
+        node: TraceNode,
+        /// The retry about to run (1 for the first retry).
+        attempt: usize,
+        /// Why the previous attempt failed.
+        error: String,
+    },
+    /// A system completed.
+    SystemCompleted {
+        /// The system node.
+        node: TraceNode,
+        /// Type name of the system's output.
+        output_type: String,
+        /// The serialized output, if its type was registered for recording.
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "present"
+        )]
+        output: Option<serde_json::Value>,
+    },
+    /// A system failed after all retries.
+    SystemFailed {
+        /// The system node.
+        node: TraceNode,
+        /// Where the error came from.
+        kind: ErrorKind,
+        /// The error message.
+        error: String,
+    },
+    /// A system timed out after all retries.
+    SystemTimedOut {
+        /// The system node.
+        node: TraceNode,
+    },
+    /// A failed or timed-out system was routed to its handler.
+    ErrorRouted {
+        /// The system node.
+        node: TraceNode,
+        /// The first node of the handler.
+        handler: TraceNode,
+    },
+    /// A call to an external service.
+    Call(RecordedCall),
+    /// The run completed.
+    RunCompleted {
+        /// Number of nodes executed.
+        nodes_executed: usize,
+    },
+    /// The run stopped with an error, including cancellation and interrupts.
+    RunFailed {
+        /// The error message.
+        error: String,
+    },
+}
+
+/// Deserializes a field that is present, including `null`, as `Some`.
+fn present<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<serde_json::Value>, D::Error> {
+    serde_json::Value::deserialize(deserializer).map(Some)
+}
+
+/// A node referenced by a [`TraceEvent`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceNode {
+    /// Position of the node in [`Graph::nodes`].
+    pub index: usize,
+    /// Name of the node.
+    pub name: String,
+}
+
+impl TraceNode {
+    /// Returns the trace reference for the node with the given ID.
+    pub(crate) fn of(graph: &Graph, id: &NodeId) -> Option<Self> {
+        graph
+            .nodes()
+            .iter()
+            .enumerate()
+            .find(|(_, node)| node.id() == *id)
+            .map(|(index, node)| Self {
+                index,
+                name: node.name().to_owned(),
+            })
+    }
+}
+
+/// A request to an external service and its response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedCall {
+    /// Kind of service, such as `"llm"`.
+    pub kind: String,
+    /// Service-specific target, such as the model name.
+    pub target: String,
+    /// The serialized request.
+    pub request: serde_json::Value,
+    /// The serialized response, or the error message if the call failed.
+    pub response: Result<serde_json::Value, String>,
+}
+
+/// Records a call made by the run executing on the current task.
+///
+/// Does nothing unless the run was started with
+/// [`RunOptions::with_recorder`](crate::RunOptions::with_recorder). Use
+/// [`is_recording`] to skip serializing the call when nothing records it.
+pub fn record_call(call: RecordedCall) {
+    let _ = ACTIVE.try_with(|active| {
+        if let Some(recorder) = &active.recorder {
+            recorder.record(TraceEvent::Call(call));
+        }
+    });
+}
+
+/// Returns `true` if the run executing on the current task records a trace.
+#[must_use]
+pub fn is_recording() -> bool {
+    ACTIVE
+        .try_with(|active| active.recorder.is_some())
+        .unwrap_or(false)
+}
+
+/// Returns `true` if the run executing on the current task is a replay.
+#[must_use]
+pub fn is_replaying() -> bool {
+    ACTIVE
+        .try_with(|active| active.replay.is_some())
+        .unwrap_or(false)
+}
+
+/// Takes the recorded call of `kind` to `target` made with `request` when
+/// the run executing on the current task is a replay.
+///
+/// Returns `None` when the run is not a replay, in which case the caller
+/// should make the call for real. A call is served from the first unserved
+/// recorded call with the same kind, target and request, so calls made
+/// concurrently, such as from parallel branches, are served the response
+/// recorded for them whatever order they arrive in. Use [`is_replaying`] to
+/// skip serializing the request when nothing replays.
+///
+/// # Errors
+///
+/// Returns [`TraceError::Diverged`] if no unserved recorded call matches,
+/// including when the only calls left to `target` were made with a
+/// different request.
+pub fn replay_call(
+    kind: &str,
+    target: &str,
+    request: &serde_json::Value,
+) -> Option<Result<RecordedCall, TraceError>> {
+    ACTIVE
+        .try_with(|active| {
+            active
+                .replay
+                .as_ref()
+                .map(|replay| replay.next_call(kind, target, request))
+        })
+        .ok()
+        .flatten()
+}
+
+/// Recorder and replay of a run, visible to the code it executes.
+#[derive(Clone)]
+pub(crate) struct ActiveRun {
+    pub(crate) recorder: Option<TraceRecorder>,
+    pub(crate) replay: Option<Arc<ReplayState>>,
+}
+
+impl ActiveRun {
+    /// Runs `future` with this run active on the current task.
+    pub(crate) async fn scope<F: Future>(self, future: F) -> F::Output {
+        ACTIVE.scope(self, future).await
+    }
+}
+
+/// Errors that can occur when storing or replaying an [`ExecutionTrace`].
+#[derive(Debug)]
+pub enum TraceError {
+    /// A trace file could not be read or written.
+    Io(std::io::Error),
+    /// A trace could not be serialized or parsed.
+    Json(serde_json::Error),
+    /// A replayed run did something the trace did not record.
+    Diverged(String),
+}
+
+impl fmt::Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceError::Io(err) => write!(f, "trace file error: {err}"),
+            TraceError::Json(err) => write!(f, "invalid trace: {err}"),
+            TraceError::Diverged(message) => write!(f, "replay diverged from trace: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for TraceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TraceError::Io(err) => Some(err),
+            TraceError::Json(err) => Some(err),
+            TraceError::Diverged(_) => None,
+        }
+    }
+}
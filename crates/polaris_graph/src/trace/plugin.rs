@@ -0,0 +1,120 @@
+//! Plugin recording session turns to trace files.
+
+use super::recorder::OutputCodecs;
+use super::{ExecutionTrace, Replay, TraceError, TraceRecorder};
+use polaris_system::api::API;
+use polaris_system::plugin::{Plugin, Version};
+use polaris_system::resource::Output;
+use polaris_system::server::Server;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+
+/// Records a trace of every session turn to a directory.
+///
+/// Inserts a [`TraceAPI`] that `polaris_sessions` uses to record each turn
+/// and save its trace as `{session_id}-{turn}.json` in the directory. The
+/// same API loads saved traces back for replay.
+///
+/// # Example
+///
+/// ```
+/// use polaris_graph::trace::TracePlugin;
+/// use polaris_system::server::Server;
+///
+/// let mut server = Server::new();
+/// server.add_plugins(TracePlugin::new("traces").with_output::<String>());
+/// ```
+#[derive(Clone)]
+pub struct TracePlugin {
+    dir: PathBuf,
+    outputs: OutputCodecs,
+}
+
+impl TracePlugin {
+    /// Creates a plugin saving traces to `dir`.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            outputs: OutputCodecs::default(),
+        }
+    }
+
+    /// Records and replays the outputs of systems returning `T`.
+    #[must_use]
+    pub fn with_output<T: Output + Serialize + DeserializeOwned>(mut self) -> Self {
+        self.outputs.register::<T>();
+        self
+    }
+}
+
+impl Plugin for TracePlugin {
+    const ID: &'static str = "polaris::trace";
+    const VERSION: Version = Version::new(0, 0, 1);
+
+    fn build(&self, server: &mut Server) {
+        server.insert_api(TraceAPI {
+            dir: self.dir.clone(),
+            outputs: self.outputs.clone(),
+        });
+    }
+}
+
+/// API for recording runs to, and replaying them from, a trace directory.
+///
+/// Inserted by [`TracePlugin`].
+pub struct TraceAPI {
+    dir: PathBuf,
+    outputs: OutputCodecs,
+}
+
+impl API for TraceAPI {}
+
+impl TraceAPI {
+    /// Returns the directory traces are saved to.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Creates a recorder for the output types registered with the plugin.
+    #[must_use]
+    pub fn recorder(&self) -> TraceRecorder {
+        TraceRecorder::with_codecs(self.outputs.clone())
+    }
+
+    /// Prepares `trace` for replay with the output types registered with
+    /// the plugin.
+    #[must_use]
+    pub fn replay(&self, trace: ExecutionTrace) -> Replay {
+        Replay::with_codecs(trace, self.outputs.clone())
+    }
+
+    /// Returns the path of the trace file named `name`.
+    #[must_use]
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{name}.json"))
+    }
+
+    /// Saves `trace` as `name`, creating the directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TraceError`] if the trace cannot be serialized or written.
+    pub fn save(&self, name: &str, trace: &ExecutionTrace) -> Result<PathBuf, TraceError> {
+        std::fs::create_dir_all(&self.dir).map_err(TraceError::Io)?;
+        let path = self.path(name);
+        trace.save(&path)?;
+        Ok(path)
+    }
+
+    /// Loads the trace saved as `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TraceError`] if the file cannot be read or parsed.
+    pub fn load(&self, name: &str) -> Result<ExecutionTrace, TraceError> {
+        ExecutionTrace::load(self.path(name))
+    }
+}
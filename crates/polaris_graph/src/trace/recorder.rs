@@ -0,0 +1,222 @@
+//! Recording runs into an [`ExecutionTrace`].
+
+use super::{ExecutionTrace, TraceEvent};
+use crate::executor::SavedOutput;
+use hashbrown::HashMap;
+use parking_lot::Mutex;
+use polaris_system::resource::{Output, Outputs};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::any::{Any, TypeId, type_name};
+use std::fmt;
+use std::sync::Arc;
+
+/// A type-erased system output.
+pub(crate) type BoxedOutput = Box<dyn Any + Send + Sync>;
+
+/// Serializes and deserializes one registered output type.
+#[derive(Clone, Copy)]
+pub(crate) struct OutputCodec {
+    /// Type name, as reported by the system producing it.
+    pub(crate) type_name: &'static str,
+    encode: fn(&(dyn Any + Send + Sync)) -> Option<Result<serde_json::Value, String>>,
+    decode: fn(serde_json::Value) -> Result<BoxedOutput, String>,
+}
+
+impl OutputCodec {
+    fn of<T: Output + Serialize + DeserializeOwned>() -> Self {
+        Self {
+            type_name: type_name::<T>(),
+            encode: |output| {
+                output
+                    .downcast_ref::<T>()
+                    .map(|value| serde_json::to_value(value).map_err(|err| err.to_string()))
+            },
+            decode: |value| {
+                serde_json::from_value::<T>(value)
+                    .map(|output| Box::new(output) as BoxedOutput)
+                    .map_err(|err| err.to_string())
+            },
+        }
+    }
+
+    /// Deserializes a recorded output.
+    pub(crate) fn decode(&self, value: serde_json::Value) -> Result<BoxedOutput, String> {
+        (self.decode)(value)
+    }
+}
+
+/// Output types whose values are recorded and replayed, or saved in
+/// checkpoints.
+///
+/// `()` is always registered, so systems without output replay without
+/// registration.
+#[derive(Clone)]
+pub(crate) struct OutputCodecs(HashMap<TypeId, OutputCodec>);
+
+impl Default for OutputCodecs {
+    fn default() -> Self {
+        let mut codecs = Self(HashMap::new());
+        codecs.register::<()>();
+        codecs
+    }
+}
+
+impl OutputCodecs {
+    /// Registers `T`.
+    pub(crate) fn register<T: Output + Serialize + DeserializeOwned>(&mut self) {
+        self.0.insert(TypeId::of::<T>(), OutputCodec::of::<T>());
+    }
+
+    /// Returns the codec for the output type `type_id`.
+    pub(crate) fn get(&self, type_id: TypeId) -> Option<&OutputCodec> {
+        self.0.get(&type_id)
+    }
+
+    /// Serializes `output` if its type is registered.
+    ///
+    /// Outputs that fail to serialize are logged and left unrecorded.
+    fn encode(
+        &self,
+        type_id: TypeId,
+        output: &(dyn Any + Send + Sync),
+    ) -> Option<serde_json::Value> {
+        let codec = self.get(type_id)?;
+        match (codec.encode)(output)? {
+            Ok(value) => Some(value),
+            Err(err) => {
+                tracing::warn!(
+                    output_type = codec.type_name,
+                    "failed to record system output: {err}"
+                );
+                None
+            }
+        }
+    }
+
+    /// Serializes every output in `outputs` whose type is registered,
+    /// ordered by type name.
+    ///
+    /// `()` carries nothing worth restoring and is skipped. Outputs that
+    /// fail to serialize are logged and left out.
+    pub(crate) fn save(&self, outputs: &Outputs) -> Vec<SavedOutput> {
+        let mut saved: Vec<SavedOutput> = self
+            .0
+            .iter()
+            .filter(|(type_id, _)| **type_id != TypeId::of::<()>())
+            .filter_map(|(&type_id, codec)| {
+                let value =
+                    outputs.read_by_type_id(type_id, |output| self.encode(type_id, output))??;
+                Some(SavedOutput {
+                    output_type: codec.type_name.to_owned(),
+                    value,
+                })
+            })
+            .collect();
+        saved.sort_by(|a, b| a.output_type.cmp(&b.output_type));
+        saved
+    }
+
+    /// Deserializes outputs saved by [`save`](Self::save).
+    ///
+    /// Fails if a saved type is not registered or its value does not
+    /// deserialize.
+    pub(crate) fn restore(&self, saved: &[SavedOutput]) -> Result<Outputs, String> {
+        let mut outputs = Outputs::new();
+        for output in saved {
+            let (&type_id, codec) = self
+                .0
+                .iter()
+                .find(|(_, codec)| codec.type_name == output.output_type)
+                .ok_or_else(|| format!("output type '{}' is not registered", output.output_type))?;
+            let value = codec
+                .decode(output.value.clone())
+                .map_err(|err| format!("output type '{}': {err}", output.output_type))?;
+            outputs.insert_boxed(type_id, value);
+        }
+        Ok(outputs)
+    }
+
+    /// Names of the registered types, for debug output.
+    pub(crate) fn type_names(&self) -> Vec<&'static str> {
+        self.0.values().map(|codec| codec.type_name).collect()
+    }
+}
+
+/// Collects the [`ExecutionTrace`] of a run.
+///
+/// Pass a recorder to a run with
+/// [`RunOptions::with_recorder`](crate::RunOptions::with_recorder). Clones
+/// share the same trace, so keep one to read the trace once the run
+/// finishes. Recording the same recorder in several runs appends their
+/// events to one trace.
+#[derive(Clone, Default)]
+pub struct TraceRecorder {
+    events: Arc<Mutex<Vec<TraceEvent>>>,
+    outputs: Arc<OutputCodecs>,
+}
+
+impl TraceRecorder {
+    /// Creates a recorder that records outputs of no type except `()`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the outputs of systems returning `T`.
+    ///
+    /// Outputs of unregistered types are recorded by type name only, so
+    /// systems returning them cannot be substituted on replay.
+    #[must_use]
+    pub fn with_output<T: Output + Serialize + DeserializeOwned>(mut self) -> Self {
+        Arc::make_mut(&mut self.outputs).register::<T>();
+        self
+    }
+
+    /// Creates a recorder with the given output types.
+    pub(crate) fn with_codecs(outputs: OutputCodecs) -> Self {
+        Self {
+            events: Arc::default(),
+            outputs: Arc::new(outputs),
+        }
+    }
+
+    /// Appends `event` to the trace.
+    pub fn record(&self, event: TraceEvent) {
+        self.events.lock().push(event);
+    }
+
+    /// Returns a copy of the trace recorded so far.
+    #[must_use]
+    pub fn trace(&self) -> ExecutionTrace {
+        ExecutionTrace {
+            events: self.events.lock().clone(),
+        }
+    }
+
+    /// Takes the trace recorded so far, leaving the recorder empty.
+    #[must_use]
+    pub fn take(&self) -> ExecutionTrace {
+        ExecutionTrace {
+            events: std::mem::take(&mut *self.events.lock()),
+        }
+    }
+
+    /// Serializes a system output if its type is registered.
+    pub(crate) fn encode_output(
+        &self,
+        type_id: TypeId,
+        output: &(dyn Any + Send + Sync),
+    ) -> Option<serde_json::Value> {
+        self.outputs.encode(type_id, output)
+    }
+}
+
+impl fmt::Debug for TraceRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TraceRecorder")
+            .field("events", &self.events.lock().len())
+            .field("outputs", &self.outputs.type_names())
+            .finish()
+    }
+}
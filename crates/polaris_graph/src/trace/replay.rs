@@ -0,0 +1,272 @@
+//! Re-running graphs against a recorded [`ExecutionTrace`].
+
+use super::recorder::OutputCodecs;
+use super::{ExecutionTrace, RecordedCall, TraceError, TraceEvent, TraceNode};
+use crate::executor::{ErrorKind, ExecutionError, SystemOutcome};
+use crate::graph::Graph;
+use crate::node::{NodeId, SystemNode};
+use hashbrown::{HashMap, HashSet};
+use parking_lot::Mutex;
+use polaris_system::resource::Output;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// A recorded trace prepared for replay.
+///
+/// Pass a replay to a run with
+/// [`RunOptions::with_replay`](crate::RunOptions::with_replay). Every
+/// [`RecordedCall`] in the trace is served back to the code that makes the
+/// same call with the same request, so a replayed run never reaches the recorded
+/// services. Systems are run for real unless selected with
+/// [`substitute`](Self::substitute) or
+/// [`substitute_all`](Self::substitute_all), in which case each execution
+/// returns the outcome recorded for the same execution of that node:
+/// its output, its error, or its timeout.
+///
+/// # Example
+///
+/// ```
+/// use polaris_graph::trace::{ExecutionTrace, Replay};
+///
+/// # let trace = ExecutionTrace::default();
+/// let replay = Replay::new(trace)
+///     .with_output::<String>()
+///     .substitute("classify");
+/// ```
+#[derive(Clone)]
+pub struct Replay {
+    trace: ExecutionTrace,
+    outputs: OutputCodecs,
+    systems: Substitution,
+}
+
+/// Systems whose recorded outcome is replayed.
+#[derive(Debug, Clone)]
+enum Substitution {
+    Named(HashSet<String>),
+    All,
+}
+
+impl Replay {
+    /// Creates a replay of `trace` that substitutes recorded calls only.
+    #[must_use]
+    pub fn new(trace: ExecutionTrace) -> Self {
+        Self::with_codecs(trace, OutputCodecs::default())
+    }
+
+    /// Creates a replay with the given output types.
+    pub(crate) fn with_codecs(trace: ExecutionTrace, outputs: OutputCodecs) -> Self {
+        Self {
+            trace,
+            outputs,
+            systems: Substitution::Named(HashSet::new()),
+        }
+    }
+
+    /// Allows substituted systems returning `T` to replay their output.
+    #[must_use]
+    pub fn with_output<T: Output + Serialize + DeserializeOwned>(mut self) -> Self {
+        self.outputs.register::<T>();
+        self
+    }
+
+    /// Replays the recorded outcome of the system named `system` instead of
+    /// running it.
+    #[must_use]
+    pub fn substitute(mut self, system: impl Into<String>) -> Self {
+        if let Substitution::Named(names) = &mut self.systems {
+            names.insert(system.into());
+        }
+        self
+    }
+
+    /// Replays the recorded outcome of every system instead of running it.
+    #[must_use]
+    pub fn substitute_all(mut self) -> Self {
+        self.systems = Substitution::All;
+        self
+    }
+
+    /// Returns the trace being replayed.
+    #[must_use]
+    pub fn trace(&self) -> &ExecutionTrace {
+        &self.trace
+    }
+}
+
+impl fmt::Debug for Replay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Replay")
+            .field("events", &self.trace.events.len())
+            .field("outputs", &self.outputs.type_names())
+            .field("systems", &self.systems)
+            .finish()
+    }
+}
+
+/// Outcome of one recorded system execution.
+enum RecordedOutcome {
+    Completed {
+        output_type: String,
+        output: Option<serde_json::Value>,
+    },
+    Failed {
+        kind: ErrorKind,
+        error: String,
+    },
+    TimedOut,
+}
+
+/// Progress of a replay through its trace during one run.
+pub(crate) struct ReplayState {
+    outputs: OutputCodecs,
+    systems: Substitution,
+    /// Recorded outcomes of substituted systems by node index, in order.
+    outcomes: Mutex<HashMap<usize, VecDeque<(String, RecordedOutcome)>>>,
+    /// Recorded calls not yet served, in order.
+    calls: Mutex<VecDeque<RecordedCall>>,
+}
+
+impl ReplayState {
+    /// Prepares `replay` for a run.
+    pub(crate) fn new(replay: Replay) -> Self {
+        let mut outcomes: HashMap<usize, VecDeque<_>> = HashMap::new();
+        let mut calls = VecDeque::new();
+        for event in replay.trace.events {
+            let (node, outcome) = match event {
+                TraceEvent::Call(call) => {
+                    calls.push_back(call);
+                    continue;
+                }
+                TraceEvent::SystemCompleted {
+                    node,
+                    output_type,
+                    output,
+                } => (
+                    node,
+                    RecordedOutcome::Completed {
+                        output_type,
+                        output,
+                    },
+                ),
+                TraceEvent::SystemFailed { node, kind, error } => {
+                    (node, RecordedOutcome::Failed { kind, error })
+                }
+                TraceEvent::SystemTimedOut { node } => (node, RecordedOutcome::TimedOut),
+                _ => continue,
+            };
+            outcomes
+                .entry(node.index)
+                .or_default()
+                .push_back((node.name, outcome));
+        }
+
+        Self {
+            outputs: replay.outputs,
+            systems: replay.systems,
+            outcomes: Mutex::new(outcomes),
+            calls: Mutex::new(calls),
+        }
+    }
+
+    /// Returns the recorded outcome of the next execution of `sys`, or
+    /// `None` if the system is not substituted.
+    pub(crate) fn system_outcome(
+        &self,
+        graph: &Graph,
+        id: &NodeId,
+        sys: &SystemNode,
+    ) -> Option<Result<SystemOutcome, ExecutionError>> {
+        let substituted = match &self.systems {
+            Substitution::All => true,
+            Substitution::Named(names) => names.contains(sys.name()),
+        };
+        substituted.then(|| {
+            self.next_outcome(graph, id, sys)
+                .map_err(ExecutionError::ReplayFailed)
+        })
+    }
+
+    fn next_outcome(
+        &self,
+        graph: &Graph,
+        id: &NodeId,
+        sys: &SystemNode,
+    ) -> Result<SystemOutcome, String> {
+        let node = TraceNode::of(graph, id).ok_or_else(|| format!("node not found: {id}"))?;
+        let (name, outcome) = self
+            .outcomes
+            .lock()
+            .get_mut(&node.index)
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| format!("no recorded outcome left for system '{}'", sys.name()))?;
+        if name != sys.name() {
+            return Err(format!(
+                "expected system '{name}' at index {}, found '{}'",
+                node.index,
+                sys.name()
+            ));
+        }
+
+        match outcome {
+            RecordedOutcome::Completed {
+                output_type,
+                output,
+            } => {
+                let codec = self.outputs.get(sys.output_type_id()).ok_or_else(|| {
+                    format!(
+                        "output type {} of system '{name}' is not registered for replay",
+                        sys.output_type_name()
+                    )
+                })?;
+                if output_type != codec.type_name {
+                    return Err(format!(
+                        "system '{name}' recorded output {output_type}, expected {}",
+                        codec.type_name
+                    ));
+                }
+                let output =
+                    output.ok_or_else(|| format!("output of system '{name}' was not recorded"))?;
+                codec
+                    .decode(output)
+                    .map(SystemOutcome::Ok)
+                    .map_err(|err| format!("invalid recorded output of system '{name}': {err}"))
+            }
+            RecordedOutcome::Failed { kind, error } => Ok(SystemOutcome::Err {
+                kind,
+                message: error,
+            }),
+            RecordedOutcome::TimedOut => Ok(SystemOutcome::Timeout),
+        }
+    }
+
+    /// Takes the first unserved recorded call of `kind` to `target` made
+    /// with `request`.
+    pub(crate) fn next_call(
+        &self,
+        kind: &str,
+        target: &str,
+        request: &serde_json::Value,
+    ) -> Result<RecordedCall, TraceError> {
+        let mut calls = self.calls.lock();
+        let position = calls.iter().position(|call| {
+            call.kind == kind && call.target == target && call.request == *request
+        });
+        match position {
+            Some(position) => Ok(calls.remove(position).expect("position is in range")),
+            None if calls
+                .iter()
+                .any(|call| call.kind == kind && call.target == target) =>
+            {
+                Err(TraceError::Diverged(format!(
+                    "{kind} call to '{target}' was made with a request that was not recorded"
+                )))
+            }
+            None => Err(TraceError::Diverged(format!(
+                "no recorded {kind} call left for '{target}'"
+            ))),
+        }
+    }
+}
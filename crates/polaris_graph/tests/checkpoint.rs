@@ -0,0 +1,748 @@
+//! Integration tests for execution cursors, checkpoints, resume, and
+//! interrupts.
+//!
+//! Graphs are rebuilt between the failing or interrupted run and the resumed
+//! run, so node IDs differ and cursors must resolve nodes by position.
+
+mod test_utils;
+
+use polaris_graph::executor::{
+    CheckpointSink, ExecutionCursor, ExecutionError, FrameState, GraphExecutor, RunOptions,
+};
+use polaris_graph::graph::{Graph, ValidationError};
+use polaris_graph::interrupt::Interrupt;
+use polaris_graph::node::SubgraphOptions;
+use polaris_system::param::{Out, SystemContext};
+use polaris_system::system;
+use polaris_system::system::{BoxFuture, System, SystemError};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use test_utils::{DecisionOutput, DecisionSystem, branch};
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Test Harness
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Ordered record of system names that ran.
+type Trace = Arc<Mutex<Vec<&'static str>>>;
+
+/// System that appends its name to a trace, optionally failing on one run.
+struct TraceSystem {
+    name: &'static str,
+    trace: Trace,
+    /// Zero-based run on which the system fails instead of tracing.
+    fail_on: Option<usize>,
+    runs: AtomicUsize,
+}
+
+impl System for TraceSystem {
+    type Output = ();
+
+    fn run<'a>(
+        &'a self,
+        _ctx: &'a SystemContext<'_>,
+    ) -> BoxFuture<'a, Result<Self::Output, SystemError>> {
+        Box::pin(async move {
+            let run = self.runs.fetch_add(1, Ordering::SeqCst);
+            if self.fail_on == Some(run) {
+                return Err(SystemError::ExecutionError(format!(
+                    "{} crashed",
+                    self.name
+                )));
+            }
+            self.trace.lock().unwrap().push(self.name);
+            Ok(())
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Boxes a tracing system that never fails.
+fn step(name: &'static str, trace: &Trace) -> Box<TraceSystem> {
+    flaky(name, trace, None)
+}
+
+/// Boxes a tracing system that fails on run `fail_on`, if set.
+fn flaky(name: &'static str, trace: &Trace, fail_on: Option<usize>) -> Box<TraceSystem> {
+    Box::new(TraceSystem {
+        name,
+        trace: trace.clone(),
+        fail_on,
+        runs: AtomicUsize::new(0),
+    })
+}
+
+/// Sink that keeps every recorded cursor in memory.
+#[derive(Default)]
+struct RecordingSink {
+    cursors: Mutex<Vec<ExecutionCursor>>,
+}
+
+impl RecordingSink {
+    fn last(&self) -> ExecutionCursor {
+        self.cursors
+            .lock()
+            .unwrap()
+            .last()
+            .cloned()
+            .expect("at least one checkpoint should be recorded")
+    }
+}
+
+impl CheckpointSink for RecordingSink {
+    fn save<'a>(
+        &'a self,
+        cursor: &'a ExecutionCursor,
+        _ctx: &'a SystemContext<'_>,
+    ) -> BoxFuture<'a, Result<(), ExecutionError>> {
+        Box::pin(async move {
+            self.cursors.lock().unwrap().push(cursor.clone());
+            Ok(())
+        })
+    }
+}
+
+/// Runs `graph` with checkpoints recorded into a new sink.
+async fn run_with_sink(graph: &Graph) -> (Result<(), ExecutionError>, Arc<RecordingSink>) {
+    let sink = Arc::new(RecordingSink::default());
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute_with(
+            graph,
+            &mut ctx,
+            None,
+            RunOptions::new().with_checkpoints(sink.clone()),
+        )
+        .await
+        .map(|_| ());
+    (result, sink)
+}
+
+/// Resumes `graph` from `cursor`.
+async fn resume(graph: &Graph, cursor: ExecutionCursor) -> Result<(), ExecutionError> {
+    let mut ctx = SystemContext::new();
+    GraphExecutor::new()
+        .execute_with(graph, &mut ctx, None, RunOptions::new().resume_from(cursor))
+        .await
+        .map(|_| ())
+}
+
+/// Runs `graph` with `options`, returning the context.
+async fn run_with(
+    graph: &Graph,
+    options: RunOptions,
+) -> Result<SystemContext<'static>, ExecutionError> {
+    let mut ctx = SystemContext::new();
+    GraphExecutor::new()
+        .execute_with(graph, &mut ctx, None, options)
+        .await?;
+    Ok(ctx)
+}
+
+fn take(trace: &Trace) -> Vec<&'static str> {
+    std::mem::take(&mut *trace.lock().unwrap())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Checkpoint Recording
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn checkpoint_recorded_after_each_node() {
+    let trace = Trace::default();
+    let mut graph = Graph::new();
+    graph.add_boxed_system(step("a", &trace));
+    graph.add_boxed_system(step("b", &trace));
+    graph.add_boxed_system(step("c", &trace));
+
+    let (result, sink) = run_with_sink(&graph).await;
+    assert!(result.is_ok());
+
+    let cursors = sink.cursors.lock().unwrap();
+    let resume_points: Vec<_> = cursors
+        .iter()
+        .map(|cursor| cursor.innermost().unwrap().node_name.as_str())
+        .collect();
+    assert_eq!(resume_points, ["b", "c", "c"]);
+    let states: Vec<_> = cursors
+        .iter()
+        .map(|cursor| &cursor.innermost().unwrap().state)
+        .collect();
+    assert_eq!(
+        states,
+        [&FrameState::Next, &FrameState::Next, &FrameState::Done]
+    );
+    assert!(cursors.iter().all(|cursor| cursor.frames().len() == 1));
+}
+
+#[tokio::test]
+async fn no_checkpoints_without_sink() {
+    let trace = Trace::default();
+    let mut graph = Graph::new();
+    graph.add_boxed_system(step("a", &trace));
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new().execute(&graph, &mut ctx, None).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn sink_failure_fails_run() {
+    struct FailingSink;
+
+    impl CheckpointSink for FailingSink {
+        fn save<'a>(
+            &'a self,
+            _cursor: &'a ExecutionCursor,
+            _ctx: &'a SystemContext<'_>,
+        ) -> BoxFuture<'a, Result<(), ExecutionError>> {
+            Box::pin(async { Err(ExecutionError::CheckpointFailed("disk full".into())) })
+        }
+    }
+
+    let trace = Trace::default();
+    let mut graph = Graph::new();
+    graph.add_boxed_system(step("a", &trace));
+    graph.add_boxed_system(step("b", &trace));
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute_with(
+            &graph,
+            &mut ctx,
+            None,
+            RunOptions::new().with_checkpoints(Arc::new(FailingSink)),
+        )
+        .await;
+
+    assert!(matches!(result, Err(ExecutionError::CheckpointFailed(_))));
+    assert_eq!(
+        take(&trace),
+        ["a"],
+        "no node should run after a failed checkpoint"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Resume
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn resume_skips_completed_nodes() {
+    let trace = Trace::default();
+    let build = |fail_on| {
+        let mut graph = Graph::new();
+        graph.add_boxed_system(step("a", &trace));
+        graph.add_boxed_system(flaky("b", &trace, fail_on));
+        graph.add_boxed_system(step("c", &trace));
+        graph
+    };
+
+    let (result, sink) = run_with_sink(&build(Some(0))).await;
+    assert!(matches!(result, Err(ExecutionError::SystemError(_))));
+    assert_eq!(take(&trace), ["a"]);
+
+    resume(&build(None), sink.last())
+        .await
+        .expect("resume should succeed");
+    assert_eq!(take(&trace), ["b", "c"]);
+}
+
+#[tokio::test]
+async fn resume_inside_loop_keeps_iteration_count() {
+    let trace = Trace::default();
+    let build = |fail_on| {
+        let mut graph = Graph::new();
+        let body_trace = trace.clone();
+        graph.add_loop_n("three_times", 3, move |g| {
+            g.add_boxed_system(step("x", &body_trace));
+            g.add_boxed_system(flaky("y", &body_trace, fail_on));
+        });
+        graph.add_boxed_system(step("done", &trace));
+        graph
+    };
+
+    // `y` fails during the second iteration.
+    let (result, sink) = run_with_sink(&build(Some(1))).await;
+    assert!(result.is_err());
+    assert_eq!(take(&trace), ["x", "y", "x"]);
+
+    let cursor = sink.last();
+    assert_eq!(cursor.frames()[0].state, FrameState::Loop { iteration: 1 });
+    assert_eq!(cursor.innermost().unwrap().node_name, "y");
+
+    resume(&build(None), cursor)
+        .await
+        .expect("resume should succeed");
+    assert_eq!(take(&trace), ["y", "x", "y", "done"]);
+}
+
+#[tokio::test]
+async fn resume_parallel_skips_completed_branches() {
+    let trace = Trace::default();
+    let build = |fail_on| {
+        let mut graph = Graph::new();
+        let (left, right) = (trace.clone(), trace.clone());
+        graph.add_parallel(
+            "fan_out",
+            [
+                branch(move |g| {
+                    g.add_boxed_system(step("left", &left));
+                }),
+                branch(move |g| {
+                    g.add_boxed_system(flaky("right", &right, fail_on));
+                }),
+            ],
+        );
+        graph.add_boxed_system(step("join", &trace));
+        graph
+    };
+
+    let (result, sink) = run_with_sink(&build(Some(0))).await;
+    assert!(result.is_err());
+    assert_eq!(take(&trace), ["left"]);
+    assert!(matches!(
+        &sink.last().frames()[0].state,
+        FrameState::Parallel { completed, .. } if *completed == [0]
+    ));
+
+    resume(&build(None), sink.last())
+        .await
+        .expect("resume should succeed");
+    assert_eq!(take(&trace), ["right", "join"]);
+}
+
+#[tokio::test]
+async fn resume_decision_reuses_recorded_branch() {
+    let trace = Trace::default();
+    let build = |fail_on| {
+        let mut graph = Graph::new();
+        graph.add_boxed_system(Box::new(DecisionSystem { take_true: true }));
+        let (on_true, on_false) = (trace.clone(), trace.clone());
+        graph.add_conditional_branch::<DecisionOutput, _, _, _>(
+            "choose",
+            |out| out.take_true,
+            move |g| {
+                g.add_boxed_system(step("then_first", &on_true));
+                g.add_boxed_system(flaky("then_second", &on_true, fail_on));
+            },
+            move |g| {
+                g.add_boxed_system(step("else", &on_false));
+            },
+        );
+        graph
+    };
+
+    let (result, sink) = run_with_sink(&build(Some(0))).await;
+    assert!(result.is_err());
+    assert_eq!(take(&trace), ["then_first"]);
+
+    // The decision output is not registered for checkpoints, so re-evaluating the
+    // predicate would fail; the recorded branch must be used instead.
+    let cursor = sink.last();
+    assert_eq!(
+        cursor.frames()[0].state,
+        FrameState::Decision { branch: true }
+    );
+    resume(&build(None), cursor)
+        .await
+        .expect("resume should succeed");
+    assert_eq!(take(&trace), ["then_second"]);
+}
+
+#[tokio::test]
+async fn resume_after_last_node_of_branch_continues_after_it() {
+    let trace = Trace::default();
+    let build = |fail_on| {
+        let mut graph = Graph::new();
+        graph.add_boxed_system(Box::new(DecisionSystem { take_true: true }));
+        let on_true = trace.clone();
+        graph.add_conditional_branch::<DecisionOutput, _, _, _>(
+            "choose",
+            |out| out.take_true,
+            move |g| {
+                g.add_boxed_system(step("then", &on_true));
+            },
+            |_| {},
+        );
+        graph.add_boxed_system(flaky("after", &trace, fail_on));
+        graph
+    };
+
+    let (result, sink) = run_with_sink(&build(Some(0))).await;
+    assert!(result.is_err());
+    assert_eq!(take(&trace), ["then"]);
+
+    // The last node of the branch is checkpointed as done.
+    let done = sink
+        .cursors
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|cursor| cursor.innermost().unwrap().state == FrameState::Done)
+        .cloned()
+        .expect("the branch end should be checkpointed");
+    assert_eq!(
+        done.frames()[0].state,
+        FrameState::Decision { branch: true }
+    );
+    assert_eq!(done.innermost().unwrap().node_name, "then");
+
+    resume(&build(None), done)
+        .await
+        .expect("resume should succeed");
+    assert_eq!(take(&trace), ["after"]);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Tally(u32);
+
+#[derive(Debug, PartialEq)]
+struct Report(u32);
+
+async fn count() -> Tally {
+    Tally(3)
+}
+
+#[system]
+async fn report(tally: Out<Tally>) -> Report {
+    Report(tally.0 * 2)
+}
+
+#[tokio::test]
+async fn resume_restores_saved_outputs() {
+    let trace = Trace::default();
+    let build = |fail_on| {
+        let mut graph = Graph::new();
+        graph.add_system(count);
+        graph.add_boxed_system(flaky("b", &trace, fail_on));
+        graph.add_system(report);
+        graph
+    };
+
+    let sink = Arc::new(RecordingSink::default());
+    let result = run_with(
+        &build(Some(0)),
+        RunOptions::new()
+            .with_checkpoints(sink.clone())
+            .with_checkpoint_output::<Tally>(),
+    )
+    .await;
+    assert!(result.is_err());
+
+    let cursor = sink.last();
+    assert_eq!(cursor.outputs().len(), 1);
+    assert_eq!(cursor.outputs()[0].value, json!(3));
+
+    // `count` is not re-run, yet `report` reads its output.
+    let ctx = run_with(
+        &build(None),
+        RunOptions::new()
+            .resume_from(cursor.clone())
+            .with_checkpoint_output::<Tally>(),
+    )
+    .await
+    .expect("resume should succeed");
+    assert_eq!(*ctx.get_output::<Report>().unwrap(), Report(6));
+
+    // Saved outputs of unregistered types are rejected.
+    let result = resume(&build(None), cursor).await;
+    assert!(
+        matches!(result, Err(ExecutionError::InvalidCursor(_))),
+        "expected InvalidCursor, got {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn resume_parallel_restores_branch_outputs() {
+    let trace = Trace::default();
+    let build = |fail_on| {
+        let mut graph = Graph::new();
+        let right = trace.clone();
+        graph.add_parallel(
+            "fan_out",
+            [
+                branch(|g| {
+                    g.add_system(count);
+                }),
+                branch(move |g| {
+                    g.add_boxed_system(flaky("right", &right, fail_on));
+                }),
+            ],
+        );
+        graph.add_system(report);
+        graph
+    };
+
+    let sink = Arc::new(RecordingSink::default());
+    let result = run_with(
+        &build(Some(0)),
+        RunOptions::new()
+            .with_checkpoints(sink.clone())
+            .with_checkpoint_output::<Tally>(),
+    )
+    .await;
+    assert!(result.is_err());
+
+    let cursor = sink.last();
+    let FrameState::Parallel { completed, outputs } = &cursor.frames()[0].state else {
+        panic!("expected a parallel frame, got {cursor:?}");
+    };
+    assert_eq!(completed, &[0]);
+    assert_eq!(outputs[0][0].value, json!(3));
+
+    let ctx = run_with(
+        &build(None),
+        RunOptions::new()
+            .resume_from(cursor)
+            .with_checkpoint_output::<Tally>(),
+    )
+    .await
+    .expect("resume should succeed");
+    assert_eq!(take(&trace), ["right"]);
+    assert_eq!(*ctx.get_output::<Report>().unwrap(), Report(6));
+}
+
+#[tokio::test]
+async fn resume_rejects_cursor_for_different_graph() {
+    let trace = Trace::default();
+    let mut original = Graph::new();
+    original.add_boxed_system(step("a", &trace));
+    original.add_boxed_system(step("b", &trace));
+
+    let (result, sink) = run_with_sink(&original).await;
+    assert!(result.is_ok());
+
+    let mut changed = Graph::new();
+    changed.add_boxed_system(step("a", &trace));
+    changed.add_boxed_system(step("renamed", &trace));
+
+    let result = resume(&changed, sink.last()).await;
+    assert!(
+        matches!(result, Err(ExecutionError::InvalidCursor(_))),
+        "expected InvalidCursor, got {result:?}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Interrupts
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Approval {
+    amount: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct Verdict {
+    approved: bool,
+}
+
+#[derive(Debug, PartialEq)]
+struct Settled(bool);
+
+async fn propose() -> Approval {
+    Approval { amount: 40 }
+}
+
+#[system]
+async fn settle(verdict: Out<Verdict>) -> Settled {
+    Settled(verdict.approved)
+}
+
+/// Proposes, waits for a verdict, then settles.
+fn approval_graph() -> Graph {
+    let mut graph = Graph::new();
+    graph
+        .add_system(propose)
+        .add_interrupt::<Approval, Verdict>("approve")
+        .add_system(settle);
+    graph
+}
+
+/// Unwraps the interrupt a run stopped at.
+fn interrupted<T>(result: Result<T, ExecutionError>) -> Interrupt {
+    match result {
+        Err(ExecutionError::Interrupted(interrupt)) => *interrupt,
+        Err(err) => panic!("expected Interrupted, got {err:?}"),
+        Ok(_) => panic!("expected Interrupted, but the run completed"),
+    }
+}
+
+/// Resumes `graph` from `cursor` with `response`, returning the context.
+async fn answer(
+    graph: &Graph,
+    cursor: ExecutionCursor,
+    response: serde_json::Value,
+) -> Result<SystemContext<'static>, ExecutionError> {
+    let mut ctx = SystemContext::new();
+    GraphExecutor::new()
+        .execute_with(
+            graph,
+            &mut ctx,
+            None,
+            RunOptions::new()
+                .resume_from(cursor)
+                .with_interrupt_response(response),
+        )
+        .await?;
+    Ok(ctx)
+}
+
+#[tokio::test]
+async fn interrupt_suspends_run_with_request() {
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute(&approval_graph(), &mut ctx, None)
+        .await;
+
+    let interrupt = interrupted(result);
+    assert_eq!(interrupt.request.node_name, "approve");
+    assert_eq!(
+        interrupt.request.decode::<Approval>().unwrap(),
+        Approval { amount: 40 }
+    );
+    let frame = interrupt.cursor.innermost().unwrap();
+    assert_eq!(frame.node_name, "approve");
+    assert_eq!(frame.state, FrameState::Interrupted);
+    assert!(ctx.get_output::<Settled>().is_err());
+}
+
+#[tokio::test]
+async fn interrupt_resumes_with_response() {
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute(&approval_graph(), &mut ctx, None)
+        .await;
+    let interrupt = interrupted(result);
+
+    let ctx = answer(
+        &approval_graph(),
+        interrupt.cursor,
+        json!({ "approved": true }),
+    )
+    .await
+    .expect("resume should succeed");
+
+    assert_eq!(*ctx.get_output::<Settled>().unwrap(), Settled(true));
+    // Nodes before the interrupt are not re-run.
+    assert!(ctx.get_output::<Approval>().is_err());
+}
+
+#[tokio::test]
+async fn interrupt_inside_loop_resumes_same_iteration() {
+    let build = || {
+        let mut graph = Graph::new();
+        graph.add_loop_n("review", 2, |g| {
+            g.add_system(propose)
+                .add_interrupt::<Approval, Verdict>("approve")
+                .add_system(settle);
+        });
+        graph
+    };
+
+    let mut ctx = SystemContext::new();
+    let first = interrupted(GraphExecutor::new().execute(&build(), &mut ctx, None).await);
+    assert_eq!(
+        first.cursor.frames()[0].state,
+        FrameState::Loop { iteration: 0 }
+    );
+
+    // The second iteration stops at the same interrupt.
+    let second = interrupted(answer(&build(), first.cursor, json!({ "approved": false })).await);
+    assert_eq!(
+        second.cursor.frames()[0].state,
+        FrameState::Loop { iteration: 1 }
+    );
+
+    let ctx = answer(&build(), second.cursor, json!({ "approved": true }))
+        .await
+        .expect("resume should succeed");
+    assert_eq!(*ctx.get_output::<Settled>().unwrap(), Settled(true));
+}
+
+#[tokio::test]
+async fn interrupt_rejects_missing_or_invalid_response() {
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute(&approval_graph(), &mut ctx, None)
+        .await;
+    let cursor = interrupted(result).cursor;
+
+    let result = resume(&approval_graph(), cursor.clone()).await;
+    assert!(
+        matches!(
+            &result,
+            Err(ExecutionError::InterruptFailed { name, .. }) if &**name == "approve"
+        ),
+        "expected InterruptFailed, got {result:?}"
+    );
+
+    let result = answer(&approval_graph(), cursor, json!({ "approved": "yes" })).await;
+    assert!(
+        matches!(
+            &result,
+            Err(ExecutionError::InterruptFailed { name, .. }) if &**name == "approve"
+        ),
+        "expected InterruptFailed, got {:?}",
+        result.map(|_| ())
+    );
+}
+
+#[tokio::test]
+async fn interrupt_inside_parallel_branch_is_rejected() {
+    let mut graph = Graph::new();
+    graph.add_parallel(
+        "fan_out",
+        [branch(|g| {
+            g.add_system(propose)
+                .add_interrupt::<Approval, Verdict>("approve");
+        })],
+    );
+
+    let errors = graph.validate().errors;
+    assert!(
+        matches!(
+            errors.as_slice(),
+            [ValidationError::NestedInterrupt { name, parent, .. }]
+                if &**name == "approve" && &**parent == "fan_out"
+        ),
+        "expected NestedInterrupt, got {errors:?}"
+    );
+
+    // Running it anyway fails the interrupt instead of suspending the run.
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new().execute(&graph, &mut ctx, None).await;
+    assert!(
+        matches!(
+            &result,
+            Err(ExecutionError::InterruptFailed { name, .. }) if &**name == "approve"
+        ),
+        "expected InterruptFailed, got {result:?}"
+    );
+}
+
+#[test]
+fn interrupt_inside_subgraph_is_rejected() {
+    let mut inner = Graph::new();
+    inner
+        .add_system(propose)
+        .add_interrupt::<Approval, Verdict>("approve");
+    let mut graph = Graph::new();
+    graph.add_subgraph("review", inner, SubgraphOptions::new());
+
+    let errors = graph.validate().errors;
+    assert!(
+        matches!(
+            errors.as_slice(),
+            [ValidationError::NestedInterrupt { name, parent, .. }]
+                if &**name == "approve" && &**parent == "review"
+        ),
+        "expected NestedInterrupt, got {errors:?}"
+    );
+}
@@ -1,16 +1,19 @@
 //! Edge case tests for graph execution.
 //!
-//! Tests covering error handling, timeouts, parallel failures, loop termination,
-//! output chaining, recursion limits, and switch edge cases.
+//! Tests covering error handling, timeouts, parallel failures and policies, loop termination,
+//! output chaining, recursion limits, switch edge cases, cancellation, and run deadlines and budgets.
 
 mod test_utils;
 
-use polaris_graph::executor::{ErrorKind, ExecutionError, GraphExecutor};
+use polaris_graph::executor::{
+    CancellationToken, ErrorKind, ExecutionBudget, ExecutionError, GraphExecutor, ParallelOutcome,
+};
 use polaris_graph::graph::Graph;
-use polaris_graph::node::RetryPolicy;
-use polaris_system::param::SystemContext;
+use polaris_graph::node::{ParallelOptions, ParallelPolicy, RetryPolicy};
+use polaris_system::param::{Res, SystemContext};
+use polaris_system::system;
 use polaris_system::system::{BoxFuture, System, SystemError};
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use test_utils::{
     ConsumerSystem, DecisionOutput, DecisionSystem, ErrorKindLog, EventuallySucceedsSystem,
@@ -207,6 +210,206 @@ async fn parallel_branch_failure_stops_execution() {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// PARALLEL POLICY TESTS
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Runs `graph` and returns the result with the final context's parallel outcome.
+async fn run_parallel(graph: &Graph) -> (Result<(), ExecutionError>, Option<ParallelOutcome>) {
+    let server = create_test_server();
+    let mut ctx = server.create_context();
+    let result = GraphExecutor::new()
+        .execute(graph, &mut ctx, None)
+        .await
+        .map(|_| ());
+    let outcome = ctx
+        .get_output::<ParallelOutcome>()
+        .ok()
+        .map(|outcome| (*outcome).clone());
+    (result, outcome)
+}
+
+/// Verifies that `CollectErrors` runs every branch and reports failures as an output.
+#[tokio::test]
+async fn parallel_collect_errors_reports_failed_branches() {
+    let mut graph = Graph::new();
+    graph.add_parallel_with(
+        "collect",
+        ParallelOptions::new().with_policy(ParallelPolicy::CollectErrors),
+        [
+            branch(|g| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }),
+            branch(|g| {
+                g.add_boxed_system(Box::new(FailingSystem));
+            }),
+            branch(|g| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }),
+        ],
+    );
+
+    let (result, outcome) = run_parallel(&graph).await;
+    assert!(result.is_ok(), "failures should not fail the node");
+
+    let outcome = outcome.expect("parallel outcome should be stored");
+    assert_eq!(&*outcome.node_name, "collect");
+    assert_eq!(outcome.succeeded, [0, 2]);
+    assert_eq!(outcome.failed.len(), 1);
+    assert_eq!(outcome.failed[0].branch, 1);
+    assert!(matches!(
+        outcome.failed[0].error,
+        ExecutionError::SystemError(_)
+    ));
+    assert!(outcome.abandoned.is_empty());
+    assert!(!outcome.is_complete());
+}
+
+/// Verifies that `FirstSuccess` completes without waiting for slower branches.
+#[tokio::test]
+async fn parallel_first_success_abandons_remaining_branches() {
+    let mut graph = Graph::new();
+    graph.add_parallel_with(
+        "race",
+        ParallelOptions::new().with_policy(ParallelPolicy::FirstSuccess),
+        [
+            branch(|g| {
+                g.add_boxed_system(Box::new(SlowSystem {
+                    duration: std::time::Duration::from_secs(30),
+                }));
+            }),
+            branch(|g| {
+                g.add_boxed_system(Box::new(FailingSystem));
+            }),
+            branch(|g| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }),
+        ],
+    );
+
+    let start = std::time::Instant::now();
+    let (result, outcome) = run_parallel(&graph).await;
+    assert!(result.is_ok());
+    assert!(start.elapsed() < std::time::Duration::from_secs(5));
+
+    let outcome = outcome.expect("parallel outcome should be stored");
+    assert_eq!(outcome.succeeded, [2]);
+    assert_eq!(outcome.abandoned, [0]);
+}
+
+/// Verifies that a quorum fails as soon as it can no longer be reached.
+#[tokio::test]
+async fn parallel_quorum_fails_when_unreachable() {
+    let mut graph = Graph::new();
+    graph.add_parallel_with(
+        "quorum",
+        ParallelOptions::new().with_policy(ParallelPolicy::Quorum(2)),
+        [
+            branch(|g| {
+                g.add_boxed_system(Box::new(FailingSystem));
+            }),
+            branch(|g| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }),
+            branch(|g| {
+                g.add_boxed_system(Box::new(FailingSystem));
+            }),
+        ],
+    );
+
+    let (result, outcome) = run_parallel(&graph).await;
+    match result {
+        Err(ExecutionError::ParallelFailed {
+            required, failures, ..
+        }) => {
+            assert_eq!(required, 2);
+            let branches: Vec<_> = failures.iter().map(|failure| failure.branch).collect();
+            assert_eq!(branches, [0, 2]);
+        }
+        other => panic!("expected ParallelFailed, got {other:?}"),
+    }
+    assert!(outcome.is_none(), "a failed node stores no outcome");
+}
+
+/// Verifies that a quorum completes once enough branches succeed.
+#[tokio::test]
+async fn parallel_quorum_tolerates_minority_failure() {
+    let mut graph = Graph::new();
+    graph.add_parallel_with(
+        "quorum",
+        ParallelOptions::new().with_policy(ParallelPolicy::Quorum(2)),
+        [
+            branch(|g| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }),
+            branch(|g| {
+                g.add_boxed_system(Box::new(FailingSystem));
+            }),
+            branch(|g| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }),
+        ],
+    );
+
+    let (result, outcome) = run_parallel(&graph).await;
+    assert!(result.is_ok());
+    assert_eq!(outcome.expect("outcome").succeeded, [0, 2]);
+}
+
+/// System that records the peak number of concurrently running instances.
+struct ConcurrencyProbe {
+    running: Arc<AtomicUsize>,
+    peak: Arc<AtomicUsize>,
+}
+
+impl System for ConcurrencyProbe {
+    type Output = ();
+
+    fn run<'a>(
+        &'a self,
+        _ctx: &'a SystemContext<'_>,
+    ) -> BoxFuture<'a, Result<Self::Output, SystemError>> {
+        Box::pin(async move {
+            let now = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "concurrency_probe"
+    }
+}
+
+/// Verifies that `max_concurrency` bounds the number of branches in flight.
+#[tokio::test]
+async fn parallel_max_concurrency_limits_running_branches() {
+    let running = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+
+    let mut graph = Graph::new();
+    graph.add_parallel_with(
+        "bounded",
+        ParallelOptions::new().with_max_concurrency(2),
+        (0..5).map(|_| {
+            let probe = ConcurrencyProbe {
+                running: Arc::clone(&running),
+                peak: Arc::clone(&peak),
+            };
+            branch(move |g| {
+                g.add_boxed_system(Box::new(probe));
+            })
+        }),
+    );
+
+    let (result, outcome) = run_parallel(&graph).await;
+    assert!(result.is_ok());
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+    assert!(outcome.expect("outcome").is_complete());
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // DECISION EDGE CASES
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -934,3 +1137,377 @@ async fn retry_with_timeout_retries_on_timeout() {
         "should have attempted twice"
     );
 }
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// CANCELLATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// System that triggers a cancellation token when executed.
+struct CancellingSystem {
+    token: CancellationToken,
+}
+
+impl System for CancellingSystem {
+    type Output = ();
+
+    fn run<'a>(
+        &'a self,
+        _ctx: &'a SystemContext<'_>,
+    ) -> BoxFuture<'a, Result<Self::Output, SystemError>> {
+        Box::pin(async move {
+            self.token.cancel();
+            Ok(())
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "cancelling_system"
+    }
+}
+
+/// A token cancelled before execution starts prevents any system from running.
+#[tokio::test]
+async fn cancelled_before_start_runs_no_systems() {
+    let flag = Arc::new(Mutex::new(false));
+    let mut graph = Graph::new();
+    graph.add_boxed_system(Box::new(FlagSystem { flag: flag.clone() }));
+
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute_with_cancellation(&graph, &mut ctx, None, &token)
+        .await;
+
+    assert!(
+        matches!(result, Err(ExecutionError::Cancelled)),
+        "expected Cancelled, got {result:?}"
+    );
+    assert!(!*flag.lock().unwrap(), "system should not have run");
+}
+
+/// Cancellation is observed between nodes, so later systems do not run.
+#[tokio::test]
+async fn cancellation_stops_before_next_node() {
+    let token = CancellationToken::new();
+    let flag = Arc::new(Mutex::new(false));
+
+    let mut graph = Graph::new();
+    graph.add_boxed_system(Box::new(CancellingSystem {
+        token: token.clone(),
+    }));
+    graph.add_boxed_system(Box::new(FlagSystem { flag: flag.clone() }));
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute_with_cancellation(&graph, &mut ctx, None, &token)
+        .await;
+
+    assert!(
+        matches!(result, Err(ExecutionError::Cancelled)),
+        "expected Cancelled, got {result:?}"
+    );
+    assert!(!*flag.lock().unwrap(), "second system should not have run");
+}
+
+/// Cancellation interrupts a long-running system instead of waiting for it.
+#[tokio::test]
+async fn cancellation_interrupts_running_system() {
+    use std::time::{Duration, Instant};
+
+    let mut graph = Graph::new();
+    graph.add_boxed_system(Box::new(SlowSystem {
+        duration: Duration::from_secs(30),
+    }));
+
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        canceller.cancel();
+    });
+
+    let start = Instant::now();
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute_with_cancellation(&graph, &mut ctx, None, &token)
+        .await;
+
+    assert!(
+        matches!(result, Err(ExecutionError::Cancelled)),
+        "expected Cancelled, got {result:?}"
+    );
+    assert!(
+        start.elapsed() < Duration::from_secs(5),
+        "cancellation should not wait for the system to finish"
+    );
+}
+
+/// Cancellation during a retry delay stops further attempts.
+#[tokio::test]
+async fn cancellation_during_retry_delay() {
+    use std::time::Duration;
+
+    let attempts = Arc::new(AtomicU32::new(0));
+    let mut graph = Graph::new();
+    graph
+        .system_boxed(Box::new(EventuallySucceedsSystem {
+            fail_count: 5,
+            attempts: attempts.clone(),
+        }))
+        .with_retry(RetryPolicy::fixed(5, Duration::from_secs(30)));
+
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        canceller.cancel();
+    });
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute_with_cancellation(&graph, &mut ctx, None, &token)
+        .await;
+
+    assert!(
+        matches!(result, Err(ExecutionError::Cancelled)),
+        "expected Cancelled, got {result:?}"
+    );
+    assert_eq!(
+        attempts.load(Ordering::SeqCst),
+        1,
+        "no retry should start after cancellation"
+    );
+}
+
+/// Cancellation is checked on each loop iteration.
+#[tokio::test]
+async fn cancellation_stops_loop() {
+    let token = CancellationToken::new();
+    let iterations = Arc::new(AtomicU32::new(0));
+
+    struct CountingCanceller {
+        token: CancellationToken,
+        iterations: Arc<AtomicU32>,
+    }
+
+    impl System for CountingCanceller {
+        type Output = ();
+
+        fn run<'a>(
+            &'a self,
+            _ctx: &'a SystemContext<'_>,
+        ) -> BoxFuture<'a, Result<Self::Output, SystemError>> {
+            Box::pin(async move {
+                if self.iterations.fetch_add(1, Ordering::SeqCst) == 2 {
+                    self.token.cancel();
+                }
+                Ok(())
+            })
+        }
+
+        fn name(&self) -> &'static str {
+            "counting_canceller"
+        }
+    }
+
+    let mut graph = Graph::new();
+    let body_token = token.clone();
+    let body_iterations = iterations.clone();
+    graph.add_loop_n("cancellable_loop", 100, move |g| {
+        g.add_boxed_system(Box::new(CountingCanceller {
+            token: body_token,
+            iterations: body_iterations,
+        }));
+    });
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute_with_cancellation(&graph, &mut ctx, None, &token)
+        .await;
+
+    assert!(
+        matches!(result, Err(ExecutionError::Cancelled)),
+        "expected Cancelled, got {result:?}"
+    );
+    assert_eq!(iterations.load(Ordering::SeqCst), 3);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// DEADLINE AND NODE BUDGET TESTS
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// A run that outlives the executor deadline fails with `DeadlineExceeded`.
+#[tokio::test]
+async fn deadline_interrupts_running_system() {
+    use std::time::{Duration, Instant};
+
+    let mut graph = Graph::new();
+    graph.add_boxed_system(Box::new(SlowSystem {
+        duration: Duration::from_secs(30),
+    }));
+
+    let start = Instant::now();
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .with_deadline(Duration::from_millis(20))
+        .execute(&graph, &mut ctx, None)
+        .await;
+
+    match result {
+        Err(ExecutionError::DeadlineExceeded { deadline, elapsed }) => {
+            assert_eq!(deadline, Duration::from_millis(20));
+            assert!(elapsed >= deadline);
+        }
+        other => panic!("expected DeadlineExceeded, got {other:?}"),
+    }
+    assert!(
+        start.elapsed() < Duration::from_secs(5),
+        "deadline should not wait for the system to finish"
+    );
+}
+
+/// The deadline is not a per-node timeout, so it bypasses timeout handlers.
+#[tokio::test]
+async fn deadline_does_not_route_to_timeout_handler() {
+    use std::time::Duration;
+
+    let mut graph = Graph::new();
+    graph
+        .system_boxed(Box::new(SlowSystem {
+            duration: Duration::from_secs(30),
+        }))
+        .with_timeout(Duration::from_secs(10))
+        .on_timeout(|g| {
+            g.add_boxed_system(Box::new(HandlerSystem));
+        });
+
+    let mut ctx = SystemContext::new();
+    let log = HandlerLog::default();
+    ctx.insert(log.clone());
+
+    let result = GraphExecutor::new()
+        .with_deadline(Duration::from_millis(20))
+        .execute(&graph, &mut ctx, None)
+        .await;
+
+    assert!(
+        matches!(result, Err(ExecutionError::DeadlineExceeded { .. })),
+        "expected DeadlineExceeded, got {result:?}"
+    );
+    assert!(!log.was_invoked(), "timeout handler should not run");
+}
+
+/// The node budget counts every loop iteration's body.
+#[tokio::test]
+async fn node_budget_exceeded_in_loop() {
+    let mut graph = Graph::new();
+    graph.add_loop_n("budgeted_loop", 100, |g| {
+        g.add_boxed_system(Box::new(SuccessSystem));
+    });
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .with_node_budget(10)
+        .execute(&graph, &mut ctx, None)
+        .await;
+
+    assert!(
+        matches!(result, Err(ExecutionError::NodeBudgetExceeded { max: 10 })),
+        "expected NodeBudgetExceeded, got {result:?}"
+    );
+}
+
+/// The node budget is shared across parallel branches.
+#[tokio::test]
+async fn node_budget_shared_across_parallel_branches() {
+    let mut graph = Graph::new();
+    graph.add_parallel(
+        "budgeted_parallel",
+        [
+            branch(|g| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }),
+            branch(|g| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }),
+        ],
+    );
+
+    // The parallel node and four systems need five executions.
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .with_node_budget(4)
+        .execute(&graph, &mut ctx, None)
+        .await;
+    assert!(
+        matches!(result, Err(ExecutionError::NodeBudgetExceeded { max: 4 })),
+        "expected NodeBudgetExceeded, got {result:?}"
+    );
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .with_node_budget(100)
+        .execute(&graph, &mut ctx, None)
+        .await;
+    assert!(result.is_ok(), "should fit in a larger budget: {result:?}");
+}
+
+/// Budget snapshot observed by a system.
+#[derive(Debug)]
+struct ObservedBudget {
+    remaining_nodes: Option<usize>,
+    remaining_time: Option<std::time::Duration>,
+}
+
+/// System that reports the remaining budget it observes.
+#[system]
+async fn observe_budget(budget: Res<ExecutionBudget>) -> ObservedBudget {
+    ObservedBudget {
+        remaining_nodes: budget.remaining_nodes(),
+        remaining_time: budget.remaining_time(),
+    }
+}
+
+/// Systems can read the remaining budget as a resource.
+#[tokio::test]
+async fn remaining_budget_readable_as_resource() {
+    use std::time::Duration;
+
+    let mut graph = Graph::new();
+    graph.add_boxed_system(Box::new(SuccessSystem));
+    graph.add_system(observe_budget);
+
+    let executor = GraphExecutor::new()
+        .with_deadline(Duration::from_secs(60))
+        .with_node_budget(10);
+
+    let mut ctx = SystemContext::new();
+    assert!(
+        executor.validate_resources(&graph, &ctx, None).is_ok(),
+        "ExecutionBudget should be treated as executor-provided"
+    );
+
+    executor
+        .execute(&graph, &mut ctx, None)
+        .await
+        .expect("execution should succeed");
+
+    let observed = ctx
+        .get_output::<ObservedBudget>()
+        .expect("ObservedBudget should be available");
+    assert_eq!(observed.remaining_nodes, Some(8));
+    assert!(
+        observed
+            .remaining_time
+            .is_some_and(|left| left > Duration::ZERO && left <= Duration::from_secs(60))
+    );
+    drop(observed);
+    assert!(
+        !ctx.contains_resource::<ExecutionBudget>(),
+        "the budget should not outlive the run"
+    );
+}
@@ -0,0 +1,319 @@
+//! Tests for exporting graph topology to Mermaid, DOT and JSON.
+
+mod test_utils;
+
+use polaris_graph::executor::GraphExecutor;
+use polaris_graph::graph::{EdgeKind, Graph, GraphDescription, NodeKind, RetryDescription};
+use polaris_graph::node::{ParallelOptions, ParallelPolicy, RetryPolicy};
+use polaris_system::param::SystemContext;
+use std::time::Duration;
+use test_utils::{
+    DecisionOutput, DecisionSystem, FailingSystem, HandlerSystem, SuccessSystem, SwitchKeySystem,
+    SwitchOutput, branch,
+};
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Fixtures
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Graph with a decision, a configured system with error and timeout
+/// handlers, a parallel node, and a loop.
+fn sample_graph() -> Graph {
+    let mut graph = Graph::new();
+    graph.add_boxed_system(Box::new(DecisionSystem { take_true: true }));
+    graph.add_conditional_branch::<DecisionOutput, _, _, _>(
+        "choose",
+        |out| out.take_true,
+        |g| {
+            g.system_boxed(Box::new(FailingSystem))
+                .with_timeout(Duration::from_millis(250))
+                .with_retry(RetryPolicy::fixed(2, Duration::from_millis(10)))
+                .on_error(|h| {
+                    h.add_boxed_system(Box::new(HandlerSystem));
+                })
+                .on_timeout(|h| {
+                    h.add_boxed_system(Box::new(SuccessSystem));
+                });
+        },
+        |g| {
+            g.add_boxed_system(Box::new(SuccessSystem));
+        },
+    );
+    graph.add_parallel_with(
+        "fan_out",
+        ParallelOptions::new()
+            .with_max_concurrency(1)
+            .with_policy(ParallelPolicy::Quorum(1)),
+        [
+            branch(|g| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }),
+            branch(|g| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }),
+        ],
+    );
+    graph.add_loop_n("repeat", 2, |g| {
+        g.add_boxed_system(Box::new(SuccessSystem));
+    });
+    graph
+}
+
+fn kind_count(description: &GraphDescription, kind: EdgeKind) -> usize {
+    description
+        .edges
+        .iter()
+        .filter(|edge| edge.kind == kind)
+        .count()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Description
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn describe_lists_every_node_and_edge() {
+    let graph = sample_graph();
+    let description = graph.describe();
+
+    assert_eq!(description.nodes.len(), graph.node_count());
+    assert_eq!(
+        description.entry,
+        graph.entry().map(|id| id.as_str().to_owned())
+    );
+    assert_eq!(kind_count(&description, EdgeKind::Error), 1);
+    assert_eq!(kind_count(&description, EdgeKind::Timeout), 1);
+    assert_eq!(kind_count(&description, EdgeKind::LoopBody), 1);
+    // Decision true/false plus two parallel branches.
+    assert_eq!(kind_count(&description, EdgeKind::Branch), 4);
+
+    let labels: Vec<_> = description
+        .edges
+        .iter()
+        .filter_map(|edge| edge.label.as_deref())
+        .collect();
+    assert!(labels.contains(&"true") && labels.contains(&"false"));
+    assert!(labels.contains(&"branch 1"));
+}
+
+#[test]
+fn describe_records_system_configuration() {
+    let description = sample_graph().describe();
+
+    let failing = description
+        .nodes
+        .iter()
+        .find(|node| node.name == "failing_system")
+        .expect("failing system should be described");
+    let NodeKind::System {
+        timeout_ms, retry, ..
+    } = &failing.kind
+    else {
+        panic!("expected a system node, got {:?}", failing.kind);
+    };
+    assert_eq!(*timeout_ms, Some(250));
+    assert_eq!(
+        *retry,
+        Some(RetryDescription::Fixed {
+            max_retries: 2,
+            delay_ms: 10
+        })
+    );
+
+    let decision = description
+        .nodes
+        .iter()
+        .find(|node| node.name == "choose")
+        .unwrap();
+    assert!(matches!(
+        &decision.kind,
+        NodeKind::Decision { predicate_input: Some(input) } if input.ends_with("DecisionOutput")
+    ));
+
+    let parallel = description
+        .nodes
+        .iter()
+        .find(|node| node.name == "fan_out")
+        .unwrap();
+    assert!(matches!(
+        &parallel.kind,
+        NodeKind::Parallel { max_concurrency: Some(1), policy, .. } if policy == "quorum(1)"
+    ));
+}
+
+#[test]
+fn describe_records_output_types() {
+    let mut graph = Graph::new();
+    graph.add_boxed_system(Box::new(SwitchKeySystem { key: "a" }));
+    graph.add_switch::<SwitchOutput, _, _, _>(
+        "route",
+        |out| out.key,
+        vec![(
+            "a",
+            Box::new(|g: &mut Graph| {
+                g.add_boxed_system(Box::new(SuccessSystem));
+            }) as Box<dyn FnOnce(&mut Graph)>,
+        )],
+        None,
+    );
+
+    let description = graph.describe();
+    let NodeKind::System { output, .. } = &description.nodes[0].kind else {
+        panic!("expected a system node");
+    };
+    assert!(output.ends_with("SwitchOutput"));
+    let switch = description
+        .nodes
+        .iter()
+        .find(|node| node.name == "route")
+        .unwrap();
+    assert!(matches!(
+        &switch.kind,
+        NodeKind::Switch { discriminator_input: Some(input) } if input.ends_with("SwitchOutput")
+    ));
+}
+
+#[test]
+fn description_round_trips_through_json() {
+    let description = sample_graph().describe();
+
+    let json = serde_json::to_value(&description).unwrap();
+    assert_eq!(json["nodes"][1]["kind"], "decision");
+    assert!(
+        json["edges"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|edge| edge["kind"] == "timeout")
+    );
+
+    let parsed: GraphDescription = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, description);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Rendering
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn mermaid_renders_shapes_and_handler_edges() {
+    let mermaid = sample_graph().to_mermaid();
+
+    assert!(mermaid.starts_with("flowchart TD\n"));
+    assert!(mermaid.contains("n0[\"decision_system\"]"));
+    assert!(mermaid.contains("{\"choose\"}"));
+    assert!(mermaid.contains("[[\"fan_out<br/>quorum(1), max 1\"]]"));
+    assert!(mermaid.contains("timeout 250ms<br/>retry 2x fixed"));
+    assert!(mermaid.contains("-. error .->"));
+    assert!(mermaid.contains("-. timeout .->"));
+    assert!(mermaid.contains("-- true -->"));
+    assert!(!mermaid.contains("classDef visited"));
+}
+
+#[test]
+fn dot_renders_shapes_and_handler_edges() {
+    let dot = sample_graph().to_dot();
+
+    assert!(dot.starts_with("digraph G {"));
+    assert!(dot.trim_end().ends_with('}'));
+    assert!(dot.contains("label=\"choose\", shape=diamond"));
+    assert!(dot.contains("shape=parallelogram"));
+    assert!(dot.contains("label=\"error\", style=dashed, color=red"));
+    assert!(dot.contains("label=\"timeout\", style=dashed, color=orange"));
+    assert!(!dot.contains("penwidth"));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Executed Path Overlay
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn execution_path_overlays_taken_branch() {
+    let mut graph = Graph::new();
+    graph.add_boxed_system(Box::new(DecisionSystem { take_true: false }));
+    graph.add_conditional_branch::<DecisionOutput, _, _, _>(
+        "choose",
+        |out| out.take_true,
+        |g| {
+            g.add_boxed_system(Box::new(FailingSystem));
+        },
+        |g| {
+            g.add_boxed_system(Box::new(SuccessSystem));
+        },
+    );
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute(&graph, &mut ctx, None)
+        .await
+        .expect("execution should succeed");
+    assert_eq!(result.path.len(), result.nodes_executed);
+
+    let description = graph
+        .describe()
+        .with_path(&result.path)
+        .with_edges(&result.edges);
+    let visited: Vec<_> = description
+        .nodes
+        .iter()
+        .filter(|node| node.visited)
+        .map(|node| node.name.as_str())
+        .collect();
+    assert_eq!(visited, ["decision_system", "choose", "success_system"]);
+
+    let taken: Vec<_> = description
+        .edges
+        .iter()
+        .filter(|edge| edge.taken)
+        .map(|edge| (edge.kind, edge.label.as_deref()))
+        .collect();
+    assert_eq!(
+        taken,
+        [
+            (EdgeKind::Sequential, None),
+            (EdgeKind::Branch, Some("false"))
+        ]
+    );
+
+    let mermaid = description.to_mermaid();
+    assert!(mermaid.contains("class n0,n1,n3 visited"));
+    assert!(mermaid.contains("linkStyle 0,2 stroke"));
+    assert!(description.to_dot().contains("penwidth=3"));
+}
+
+#[tokio::test]
+async fn execution_edges_mark_only_followed_edges() {
+    let mut graph = Graph::new();
+    let ok = graph.add_boxed_system(Box::new(SuccessSystem));
+    let failing = graph.add_boxed_system(Box::new(FailingSystem));
+    graph.add_error_handler_for([ok, failing], |h| {
+        h.add_boxed_system(Box::new(HandlerSystem));
+    });
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute(&graph, &mut ctx, None)
+        .await
+        .expect("the error should be handled");
+
+    // Both ends of the unused error edge from the first system were visited,
+    // but only the edges the run followed are taken.
+    let description = graph
+        .describe()
+        .with_path(&result.path)
+        .with_edges(&result.edges);
+    assert!(description.nodes.iter().all(|node| node.visited));
+    let taken: Vec<_> = description
+        .edges
+        .iter()
+        .map(|edge| (edge.kind, edge.taken))
+        .collect();
+    assert_eq!(
+        taken,
+        [
+            (EdgeKind::Sequential, true),
+            (EdgeKind::Error, false),
+            (EdgeKind::Error, true),
+        ]
+    );
+}
@@ -1,29 +1,32 @@
 //! Integration tests for the graph hook system.
 //!
 //! Ensures lifecycle schedules (graph, system, decision, switch,
-//! loop, parallel) and custom schedule markers attached to system nodes
+//! loop, parallel, subgraph) and custom schedule markers attached to system nodes
 //! are correctly invoked.
 
 mod test_utils;
 
 use polaris_graph::ExecutionError;
-use polaris_graph::executor::GraphExecutor;
+use polaris_graph::executor::{CancellationToken, GraphExecutor};
 use polaris_graph::graph::Graph;
 use polaris_graph::hooks::HooksAPI;
 use polaris_graph::hooks::events::GraphEvent;
 use polaris_graph::hooks::schedule::{
-    OnDecisionComplete, OnDecisionStart, OnGraphComplete, OnGraphFailure, OnGraphStart, OnLoopEnd,
-    OnLoopIteration, OnLoopStart, OnParallelComplete, OnParallelStart, OnSwitchComplete,
+    OnDecisionComplete, OnDecisionStart, OnGraphCancelled, OnGraphComplete, OnGraphFailure,
+    OnGraphInterrupted, OnGraphStart, OnLoopEnd, OnLoopIteration, OnLoopStart, OnParallelComplete,
+    OnParallelStart, OnSubgraphComplete, OnSubgraphFailure, OnSubgraphStart, OnSwitchComplete,
     OnSwitchStart, OnSystemComplete, OnSystemError, OnSystemStart,
 };
-use polaris_graph::node::NodeId;
+use polaris_graph::node::{NodeId, SubgraphOptions};
 use polaris_system::param::SystemContext;
 use polaris_system::plugin::Schedule;
+use polaris_system::resource::LocalResource;
 use polaris_system::system;
 use polaris_system::system::SystemError;
 use std::sync::{Arc, Mutex};
 use test_utils::{
-    DecisionOutput, DecisionSystem, FailingSystem, SuccessSystem, SwitchKeySystem, SwitchOutput,
+    DecisionOutput, DecisionSystem, FailingSystem, SlowSystem, SuccessSystem, SwitchKeySystem,
+    SwitchOutput,
 };
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -68,6 +71,8 @@ fn register_all_builtin_hooks(hooks: &HooksAPI) -> EventLog {
         OnGraphStart => "OnGraphStart",
         OnGraphComplete => "OnGraphComplete",
         OnGraphFailure => "OnGraphFailure",
+        OnGraphCancelled => "OnGraphCancelled",
+        OnGraphInterrupted => "OnGraphInterrupted",
         OnSystemStart => "OnSystemStart",
         OnSystemComplete => "OnSystemComplete",
         OnSystemError => "OnSystemError",
@@ -80,6 +85,9 @@ fn register_all_builtin_hooks(hooks: &HooksAPI) -> EventLog {
         OnLoopEnd => "OnLoopEnd",
         OnParallelStart => "OnParallelStart",
         OnParallelComplete => "OnParallelComplete",
+        OnSubgraphStart => "OnSubgraphStart",
+        OnSubgraphComplete => "OnSubgraphComplete",
+        OnSubgraphFailure => "OnSubgraphFailure",
     );
     log
 }
@@ -188,6 +196,58 @@ async fn failing_system_lifecycle() {
     ]);
 }
 
+#[tokio::test]
+async fn cancelled_graph_lifecycle() {
+    let mut graph = Graph::new();
+    let slow_id = graph.add_boxed_system(Box::new(SlowSystem {
+        duration: std::time::Duration::from_secs(30),
+    }));
+
+    let hooks = HooksAPI::new();
+    let log = register_all_builtin_hooks(&hooks);
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        canceller.cancel();
+    });
+
+    let mut ctx = SystemContext::new();
+    let result = GraphExecutor::new()
+        .execute_with_cancellation(&graph, &mut ctx, Some(&hooks), &token)
+        .await;
+    assert!(matches!(result, Err(ExecutionError::Cancelled)));
+
+    assert_event_sequence!(log, [
+        "OnGraphStart"      => GraphEvent::GraphStart { node_count: 1, .. },
+        "OnSystemStart"     => GraphEvent::SystemStart { node_id, system_name: "slow_system" } if *node_id == slow_id,
+        "OnGraphCancelled"  => GraphEvent::GraphCancelled { duration } if !duration.is_zero(),
+    ]);
+}
+
+#[tokio::test]
+async fn interrupted_graph_lifecycle() {
+    #[system]
+    async fn propose() -> i32 {
+        7
+    }
+
+    let mut graph = Graph::new();
+    graph.add_system(propose);
+    graph.add_interrupt::<i32, bool>("approve");
+    let approve_id = node_id_by_name(&graph, "approve");
+
+    let (result, log) = execute_with_hooks(&graph).await;
+    assert!(matches!(result, Err(ExecutionError::Interrupted(_))));
+
+    assert_event_sequence!(log, [
+        "OnGraphStart"        => GraphEvent::GraphStart { node_count: 2, .. },
+        "OnSystemStart"       => GraphEvent::SystemStart { system_name: "propose", .. },
+        "OnSystemComplete"    => GraphEvent::SystemComplete { system_name: "propose", .. },
+        "OnGraphInterrupted"  => GraphEvent::GraphInterrupted { node_id, node_name, .. } if *node_id == approve_id && &**node_name == "approve",
+    ]);
+}
+
 #[tokio::test]
 async fn sequential_systems() {
     #[system]
@@ -264,14 +324,14 @@ async fn decision_hooks() {
         "OnGraphStart"        => GraphEvent::GraphStart { node_count: 4, .. },
         "OnSystemStart"       => GraphEvent::SystemStart { node_id, system_name: "decision_system" } if *node_id == decision_sys_id,
         "OnSystemComplete"    => GraphEvent::SystemComplete { node_id, system_name: "decision_system", duration } if *node_id == decision_sys_id && !duration.is_zero(),
-        "OnDecisionStart"     => GraphEvent::DecisionStart { node_id, node_name: "test_decision" } if *node_id == decision_id,
+        "OnDecisionStart"     => GraphEvent::DecisionStart { node_id, node_name } if *node_id == decision_id && &**node_name == "test_decision",
         "OnSystemStart"       => GraphEvent::SystemStart { node_id, system_name: "success_system" } if *node_id == true_branch_id,
         "OnSystemComplete"    => GraphEvent::SystemComplete { node_id, system_name: "success_system", duration } if *node_id == true_branch_id && !duration.is_zero(),
         "OnDecisionComplete"  => GraphEvent::DecisionComplete {
             node_id,
-            node_name: "test_decision",
+            node_name,
             selected_branch: "true",
-        } if *node_id == decision_id,
+        } if *node_id == decision_id && &**node_name == "test_decision",
         "OnGraphComplete"     => GraphEvent::GraphComplete { nodes_executed: 3, duration } if !duration.is_zero(),
     ]);
 }
@@ -298,25 +358,25 @@ async fn loop_hooks() {
         "OnGraphStart"      => GraphEvent::GraphStart { node_count: 2, .. },
         "OnLoopStart"       => GraphEvent::LoopStart {
             node_id,
-            loop_name: "test_loop",
+            loop_name,
             max_iterations: Some(3),
-        } if *node_id == loop_id,
-        "OnLoopIteration"   => GraphEvent::LoopIteration { node_id, loop_name: "test_loop", iteration: 0 } if *node_id == loop_id,
+        } if *node_id == loop_id && &**loop_name == "test_loop",
+        "OnLoopIteration"   => GraphEvent::LoopIteration { node_id, loop_name, iteration: 0 } if *node_id == loop_id && &**loop_name == "test_loop",
         "OnSystemStart"     => GraphEvent::SystemStart { node_id, system_name: "loop_body" } if *node_id == body_id,
         "OnSystemComplete"  => GraphEvent::SystemComplete { node_id, system_name: "loop_body", duration } if *node_id == body_id && !duration.is_zero(),
-        "OnLoopIteration"   => GraphEvent::LoopIteration { node_id, loop_name: "test_loop", iteration: 1 } if *node_id == loop_id,
+        "OnLoopIteration"   => GraphEvent::LoopIteration { node_id, loop_name, iteration: 1 } if *node_id == loop_id && &**loop_name == "test_loop",
         "OnSystemStart"     => GraphEvent::SystemStart { node_id, system_name: "loop_body" } if *node_id == body_id,
         "OnSystemComplete"  => GraphEvent::SystemComplete { node_id, system_name: "loop_body", duration } if *node_id == body_id && !duration.is_zero(),
-        "OnLoopIteration"   => GraphEvent::LoopIteration { node_id, loop_name: "test_loop", iteration: 2 } if *node_id == loop_id,
+        "OnLoopIteration"   => GraphEvent::LoopIteration { node_id, loop_name, iteration: 2 } if *node_id == loop_id && &**loop_name == "test_loop",
         "OnSystemStart"     => GraphEvent::SystemStart { node_id, system_name: "loop_body" } if *node_id == body_id,
         "OnSystemComplete"  => GraphEvent::SystemComplete { node_id, system_name: "loop_body", duration } if *node_id == body_id && !duration.is_zero(),
         "OnLoopEnd"         => GraphEvent::LoopEnd {
             node_id,
-            loop_name: "test_loop",
+            loop_name,
             iterations: 3,
             nodes_executed: 3,
             duration,
-        } if *node_id == loop_id && !duration.is_zero(),
+        } if *node_id == loop_id && !duration.is_zero() && &**loop_name == "test_loop",
         "OnGraphComplete"   => GraphEvent::GraphComplete { nodes_executed: 4, duration } if !duration.is_zero(),
     ]);
 }
@@ -357,20 +417,20 @@ async fn parallel_hooks() {
         "OnGraphStart"        => GraphEvent::GraphStart { node_count: 3, .. },
         "OnParallelStart"     => GraphEvent::ParallelStart {
             node_id,
-            node_name: "test_parallel",
+            node_name,
             branch_count: 2,
-        } if *node_id == parallel_id,
+        } if *node_id == parallel_id && &**node_name == "test_parallel",
         "OnSystemStart"       => GraphEvent::SystemStart { node_id, system_name: "branch_a" } if *node_id == branch_a_id,
         "OnSystemComplete"    => GraphEvent::SystemComplete { node_id, system_name: "branch_a", duration } if *node_id == branch_a_id && !duration.is_zero(),
         "OnSystemStart"       => GraphEvent::SystemStart { node_id, system_name: "branch_b" } if *node_id == branch_b_id,
         "OnSystemComplete"    => GraphEvent::SystemComplete { node_id, system_name: "branch_b", duration } if *node_id == branch_b_id && !duration.is_zero(),
         "OnParallelComplete"  => GraphEvent::ParallelComplete {
             node_id,
-            node_name: "test_parallel",
+            node_name,
             branch_count: 2,
             total_nodes_executed: 2,
             duration,
-        } if *node_id == parallel_id && !duration.is_zero(),
+        } if *node_id == parallel_id && !duration.is_zero() && &**node_name == "test_parallel",
         "OnGraphComplete"     => GraphEvent::GraphComplete { nodes_executed: 3, duration } if !duration.is_zero(),
     ]);
 }
@@ -411,22 +471,109 @@ async fn switch_hooks() {
         "OnSystemComplete"  => GraphEvent::SystemComplete { node_id, system_name: "switch_key_system", duration } if *node_id == switch_sys_id && !duration.is_zero(),
         "OnSwitchStart"     => GraphEvent::SwitchStart {
             node_id,
-            node_name: "test_switch",
+            node_name,
             case_count: 2,
             has_default: false,
-        } if *node_id == switch_id,
+        } if *node_id == switch_id && &**node_name == "test_switch",
         "OnSystemStart"     => GraphEvent::SystemStart { node_id, system_name: "success_system" } if *node_id == alpha_id,
         "OnSystemComplete"  => GraphEvent::SystemComplete { node_id, system_name: "success_system", duration } if *node_id == alpha_id && !duration.is_zero(),
         "OnSwitchComplete"  => GraphEvent::SwitchComplete {
             node_id,
-            node_name: "test_switch",
-            selected_case: "alpha",
+            node_name,
+            selected_case,
             used_default: false,
-        } if *node_id == switch_id,
+        } if *node_id == switch_id && &**node_name == "test_switch" && &**selected_case == "alpha",
         "OnGraphComplete"   => GraphEvent::GraphComplete { nodes_executed: 3, duration } if !duration.is_zero(),
     ]);
 }
 
+#[tokio::test]
+async fn subgraph_hooks() {
+    let mut inner = Graph::new();
+    let inner_id = inner.add_boxed_system(Box::new(SuccessSystem));
+
+    let mut graph = Graph::new();
+    graph.add_subgraph("test_subgraph", inner, SubgraphOptions::new());
+    let subgraph_id = node_id_by_name(&graph, "test_subgraph");
+
+    let (result, log) = execute_with_hooks(&graph).await;
+    assert!(result.is_ok());
+
+    assert_event_sequence!(log, [
+        "OnGraphStart"        => GraphEvent::GraphStart { node_count: 1, .. },
+        "OnSubgraphStart"     => GraphEvent::SubgraphStart {
+            node_id,
+            node_name,
+            node_count: 1,
+        } if *node_id == subgraph_id && &**node_name == "test_subgraph",
+        "OnSystemStart"       => GraphEvent::SystemStart { node_id, system_name: "success_system" } if *node_id == inner_id,
+        "OnSystemComplete"    => GraphEvent::SystemComplete { node_id, system_name: "success_system", duration } if *node_id == inner_id && !duration.is_zero(),
+        "OnSubgraphComplete"  => GraphEvent::SubgraphComplete {
+            node_id,
+            node_name,
+            nodes_executed: 1,
+            duration,
+        } if *node_id == subgraph_id && !duration.is_zero() && &**node_name == "test_subgraph",
+        "OnGraphComplete"     => GraphEvent::GraphComplete { nodes_executed: 2, duration } if !duration.is_zero(),
+    ]);
+}
+
+#[tokio::test]
+async fn subgraph_failure_hook() {
+    let mut inner = Graph::new();
+    let inner_id = inner.add_boxed_system(Box::new(FailingSystem));
+
+    let mut graph = Graph::new();
+    graph.add_subgraph("test_subgraph", inner, SubgraphOptions::new());
+    let subgraph_id = node_id_by_name(&graph, "test_subgraph");
+
+    let (result, log) = execute_with_hooks(&graph).await;
+    assert!(matches!(result, Err(ExecutionError::SubgraphFailed { .. })));
+
+    assert_event_sequence!(log, [
+        "OnGraphStart"        => GraphEvent::GraphStart { node_count: 1, .. },
+        "OnSubgraphStart"     => GraphEvent::SubgraphStart { node_id, .. } if *node_id == subgraph_id,
+        "OnSystemStart"       => GraphEvent::SystemStart { node_id, system_name: "failing_system" } if *node_id == inner_id,
+        "OnSystemError"       => GraphEvent::SystemError { node_id, system_name: "failing_system", .. } if *node_id == inner_id,
+        "OnSubgraphFailure"   => GraphEvent::SubgraphFailure {
+            node_id,
+            node_name,
+            error,
+            ..
+        } if *node_id == subgraph_id && error.contains("intentional failure") && &**node_name == "test_subgraph",
+        "OnGraphFailure"      => GraphEvent::GraphFailure { .. },
+    ]);
+}
+
+#[tokio::test]
+async fn subgraph_hooks_run_on_subgraph_context() {
+    /// Provided by subgraph hooks.
+    struct Provided;
+    impl LocalResource for Provided {}
+
+    let mut inner = Graph::new();
+    inner.add_boxed_system(Box::new(SuccessSystem));
+    let mut graph = Graph::new();
+    graph.add_subgraph("test_subgraph", inner, SubgraphOptions::new());
+
+    let hooks = HooksAPI::new();
+    hooks
+        .register_provider::<(OnSubgraphStart, OnSubgraphComplete), Provided, _>(
+            "provider",
+            |_event: &GraphEvent| Some(Provided),
+        )
+        .expect("hook registration should succeed");
+
+    let mut ctx = SystemContext::new();
+    GraphExecutor::new()
+        .execute(&graph, &mut ctx, Some(&hooks))
+        .await
+        .expect("execution should succeed");
+
+    // Neither hook provides into the parent context.
+    assert!(!ctx.contains_resource::<Provided>());
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Custom Schedule Tests
 // ═══════════════════════════════════════════════════════════════════════════════
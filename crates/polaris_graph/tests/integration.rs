@@ -11,11 +11,16 @@
 //! - Graphs define execution flow
 //! - Outputs chain between systems
 
-use polaris_graph::executor::GraphExecutor;
+use polaris_graph::executor::{ExecutionError, GraphExecutor, current_system};
+use polaris_graph::gather::{Branches, Reducer};
 use polaris_graph::graph::Graph;
-use polaris_system::param::{Res, ResMut, SystemAccess, SystemContext, SystemParam};
+use polaris_graph::node::{ParallelOptions, SubgraphOptions};
+use polaris_system::param::{
+    Out, Res, ResMut, ResOrDefault, SystemAccess, SystemContext, SystemParam,
+};
 use polaris_system::resource::{GlobalResource, LocalResource};
 use polaris_system::server::Server;
+use polaris_system::system;
 use polaris_system::system::{BoxFuture, System, SystemError};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -461,6 +466,60 @@ async fn validate_resources_detects_missing_local_resource() {
     }
 }
 
+/// Tuning a deployment may leave out; systems fall back to the default.
+#[derive(Debug, Default)]
+struct Scaling {
+    offset: i32,
+}
+impl GlobalResource for Scaling {}
+
+#[system]
+async fn adaptive_compute(
+    config: Option<Res<AppConfig>>,
+    memory: Option<ResMut<AgentMemory>>,
+    scaling: ResOrDefault<Scaling>,
+) -> ComputeResult {
+    let value = config.map_or(1, |config| config.multiplier) + scaling.offset;
+    if let Some(mut memory) = memory {
+        memory.history.push(value);
+    }
+    ComputeResult { value }
+}
+
+#[tokio::test]
+async fn optional_resources_are_not_required() {
+    let server = Server::new();
+    let mut graph = Graph::new();
+    graph.add_system(adaptive_compute);
+
+    let mut ctx = server.create_context();
+    let executor = GraphExecutor::new();
+    assert!(executor.validate_resources(&graph, &ctx, None).is_ok());
+
+    executor.execute(&graph, &mut ctx, None).await.unwrap();
+    assert_eq!(ctx.get_output::<ComputeResult>().unwrap().value, 1);
+}
+
+#[tokio::test]
+async fn optional_resources_are_used_when_present() {
+    let mut server = Server::new();
+    server.insert_global(AppConfig { multiplier: 10 });
+    server.insert_global(Scaling { offset: 2 });
+    server.register_local(AgentMemory::new);
+
+    let mut graph = Graph::new();
+    graph.add_system(adaptive_compute);
+
+    let mut ctx = server.create_context();
+    GraphExecutor::new()
+        .execute(&graph, &mut ctx, None)
+        .await
+        .unwrap();
+
+    assert_eq!(ctx.get_output::<ComputeResult>().unwrap().value, 12);
+    assert_eq!(ctx.get_resource::<AgentMemory>().unwrap().history, [12]);
+}
+
 #[tokio::test]
 async fn validate_resources_checks_hierarchy() {
     // Test that Res<T> validation walks up the parent chain
@@ -647,6 +706,105 @@ async fn parallel_outputs_visible_after_join() {
     assert_eq!(output.value, 42 + 5); // 42 + len("hello")
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Parallel Output Gathering Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Partial result produced by every branch of a map-reduce fan-out.
+#[derive(Debug, Clone, PartialEq)]
+struct Partial(u32);
+
+async fn map_one() -> Partial {
+    Partial(1)
+}
+
+async fn map_two() -> Partial {
+    Partial(2)
+}
+
+async fn map_three() -> Partial {
+    Partial(3)
+}
+
+/// Builds a three-branch fan-out whose `Partial` outputs use `reducer`.
+fn map_graph(reducer: Reducer<Partial>) -> Graph {
+    let mut graph = Graph::new();
+    graph.add_parallel_with(
+        "map",
+        ParallelOptions::new().gather(reducer),
+        vec![
+            |g: &mut Graph| {
+                g.add_system(map_one);
+            },
+            |g: &mut Graph| {
+                g.add_system(map_two);
+            },
+            |g: &mut Graph| {
+                g.add_system(map_three);
+            },
+        ],
+    );
+    graph
+}
+
+/// Tests that `Reducer::Collect` exposes every branch value to a join system.
+#[tokio::test]
+async fn parallel_gather_collects_every_branch() {
+    #[derive(Debug)]
+    struct Total(u32);
+
+    #[system]
+    async fn reduce(partials: Out<Branches<Partial>>) -> Total {
+        Total(partials.values().map(|partial| partial.0).sum())
+    }
+
+    let mut graph = map_graph(Reducer::Collect);
+    graph.add_system(reduce);
+    assert!(graph.validate().warnings.is_empty());
+
+    let mut ctx = Server::new().create_context();
+    let result = GraphExecutor::new().execute(&graph, &mut ctx, None).await;
+    assert!(result.is_ok(), "Execution failed: {:?}", result.err());
+
+    let partials = ctx.get_output::<Branches<Partial>>().unwrap();
+    let collected: Vec<_> = partials.iter().map(|(branch, p)| (branch, p.0)).collect();
+    assert_eq!(collected, [(0, 1), (1, 2), (2, 3)]);
+    assert_eq!(ctx.get_output::<Total>().unwrap().0, 6);
+}
+
+/// Tests that a custom reducer merges branch values into a single output.
+#[tokio::test]
+async fn parallel_gather_reduces_with_custom_merge() {
+    let graph = map_graph(Reducer::reduce(|partials: Branches<Partial>| {
+        Partial(
+            partials
+                .values()
+                .map(|partial| partial.0)
+                .max()
+                .unwrap_or(0),
+        )
+    }));
+
+    let mut ctx = Server::new().create_context();
+    let result = GraphExecutor::new().execute(&graph, &mut ctx, None).await;
+    assert!(result.is_ok(), "Execution failed: {:?}", result.err());
+
+    assert_eq!(*ctx.get_output::<Partial>().unwrap(), Partial(3));
+}
+
+/// Tests that `Reducer::LastWins` keeps the last branch in declaration order.
+#[tokio::test]
+async fn parallel_gather_last_wins() {
+    let graph = map_graph(Reducer::LastWins);
+
+    let mut ctx = Server::new().create_context();
+    let result = GraphExecutor::new().execute(&graph, &mut ctx, None).await;
+    assert!(result.is_ok(), "Execution failed: {:?}", result.err());
+
+    assert_eq!(*ctx.get_output::<Partial>().unwrap(), Partial(3));
+    assert!(ctx.get_output::<Branches<Partial>>().is_err());
+}
+
 /// Tests conditional diverge/converge execution:
 /// `decision` -> (true) -> `true_step` -> converge
 #[tokio::test]
@@ -711,3 +869,208 @@ async fn conditional_diverge_converge_diamond() {
     assert_eq!(output.step, "converge");
     assert_eq!(output.value, 100);
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Subgraph Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone)]
+struct Task {
+    value: i32,
+}
+
+#[derive(Debug)]
+struct Draft {
+    value: i32,
+}
+
+#[derive(Debug)]
+struct Answer {
+    value: i32,
+}
+
+async fn plan_task() -> Task {
+    Task { value: 4 }
+}
+
+#[system]
+async fn draft_answer(task: Out<Task>, config: Res<AppConfig>) -> Draft {
+    Draft {
+        value: task.value * config.multiplier,
+    }
+}
+
+#[system]
+async fn finish_answer(draft: Out<Draft>) -> Answer {
+    Answer {
+        value: draft.value + 1,
+    }
+}
+
+#[system]
+async fn record_answer(answer: Out<Answer>, mut memory: ResMut<AgentMemory>) -> ComputeResult {
+    memory.history.push(answer.value);
+    ComputeResult {
+        value: answer.value,
+    }
+}
+
+#[system]
+async fn remember_and_fail(mut memory: ResMut<AgentMemory>) -> Result<Answer, SystemError> {
+    memory.history.push(7);
+    Err(SystemError::ExecutionError("worker gave up".into()))
+}
+
+/// Graph that turns a bound `Task` into an `Answer` via an internal `Draft`.
+fn worker_graph() -> Graph {
+    let mut graph = Graph::new();
+    graph.add_system(draft_answer).add_system(finish_answer);
+    graph
+}
+
+/// Tests that bound inputs flow into a subgraph and bound outputs flow back,
+/// while the subgraph still sees the server's globals.
+#[tokio::test]
+async fn subgraph_maps_bound_inputs_and_outputs() {
+    let mut server = Server::new();
+    server.insert_global(AppConfig { multiplier: 10 });
+    server.register_local(AgentMemory::new);
+
+    let mut graph = Graph::new();
+    graph
+        .add_system(plan_task)
+        .add_subgraph(
+            "worker",
+            worker_graph(),
+            SubgraphOptions::new().input::<Task>().output::<Answer>(),
+        )
+        .add_system(record_answer);
+
+    let mut ctx = server.create_context();
+    let result = GraphExecutor::new().execute(&graph, &mut ctx, None).await;
+    assert!(result.is_ok(), "execution failed: {:?}", result.err());
+
+    assert_eq!(ctx.get_output::<ComputeResult>().unwrap().value, 41);
+    assert_eq!(ctx.get_resource::<AgentMemory>().unwrap().history, [41]);
+    assert!(
+        !ctx.contains_output::<Draft>(),
+        "unbound subgraph outputs should not leak into the parent"
+    );
+}
+
+/// Tests that parent outputs are invisible to a subgraph unless bound.
+#[tokio::test]
+async fn subgraph_does_not_see_unbound_parent_outputs() {
+    let mut server = Server::new();
+    server.insert_global(AppConfig { multiplier: 10 });
+
+    let mut graph = Graph::new();
+    graph.add_system(plan_task).add_subgraph(
+        "worker",
+        worker_graph(),
+        SubgraphOptions::new().output::<Answer>(),
+    );
+
+    let mut ctx = server.create_context();
+    let result = GraphExecutor::new().execute(&graph, &mut ctx, None).await;
+
+    match result {
+        Err(ExecutionError::SubgraphFailed { name, error, .. }) => {
+            assert_eq!(&*name, "worker");
+            assert!(matches!(*error, ExecutionError::SystemError(_)));
+        }
+        other => panic!("expected SubgraphFailed, got {other:?}"),
+    }
+}
+
+/// Tests that a lent local resource is returned to the parent with the
+/// subgraph's changes, even when the subgraph fails.
+#[tokio::test]
+async fn subgraph_returns_lent_resource_on_failure() {
+    let mut server = Server::new();
+    server.register_local(AgentMemory::new);
+
+    let mut worker = Graph::new();
+    worker.add_system(remember_and_fail);
+
+    let mut graph = Graph::new();
+    graph.add_subgraph(
+        "worker",
+        worker,
+        SubgraphOptions::new().resource::<AgentMemory>(),
+    );
+
+    let mut ctx = server.create_context();
+    let result = GraphExecutor::new().execute(&graph, &mut ctx, None).await;
+
+    assert!(matches!(result, Err(ExecutionError::SubgraphFailed { .. })));
+    assert_eq!(ctx.get_resource::<AgentMemory>().unwrap().history, [7]);
+}
+
+/// Tests that a subgraph's own node limit stops it without affecting the
+/// parent's budget.
+#[tokio::test]
+async fn subgraph_node_limit_fails_subgraph() {
+    let mut worker = Graph::new();
+    worker.add_loop_n("spin", 10, |g| {
+        g.add_system(plan_task);
+    });
+
+    let mut graph = Graph::new();
+    graph.add_subgraph("worker", worker, SubgraphOptions::new().with_max_nodes(3));
+
+    let mut ctx = Server::new().create_context();
+    let result = GraphExecutor::new().execute(&graph, &mut ctx, None).await;
+
+    match result {
+        Err(ExecutionError::SubgraphFailed { error, .. }) => {
+            assert!(
+                matches!(*error, ExecutionError::NodeBudgetExceeded { max: 3 }),
+                "expected NodeBudgetExceeded, got {error:?}"
+            );
+        }
+        other => panic!("expected SubgraphFailed, got {other:?}"),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Current System Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[system]
+async fn left_branch() {
+    tokio::task::yield_now().await;
+    assert_eq!(current_system().unwrap().name, "left_branch");
+}
+
+#[system]
+async fn right_branch() {
+    tokio::task::yield_now().await;
+    assert_eq!(current_system().unwrap().name, "right_branch");
+}
+
+/// Tests that each system sees itself as the current one, including
+/// parallel branches polled together.
+#[tokio::test]
+async fn current_system_is_the_running_node() {
+    let mut graph = Graph::new();
+    graph.add_parallel(
+        "fork",
+        vec![
+            |g: &mut Graph| {
+                g.add_system(left_branch);
+            },
+            |g: &mut Graph| {
+                g.add_system(right_branch);
+            },
+        ],
+    );
+
+    let mut ctx = Server::new().create_context();
+    GraphExecutor::new()
+        .execute(&graph, &mut ctx, None)
+        .await
+        .unwrap();
+
+    assert_eq!(current_system(), None);
+}
@@ -0,0 +1,352 @@
+//! Tests for building graphs from declarative specs.
+
+use polaris_graph::executor::{ExecutionResult, GraphExecutor};
+use polaris_graph::graph::{Graph, ValidationError};
+use polaris_graph::spec::{GraphSpec, SpecError, SpecRegistry};
+use polaris_system::param::SystemContext;
+use polaris_system::system;
+use polaris_system::system::SystemError;
+use std::time::Duration;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Fixtures
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug)]
+struct Ticket {
+    kind: &'static str,
+    urgent: bool,
+}
+
+#[derive(Debug)]
+struct Draft {
+    done: bool,
+}
+
+async fn classify() -> Ticket {
+    Ticket {
+        kind: "billing",
+        urgent: true,
+    }
+}
+
+async fn escalate() {}
+
+async fn queue() {}
+
+async fn billing() {}
+
+async fn technical() {}
+
+async fn fallback() {}
+
+async fn lookup_orders() {}
+
+async fn lookup_invoices() {}
+
+async fn draft() -> Draft {
+    Draft { done: false }
+}
+
+async fn refine() -> Draft {
+    Draft { done: true }
+}
+
+async fn recover() {}
+
+async fn apologize() {}
+
+#[system]
+async fn flaky() -> Result<(), SystemError> {
+    Err(SystemError::ExecutionError("upstream unavailable".into()))
+}
+
+async fn stall() {
+    tokio::time::sleep(Duration::from_secs(5)).await;
+}
+
+/// Registry holding every fixture system, predicate and discriminator.
+fn registry() -> SpecRegistry {
+    let mut registry = SpecRegistry::new();
+    registry
+        .register_system("classify", classify)
+        .register_system("escalate", escalate)
+        .register_system("queue", queue)
+        .register_system("billing", billing)
+        .register_system("technical", technical)
+        .register_system("fallback", fallback)
+        .register_system("lookup_orders", lookup_orders)
+        .register_system("lookup_invoices", lookup_invoices)
+        .register_system("draft", draft)
+        .register_system("refine", refine)
+        .register_system("recover", recover)
+        .register_system("apologize", apologize)
+        .register_system("flaky", flaky)
+        .register_system("stall", stall)
+        .register_predicate("urgent", |ticket: &Ticket| ticket.urgent)
+        .register_predicate("refined", |draft: &Draft| draft.done)
+        .register_discriminator("ticket_kind", |ticket: &Ticket| ticket.kind);
+    registry
+}
+
+/// Spec using every step kind.
+const SUPPORT_SPEC: &str = r#"{
+    "steps": [
+        { "kind": "system", "system": "classify" },
+        {
+            "kind": "conditional",
+            "name": "is_urgent",
+            "predicate": "urgent",
+            "then": [{ "kind": "system", "system": "escalate" }],
+            "otherwise": [{ "kind": "system", "system": "queue" }]
+        },
+        {
+            "kind": "switch",
+            "name": "route",
+            "discriminator": "ticket_kind",
+            "cases": [
+                { "key": "billing", "steps": [{ "kind": "system", "system": "billing" }] },
+                { "key": "technical", "steps": [{ "kind": "system", "system": "technical" }] }
+            ],
+            "default": [{ "kind": "system", "system": "fallback" }]
+        },
+        {
+            "kind": "parallel",
+            "name": "lookup",
+            "max_concurrency": 1,
+            "policy": "collect_errors",
+            "branches": [
+                [{ "kind": "system", "system": "lookup_orders" }],
+                [{ "kind": "system", "system": "lookup_invoices" }]
+            ]
+        },
+        { "kind": "system", "system": "draft" },
+        {
+            "kind": "loop",
+            "name": "refine_loop",
+            "until": "refined",
+            "max_iterations": 3,
+            "body": [{ "kind": "system", "system": "refine" }]
+        }
+    ]
+}"#;
+
+/// Names of the nodes a run visited, in order.
+fn visited<'a>(graph: &'a Graph, result: &ExecutionResult) -> Vec<&'a str> {
+    result
+        .path
+        .iter()
+        .map(|id| graph.get_node(id.clone()).unwrap().name())
+        .collect()
+}
+
+async fn run(graph: &Graph) -> ExecutionResult {
+    let mut ctx = SystemContext::new();
+    GraphExecutor::new()
+        .execute(graph, &mut ctx, None)
+        .await
+        .expect("graph should run")
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Building
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn json_spec_builds_every_step_kind() {
+    let spec = GraphSpec::from_json(SUPPORT_SPEC).unwrap();
+    let graph = spec.build(&registry()).unwrap();
+
+    let result = run(&graph).await;
+    assert_eq!(
+        visited(&graph, &result),
+        [
+            "classify",
+            "is_urgent",
+            "escalate",
+            "route",
+            "billing",
+            "lookup",
+            "lookup_orders",
+            "lookup_invoices",
+            "draft",
+            "refine_loop",
+            "refine",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn system_step_handlers_are_wired() {
+    let spec = GraphSpec::from_json(
+        r#"{
+            "steps": [
+                {
+                    "kind": "system",
+                    "system": "flaky",
+                    "retry": { "strategy": "fixed", "max_retries": 1, "delay_ms": 1 },
+                    "on_error": [{ "kind": "system", "system": "recover" }]
+                },
+                {
+                    "kind": "system",
+                    "system": "stall",
+                    "timeout_ms": 10,
+                    "on_timeout": [{ "kind": "system", "system": "apologize" }]
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let graph = spec.build(&registry()).unwrap();
+
+    let result = run(&graph).await;
+    assert_eq!(visited(&graph, &result), ["flaky", "recover"]);
+
+    // The timeout handler is only reachable from `stall`, so run it alone.
+    let spec = GraphSpec {
+        steps: spec.steps[1..].to_vec(),
+        on_error: Vec::new(),
+    };
+    let graph = spec.build(&registry()).unwrap();
+    let result = run(&graph).await;
+    assert_eq!(visited(&graph, &result), ["stall", "apologize"]);
+}
+
+#[tokio::test]
+async fn graph_level_error_handler_covers_fallible_systems() {
+    let spec = GraphSpec::from_json(
+        r#"{
+            "steps": [{ "kind": "system", "system": "flaky" }],
+            "on_error": [{ "kind": "system", "system": "recover" }]
+        }"#,
+    )
+    .unwrap();
+    let graph = spec.build(&registry()).unwrap();
+
+    let result = run(&graph).await;
+    assert_eq!(visited(&graph, &result), ["flaky", "recover"]);
+}
+
+#[test]
+fn build_into_extends_existing_graph() {
+    let spec =
+        GraphSpec::from_json(r#"{ "steps": [{ "kind": "system", "system": "queue" }] }"#).unwrap();
+
+    let mut graph = Graph::new();
+    graph.add_system(classify);
+    spec.build_into(&mut graph, &registry()).unwrap();
+
+    assert_eq!(graph.node_count(), 2);
+    assert_eq!(graph.edge_count(), 1);
+}
+
+#[test]
+fn spec_round_trips_through_json() {
+    let spec = GraphSpec::from_json(SUPPORT_SPEC).unwrap();
+    let json = serde_json::to_string(&spec).unwrap();
+    assert_eq!(GraphSpec::from_json(&json).unwrap(), spec);
+}
+
+#[cfg(feature = "yaml")]
+#[tokio::test]
+async fn yaml_spec_builds() {
+    let spec = GraphSpec::from_yaml(
+        "
+steps:
+  - kind: system
+    system: classify
+  - kind: conditional
+    name: is_urgent
+    predicate: urgent
+    then:
+      - kind: system
+        system: escalate
+    otherwise:
+      - kind: system
+        system: queue
+",
+    )
+    .unwrap();
+    let graph = spec.build(&registry()).unwrap();
+
+    let result = run(&graph).await;
+    assert_eq!(
+        visited(&graph, &result),
+        ["classify", "is_urgent", "escalate"]
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Errors
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn malformed_input_is_parse_error() {
+    let result = GraphSpec::from_json(r#"{ "steps": [{ "kind": "teleport" }] }"#);
+    assert!(matches!(result, Err(SpecError::Parse(_))));
+}
+
+#[test]
+fn unknown_system_is_reported() {
+    let spec = GraphSpec::from_json(r#"{ "steps": [{ "kind": "system", "system": "missing" }] }"#)
+        .unwrap();
+    let result = spec.build(&registry());
+    assert!(
+        matches!(&result, Err(SpecError::UnknownSystem(name)) if name == "missing"),
+        "expected UnknownSystem, got {result:?}"
+    );
+}
+
+#[test]
+fn unknown_name_inside_branch_is_reported() {
+    let spec = GraphSpec::from_json(
+        r#"{
+            "steps": [
+                { "kind": "system", "system": "classify" },
+                {
+                    "kind": "loop",
+                    "name": "outer",
+                    "max_iterations": 2,
+                    "body": [{
+                        "kind": "conditional",
+                        "name": "inner",
+                        "predicate": "missing",
+                        "then": [{ "kind": "system", "system": "queue" }]
+                    }]
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let result = spec.build(&registry());
+    assert!(
+        matches!(&result, Err(SpecError::UnknownPredicate(name)) if name == "missing"),
+        "expected UnknownPredicate, got {result:?}"
+    );
+}
+
+#[test]
+fn invalid_graph_fails_validation() {
+    let spec = GraphSpec::from_json(
+        r#"{
+            "steps": [{
+                "kind": "loop",
+                "name": "forever",
+                "body": [{ "kind": "system", "system": "queue" }]
+            }]
+        }"#,
+    )
+    .unwrap();
+
+    match spec.build(&registry()) {
+        Err(SpecError::Invalid(result)) => assert!(
+            result
+                .errors
+                .iter()
+                .any(|err| matches!(err, ValidationError::NoTerminationCondition { .. })),
+            "expected NoTerminationCondition, got {:?}",
+            result.errors
+        ),
+        other => panic!("expected Invalid, got {other:?}"),
+    }
+}
@@ -0,0 +1,526 @@
+//! Tests for recording execution traces and replaying them.
+
+use polaris_graph::executor::{ErrorKind, ExecutionError, GraphExecutor, RunOptions};
+use polaris_graph::graph::Graph;
+use polaris_graph::node::{RetryPolicy, SubgraphOptions};
+use polaris_graph::trace::{
+    self, ExecutionTrace, RecordedCall, Replay, TraceError, TraceEvent, TraceRecorder,
+};
+use polaris_system::param::{Out, Res, SystemContext};
+use polaris_system::resource::LocalResource;
+use polaris_system::system;
+use polaris_system::system::SystemError;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Fixtures
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Ticket {
+    kind: String,
+    urgent: bool,
+}
+
+/// Source read by `classify`; left out of replays that must not run it.
+struct Inbox(Ticket);
+
+impl LocalResource for Inbox {}
+
+#[system]
+async fn classify(inbox: Res<Inbox>) -> Ticket {
+    inbox.0.clone()
+}
+
+async fn escalate() {}
+
+async fn queue() {}
+
+#[system]
+async fn reply(ticket: Out<Ticket>) -> String {
+    format!("re: {}", ticket.kind)
+}
+
+#[system]
+async fn flaky() -> Result<(), SystemError> {
+    Err(SystemError::ExecutionError("upstream unavailable".into()))
+}
+
+async fn recover() {}
+
+/// Number of real calls made to the weather service.
+static FORECAST_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// Calls a weather service through the trace, as an integration would.
+fn call_weather(days: u32) -> Result<String, SystemError> {
+    let request = json!({ "days": days });
+    if let Some(call) = trace::replay_call("weather", "paris", &request) {
+        let call = call.map_err(|err| SystemError::ExecutionError(err.to_string()))?;
+        let response = call.response.map_err(SystemError::ExecutionError)?;
+        return Ok(response.as_str().unwrap_or_default().to_owned());
+    }
+
+    FORECAST_CALLS.fetch_add(1, Ordering::SeqCst);
+    let reading = if days == 1 { "sunny" } else { "rainy" }.to_owned();
+    trace::record_call(RecordedCall {
+        kind: "weather".into(),
+        target: "paris".into(),
+        request,
+        response: Ok(json!(reading)),
+    });
+    Ok(reading)
+}
+
+#[system]
+async fn forecast() -> Result<String, SystemError> {
+    call_weather(1)
+}
+
+/// Reading of a two-day forecast.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Outlook(String);
+
+#[system]
+async fn outlook() -> Result<Outlook, SystemError> {
+    call_weather(2).map(Outlook)
+}
+
+/// `classify` → `is_urgent` (`escalate` | `queue`) → `reply`
+fn support_graph() -> Graph {
+    let mut graph = Graph::new();
+    graph
+        .add_system(classify)
+        .add_conditional_branch::<Ticket, _, _, _>(
+            "is_urgent",
+            |ticket| ticket.urgent,
+            |g| {
+                g.add_system(escalate);
+            },
+            |g| {
+                g.add_system(queue);
+            },
+        )
+        .add_system(reply);
+    graph
+}
+
+fn inbox_context() -> SystemContext<'static> {
+    let mut ctx = SystemContext::new();
+    ctx.insert(Inbox(Ticket {
+        kind: "billing".into(),
+        urgent: true,
+    }));
+    ctx
+}
+
+async fn record(graph: &Graph, ctx: &mut SystemContext<'_>) -> ExecutionTrace {
+    let recorder = TraceRecorder::new()
+        .with_output::<Ticket>()
+        .with_output::<String>();
+    let options = RunOptions::new().with_recorder(recorder.clone());
+    let _ = GraphExecutor::new()
+        .execute_with(graph, ctx, None, options)
+        .await;
+    recorder.take()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Recording
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn records_path_branches_and_outputs() {
+    let graph = support_graph();
+    let trace = record(&graph, &mut inbox_context()).await;
+
+    assert_eq!(
+        trace.path().collect::<Vec<_>>(),
+        ["classify", "is_urgent", "escalate", "reply"]
+    );
+    assert!(trace.events.iter().any(|event| matches!(
+        event,
+        TraceEvent::BranchSelected { node, branch } if node.name == "is_urgent" && branch == "true"
+    )));
+
+    let outputs: Vec<_> = trace
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            TraceEvent::SystemCompleted { node, output, .. } => {
+                Some((node.name.as_str(), output.clone()))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        outputs,
+        [
+            (
+                "classify",
+                Some(json!({ "kind": "billing", "urgent": true }))
+            ),
+            ("escalate", Some(json!(null))),
+            ("reply", Some(json!("re: billing"))),
+        ]
+    );
+    assert_eq!(
+        trace.events.last(),
+        Some(&TraceEvent::RunCompleted { nodes_executed: 4 })
+    );
+}
+
+#[tokio::test]
+async fn unregistered_outputs_are_recorded_by_type_only() {
+    let graph = support_graph();
+    let recorder = TraceRecorder::new();
+    let options = RunOptions::new().with_recorder(recorder.clone());
+    GraphExecutor::new()
+        .execute_with(&graph, &mut inbox_context(), None, options)
+        .await
+        .unwrap();
+
+    let trace = recorder.take();
+    let classify = trace.events.iter().find_map(|event| match event {
+        TraceEvent::SystemCompleted {
+            node,
+            output_type,
+            output,
+        } if node.name == "classify" => Some((output_type.clone(), output.clone())),
+        _ => None,
+    });
+    let (output_type, output) = classify.expect("classify should be recorded");
+    assert!(output_type.ends_with("Ticket"), "got {output_type}");
+    assert_eq!(output, None);
+}
+
+#[tokio::test]
+async fn records_switch_cases_and_loop_iterations() {
+    let mut graph = Graph::new();
+    graph
+        .add_system(classify)
+        .add_switch::<Ticket, _, _, _>(
+            "route",
+            |ticket| {
+                if ticket.kind == "billing" {
+                    "billing"
+                } else {
+                    "other"
+                }
+            },
+            vec![(
+                "billing",
+                Box::new(|g: &mut Graph| {
+                    g.add_system(escalate);
+                }) as Box<dyn FnOnce(&mut Graph)>,
+            )],
+            None,
+        )
+        .add_loop_n("poll", 2, |g| {
+            g.add_system(queue);
+        });
+
+    let trace = record(&graph, &mut inbox_context()).await;
+
+    assert!(trace.events.iter().any(|event| matches!(
+        event,
+        TraceEvent::BranchSelected { node, branch } if node.name == "route" && branch == "billing"
+    )));
+    let iterations: Vec<_> = trace
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            TraceEvent::LoopIteration { iteration, .. } => Some(*iteration),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(iterations, [0, 1]);
+}
+
+#[tokio::test]
+async fn records_retries_and_error_routing() {
+    let mut graph = Graph::new();
+    graph
+        .system(flaky)
+        .with_retry(RetryPolicy::fixed(1, Duration::from_millis(1)))
+        .on_error(|g| {
+            g.add_system(recover);
+        });
+
+    let trace = record(&graph, &mut SystemContext::new()).await;
+
+    let errors: Vec<_> = trace
+        .events
+        .iter()
+        .filter(|event| {
+            matches!(
+                event,
+                TraceEvent::SystemRetried { .. }
+                    | TraceEvent::SystemFailed { .. }
+                    | TraceEvent::ErrorRouted { .. }
+            )
+        })
+        .collect();
+    assert!(matches!(
+        errors.as_slice(),
+        [
+            TraceEvent::SystemRetried { attempt: 1, error, .. },
+            TraceEvent::SystemFailed { kind: ErrorKind::Execution, .. },
+            TraceEvent::ErrorRouted { handler, .. },
+        ] if error.contains("upstream unavailable") && handler.name == "recover"
+    ));
+    assert_eq!(trace.path().collect::<Vec<_>>(), ["flaky", "recover"]);
+}
+
+#[tokio::test]
+async fn records_failed_run() {
+    let mut graph = Graph::new();
+    graph.add_system(flaky);
+
+    let trace = record(&graph, &mut SystemContext::new()).await;
+
+    assert!(matches!(
+        trace.events.last(),
+        Some(TraceEvent::RunFailed { error }) if error.contains("upstream unavailable")
+    ));
+}
+
+#[tokio::test]
+async fn trace_round_trips_through_json_file() {
+    let graph = support_graph();
+    let trace = record(&graph, &mut inbox_context()).await;
+
+    let path = std::env::temp_dir().join(format!("polaris-trace-{}.json", std::process::id()));
+    trace.save(&path).unwrap();
+    let loaded = ExecutionTrace::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.unwrap(), trace);
+}
+
+#[test]
+fn malformed_trace_is_json_error() {
+    let result = ExecutionTrace::from_json(r#"{ "events": [{ "event": "teleport" }] }"#);
+    assert!(matches!(result, Err(TraceError::Json(_))));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Replay
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn replay_substitutes_recorded_outputs() {
+    let graph = support_graph();
+    let trace = record(&graph, &mut inbox_context()).await;
+
+    // Without an inbox, `classify` would fail if it ran.
+    let replay = Replay::new(trace.clone())
+        .with_output::<Ticket>()
+        .substitute("classify");
+    let recorder = TraceRecorder::new();
+    let options = RunOptions::new()
+        .with_replay(replay)
+        .with_recorder(recorder.clone());
+    let mut ctx = SystemContext::new();
+    GraphExecutor::new()
+        .execute_with(&graph, &mut ctx, None, options)
+        .await
+        .unwrap();
+
+    assert_eq!(*ctx.get_output::<String>().unwrap(), "re: billing");
+    assert!(recorder.take().path().eq(trace.path()));
+}
+
+#[tokio::test]
+async fn replay_substitutes_recorded_failures() {
+    let mut graph = Graph::new();
+    graph.system(flaky).on_error(|g| {
+        g.add_system(recover);
+    });
+    let trace = record(&graph, &mut SystemContext::new()).await;
+
+    let options = RunOptions::new().with_replay(Replay::new(trace).substitute_all());
+    let result = GraphExecutor::new()
+        .execute_with(&graph, &mut SystemContext::new(), None, options)
+        .await
+        .unwrap();
+
+    let names: Vec<_> = result
+        .path
+        .iter()
+        .map(|id| graph.get_node(id.clone()).unwrap().name())
+        .collect();
+    assert_eq!(names, ["flaky", "recover"]);
+}
+
+#[tokio::test]
+async fn replay_without_registered_output_fails() {
+    let graph = support_graph();
+    let trace = record(&graph, &mut inbox_context()).await;
+
+    let options = RunOptions::new().with_replay(Replay::new(trace).substitute("classify"));
+    let result = GraphExecutor::new()
+        .execute_with(&graph, &mut SystemContext::new(), None, options)
+        .await;
+
+    assert!(
+        matches!(&result, Err(ExecutionError::ReplayFailed(message)) if message.contains("not registered")),
+        "expected ReplayFailed, got {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn replay_of_unrecorded_system_fails() {
+    let graph = support_graph();
+    let options =
+        RunOptions::new().with_replay(Replay::new(ExecutionTrace::default()).substitute_all());
+    let result = GraphExecutor::new()
+        .execute_with(&graph, &mut inbox_context(), None, options)
+        .await;
+
+    assert!(
+        matches!(&result, Err(ExecutionError::ReplayFailed(message)) if message.contains("no recorded outcome")),
+        "expected ReplayFailed, got {result:?}"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Recorded Calls
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn calls_are_recorded_and_served_on_replay() {
+    let mut graph = Graph::new();
+    graph.add_system(forecast);
+
+    let trace = record(&graph, &mut SystemContext::new()).await;
+    let calls: Vec<_> = trace.calls("weather").collect();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].target, "paris");
+    assert_eq!(calls[0].response, Ok(json!("sunny")));
+
+    let before = FORECAST_CALLS.load(Ordering::SeqCst);
+    let mut ctx = SystemContext::new();
+    let options = RunOptions::new().with_replay(Replay::new(trace));
+    GraphExecutor::new()
+        .execute_with(&graph, &mut ctx, None, options)
+        .await
+        .unwrap();
+
+    assert_eq!(FORECAST_CALLS.load(Ordering::SeqCst), before);
+    assert_eq!(*ctx.get_output::<String>().unwrap(), "sunny");
+}
+
+#[tokio::test]
+async fn replayed_call_to_other_target_diverges() {
+    let mut graph = Graph::new();
+    graph.add_system(forecast);
+
+    let mut trace = record(&graph, &mut SystemContext::new()).await;
+    for event in &mut trace.events {
+        if let TraceEvent::Call(call) = event {
+            call.target = "oslo".into();
+        }
+    }
+
+    let options = RunOptions::new().with_replay(Replay::new(trace));
+    let result = GraphExecutor::new()
+        .execute_with(&graph, &mut SystemContext::new(), None, options)
+        .await;
+
+    assert!(
+        matches!(&result, Err(ExecutionError::SystemError(message)) if message.contains("diverged")),
+        "expected divergence, got {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn replayed_call_with_other_request_diverges() {
+    let mut graph = Graph::new();
+    graph.add_system(forecast);
+
+    let mut trace = record(&graph, &mut SystemContext::new()).await;
+    for event in &mut trace.events {
+        if let TraceEvent::Call(call) = event {
+            call.request = json!({ "days": 3 });
+        }
+    }
+
+    let options = RunOptions::new().with_replay(Replay::new(trace));
+    let result = GraphExecutor::new()
+        .execute_with(&graph, &mut SystemContext::new(), None, options)
+        .await;
+
+    assert!(
+        matches!(&result, Err(ExecutionError::SystemError(message)) if message.contains("not recorded")),
+        "expected divergence, got {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn calls_from_parallel_branches_are_served_by_request() {
+    let mut graph = Graph::new();
+    graph.add_parallel(
+        "forecasts",
+        [
+            Box::new(|g: &mut Graph| {
+                g.add_system(forecast);
+            }) as Box<dyn FnOnce(&mut Graph)>,
+            Box::new(|g: &mut Graph| {
+                g.add_system(outlook);
+            }),
+        ],
+    );
+
+    let mut trace = record(&graph, &mut SystemContext::new()).await;
+    assert_eq!(trace.calls("weather").count(), 2);
+    // Serve the calls in the opposite order to the one they were made in.
+    trace.events.reverse();
+
+    let mut ctx = SystemContext::new();
+    let options = RunOptions::new().with_replay(Replay::new(trace));
+    GraphExecutor::new()
+        .execute_with(&graph, &mut ctx, None, options)
+        .await
+        .unwrap();
+
+    assert_eq!(*ctx.get_output::<String>().unwrap(), "sunny");
+    assert_eq!(ctx.get_output::<Outlook>().unwrap().0, "rainy");
+}
+
+#[tokio::test]
+async fn calls_inside_subgraphs_are_recorded_and_served_on_replay() {
+    let subgraph = || {
+        let mut inner = Graph::new();
+        inner.add_system(forecast);
+        let mut graph = Graph::new();
+        graph.add_subgraph("weather", inner, SubgraphOptions::new().output::<String>());
+        graph
+    };
+
+    let mut trace = record(&subgraph(), &mut SystemContext::new()).await;
+    assert_eq!(trace.calls("weather").count(), 1);
+    for event in &mut trace.events {
+        if let TraceEvent::Call(call) = event {
+            call.response = Ok(json!("foggy"));
+        }
+    }
+
+    let mut ctx = SystemContext::new();
+    let options = RunOptions::new().with_replay(Replay::new(trace));
+    GraphExecutor::new()
+        .execute_with(&subgraph(), &mut ctx, None, options)
+        .await
+        .unwrap();
+
+    assert_eq!(*ctx.get_output::<String>().unwrap(), "foggy");
+}
+
+#[test]
+fn calls_outside_a_run_are_not_recorded_or_replayed() {
+    assert!(!trace::is_recording());
+    assert!(!trace::is_replaying());
+    assert!(trace::replay_call("weather", "paris", &json!({ "days": 1 })).is_none());
+}
@@ -6,13 +6,18 @@
 //! - Decision node requirements
 //! - Parallel node requirements
 //! - Loop node requirements
+//! - Subgraph node requirements
+//! - Output dataflow
 //! - Error display formatting
 
 use polaris_graph::CaughtError;
+use polaris_graph::gather::{Branches, Reducer};
 use polaris_graph::graph::{Graph, ValidationError, ValidationWarning};
-use polaris_graph::node::NodeId;
-use polaris_system::param::{ERROR_CONTEXT, ErrOut, SystemAccess, SystemContext, SystemParam};
+use polaris_graph::node::{NodeId, ParallelOptions, ParallelPolicy, SubgraphOptions};
+use polaris_system::param::{ERROR_CONTEXT, ErrOut, Out, SystemAccess, SystemContext, SystemParam};
+use polaris_system::system;
 use polaris_system::system::{BoxFuture, System, SystemError};
+use std::any::TypeId;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Test Systems
@@ -127,6 +132,41 @@ fn validate_graph_with_parallel_succeeds() {
     assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
 }
 
+#[test]
+fn validate_parallel_with_invalid_options_fails() {
+    let mut graph = Graph::new();
+    graph.add_parallel_with(
+        "parallel",
+        ParallelOptions::new()
+            .with_max_concurrency(0)
+            .with_policy(ParallelPolicy::Quorum(3)),
+        vec![
+            |g: &mut Graph| {
+                g.add_system(branch_a);
+            },
+            |g: &mut Graph| {
+                g.add_system(branch_b);
+            },
+        ],
+    );
+
+    let result = graph.validate();
+    assert!(
+        result
+            .errors
+            .iter()
+            .any(|err| matches!(err, ValidationError::ZeroParallelConcurrency { .. }))
+    );
+    assert!(result.errors.iter().any(|err| matches!(
+        err,
+        ValidationError::InvalidQuorum {
+            quorum: 3,
+            branches: 2,
+            ..
+        }
+    )));
+}
+
 #[test]
 fn validate_graph_with_loop_succeeds() {
     let mut graph = Graph::new();
@@ -160,7 +200,7 @@ fn validation_error_invalid_entry_point_display() {
 fn validation_error_missing_predicate_display() {
     let err = ValidationError::MissingPredicate {
         node: NodeId::from_string("3"),
-        name: "decision",
+        name: "decision".into(),
     };
     let msg = format!("{err}");
     assert!(msg.contains("decision"));
@@ -171,7 +211,7 @@ fn validation_error_missing_predicate_display() {
 fn validation_error_missing_branch_display() {
     let err = ValidationError::MissingBranch {
         node: NodeId::from_string("2"),
-        name: "choice",
+        name: "choice".into(),
         branch: "true",
     };
     let msg = format!("{err}");
@@ -183,7 +223,7 @@ fn validation_error_missing_branch_display() {
 fn validation_error_no_termination_condition_display() {
     let err = ValidationError::NoTerminationCondition {
         node: NodeId::from_string("1"),
-        name: "infinite_loop",
+        name: "infinite_loop".into(),
     };
     let msg = format!("{err}");
     assert!(msg.contains("termination condition"));
@@ -232,6 +272,31 @@ fn validate_parallel_conflicting_outputs_warns() {
     );
 }
 
+#[test]
+fn validate_parallel_gathered_outputs_no_warning() {
+    let mut graph = Graph::new();
+    graph.add_parallel_with(
+        "gathered",
+        ParallelOptions::new().gather::<i32>(Reducer::Collect),
+        vec![
+            |g: &mut Graph| {
+                g.add_system(branch_a);
+            },
+            |g: &mut Graph| {
+                g.add_system(branch_b);
+            },
+        ],
+    );
+
+    let result = graph.validate();
+    assert!(result.is_ok(), "graph should be structurally valid");
+    assert!(
+        result.warnings.is_empty(),
+        "gathered outputs should not warn, got: {:?}",
+        result.warnings
+    );
+}
+
 #[test]
 fn validate_parallel_different_outputs_no_warning() {
     // Branches produce different output types (i32 vs String)
@@ -305,15 +370,68 @@ fn validate_loop_predicate_output_produced() {
         LoopState { done: true }
     }
 
-    // Loop predicate reads LoopState, body produces LoopState
+    async fn init_loop_state() -> LoopState {
+        LoopState { done: false }
+    }
+
+    // Loop predicate reads LoopState, body produces LoopState. The predicate
+    // runs before the first iteration, so the state is also seeded upfront.
     let mut graph = Graph::new();
-    graph.add_loop::<LoopState, _, _>(
-        "good_loop",
-        |state| state.done,
-        |g| {
-            g.add_system(produce_loop_state);
-        },
+    graph
+        .add_system(init_loop_state)
+        .add_loop::<LoopState, _, _>(
+            "good_loop",
+            |state| state.done,
+            |g| {
+                g.add_system(produce_loop_state);
+            },
+        );
+
+    let result = graph.validate();
+    assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Subgraph Validation
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn validate_subgraph_reports_inner_errors() {
+    let mut graph = Graph::new();
+    graph
+        .add_system(first_step)
+        .add_subgraph("empty_worker", Graph::new(), SubgraphOptions::new());
+
+    let result = graph.validate();
+    let inner = result
+        .errors
+        .iter()
+        .find_map(|err| match err {
+            ValidationError::InvalidSubgraph { name, errors, .. } => Some((name, errors)),
+            _ => None,
+        })
+        .expect("expected InvalidSubgraph error");
+    assert_eq!(&**inner.0, "empty_worker");
+    assert!(matches!(
+        inner.1.as_slice(),
+        [ValidationError::NoEntryPoint]
+    ));
+    assert!(
+        result.errors[0].to_string().contains("empty_worker"),
+        "display should name the subgraph: {}",
+        result.errors[0]
     );
+}
+
+#[test]
+fn validate_subgraph_valid_inner_graph() {
+    let mut worker = Graph::new();
+    worker.add_system(second_step);
+
+    let mut graph = Graph::new();
+    graph
+        .add_system(first_step)
+        .add_subgraph("worker", worker, SubgraphOptions::new());
 
     let result = graph.validate();
     assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
@@ -481,6 +599,443 @@ fn err_out_param_accepted_behind_error_edge() {
     assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Output Dataflow Validation
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone)]
+struct Draft;
+
+async fn write_draft() -> Draft {
+    Draft
+}
+
+#[system]
+async fn review_draft(_draft: Out<Draft>) {}
+
+#[system]
+async fn maybe_review_draft(_draft: Option<Out<Draft>>) {}
+
+#[system]
+async fn review_drafts(_drafts: Out<Branches<Draft>>) {}
+
+/// Returns the reader name and path of each `OutputNotProduced` error.
+fn missing_outputs(graph: &Graph) -> Vec<(String, Vec<String>)> {
+    graph
+        .validate_outputs(std::iter::empty())
+        .into_iter()
+        .filter_map(|err| match err {
+            ValidationError::OutputNotProduced { name, path, .. } => Some((name.to_string(), path)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn validate_output_without_producer_warns() {
+    let mut graph = Graph::new();
+    graph.add_system(first_step).add_system(review_draft);
+
+    // The output may be inserted before the run, so `validate` only warns.
+    let result = graph.validate();
+    assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
+    assert!(
+        matches!(
+            result.warnings.as_slice(),
+            [ValidationWarning::OutputNotProduced { name, .. }] if &**name == "review_draft"
+        ),
+        "warnings: {:?}",
+        result.warnings
+    );
+
+    let errors = graph.validate_outputs(std::iter::empty());
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    let display = errors[0].to_string();
+    assert!(display.contains("review_draft"), "display: {display}");
+    assert!(display.contains("Draft"), "display: {display}");
+    assert!(
+        display.ends_with("first_step -> review_draft"),
+        "display: {display}"
+    );
+}
+
+#[test]
+fn validate_output_after_producer_succeeds() {
+    let mut graph = Graph::new();
+    graph
+        .add_system(write_draft)
+        .add_system(first_step)
+        .add_system(review_draft);
+
+    let result = graph.validate();
+    assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
+    assert!(!result.has_warnings(), "warnings: {:?}", result.warnings);
+}
+
+#[test]
+fn validate_optional_output_is_not_required() {
+    let mut graph = Graph::new();
+    graph.add_system(first_step).add_system(maybe_review_draft);
+
+    let result = graph.validate();
+    assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
+    assert!(!result.has_warnings(), "warnings: {:?}", result.warnings);
+}
+
+#[test]
+fn validate_outputs_accepts_outputs_present_upfront() {
+    let mut graph = Graph::new();
+    graph.add_system(review_draft);
+
+    assert_eq!(graph.validate_outputs(std::iter::empty()).len(), 1);
+    assert!(graph.validate_outputs([TypeId::of::<Draft>()]).is_empty());
+}
+
+#[test]
+fn validate_output_produced_on_one_branch_reports_other_branch() {
+    let mut graph = Graph::new();
+    graph
+        .add_system(first_step)
+        .add_conditional_branch::<i32, _, _, _>(
+            "has_draft",
+            |step| *step > 0,
+            |g| {
+                g.add_system(write_draft);
+            },
+            |g| {
+                g.add_system(second_step);
+            },
+        )
+        .add_system(review_draft);
+
+    assert_eq!(
+        missing_outputs(&graph),
+        [(
+            "review_draft".to_owned(),
+            vec![
+                "first_step".to_owned(),
+                "has_draft (false)".to_owned(),
+                "second_step".to_owned(),
+                "review_draft".to_owned(),
+            ]
+        )]
+    );
+}
+
+#[test]
+fn validate_output_produced_on_every_branch_succeeds() {
+    let mut graph = Graph::new();
+    graph
+        .add_system(first_step)
+        .add_conditional_branch::<i32, _, _, _>(
+            "has_draft",
+            |step| *step > 0,
+            |g| {
+                g.add_system(write_draft);
+            },
+            |g| {
+                g.add_system(second_step).add_system(write_draft);
+            },
+        )
+        .add_system(review_draft);
+
+    let result = graph.validate();
+    assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
+}
+
+#[test]
+fn validate_predicate_input_not_produced() {
+    let mut graph = Graph::new();
+    graph.add_conditional_branch::<Draft, _, _, _>(
+        "has_draft",
+        |_| true,
+        |g| {
+            g.add_system(first_step);
+        },
+        |g| {
+            g.add_system(second_step);
+        },
+    );
+
+    assert_eq!(
+        missing_outputs(&graph),
+        [("has_draft".to_owned(), vec!["has_draft".to_owned()])]
+    );
+}
+
+#[test]
+fn validate_output_missing_in_switch_case() {
+    let mut graph = Graph::new();
+    graph
+        .add_system(first_step)
+        .add_switch::<i32, _, _, _>(
+            "route",
+            |step| if *step > 0 { "draft" } else { "skip" },
+            vec![
+                (
+                    "draft",
+                    Box::new(|g: &mut Graph| {
+                        g.add_system(write_draft);
+                    }) as Box<dyn FnOnce(&mut Graph)>,
+                ),
+                (
+                    "skip",
+                    Box::new(|g: &mut Graph| {
+                        g.add_system(second_step);
+                    }),
+                ),
+            ],
+            Some(Box::new(|g: &mut Graph| {
+                g.add_system(write_draft);
+            })),
+        )
+        .add_system(review_draft);
+
+    assert_eq!(
+        missing_outputs(&graph),
+        [(
+            "review_draft".to_owned(),
+            vec![
+                "first_step".to_owned(),
+                "route (skip)".to_owned(),
+                "second_step".to_owned(),
+                "review_draft".to_owned(),
+            ]
+        )]
+    );
+}
+
+#[test]
+fn validate_output_from_loop_body() {
+    // Without a predicate the body runs at least once.
+    let mut graph = Graph::new();
+    graph
+        .add_loop_n("repeat", 3, |g| {
+            g.add_system(write_draft);
+        })
+        .add_system(review_draft);
+    let result = graph.validate();
+    assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
+
+    // With a predicate the loop may exit before the first iteration.
+    let mut graph = Graph::new();
+    graph
+        .add_system(first_step)
+        .add_loop::<i32, _, _>(
+            "until_positive",
+            |step| *step > 0,
+            |g| {
+                g.add_system(write_draft).add_system(second_step);
+            },
+        )
+        .add_system(review_draft);
+    assert_eq!(
+        missing_outputs(&graph),
+        [(
+            "review_draft".to_owned(),
+            vec![
+                "first_step".to_owned(),
+                "until_positive".to_owned(),
+                "review_draft".to_owned(),
+            ]
+        )]
+    );
+}
+
+#[test]
+fn validate_parallel_branches_start_without_outputs() {
+    let mut graph = Graph::new();
+    graph.add_system(write_draft).add_parallel(
+        "fan_out",
+        [|g: &mut Graph| {
+            g.add_system(review_draft);
+        }],
+    );
+
+    assert_eq!(
+        missing_outputs(&graph),
+        [(
+            "review_draft".to_owned(),
+            vec![
+                "write_draft".to_owned(),
+                "fan_out (branch 0)".to_owned(),
+                "review_draft".to_owned(),
+            ]
+        )]
+    );
+}
+
+#[test]
+fn validate_parallel_output_missing_from_a_branch() {
+    // Branches may fail without failing the node, so only a branch that
+    // writes a draft guarantees one.
+    let mut graph = Graph::new();
+    graph
+        .add_parallel_with(
+            "fan_out",
+            ParallelOptions::new().with_policy(ParallelPolicy::CollectErrors),
+            vec![
+                |g: &mut Graph| {
+                    g.add_system(write_draft);
+                },
+                |g: &mut Graph| {
+                    g.add_system(second_step);
+                },
+            ],
+        )
+        .add_system(review_draft);
+
+    assert_eq!(
+        missing_outputs(&graph),
+        [(
+            "review_draft".to_owned(),
+            vec![
+                "fan_out (branch 1)".to_owned(),
+                "second_step".to_owned(),
+                "review_draft".to_owned(),
+            ]
+        )]
+    );
+}
+
+#[test]
+fn validate_parallel_output_guaranteed_by_quorum() {
+    let branches = || {
+        vec![
+            Box::new(|g: &mut Graph| {
+                g.add_system(write_draft);
+            }) as Box<dyn FnOnce(&mut Graph)>,
+            Box::new(|g: &mut Graph| {
+                g.add_system(write_draft);
+            }),
+            Box::new(|g: &mut Graph| {
+                g.add_system(second_step);
+            }),
+        ]
+    };
+
+    // Any two succeeding branches include one that writes a draft.
+    let mut graph = Graph::new();
+    graph
+        .add_parallel_with(
+            "fan_out",
+            ParallelOptions::new().with_policy(ParallelPolicy::Quorum(2)),
+            branches(),
+        )
+        .add_system(review_draft);
+    let result = graph.validate();
+    assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
+
+    // The first success may be the branch without one.
+    let mut graph = Graph::new();
+    graph
+        .add_parallel_with(
+            "fan_out",
+            ParallelOptions::new().with_policy(ParallelPolicy::FirstSuccess),
+            branches(),
+        )
+        .add_system(review_draft);
+    assert_eq!(missing_outputs(&graph).len(), 1);
+}
+
+#[test]
+fn validate_parallel_collected_output() {
+    let mut graph = Graph::new();
+    graph
+        .add_parallel_with(
+            "fan_out",
+            ParallelOptions::new().gather::<Draft>(Reducer::Collect),
+            [|g: &mut Graph| {
+                g.add_system(write_draft);
+            }],
+        )
+        .add_system(review_drafts)
+        .add_system(review_draft);
+
+    let missing = missing_outputs(&graph);
+    assert_eq!(missing.len(), 1, "missing: {missing:?}");
+    assert_eq!(missing[0].0, "review_draft");
+}
+
+#[test]
+fn validate_subgraph_outputs_follow_bindings() {
+    let reviewer = || {
+        let mut graph = Graph::new();
+        graph.add_system(review_draft);
+        graph
+    };
+
+    let mut graph = Graph::new();
+    graph.add_system(write_draft).add_subgraph(
+        "reviewer",
+        reviewer(),
+        SubgraphOptions::new().input::<Draft>(),
+    );
+    let result = graph.validate();
+    assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
+
+    let mut graph = Graph::new();
+    graph
+        .add_system(write_draft)
+        .add_subgraph("reviewer", reviewer(), SubgraphOptions::new());
+    assert_eq!(
+        missing_outputs(&graph),
+        [(
+            "review_draft".to_owned(),
+            vec![
+                "write_draft".to_owned(),
+                "reviewer (subgraph)".to_owned(),
+                "review_draft".to_owned(),
+            ]
+        )]
+    );
+
+    let mut writer = Graph::new();
+    writer.add_system(write_draft);
+    let mut graph = Graph::new();
+    graph
+        .add_subgraph("writer", writer, SubgraphOptions::new().output::<Draft>())
+        .add_system(review_draft);
+    let result = graph.validate();
+    assert!(result.is_ok(), "Validation failed: {:?}", result.errors);
+}
+
+#[test]
+fn validate_error_handler_lacks_failed_output() {
+    let mut graph = Graph::new();
+    graph
+        .system(write_draft)
+        .on_error(|g| {
+            g.add_system(review_draft);
+        })
+        .done()
+        .add_system(review_draft);
+
+    assert_eq!(
+        missing_outputs(&graph),
+        [(
+            "review_draft".to_owned(),
+            vec!["write_draft (error)".to_owned(), "review_draft".to_owned()]
+        )]
+    );
+}
+
+#[test]
+fn validate_interrupt_request_not_produced() {
+    let mut graph = Graph::new();
+    graph
+        .add_system(first_step)
+        .add_interrupt::<String, bool>("approve");
+
+    assert_eq!(
+        missing_outputs(&graph),
+        [(
+            "approve".to_owned(),
+            vec!["first_step".to_owned(), "approve".to_owned()]
+        )]
+    );
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // ValidationResult API
 // ─────────────────────────────────────────────────────────────────────────────
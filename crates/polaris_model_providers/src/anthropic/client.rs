@@ -1,6 +1,7 @@
 //! Anthropic API client.
 
 use super::types::{CreateMessageRequest, MessageResponse};
+use futures::Stream;
 use polaris_models::llm::GenerationError;
 use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderValue};
 
@@ -27,6 +28,39 @@ impl AnthropicClient {
         &self,
         request: &CreateMessageRequest,
     ) -> Result<MessageResponse, GenerationError> {
+        let response = self.send(request).await?;
+
+        let body = response
+            .text()
+            .await
+            .map_err(|err| GenerationError::Http(err.to_string()))?;
+
+        serde_json::from_str(&body).map_err(|err| {
+            GenerationError::InvalidResponse(format!(
+                "Failed to parse response: {err}\nBody: {body}"
+            ))
+        })
+    }
+
+    /// Sends a streaming create message request to the Anthropic API.
+    ///
+    /// Returns the raw server-sent event byte stream.
+    pub async fn create_message_stream(
+        &self,
+        request: &CreateMessageRequest,
+    ) -> Result<
+        impl Stream<Item = Result<impl AsRef<[u8]>, reqwest::Error>> + Send + 'static,
+        GenerationError,
+    > {
+        let response = self.send(request).await?;
+        Ok(response.bytes_stream())
+    }
+
+    /// Sends a request and returns the response if the status is successful.
+    async fn send(
+        &self,
+        request: &CreateMessageRequest,
+    ) -> Result<reqwest::Response, GenerationError> {
         let url = format!("{}/v1/messages", self.base_url);
 
         let mut headers = HeaderMap::new();
@@ -61,12 +95,11 @@ impl AnthropicClient {
             .map_err(|err| GenerationError::Http(err.to_string()))?;
 
         let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(|err| GenerationError::Http(err.to_string()))?;
-
         if !status.is_success() {
+            let body = response
+                .text()
+                .await
+                .map_err(|err| GenerationError::Http(err.to_string()))?;
             return Err(GenerationError::Provider {
                 status: Some(status.as_u16()),
                 message: body,
@@ -74,11 +107,7 @@ impl AnthropicClient {
             });
         }
 
-        serde_json::from_str(&body).map_err(|err| {
-            GenerationError::InvalidResponse(format!(
-                "Failed to parse response: {err}\nBody: {body}"
-            ))
-        })
+        Ok(response)
     }
 }
 
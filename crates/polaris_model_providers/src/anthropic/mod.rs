@@ -13,6 +13,7 @@
 mod client;
 mod plugin;
 mod provider;
+mod stream;
 mod types;
 
 pub use plugin::AnthropicPlugin;
@@ -2,7 +2,7 @@
 
 use super::provider::AnthropicProvider;
 use polaris_models::{ModelRegistry, ModelsPlugin};
-use polaris_system::plugin::{Plugin, PluginId, Version};
+use polaris_system::plugin::{Dependency, Plugin, PluginError, Version};
 use polaris_system::server::Server;
 use std::sync::Arc;
 
@@ -16,19 +16,19 @@ use std::sync::Arc;
 /// server.add_plugins(AnthropicPlugin::from_env("ANTHROPIC_API_KEY"));
 /// ```
 pub struct AnthropicPlugin {
-    api_key: String,
+    /// The API key, or why it could not be read.
+    api_key: Result<String, String>,
 }
 
 impl AnthropicPlugin {
     /// Creates a plugin that reads the API key from the specified environment variable.
     ///
-    /// # Panics
-    ///
-    /// Panics if the environment variable is not set.
+    /// If the environment variable is not set, building the plugin fails
+    /// with a [`PluginError`], which aborts [`Server::try_finish`].
     #[must_use]
     pub fn from_env(env_var: &str) -> Self {
-        let api_key = std::env::var(env_var).unwrap_or_else(|_| {
-            panic!("Environment variable {env_var} for AnthropicPlugin not set. Please set it to your Anthropic API key.");
+        let api_key = std::env::var(env_var).map_err(|_| {
+            format!("Environment variable {env_var} for AnthropicPlugin not set. Please set it to your Anthropic API key.")
         });
         Self { api_key }
     }
@@ -38,19 +38,27 @@ impl Plugin for AnthropicPlugin {
     const ID: &'static str = "polaris::provider::anthropic";
     const VERSION: Version = Version::new(0, 0, 1);
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<ModelsPlugin>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ModelsPlugin>()]
     }
 
     fn build(&self, server: &mut Server) {
-        let provider = AnthropicProvider::new(self.api_key.clone());
+        if let Err(err) = self.try_build(server) {
+            panic!("{err}");
+        }
+    }
+
+    fn try_build(&self, server: &mut Server) -> Result<(), PluginError> {
+        let api_key = self.api_key.clone().map_err(PluginError::other)?;
+        let provider = AnthropicProvider::new(api_key);
 
         let Some(mut registry) = server.get_resource_mut::<ModelRegistry>() else {
-            panic!(
-                "ModelRegistry not found. Make sure to add ModelsPlugin before AnthropicPlugin."
-            );
+            return Err(PluginError::other(
+                "ModelRegistry not found. Make sure to add ModelsPlugin before AnthropicPlugin.",
+            ));
         };
 
-        registry.register_llm_provider("anthropic", Arc::new(provider));
+        registry.try_register_llm_provider("anthropic", Arc::new(provider))?;
+        Ok(())
     }
 }
@@ -1,19 +1,25 @@
 //! Anthropic [`LlmProvider`] implementation.
 
 use super::client::AnthropicClient;
+use super::stream::event_stream;
 use super::types::{
-    ContentBlock, ContentBlockParam, CreateMessageRequest, ImageMediaType, ImageSource,
-    MessageParam, OutputFormat, Role, ToolChoiceParam, ToolDef, ToolResultBlock, ToolResultContent,
+    CacheControlParam, ContentBlock, ContentBlockParam, CreateMessageRequest, ImageMediaType,
+    ImageSource, MessageParam, OutputFormat, Role, StopReason as AnthropicStopReason, SystemBlock,
+    SystemPrompt, ToolChoiceParam, ToolDef, ToolResultBlock, ToolResultContent, UsageResponse,
 };
 use crate::schema::normalize_schema_for_strict_mode;
 use async_trait::async_trait;
 use polaris_models::llm::{
-    AssistantBlock, GenerationError, ImageBlock, ImageMediaType as PolarisImageMediaType,
-    LlmProvider, LlmRequest, LlmResponse, Message, ToolCall, ToolChoice, ToolFunction,
+    AssistantBlock, CacheControl, GenerationError, ImageBlock,
+    ImageMediaType as PolarisImageMediaType, LlmProvider, LlmRequest, LlmResponse, LlmStream,
+    Message, StopReason, ToolCall, ToolChoice, ToolFunction,
     ToolResultContent as PolarisToolResult, ToolResultStatus, Usage, UserBlock,
 };
 
 /// Default maximum tokens for generation requests.
+///
+/// Anthropic requires `max_tokens` on every request; this is used when
+/// [`LlmRequest::max_output_tokens`] is unset.
 const DEFAULT_MAX_TOKENS: u32 = 4096;
 
 /// Anthropic [`LlmProvider`] implementation.
@@ -45,12 +51,34 @@ impl LlmProvider for AnthropicProvider {
 
         Ok(convert_response(response))
     }
+
+    async fn generate_stream(
+        &self,
+        model: &str,
+        request: LlmRequest,
+    ) -> Result<LlmStream, GenerationError> {
+        let mut anthropic_request = convert_request(model, &request)?;
+        anthropic_request.stream = Some(true);
+
+        let bytes = self
+            .client
+            .create_message_stream(&anthropic_request)
+            .await?;
+
+        Ok(event_stream(bytes))
+    }
 }
 
 fn convert_request(
     model: &str,
     request: &LlmRequest,
 ) -> Result<CreateMessageRequest, GenerationError> {
+    if request.seed.is_some() {
+        return Err(GenerationError::UnsupportedParameter(
+            "seed is not supported by Anthropic".to_string(),
+        ));
+    }
+
     let messages = request
         .messages
         .iter()
@@ -65,10 +93,22 @@ fn convert_request(
                 description: Some(tool.description.clone()),
                 input_schema: normalize_schema_for_strict_mode(tool.parameters.clone()),
                 strict: Some(true),
+                cache_control: tool.cache_control.map(convert_cache_control),
             })
             .collect()
     });
 
+    let system = request
+        .system
+        .clone()
+        .map(|text| match request.system_cache_control {
+            Some(cache_control) => SystemPrompt::Blocks(vec![SystemBlock::Text {
+                text,
+                cache_control: Some(convert_cache_control(cache_control)),
+            }]),
+            None => SystemPrompt::Text(text),
+        });
+
     let tool_choice = request.tool_choice.as_ref().map(convert_tool_choice);
 
     let output_format = request
@@ -78,14 +118,17 @@ fn convert_request(
 
     Ok(CreateMessageRequest {
         model: model.to_string(),
-        max_tokens: DEFAULT_MAX_TOKENS,
+        max_tokens: request.max_output_tokens.unwrap_or(DEFAULT_MAX_TOKENS),
         messages,
-        system: request.system.clone(),
+        system,
         tools,
         tool_choice,
-        temperature: None,
-        stop_sequences: None,
+        temperature: request.temperature,
+        top_p: request.top_p,
+        top_k: request.top_k,
+        stop_sequences: request.stop_sequences.clone(),
         output_format,
+        stream: None,
     })
 }
 
@@ -139,6 +182,7 @@ fn convert_user_block(block: &UserBlock) -> Result<ContentBlockParam, Generation
     match block {
         UserBlock::Text(block) => Ok(ContentBlockParam::Text {
             text: block.text.clone(),
+            cache_control: block.cache_control.map(convert_cache_control),
         }),
         UserBlock::Image(image) => {
             let source = convert_image_to_source(image)?;
@@ -177,6 +221,7 @@ fn convert_assistant_block(block: &AssistantBlock) -> Result<ContentBlockParam,
     match block {
         AssistantBlock::Text(block) => Ok(ContentBlockParam::Text {
             text: block.text.clone(),
+            cache_control: block.cache_control.map(convert_cache_control),
         }),
         AssistantBlock::ToolCall(call) => Ok(ContentBlockParam::ToolUse {
             id: call.id.clone(),
@@ -193,6 +238,12 @@ fn convert_assistant_block(block: &AssistantBlock) -> Result<ContentBlockParam,
     }
 }
 
+fn convert_cache_control(cache_control: CacheControl) -> CacheControlParam {
+    match cache_control {
+        CacheControl::Ephemeral => CacheControlParam::Ephemeral,
+    }
+}
+
 fn convert_tool_choice(choice: &ToolChoice) -> ToolChoiceParam {
     match choice {
         ToolChoice::Auto => ToolChoiceParam::Auto {
@@ -218,19 +269,43 @@ fn convert_response(response: super::types::MessageResponse) -> LlmResponse {
 
     LlmResponse {
         content,
-        usage: Usage {
-            input_tokens: Some(response.usage.input_tokens),
-            output_tokens: Some(response.usage.output_tokens),
-            total_tokens: Some(response.usage.input_tokens + response.usage.output_tokens),
-        },
+        usage: convert_usage(&response.usage),
+        stop_reason: response.stop_reason.map(convert_stop_reason),
+    }
+}
+
+/// Converts Anthropic usage to Polaris usage.
+pub(super) fn convert_usage(usage: &UsageResponse) -> Usage {
+    Usage {
+        input_tokens: Some(usage.input_tokens),
+        output_tokens: Some(usage.output_tokens),
+        total_tokens: Some(usage.input_tokens + usage.output_tokens),
+        cache_read_input_tokens: usage.cache_read_input_tokens,
+        cache_creation_input_tokens: usage.cache_creation_input_tokens,
+        reasoning_tokens: None,
+    }
+}
+
+/// Converts an Anthropic stop reason to a normalized stop reason.
+pub(super) fn convert_stop_reason(reason: AnthropicStopReason) -> StopReason {
+    match reason {
+        AnthropicStopReason::EndTurn => StopReason::EndTurn,
+        AnthropicStopReason::MaxTokens | AnthropicStopReason::ModelContextWindowExceeded => {
+            StopReason::MaxTokens
+        }
+        AnthropicStopReason::StopSequence => StopReason::StopSequence,
+        AnthropicStopReason::ToolUse => StopReason::ToolUse,
+        AnthropicStopReason::PauseTurn => StopReason::Paused,
+        AnthropicStopReason::Refusal => StopReason::ContentFilter,
+        AnthropicStopReason::Other => StopReason::Other,
     }
 }
 
 fn convert_content_block(block: ContentBlock) -> Option<AssistantBlock> {
     match block {
-        ContentBlock::Text { text } => Some(AssistantBlock::Text(polaris_models::llm::TextBlock {
-            text,
-        })),
+        ContentBlock::Text { text } => Some(AssistantBlock::Text(
+            polaris_models::llm::TextBlock::new(text),
+        )),
         ContentBlock::ToolUse { id, name, input } => Some(AssistantBlock::ToolCall(ToolCall {
             id: id.clone(),
             call_id: None,
@@ -254,3 +329,217 @@ fn convert_content_block(block: ContentBlock) -> Option<AssistantBlock> {
         ContentBlock::RedactedThinking { .. } => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polaris_models::llm::{TextBlock, ToolDefinition};
+    use serde_json::json;
+
+    fn request() -> LlmRequest {
+        LlmRequest {
+            messages: vec![Message::user("Hello")],
+            ..LlmRequest::default()
+        }
+    }
+
+    fn convert(request: &LlmRequest) -> serde_json::Value {
+        serde_json::to_value(convert_request("claude", request).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn sampling_parameters_are_forwarded() {
+        let body = convert(&LlmRequest {
+            temperature: Some(0.5),
+            top_p: Some(0.9),
+            top_k: Some(40),
+            max_output_tokens: Some(256),
+            stop_sequences: Some(vec!["END".into()]),
+            ..request()
+        });
+
+        assert_eq!(body["temperature"], json!(0.5));
+        assert_eq!(body["top_p"], json!(0.9_f32));
+        assert_eq!(body["top_k"], json!(40));
+        assert_eq!(body["max_tokens"], json!(256));
+        assert_eq!(body["stop_sequences"], json!(["END"]));
+    }
+
+    #[test]
+    fn unset_sampling_parameters_are_omitted() {
+        let body = convert(&request());
+
+        assert_eq!(body["max_tokens"], json!(DEFAULT_MAX_TOKENS));
+        for field in ["temperature", "top_p", "top_k", "stop_sequences"] {
+            assert!(body.get(field).is_none(), "{field} should be omitted");
+        }
+    }
+
+    #[test]
+    fn seed_is_rejected() {
+        let err = convert_request(
+            "claude",
+            &LlmRequest {
+                seed: Some(7),
+                ..request()
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, GenerationError::UnsupportedParameter(_)),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn stop_reasons_are_normalized() {
+        let cases = [
+            ("end_turn", StopReason::EndTurn),
+            ("max_tokens", StopReason::MaxTokens),
+            ("model_context_window_exceeded", StopReason::MaxTokens),
+            ("stop_sequence", StopReason::StopSequence),
+            ("tool_use", StopReason::ToolUse),
+            ("pause_turn", StopReason::Paused),
+            ("refusal", StopReason::ContentFilter),
+            ("a_future_reason", StopReason::Other),
+        ];
+
+        for (wire, expected) in cases {
+            let reason: AnthropicStopReason = serde_json::from_value(json!(wire)).unwrap();
+            assert_eq!(convert_stop_reason(reason), expected, "{wire}");
+        }
+    }
+
+    #[test]
+    fn response_carries_stop_reason_and_cache_usage() {
+        let response = serde_json::from_value(json!({
+            "id": "msg_1",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude",
+            "content": [{ "type": "text", "text": "Hi" }],
+            "stop_reason": "stop_sequence",
+            "stop_sequence": "END",
+            "usage": {
+                "input_tokens": 10,
+                "output_tokens": 5,
+                "cache_creation_input_tokens": 100,
+                "cache_read_input_tokens": 200
+            }
+        }))
+        .unwrap();
+
+        let response = convert_response(response);
+        assert_eq!(response.stop_reason, Some(StopReason::StopSequence));
+        assert_eq!(response.usage.input_tokens, Some(10));
+        assert_eq!(response.usage.output_tokens, Some(5));
+        assert_eq!(response.usage.total_tokens, Some(15));
+        assert_eq!(response.usage.cache_creation_input_tokens, Some(100));
+        assert_eq!(response.usage.cache_read_input_tokens, Some(200));
+        assert_eq!(response.usage.reasoning_tokens, None);
+    }
+
+    #[test]
+    fn cache_control_is_serialized_on_marked_blocks() {
+        let schema = json!({
+            "type": "object",
+            "properties": {},
+            "required": [],
+            "additionalProperties": false
+        });
+        let tool = |name: &str| ToolDefinition {
+            name: name.to_owned(),
+            description: format!("The {name} tool."),
+            parameters: schema.clone(),
+            cache_control: None,
+        };
+        let request = LlmRequest {
+            system: Some("Be brief.".into()),
+            system_cache_control: Some(CacheControl::Ephemeral),
+            tools: Some(vec![
+                tool("search"),
+                tool("fetch").with_cache_control(CacheControl::Ephemeral),
+            ]),
+            messages: vec![
+                Message::User {
+                    content: vec![
+                        UserBlock::Text(
+                            TextBlock::new("Long document.")
+                                .with_cache_control(CacheControl::Ephemeral),
+                        ),
+                        UserBlock::Text(TextBlock::new("Summarize it.")),
+                    ],
+                },
+                Message::Assistant {
+                    id: None,
+                    content: vec![AssistantBlock::Text(
+                        TextBlock::new("Summary.").with_cache_control(CacheControl::Ephemeral),
+                    )],
+                },
+            ],
+            ..LlmRequest::default()
+        };
+
+        assert_eq!(
+            convert(&request),
+            json!({
+                "model": "claude",
+                "max_tokens": DEFAULT_MAX_TOKENS,
+                "system": [
+                    {
+                        "type": "text",
+                        "text": "Be brief.",
+                        "cache_control": { "type": "ephemeral" }
+                    }
+                ],
+                "tools": [
+                    {
+                        "name": "search",
+                        "description": "The search tool.",
+                        "input_schema": schema,
+                        "strict": true
+                    },
+                    {
+                        "name": "fetch",
+                        "description": "The fetch tool.",
+                        "input_schema": schema,
+                        "strict": true,
+                        "cache_control": { "type": "ephemeral" }
+                    }
+                ],
+                "messages": [
+                    {
+                        "role": "user",
+                        "content": [
+                            {
+                                "type": "text",
+                                "text": "Long document.",
+                                "cache_control": { "type": "ephemeral" }
+                            },
+                            { "type": "text", "text": "Summarize it." }
+                        ]
+                    },
+                    {
+                        "role": "assistant",
+                        "content": [
+                            {
+                                "type": "text",
+                                "text": "Summary.",
+                                "cache_control": { "type": "ephemeral" }
+                            }
+                        ]
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn system_prompt_without_breakpoint_is_plain_text() {
+        let body = convert(&LlmRequest {
+            system: Some("Be brief.".into()),
+            ..request()
+        });
+        assert_eq!(body["system"], json!("Be brief."));
+    }
+}
@@ -0,0 +1,377 @@
+//! Server-sent event decoding for the streaming Messages API.
+
+use super::provider::{convert_stop_reason, convert_usage};
+use super::types::{ContentBlock, ContentDelta, StreamError, StreamPayload};
+use futures::{Stream, StreamExt};
+use polaris_models::llm::{GenerationError, LlmStream, StopReason, StreamEvent, Usage};
+use std::collections::VecDeque;
+
+/// Converts a raw server-sent event byte stream into Polaris stream events.
+pub fn event_stream<S, B>(bytes: S) -> LlmStream
+where
+    S: Stream<Item = Result<B, reqwest::Error>> + Send + 'static,
+    B: AsRef<[u8]>,
+{
+    let state = StreamState {
+        bytes: Box::pin(bytes),
+        decoder: SseDecoder::default(),
+        pending: VecDeque::new(),
+        usage: Usage::default(),
+        stop_reason: None,
+        finished: false,
+    };
+
+    Box::pin(futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((event, state));
+            }
+            if state.finished {
+                return None;
+            }
+
+            match state.bytes.next().await {
+                Some(Ok(chunk)) => {
+                    for data in state.decoder.push(chunk.as_ref()) {
+                        state.handle_data(&data);
+                    }
+                }
+                Some(Err(err)) => {
+                    state.fail(GenerationError::Http(err.to_string()));
+                }
+                None => {
+                    state.fail(GenerationError::InvalidResponse(
+                        "Anthropic stream ended before message_stop".to_string(),
+                    ));
+                }
+            }
+        }
+    }))
+}
+
+/// Decoding state carried between polls of the byte stream.
+struct StreamState<S> {
+    bytes: std::pin::Pin<Box<S>>,
+    decoder: SseDecoder,
+    pending: VecDeque<Result<StreamEvent, GenerationError>>,
+    usage: Usage,
+    stop_reason: Option<StopReason>,
+    finished: bool,
+}
+
+impl<S> StreamState<S> {
+    /// Handles the `data` field of a single server-sent event.
+    fn handle_data(&mut self, data: &str) {
+        if self.finished {
+            return;
+        }
+
+        let payload = match serde_json::from_str::<StreamPayload>(data) {
+            Ok(payload) => payload,
+            Err(err) => {
+                self.fail(GenerationError::InvalidResponse(format!(
+                    "Failed to parse stream event: {err}\nData: {data}"
+                )));
+                return;
+            }
+        };
+
+        match payload {
+            StreamPayload::MessageStart { message } => {
+                self.usage = convert_usage(&message.usage);
+            }
+            StreamPayload::ContentBlockStart {
+                index,
+                content_block,
+            } => match content_block {
+                ContentBlock::Text { text } => {
+                    if !text.is_empty() {
+                        self.emit(StreamEvent::TextDelta { index, text });
+                    }
+                }
+                ContentBlock::ToolUse { id, name, .. } => {
+                    self.emit(StreamEvent::ToolCallStart {
+                        index,
+                        id,
+                        call_id: None,
+                        name,
+                    });
+                }
+                ContentBlock::Thinking {
+                    thinking,
+                    signature,
+                } => {
+                    self.emit(StreamEvent::ReasoningStart { index, id: None });
+                    if !thinking.is_empty() {
+                        self.emit(StreamEvent::ReasoningDelta {
+                            index,
+                            text: thinking,
+                        });
+                    }
+                    if !signature.is_empty() {
+                        self.emit(StreamEvent::ReasoningSignature { index, signature });
+                    }
+                }
+                ContentBlock::RedactedThinking { .. } => {}
+            },
+            StreamPayload::ContentBlockDelta { index, delta } => match delta {
+                ContentDelta::TextDelta { text } => {
+                    self.emit(StreamEvent::TextDelta { index, text });
+                }
+                ContentDelta::InputJsonDelta { partial_json } => {
+                    self.emit(StreamEvent::ToolCallDelta {
+                        index,
+                        arguments: partial_json,
+                    });
+                }
+                ContentDelta::ThinkingDelta { thinking } => {
+                    self.emit(StreamEvent::ReasoningDelta {
+                        index,
+                        text: thinking,
+                    });
+                }
+                ContentDelta::SignatureDelta { signature } => {
+                    self.emit(StreamEvent::ReasoningSignature { index, signature });
+                }
+                ContentDelta::Unknown => {}
+            },
+            StreamPayload::MessageDelta { delta, usage } => {
+                let input_tokens = self.usage.input_tokens.unwrap_or_default();
+                self.usage.output_tokens = Some(usage.output_tokens);
+                self.usage.total_tokens = Some(input_tokens + usage.output_tokens);
+                if let Some(reason) = delta.stop_reason {
+                    self.stop_reason = Some(convert_stop_reason(reason));
+                }
+            }
+            StreamPayload::MessageStop => {
+                if let Some(reason) = self.stop_reason.take() {
+                    self.emit(StreamEvent::Stop { reason });
+                }
+                let usage = std::mem::take(&mut self.usage);
+                self.emit(StreamEvent::Usage(usage));
+                self.finished = true;
+            }
+            StreamPayload::Error { error } => self.fail(convert_stream_error(error)),
+            StreamPayload::ContentBlockStop | StreamPayload::Ping | StreamPayload::Unknown => {}
+        }
+    }
+
+    fn emit(&mut self, event: StreamEvent) {
+        self.pending.push_back(Ok(event));
+    }
+
+    /// Queues a terminal error and stops reading further events.
+    fn fail(&mut self, err: GenerationError) {
+        self.pending.push_back(Err(err));
+        self.finished = true;
+    }
+}
+
+/// Maps a mid-stream error event to a [`GenerationError`].
+fn convert_stream_error(error: StreamError) -> GenerationError {
+    match error.error_type.as_str() {
+        "authentication_error" | "permission_error" => GenerationError::Auth(error.message),
+        "invalid_request_error" => GenerationError::InvalidRequest(error.message),
+        "rate_limit_error" => GenerationError::RateLimited { retry_after: None },
+        _ => GenerationError::Provider {
+            status: None,
+            message: format!("{}: {}", error.error_type, error.message),
+            source: None,
+        },
+    }
+}
+
+/// Incremental decoder for the `text/event-stream` format.
+///
+/// Only the `data` field is retained; Anthropic repeats the event name
+/// inside the JSON payload's `type` field.
+#[derive(Default)]
+struct SseDecoder {
+    buffer: Vec<u8>,
+    data: String,
+}
+
+impl SseDecoder {
+    /// Feeds a chunk of bytes and returns the data of any completed events.
+    fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        while let Some(newline) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            if line.is_empty() {
+                if !self.data.is_empty() {
+                    events.push(std::mem::take(&mut self.data));
+                }
+            } else if let Some(value) = line.strip_prefix("data:") {
+                if !self.data.is_empty() {
+                    self.data.push('\n');
+                }
+                self.data.push_str(value.strip_prefix(' ').unwrap_or(value));
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polaris_models::llm::{AssistantBlock, StreamAccumulator};
+
+    /// Splits `body` into chunks of `size` bytes, as a network read might.
+    fn chunked(body: &str, size: usize) -> LlmStream {
+        let chunks: Vec<Result<Vec<u8>, reqwest::Error>> = body
+            .as_bytes()
+            .chunks(size)
+            .map(|chunk| Ok(chunk.to_vec()))
+            .collect();
+        event_stream(futures::stream::iter(chunks))
+    }
+
+    /// Formats payloads as server-sent events.
+    fn sse(payloads: &[&str]) -> String {
+        payloads
+            .iter()
+            .map(|payload| {
+                let name = serde_json::from_str::<serde_json::Value>(payload).unwrap()["type"]
+                    .as_str()
+                    .unwrap()
+                    .to_owned();
+                format!("event: {name}\r\ndata: {payload}\r\n\r\n")
+            })
+            .collect()
+    }
+
+    const MESSAGE_START: &str = r#"{"type":"message_start","message":{"id":"msg_1","type":"message","role":"assistant","model":"claude","content":[],"stop_reason":null,"usage":{"input_tokens":12,"output_tokens":1}}}"#;
+    const MESSAGE_DELTA: &str = r#"{"type":"message_delta","delta":{"stop_reason":"tool_use"},"usage":{"output_tokens":9}}"#;
+    const MESSAGE_STOP: &str = r#"{"type":"message_stop"}"#;
+
+    #[test]
+    fn decoder_joins_events_split_across_chunks() {
+        let mut decoder = SseDecoder::default();
+        assert!(decoder.push(b"event: ping\nda").is_empty());
+        assert!(decoder.push(b"ta: {\"type\":").is_empty());
+        assert!(decoder.push(b"\"ping\"}\r").is_empty());
+        assert_eq!(decoder.push(b"\n\r\ndata: 1\n"), vec![r#"{"type":"ping"}"#]);
+        assert_eq!(decoder.push(b"data: 2\n\n"), vec!["1\n2"]);
+    }
+
+    #[test]
+    fn decoder_keeps_multibyte_characters_split_across_chunks() {
+        let event = "data: caf\u{e9}\n\n".as_bytes();
+        let (first, second) = event.split_at(9);
+        let mut decoder = SseDecoder::default();
+        assert!(decoder.push(first).is_empty());
+        assert_eq!(decoder.push(second), vec!["caf\u{e9}"]);
+    }
+
+    #[tokio::test]
+    async fn stream_split_mid_event_reassembles() {
+        let body = sse(&[
+            MESSAGE_START,
+            r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+            r#"{"type":"ping"}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Checking "}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"the weather."}}"#,
+            r#"{"type":"content_block_stop","index":0}"#,
+            r#"{"type":"content_block_start","index":1,"content_block":{"type":"tool_use","id":"toolu_1","name":"get_weather","input":{}}}"#,
+            r#"{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"location\":"}}"#,
+            r#"{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"\"Tokyo\"}"}}"#,
+            r#"{"type":"content_block_stop","index":1}"#,
+            MESSAGE_DELTA,
+            MESSAGE_STOP,
+        ]);
+
+        for size in [1, 7, 64, body.len()] {
+            let response = StreamAccumulator::collect(chunked(&body, size))
+                .await
+                .unwrap();
+            assert_eq!(response.text(), "Checking the weather.");
+            let AssistantBlock::ToolCall(call) = &response.content[1] else {
+                panic!("expected tool call, got {:?}", response.content[1]);
+            };
+            assert_eq!(call.id, "toolu_1");
+            assert_eq!(call.function.arguments["location"], "Tokyo");
+            assert_eq!(response.stop_reason, Some(StopReason::ToolUse));
+            assert_eq!(response.usage.input_tokens, Some(12));
+            assert_eq!(response.usage.output_tokens, Some(9));
+        }
+    }
+
+    #[tokio::test]
+    async fn interleaved_blocks_reassemble_by_index() {
+        let body = sse(&[
+            MESSAGE_START,
+            r#"{"type":"content_block_start","index":1,"content_block":{"type":"text","text":""}}"#,
+            r#"{"type":"content_block_start","index":0,"content_block":{"type":"thinking","thinking":"","signature":""}}"#,
+            r#"{"type":"content_block_delta","index":1,"delta":{"type":"text_delta","text":"answer"}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"thinking_delta","thinking":"reasoning"}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"signature_delta","signature":"sig"}}"#,
+            MESSAGE_DELTA,
+            MESSAGE_STOP,
+        ]);
+
+        let response = StreamAccumulator::collect(chunked(&body, 16))
+            .await
+            .unwrap();
+        let AssistantBlock::Reasoning(reasoning) = &response.content[0] else {
+            panic!("expected reasoning, got {:?}", response.content[0]);
+        };
+        assert_eq!(reasoning.reasoning, vec!["reasoning"]);
+        assert_eq!(reasoning.signature.as_deref(), Some("sig"));
+        assert_eq!(response.text(), "answer");
+    }
+
+    #[tokio::test]
+    async fn delta_of_another_block_type_fails_the_stream() {
+        let body = sse(&[
+            MESSAGE_START,
+            r#"{"type":"content_block_start","index":0,"content_block":{"type":"tool_use","id":"toolu_1","name":"get_weather","input":{}}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"oops"}}"#,
+            MESSAGE_STOP,
+        ]);
+
+        let err = StreamAccumulator::collect(chunked(&body, 32))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GenerationError::InvalidResponse(_)), "{err}");
+    }
+
+    #[tokio::test]
+    async fn stream_without_message_stop_fails() {
+        let body = sse(&[
+            MESSAGE_START,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"cut"}}"#,
+        ]);
+
+        let err = StreamAccumulator::collect(chunked(&body, 32))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GenerationError::InvalidResponse(_)), "{err}");
+    }
+
+    #[tokio::test]
+    async fn error_events_are_classified() {
+        let body = sse(&[
+            MESSAGE_START,
+            r#"{"type":"error","error":{"type":"rate_limit_error","message":"slow down"}}"#,
+        ]);
+
+        let err = StreamAccumulator::collect(chunked(&body, 32))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GenerationError::RateLimited { .. }), "{err}");
+
+        let body =
+            sse(&[r#"{"type":"error","error":{"type":"overloaded_error","message":"busy"}}"#]);
+        let err = StreamAccumulator::collect(chunked(&body, 32))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GenerationError::Provider { .. }), "{err}");
+    }
+}
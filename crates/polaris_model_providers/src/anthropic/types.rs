@@ -22,7 +22,7 @@ pub struct CreateMessageRequest {
     pub messages: Vec<MessageParam>,
     /// System prompt.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub system: Option<String>,
+    pub system: Option<SystemPrompt>,
     /// Tool definitions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tools: Option<Vec<ToolDef>>,
@@ -32,12 +32,21 @@ pub struct CreateMessageRequest {
     /// Temperature for sampling.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f32>,
+    /// Nucleus sampling threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    /// Top-k sampling cutoff.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<u32>,
     /// Stop sequences.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stop_sequences: Option<Vec<String>>,
     /// Structured output format (beta).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_format: Option<OutputFormat>,
+    /// Whether to stream the response as server-sent events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
 }
 
 /// Message role.
@@ -63,6 +72,9 @@ pub enum ContentBlockParam {
     Text {
         /// The text content.
         text: String,
+        /// Cache breakpoint after this block.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControlParam>,
     },
     /// Image content.
     Image {
@@ -98,6 +110,40 @@ pub enum ContentBlockParam {
     },
 }
 
+/// System prompt content.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum SystemPrompt {
+    /// Plain text prompt.
+    Text(String),
+    /// Text blocks, used when the prompt carries a cache breakpoint.
+    Blocks(Vec<SystemBlock>),
+}
+
+/// Text block within a system prompt.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SystemBlock {
+    /// Text content.
+    Text {
+        /// The text content.
+        text: String,
+        /// Cache breakpoint after this block.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControlParam>,
+    },
+}
+
+/// Prompt caching breakpoint.
+///
+/// See: <https://docs.anthropic.com/en/docs/build-with-claude/prompt-caching>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CacheControlParam {
+    /// Short-lived cache entry (five minutes by default).
+    Ephemeral,
+}
+
 /// Content block allowed in tool results.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -158,6 +204,9 @@ pub struct ToolDef {
     /// Enable strict mode (beta).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strict: Option<bool>,
+    /// Cache breakpoint after this tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControlParam>,
 }
 
 /// Tool choice configuration.
@@ -261,6 +310,9 @@ pub enum ContentBlock {
         /// The thinking text.
         thinking: String,
         /// Signature.
+        ///
+        /// Absent from `content_block_start` events when streaming.
+        #[serde(default)]
         signature: String,
     },
     /// Redacted thinking.
@@ -287,6 +339,11 @@ pub enum StopReason {
     PauseTurn,
     /// Refusal.
     Refusal,
+    /// Input plus output reached the model's context window.
+    ModelContextWindowExceeded,
+    /// Stop reasons not known to this client.
+    #[serde(other)]
+    Other,
 }
 
 /// Token usage information.
@@ -298,10 +355,113 @@ pub struct UsageResponse {
     pub output_tokens: u64,
     /// Cache creation tokens.
     #[serde(default)]
-    #[expect(dead_code, reason = "field used for deserialization completeness")]
-    pub cache_creation_input_tokens: u64,
+    pub cache_creation_input_tokens: Option<u64>,
     /// Cache read tokens.
     #[serde(default)]
-    #[expect(dead_code, reason = "field used for deserialization completeness")]
-    pub cache_read_input_tokens: u64,
+    pub cache_read_input_tokens: Option<u64>,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Streaming Types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Server-sent event payload from the streaming Messages API.
+///
+/// See: <https://docs.anthropic.com/en/docs/build-with-claude/streaming>
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StreamPayload {
+    /// Start of the message, carrying initial usage.
+    MessageStart {
+        /// The (empty) message.
+        message: MessageResponse,
+    },
+    /// Start of a content block.
+    ContentBlockStart {
+        /// Index of the content block.
+        index: usize,
+        /// The initial block content.
+        content_block: ContentBlock,
+    },
+    /// Incremental update to a content block.
+    ContentBlockDelta {
+        /// Index of the content block.
+        index: usize,
+        /// The delta.
+        delta: ContentDelta,
+    },
+    /// End of a content block.
+    ContentBlockStop,
+    /// Top-level message changes, carrying cumulative output usage.
+    MessageDelta {
+        /// Changed message fields.
+        delta: MessageDeltaBody,
+        /// Cumulative usage.
+        usage: MessageDeltaUsage,
+    },
+    /// End of the message.
+    MessageStop,
+    /// Keep-alive.
+    Ping,
+    /// Error raised mid-stream.
+    Error {
+        /// Error details.
+        error: StreamError,
+    },
+    /// Event types not handled by this client.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Incremental content within a streamed content block.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentDelta {
+    /// Text fragment.
+    TextDelta {
+        /// The text.
+        text: String,
+    },
+    /// Partial JSON for tool input.
+    InputJsonDelta {
+        /// The JSON fragment.
+        partial_json: String,
+    },
+    /// Thinking fragment.
+    ThinkingDelta {
+        /// The thinking text.
+        thinking: String,
+    },
+    /// Thinking block signature.
+    SignatureDelta {
+        /// The signature.
+        signature: String,
+    },
+    /// Delta types not handled by this client.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Message fields changed by a `message_delta` event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessageDeltaBody {
+    /// Reason generation stopped.
+    pub stop_reason: Option<StopReason>,
+}
+
+/// Usage reported by a `message_delta` event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessageDeltaUsage {
+    /// Cumulative output tokens.
+    pub output_tokens: u64,
+}
+
+/// Error details from a streamed `error` event.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StreamError {
+    /// Error type (e.g. `overloaded_error`).
+    #[serde(rename = "type")]
+    pub error_type: String,
+    /// Human-readable message.
+    pub message: String,
 }
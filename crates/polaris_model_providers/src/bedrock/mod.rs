@@ -26,6 +26,7 @@ mod plugin;
 mod provider;
 mod request;
 mod response;
+mod stream;
 mod types;
 
 pub use plugin::BedrockPlugin;
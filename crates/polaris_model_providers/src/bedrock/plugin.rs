@@ -4,7 +4,7 @@ use super::provider::BedrockProvider;
 use aws_sdk_bedrockruntime::Client;
 use polaris_models::ModelRegistry;
 use polaris_models::ModelsPlugin;
-use polaris_system::plugin::{Plugin, PluginId, Version};
+use polaris_system::plugin::{Dependency, Plugin, PluginError, Version};
 use polaris_system::server::Server;
 use std::sync::Arc;
 
@@ -61,11 +61,17 @@ impl Plugin for BedrockPlugin {
     const ID: &'static str = "polaris::provider::bedrock";
     const VERSION: Version = Version::new(0, 0, 1);
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<ModelsPlugin>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ModelsPlugin>()]
     }
 
     fn build(&self, server: &mut Server) {
+        if let Err(err) = self.try_build(server) {
+            panic!("{err}");
+        }
+    }
+
+    fn try_build(&self, server: &mut Server) -> Result<(), PluginError> {
         let sdk_config = match &self.sdk_config {
             Some(config) => config.clone(),
             None => std::thread::scope(|s| {
@@ -83,9 +89,12 @@ impl Plugin for BedrockPlugin {
         let provider = BedrockProvider::new(Arc::new(client));
 
         let Some(mut registry) = server.get_resource_mut::<ModelRegistry>() else {
-            panic!("ModelRegistry not found. Make sure to add ModelsPlugin before BedrockPlugin.");
+            return Err(PluginError::other(
+                "ModelRegistry not found. Make sure to add ModelsPlugin before BedrockPlugin.",
+            ));
         };
 
-        registry.register_llm_provider("bedrock", Arc::new(provider));
+        registry.try_register_llm_provider("bedrock", Arc::new(provider))?;
+        Ok(())
     }
 }
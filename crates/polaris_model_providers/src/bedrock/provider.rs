@@ -1,11 +1,14 @@
 //! AWS Bedrock [`LlmProvider`] implementation.
 
-use super::request::{build_output_config, build_tool_config, convert_message};
+use super::request::{
+    build_inference_config, build_output_config, build_system, build_tool_config, convert_message,
+};
 use super::response::convert_response;
+use super::stream::event_stream;
 use async_trait::async_trait;
 use aws_sdk_bedrockruntime::Client;
 use aws_sdk_bedrockruntime::types as bedrock;
-use polaris_models::llm::{GenerationError, LlmProvider, LlmRequest, LlmResponse};
+use polaris_models::llm::{GenerationError, LlmProvider, LlmRequest, LlmResponse, LlmStream};
 use std::sync::Arc;
 
 /// AWS Bedrock [`LlmProvider`] implementation.
@@ -28,8 +31,63 @@ impl LlmProvider for BedrockProvider {
         model: &str,
         request: LlmRequest,
     ) -> Result<LlmResponse, GenerationError> {
-        let tool_config = build_tool_config(&request)?;
-        let output_config = build_output_config(&request)?;
+        let converse = ConverseParts::from_request(&request)?;
+
+        let response = self
+            .client
+            .converse()
+            .model_id(model)
+            .set_messages(Some(converse.messages))
+            .set_system(converse.system)
+            .set_tool_config(converse.tool_config)
+            .set_output_config(converse.output_config)
+            .set_inference_config(converse.inference_config)
+            .send()
+            .await
+            .map_err(|err| provider_error(err.into_service_error()))?;
+
+        convert_response(response)
+    }
+
+    async fn generate_stream(
+        &self,
+        model: &str,
+        request: LlmRequest,
+    ) -> Result<LlmStream, GenerationError> {
+        let converse = ConverseParts::from_request(&request)?;
+
+        let response = self
+            .client
+            .converse_stream()
+            .model_id(model)
+            .set_messages(Some(converse.messages))
+            .set_system(converse.system)
+            .set_tool_config(converse.tool_config)
+            .set_output_config(converse.output_config)
+            .set_inference_config(converse.inference_config)
+            .send()
+            .await
+            .map_err(|err| provider_error(err.into_service_error()))?;
+
+        Ok(event_stream(response))
+    }
+}
+
+/// Request fields shared by the `Converse` and `ConverseStream` operations.
+struct ConverseParts {
+    messages: Vec<bedrock::Message>,
+    system: Option<Vec<bedrock::SystemContentBlock>>,
+    tool_config: Option<bedrock::ToolConfiguration>,
+    output_config: Option<bedrock::OutputConfig>,
+    inference_config: Option<bedrock::InferenceConfiguration>,
+}
+
+impl ConverseParts {
+    /// Converts a Polaris request into Bedrock request fields.
+    fn from_request(request: &LlmRequest) -> Result<Self, GenerationError> {
+        let tool_config = build_tool_config(request)?;
+        let output_config = build_output_config(request)?;
+        let inference_config = build_inference_config(request)?;
 
         // Bedrock requires tool definitions whenever tool blocks appear in
         // the message history. Surface a clear error instead of letting a
@@ -49,30 +107,26 @@ impl LlmProvider for BedrockProvider {
             .map(convert_message)
             .collect::<Result<Vec<_>, _>>()?;
 
-        let system = request
-            .system
-            .as_ref()
-            .map(|s| vec![bedrock::SystemContentBlock::Text(s.clone())]);
+        let system = build_system(request)?;
 
-        let response = self
-            .client
-            .converse()
-            .model_id(model)
-            .set_messages(Some(messages))
-            .set_system(system)
-            .set_tool_config(tool_config)
-            .set_output_config(output_config)
-            .send()
-            .await
-            .map_err(|err| {
-                let service_err = err.into_service_error();
-                GenerationError::Provider {
-                    status: None,
-                    message: service_err.to_string(),
-                    source: Some(Box::new(service_err)),
-                }
-            })?;
+        Ok(Self {
+            messages,
+            system,
+            tool_config,
+            output_config,
+            inference_config,
+        })
+    }
+}
 
-        convert_response(response)
+/// Wraps a Bedrock service error in a [`GenerationError::Provider`].
+pub(super) fn provider_error<E>(err: E) -> GenerationError
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    GenerationError::Provider {
+        status: None,
+        message: err.to_string(),
+        source: Some(Box::new(err)),
     }
 }
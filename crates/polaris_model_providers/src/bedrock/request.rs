@@ -16,20 +16,33 @@ use polaris_models::llm::{self as polaris_llm, GenerationError, LlmRequest};
 pub fn convert_message(
     message: &polaris_llm::Message,
 ) -> Result<bedrock::Message, GenerationError> {
-    let (role, blocks) = match message {
+    let mut blocks = Vec::new();
+    let role = match message {
         polaris_llm::Message::User { content } => {
-            let blocks = content
-                .iter()
-                .map(convert_user_block)
-                .collect::<Result<Vec<_>, _>>()?;
-            (bedrock::ConversationRole::User, blocks)
+            for block in content {
+                blocks.push(convert_user_block(block)?);
+                if let polaris_llm::UserBlock::Text(text) = block
+                    && let Some(cache_control) = text.cache_control
+                {
+                    blocks.push(bedrock::ContentBlock::CachePoint(convert_cache_control(
+                        cache_control,
+                    )?));
+                }
+            }
+            bedrock::ConversationRole::User
         }
         polaris_llm::Message::Assistant { content, .. } => {
-            let blocks = content
-                .iter()
-                .map(convert_assistant_block)
-                .collect::<Result<Vec<_>, _>>()?;
-            (bedrock::ConversationRole::Assistant, blocks)
+            for block in content {
+                blocks.push(convert_assistant_block(block)?);
+                if let polaris_llm::AssistantBlock::Text(text) = block
+                    && let Some(cache_control) = text.cache_control
+                {
+                    blocks.push(bedrock::ContentBlock::CachePoint(convert_cache_control(
+                        cache_control,
+                    )?));
+                }
+            }
+            bedrock::ConversationRole::Assistant
         }
     };
 
@@ -90,10 +103,44 @@ fn convert_assistant_block(
     }
 }
 
+/// Builds the Bedrock system prompt from a generation request.
+pub fn build_system(
+    request: &LlmRequest,
+) -> Result<Option<Vec<bedrock::SystemContentBlock>>, GenerationError> {
+    let Some(system) = &request.system else {
+        return Ok(None);
+    };
+
+    let mut blocks = vec![bedrock::SystemContentBlock::Text(system.clone())];
+    if let Some(cache_control) = request.system_cache_control {
+        blocks.push(bedrock::SystemContentBlock::CachePoint(
+            convert_cache_control(cache_control)?,
+        ));
+    }
+
+    Ok(Some(blocks))
+}
+
 // -----------------------------------------------------------------------------
 // Content block conversions
 // -----------------------------------------------------------------------------
 
+/// Converts a Polaris cache marker to a Bedrock cache point.
+fn convert_cache_control(
+    cache_control: polaris_llm::CacheControl,
+) -> Result<bedrock::CachePointBlock, GenerationError> {
+    let cache_type = match cache_control {
+        polaris_llm::CacheControl::Ephemeral => bedrock::CachePointType::Default,
+    };
+
+    bedrock::CachePointBlock::builder()
+        .r#type(cache_type)
+        .build()
+        .map_err(|err| {
+            GenerationError::InvalidRequest(format!("failed to build cache point: {err}"))
+        })
+}
+
 /// Converts a Polaris image to a Bedrock image block.
 fn convert_image_to_block(
     image: &polaris_llm::ImageBlock,
@@ -187,10 +234,15 @@ pub fn build_tool_config(
         _ => return Ok(None),
     };
 
-    let tool_specs: Vec<bedrock::Tool> = tools
-        .iter()
-        .map(convert_tool_spec)
-        .collect::<Result<Vec<_>, _>>()?;
+    let mut tool_specs = Vec::with_capacity(tools.len());
+    for tool in tools {
+        tool_specs.push(convert_tool_spec(tool)?);
+        if let Some(cache_control) = tool.cache_control {
+            tool_specs.push(bedrock::Tool::CachePoint(convert_cache_control(
+                cache_control,
+            )?));
+        }
+    }
 
     let mut config_builder = bedrock::ToolConfiguration::builder().set_tools(Some(tool_specs));
 
@@ -296,3 +348,162 @@ pub fn build_output_config(
 
     Ok(Some(output_config))
 }
+
+// -----------------------------------------------------------------------------
+// Inference configuration
+// -----------------------------------------------------------------------------
+
+/// Builds a Bedrock inference configuration from a generation request.
+///
+/// `top_k` and `seed` are not part of the Converse API's common inference
+/// parameters and are rejected rather than silently dropped.
+pub fn build_inference_config(
+    request: &LlmRequest,
+) -> Result<Option<bedrock::InferenceConfiguration>, GenerationError> {
+    if request.top_k.is_some() {
+        return Err(GenerationError::UnsupportedParameter(
+            "top_k is not supported by the Bedrock Converse API".to_string(),
+        ));
+    }
+    if request.seed.is_some() {
+        return Err(GenerationError::UnsupportedParameter(
+            "seed is not supported by the Bedrock Converse API".to_string(),
+        ));
+    }
+
+    if request.temperature.is_none()
+        && request.top_p.is_none()
+        && request.max_output_tokens.is_none()
+        && request.stop_sequences.is_none()
+    {
+        return Ok(None);
+    }
+
+    let max_tokens = request
+        .max_output_tokens
+        .map(i32::try_from)
+        .transpose()
+        .map_err(|err| {
+            GenerationError::InvalidRequest(format!("max_output_tokens is out of range: {err}"))
+        })?;
+
+    Ok(Some(
+        bedrock::InferenceConfiguration::builder()
+            .set_max_tokens(max_tokens)
+            .set_temperature(request.temperature)
+            .set_top_p(request.top_p)
+            .set_stop_sequences(request.stop_sequences.clone())
+            .build(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inference_config_carries_sampling_parameters() {
+        let config = build_inference_config(&LlmRequest {
+            temperature: Some(0.5),
+            top_p: Some(0.9),
+            max_output_tokens: Some(256),
+            stop_sequences: Some(vec!["END".into()]),
+            ..LlmRequest::default()
+        })
+        .unwrap()
+        .expect("config should be built");
+
+        assert_eq!(config.temperature(), Some(0.5));
+        assert_eq!(config.top_p(), Some(0.9));
+        assert_eq!(config.max_tokens(), Some(256));
+        assert_eq!(config.stop_sequences(), ["END"]);
+    }
+
+    #[test]
+    fn inference_config_is_omitted_without_parameters() {
+        assert!(
+            build_inference_config(&LlmRequest::default())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unsupported_parameters_are_rejected() {
+        for request in [
+            LlmRequest {
+                top_k: Some(40),
+                ..LlmRequest::default()
+            },
+            LlmRequest {
+                seed: Some(7),
+                ..LlmRequest::default()
+            },
+        ] {
+            let err = build_inference_config(&request).unwrap_err();
+            assert!(
+                matches!(err, GenerationError::UnsupportedParameter(_)),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range_max_tokens_is_rejected() {
+        let err = build_inference_config(&LlmRequest {
+            max_output_tokens: Some(u32::MAX),
+            ..LlmRequest::default()
+        })
+        .unwrap_err();
+        assert!(matches!(err, GenerationError::InvalidRequest(_)), "{err}");
+    }
+
+    #[test]
+    fn cache_points_follow_marked_blocks() {
+        let cached = polaris_llm::CacheControl::Ephemeral;
+        let request = LlmRequest {
+            system: Some("Be brief.".into()),
+            system_cache_control: Some(cached),
+            tools: Some(vec![polaris_llm::ToolDefinition {
+                name: "search".into(),
+                description: "Searches.".into(),
+                parameters: serde_json::json!({ "type": "object", "properties": {} }),
+                cache_control: Some(cached),
+            }]),
+            ..LlmRequest::default()
+        };
+
+        let system = build_system(&request).unwrap().unwrap();
+        assert!(matches!(
+            system.as_slice(),
+            [
+                bedrock::SystemContentBlock::Text(_),
+                bedrock::SystemContentBlock::CachePoint(_)
+            ]
+        ));
+
+        let tools = build_tool_config(&request).unwrap().unwrap();
+        assert!(matches!(
+            tools.tools(),
+            [bedrock::Tool::ToolSpec(_), bedrock::Tool::CachePoint(_)]
+        ));
+
+        let message = convert_message(&polaris_llm::Message::User {
+            content: vec![
+                polaris_llm::UserBlock::Text(
+                    polaris_llm::TextBlock::new("Long document.").with_cache_control(cached),
+                ),
+                polaris_llm::UserBlock::Text(polaris_llm::TextBlock::new("Summarize it.")),
+            ],
+        })
+        .unwrap();
+        assert!(matches!(
+            message.content(),
+            [
+                bedrock::ContentBlock::Text(_),
+                bedrock::ContentBlock::CachePoint(_),
+                bedrock::ContentBlock::Text(_)
+            ]
+        ));
+    }
+}
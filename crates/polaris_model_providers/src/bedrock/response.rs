@@ -22,8 +22,13 @@ pub fn convert_response(response: ConverseOutput) -> Result<LlmResponse, Generat
     };
 
     let usage = convert_usage(response.usage);
+    let stop_reason = Some(convert_stop_reason(&response.stop_reason));
 
-    Ok(LlmResponse { content, usage })
+    Ok(LlmResponse {
+        content,
+        usage,
+        stop_reason,
+    })
 }
 
 /// Converts a Bedrock content block to a Polaris assistant block.
@@ -31,11 +36,9 @@ fn convert_content_block(
     block: bedrock::ContentBlock,
 ) -> Result<polaris_llm::AssistantBlock, GenerationError> {
     match block {
-        bedrock::ContentBlock::Text(text) => {
-            Ok(polaris_llm::AssistantBlock::Text(polaris_llm::TextBlock {
-                text,
-            }))
-        }
+        bedrock::ContentBlock::Text(text) => Ok(polaris_llm::AssistantBlock::Text(
+            polaris_llm::TextBlock::new(text),
+        )),
         bedrock::ContentBlock::ToolUse(tool_use) => Ok(convert_tool_use(tool_use)),
         bedrock::ContentBlock::ReasoningContent(reasoning) => convert_reasoning(reasoning),
         other => Err(GenerationError::InvalidResponse(format!(
@@ -89,10 +92,97 @@ fn convert_reasoning(
 }
 
 /// Converts Bedrock token usage to Polaris usage.
-fn convert_usage(usage: Option<bedrock::TokenUsage>) -> polaris_llm::Usage {
+pub fn convert_usage(usage: Option<bedrock::TokenUsage>) -> polaris_llm::Usage {
     usage.map_or_else(polaris_llm::Usage::default, |u| polaris_llm::Usage {
         input_tokens: Some(u.input_tokens as u64),
         output_tokens: Some(u.output_tokens as u64),
         total_tokens: Some(u.total_tokens as u64),
+        cache_read_input_tokens: u.cache_read_input_tokens.map(|tokens| tokens as u64),
+        cache_creation_input_tokens: u.cache_write_input_tokens.map(|tokens| tokens as u64),
+        reasoning_tokens: None,
     })
 }
+
+/// Converts a Bedrock stop reason to a normalized stop reason.
+pub fn convert_stop_reason(reason: &bedrock::StopReason) -> polaris_llm::StopReason {
+    match reason {
+        bedrock::StopReason::EndTurn => polaris_llm::StopReason::EndTurn,
+        bedrock::StopReason::MaxTokens => polaris_llm::StopReason::MaxTokens,
+        bedrock::StopReason::StopSequence => polaris_llm::StopReason::StopSequence,
+        bedrock::StopReason::ToolUse => polaris_llm::StopReason::ToolUse,
+        bedrock::StopReason::ContentFiltered | bedrock::StopReason::GuardrailIntervened => {
+            polaris_llm::StopReason::ContentFilter
+        }
+        _ => polaris_llm::StopReason::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stop_reasons_are_normalized() {
+        let cases = [
+            (
+                bedrock::StopReason::EndTurn,
+                polaris_llm::StopReason::EndTurn,
+            ),
+            (
+                bedrock::StopReason::MaxTokens,
+                polaris_llm::StopReason::MaxTokens,
+            ),
+            (
+                bedrock::StopReason::StopSequence,
+                polaris_llm::StopReason::StopSequence,
+            ),
+            (
+                bedrock::StopReason::ToolUse,
+                polaris_llm::StopReason::ToolUse,
+            ),
+            (
+                bedrock::StopReason::ContentFiltered,
+                polaris_llm::StopReason::ContentFilter,
+            ),
+            (
+                bedrock::StopReason::GuardrailIntervened,
+                polaris_llm::StopReason::ContentFilter,
+            ),
+            (
+                bedrock::StopReason::from("a_future_reason"),
+                polaris_llm::StopReason::Other,
+            ),
+        ];
+
+        for (reason, expected) in cases {
+            assert_eq!(convert_stop_reason(&reason), expected, "{reason:?}");
+        }
+    }
+
+    #[test]
+    fn usage_includes_cache_tokens() {
+        let usage = bedrock::TokenUsage::builder()
+            .input_tokens(10)
+            .output_tokens(5)
+            .total_tokens(15)
+            .cache_read_input_tokens(200)
+            .cache_write_input_tokens(100)
+            .build()
+            .unwrap();
+
+        let usage = convert_usage(Some(usage));
+        assert_eq!(usage.input_tokens, Some(10));
+        assert_eq!(usage.output_tokens, Some(5));
+        assert_eq!(usage.total_tokens, Some(15));
+        assert_eq!(usage.cache_read_input_tokens, Some(200));
+        assert_eq!(usage.cache_creation_input_tokens, Some(100));
+        assert_eq!(usage.reasoning_tokens, None);
+    }
+
+    #[test]
+    fn missing_usage_is_empty() {
+        let usage = convert_usage(None);
+        assert_eq!(usage.input_tokens, None);
+        assert_eq!(usage.output_tokens, None);
+    }
+}
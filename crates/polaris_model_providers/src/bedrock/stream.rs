@@ -0,0 +1,77 @@
+//! Bedrock `ConverseStream` to Polaris stream event conversions.
+
+use super::provider::provider_error;
+use super::response::{convert_stop_reason, convert_usage};
+use aws_sdk_bedrockruntime::operation::converse_stream::ConverseStreamOutput;
+use aws_sdk_bedrockruntime::types as bedrock;
+use polaris_models::llm::{LlmStream, StreamEvent};
+
+/// Converts a Bedrock event stream into Polaris stream events.
+pub fn event_stream(output: ConverseStreamOutput) -> LlmStream {
+    Box::pin(futures::stream::unfold(
+        Some(output.stream),
+        |receiver| async move {
+            let mut receiver = receiver?;
+            loop {
+                match receiver.recv().await {
+                    Ok(Some(event)) => {
+                        if let Some(event) = convert_stream_event(event) {
+                            return Some((Ok(event), Some(receiver)));
+                        }
+                    }
+                    Ok(None) => return None,
+                    Err(err) => return Some((Err(provider_error(err.into_service_error())), None)),
+                }
+            }
+        },
+    ))
+}
+
+/// Converts a single Bedrock stream event.
+///
+/// Returns `None` for events that carry no incremental content.
+fn convert_stream_event(event: bedrock::ConverseStreamOutput) -> Option<StreamEvent> {
+    match event {
+        bedrock::ConverseStreamOutput::ContentBlockStart(start) => {
+            let index = start.content_block_index as usize;
+            match start.start? {
+                bedrock::ContentBlockStart::ToolUse(tool_use) => Some(StreamEvent::ToolCallStart {
+                    index,
+                    id: tool_use.tool_use_id,
+                    call_id: None,
+                    name: tool_use.name,
+                }),
+                _ => None,
+            }
+        }
+        bedrock::ConverseStreamOutput::ContentBlockDelta(delta) => {
+            let index = delta.content_block_index as usize;
+            match delta.delta? {
+                bedrock::ContentBlockDelta::Text(text) => {
+                    Some(StreamEvent::TextDelta { index, text })
+                }
+                bedrock::ContentBlockDelta::ToolUse(tool_use) => Some(StreamEvent::ToolCallDelta {
+                    index,
+                    arguments: tool_use.input,
+                }),
+                bedrock::ContentBlockDelta::ReasoningContent(reasoning) => match reasoning {
+                    bedrock::ReasoningContentBlockDelta::Text(text) => {
+                        Some(StreamEvent::ReasoningDelta { index, text })
+                    }
+                    bedrock::ReasoningContentBlockDelta::Signature(signature) => {
+                        Some(StreamEvent::ReasoningSignature { index, signature })
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        bedrock::ConverseStreamOutput::MessageStop(stop) => Some(StreamEvent::Stop {
+            reason: convert_stop_reason(&stop.stop_reason),
+        }),
+        bedrock::ConverseStreamOutput::Metadata(metadata) => metadata
+            .usage
+            .map(|usage| StreamEvent::Usage(convert_usage(Some(usage)))),
+        _ => None,
+    }
+}
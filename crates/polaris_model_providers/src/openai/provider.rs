@@ -7,15 +7,17 @@ use async_openai::types::responses::{
     CreateResponseArgs, EasyInputContent, EasyInputMessage, FunctionCallOutput,
     FunctionCallOutputItemParam, FunctionTool, FunctionToolCall, InputContent, InputImageContent,
     InputItem, InputParam, InputTextContent, Item, OutputItem, OutputMessageContent, ReasoningItem,
-    Response, ResponseFormatJsonSchema, ResponseTextParam, ResponseUsage, Role, SummaryPart,
+    Response, ResponseCompletedEvent, ResponseFormatJsonSchema, ResponseIncompleteEvent,
+    ResponseStreamEvent, ResponseTextParam, ResponseUsage, Role, Status, SummaryPart,
     SummaryTextContent, TextResponseFormatConfiguration, Tool, ToolChoiceFunction,
     ToolChoiceOptions, ToolChoiceParam,
 };
 use async_trait::async_trait;
+use futures::StreamExt;
 use polaris_models::llm::{
-    AssistantBlock, GenerationError, ImageMediaType, LlmProvider, LlmRequest, LlmResponse, Message,
-    ReasoningBlock, TextBlock, ToolCall, ToolChoice, ToolFunction,
-    ToolResultContent as PolarisToolResult, ToolResultStatus, Usage, UserBlock,
+    AssistantBlock, GenerationError, ImageMediaType, LlmProvider, LlmRequest, LlmResponse,
+    LlmStream, Message, ReasoningBlock, StopReason, StreamEvent, TextBlock, ToolCall, ToolChoice,
+    ToolFunction, ToolResultContent as PolarisToolResult, ToolResultStatus, Usage, UserBlock,
 };
 
 /// `OpenAI` [`LlmProvider`] implementation using the Responses API.
@@ -50,6 +52,27 @@ impl LlmProvider for OpenAiProvider {
             .map_err(convert_error)?;
         convert_response(response)
     }
+
+    async fn generate_stream(
+        &self,
+        model: &str,
+        request: LlmRequest,
+    ) -> Result<LlmStream, GenerationError> {
+        let create_response = convert_request(model, &request)?;
+        let stream = self
+            .client
+            .responses()
+            .create_stream(create_response)
+            .await
+            .map_err(convert_error)?;
+
+        Ok(Box::pin(stream.flat_map(|event| {
+            futures::stream::iter(match event {
+                Ok(event) => convert_stream_event(event),
+                Err(err) => vec![Err(convert_error(err))],
+            })
+        })))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -60,6 +83,8 @@ fn convert_request(
     model: &str,
     request: &LlmRequest,
 ) -> Result<async_openai::types::responses::CreateResponse, GenerationError> {
+    reject_unsupported_parameters(request)?;
+
     let input_items = convert_messages(&request.messages)?;
 
     let tools: Option<Vec<Tool>> = request.tools.as_ref().map(|tools| {
@@ -108,12 +133,37 @@ fn convert_request(
     if let Some(text) = text {
         builder.text(text);
     }
+    if let Some(temperature) = request.temperature {
+        builder.temperature(temperature);
+    }
+    if let Some(top_p) = request.top_p {
+        builder.top_p(top_p);
+    }
+    if let Some(max_output_tokens) = request.max_output_tokens {
+        builder.max_output_tokens(max_output_tokens);
+    }
 
     builder.build().map_err(|build_err| {
         GenerationError::InvalidRequest(format!("Failed to build CreateResponse: {build_err}"))
     })
 }
 
+/// Rejects generation parameters that the Responses API does not accept.
+fn reject_unsupported_parameters(request: &LlmRequest) -> Result<(), GenerationError> {
+    let unsupported = [
+        ("top_k", request.top_k.is_some()),
+        ("stop_sequences", request.stop_sequences.is_some()),
+        ("seed", request.seed.is_some()),
+    ];
+
+    match unsupported.iter().find(|(_, is_set)| *is_set) {
+        Some((name, _)) => Err(GenerationError::UnsupportedParameter(format!(
+            "{name} is not supported by the OpenAI Responses API"
+        ))),
+        None => Ok(()),
+    }
+}
+
 fn convert_messages(messages: &[Message]) -> Result<Vec<InputItem>, GenerationError> {
     let mut items = Vec::new();
 
@@ -341,6 +391,8 @@ fn convert_tool_choice(choice: &ToolChoice) -> ToolChoiceParam {
 // ---------------------------------------------------------------------------
 
 fn convert_response(response: Response) -> Result<LlmResponse, GenerationError> {
+    let stop_reason = convert_stop_reason(&response);
+
     let content = response
         .output
         .into_iter()
@@ -352,7 +404,43 @@ fn convert_response(response: Response) -> Result<LlmResponse, GenerationError>
 
     let usage = response.usage.map(convert_usage).unwrap_or_default();
 
-    Ok(LlmResponse { content, usage })
+    Ok(LlmResponse {
+        content,
+        usage,
+        stop_reason,
+    })
+}
+
+/// Derives a normalized stop reason from the response status.
+///
+/// The Responses API has no explicit stop reason; completed responses
+/// containing function calls are reported as [`StopReason::ToolUse`].
+fn convert_stop_reason(response: &Response) -> Option<StopReason> {
+    match response.status {
+        Status::Completed => {
+            let has_tool_calls = response
+                .output
+                .iter()
+                .any(|item| matches!(item, OutputItem::FunctionCall(_)));
+            Some(if has_tool_calls {
+                StopReason::ToolUse
+            } else {
+                StopReason::EndTurn
+            })
+        }
+        Status::Incomplete => Some(
+            match response
+                .incomplete_details
+                .as_ref()
+                .map(|details| details.reason.as_str())
+            {
+                Some("max_output_tokens") => StopReason::MaxTokens,
+                Some("content_filter") => StopReason::ContentFilter,
+                _ => StopReason::Other,
+            },
+        ),
+        _ => None,
+    }
 }
 
 fn convert_output_item(item: OutputItem) -> Result<Vec<AssistantBlock>, GenerationError> {
@@ -429,17 +517,107 @@ fn convert_output_message_content(
 ) -> Result<AssistantBlock, GenerationError> {
     match content {
         OutputMessageContent::OutputText(text) => {
-            Ok(AssistantBlock::Text(TextBlock { text: text.text }))
+            Ok(AssistantBlock::Text(TextBlock::new(text.text)))
         }
         OutputMessageContent::Refusal(refusal) => Err(GenerationError::Refusal(refusal.refusal)),
     }
 }
 
+/// Converts OpenAI usage, whose input tokens include cached tokens, so that
+/// cache reads are counted apart from `input_tokens`.
 fn convert_usage(usage: ResponseUsage) -> Usage {
+    let cached = usage.input_tokens_details.cached_tokens;
     Usage {
-        input_tokens: Some(u64::from(usage.input_tokens)),
+        input_tokens: Some(u64::from(usage.input_tokens.saturating_sub(cached))),
         output_tokens: Some(u64::from(usage.output_tokens)),
         total_tokens: Some(u64::from(usage.total_tokens)),
+        cache_read_input_tokens: Some(u64::from(cached)),
+        cache_creation_input_tokens: None,
+        reasoning_tokens: Some(u64::from(usage.output_tokens_details.reasoning_tokens)),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Stream conversion (OpenAI -> Polaris)
+// ---------------------------------------------------------------------------
+
+/// Converts a Responses API stream event into Polaris stream events.
+///
+/// Returns an empty list for events that carry no incremental content.
+fn convert_stream_event(event: ResponseStreamEvent) -> Vec<Result<StreamEvent, GenerationError>> {
+    match event {
+        ResponseStreamEvent::ResponseOutputItemAdded(added) => {
+            let index = added.output_index as usize;
+            match added.item {
+                OutputItem::FunctionCall(call) => {
+                    if call.id.is_none() {
+                        tracing::warn!(
+                            call_id = call.call_id,
+                            function = call.name,
+                            "OpenAI function call is missing an item ID"
+                        );
+                    }
+                    vec![Ok(StreamEvent::ToolCallStart {
+                        index,
+                        id: call.id.unwrap_or_default(),
+                        call_id: Some(call.call_id),
+                        name: call.name,
+                    })]
+                }
+                OutputItem::Reasoning(reasoning) => vec![Ok(StreamEvent::ReasoningStart {
+                    index,
+                    id: Some(reasoning.id),
+                })],
+                _ => Vec::new(),
+            }
+        }
+        ResponseStreamEvent::ResponseOutputTextDelta(delta) => vec![Ok(StreamEvent::TextDelta {
+            index: delta.output_index as usize,
+            text: delta.delta,
+        })],
+        ResponseStreamEvent::ResponseFunctionCallArgumentsDelta(delta) => {
+            vec![Ok(StreamEvent::ToolCallDelta {
+                index: delta.output_index as usize,
+                arguments: delta.delta,
+            })]
+        }
+        ResponseStreamEvent::ResponseReasoningSummaryTextDelta(delta) => {
+            vec![Ok(StreamEvent::ReasoningDelta {
+                index: delta.output_index as usize,
+                text: delta.delta,
+            })]
+        }
+        ResponseStreamEvent::ResponseRefusalDone(refusal) => {
+            vec![Err(GenerationError::Refusal(refusal.refusal))]
+        }
+        ResponseStreamEvent::ResponseCompleted(ResponseCompletedEvent { response, .. })
+        | ResponseStreamEvent::ResponseIncomplete(ResponseIncompleteEvent { response, .. }) => {
+            let mut events = Vec::with_capacity(2);
+            if let Some(reason) = convert_stop_reason(&response) {
+                events.push(Ok(StreamEvent::Stop { reason }));
+            }
+            events.push(Ok(StreamEvent::Usage(
+                response.usage.map(convert_usage).unwrap_or_default(),
+            )));
+            events
+        }
+        ResponseStreamEvent::ResponseFailed(failed) => {
+            let message = failed.response.error.map_or_else(
+                || "response failed without error details".to_string(),
+                |err| format!("{}: {}", err.code, err.message),
+            );
+            vec![Err(GenerationError::Provider {
+                status: None,
+                message,
+                source: None,
+            })]
+        }
+        ResponseStreamEvent::ResponseError(err) => vec![Err(GenerationError::Provider {
+            status: None,
+            message: err.message,
+            source: None,
+        })],
+        _ => Vec::new(),
     }
 }
 
@@ -478,3 +656,150 @@ fn convert_error(err: OpenAIError) -> GenerationError {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request() -> LlmRequest {
+        LlmRequest {
+            messages: vec![Message::user("Hello")],
+            ..LlmRequest::default()
+        }
+    }
+
+    fn convert(request: &LlmRequest) -> serde_json::Value {
+        serde_json::to_value(convert_request("gpt", request).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn sampling_parameters_are_forwarded() {
+        let body = convert(&LlmRequest {
+            temperature: Some(0.5),
+            top_p: Some(0.9),
+            max_output_tokens: Some(256),
+            ..request()
+        });
+
+        assert_eq!(body["temperature"], json!(0.5));
+        assert_eq!(body["top_p"], json!(0.9_f32));
+        assert_eq!(body["max_output_tokens"], json!(256));
+    }
+
+    #[test]
+    fn unset_sampling_parameters_are_omitted() {
+        let body = convert(&request());
+
+        for field in ["temperature", "top_p", "max_output_tokens"] {
+            assert!(body.get(field).is_none(), "{field} should be omitted");
+        }
+    }
+
+    #[test]
+    fn unsupported_parameters_are_rejected() {
+        let requests = [
+            LlmRequest {
+                top_k: Some(40),
+                ..request()
+            },
+            LlmRequest {
+                stop_sequences: Some(vec!["END".into()]),
+                ..request()
+            },
+            LlmRequest {
+                seed: Some(7),
+                ..request()
+            },
+        ];
+
+        for request in requests {
+            let err = convert_request("gpt", &request).unwrap_err();
+            assert!(
+                matches!(err, GenerationError::UnsupportedParameter(_)),
+                "{err}"
+            );
+        }
+    }
+
+    fn response(status: &str, output: serde_json::Value) -> serde_json::Value {
+        json!({
+            "id": "resp_1",
+            "object": "response",
+            "created_at": 0,
+            "model": "gpt",
+            "status": status,
+            "output": output,
+            "usage": {
+                "input_tokens": 10,
+                "input_tokens_details": { "cached_tokens": 4 },
+                "output_tokens": 8,
+                "output_tokens_details": { "reasoning_tokens": 3 },
+                "total_tokens": 18
+            }
+        })
+    }
+
+    fn incomplete(reason: &str) -> serde_json::Value {
+        let mut response = response("incomplete", json!([]));
+        response["incomplete_details"] = json!({ "reason": reason });
+        response
+    }
+
+    #[test]
+    fn stop_reason_follows_response_status() {
+        let message = json!([{
+            "type": "message",
+            "id": "msg_1",
+            "role": "assistant",
+            "status": "completed",
+            "content": [{ "type": "output_text", "text": "Hi", "annotations": [] }]
+        }]);
+        let function_call = json!([{
+            "type": "function_call",
+            "id": "fc_1",
+            "call_id": "call_1",
+            "name": "get_weather",
+            "arguments": "{}",
+            "status": "completed"
+        }]);
+        let cases = [
+            (response("completed", message), Some(StopReason::EndTurn)),
+            (
+                response("completed", function_call),
+                Some(StopReason::ToolUse),
+            ),
+            (incomplete("max_output_tokens"), Some(StopReason::MaxTokens)),
+            (
+                incomplete("content_filter"),
+                Some(StopReason::ContentFilter),
+            ),
+            (incomplete("a_future_reason"), Some(StopReason::Other)),
+            (response("in_progress", json!([])), None),
+        ];
+
+        for (response, expected) in cases {
+            let response: Response = serde_json::from_value(response).unwrap();
+            assert_eq!(
+                convert_stop_reason(&response),
+                expected,
+                "{:?}",
+                response.status
+            );
+        }
+    }
+
+    #[test]
+    fn usage_includes_cached_and_reasoning_tokens() {
+        let response: Response = serde_json::from_value(response("completed", json!([]))).unwrap();
+
+        let usage = convert_response(response).unwrap().usage;
+        // Cached tokens are reported apart from the 10 input tokens.
+        assert_eq!(usage.input_tokens, Some(6));
+        assert_eq!(usage.output_tokens, Some(8));
+        assert_eq!(usage.total_tokens, Some(18));
+        assert_eq!(usage.cache_read_input_tokens, Some(4));
+        assert_eq!(usage.cache_creation_input_tokens, None);
+        assert_eq!(usage.reasoning_tokens, Some(3));
+    }
+}
@@ -1,8 +1,9 @@
 //! Shared test helpers for provider integration tests.
 
+use futures::StreamExt;
 use polaris_models::llm::{
-    AssistantBlock, ImageMediaType, Llm, LlmRequest, LlmResponse, Message, ToolCall, ToolChoice,
-    ToolDefinition, UserBlock,
+    AssistantBlock, ImageMediaType, Llm, LlmRequest, LlmResponse, Message, StopReason,
+    StreamAccumulator, StreamEvent, ToolCall, ToolChoice, ToolDefinition, UserBlock,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -45,6 +46,7 @@ fn weather_tool() -> ToolDefinition {
             "required": ["location"],
             "additionalProperties": false
         }),
+        cache_control: None,
     }
 }
 
@@ -78,6 +80,18 @@ pub trait LlmTestExt {
 
     /// Tests that an invalid model returns an error.
     fn test_invalid_model_error(&self) -> impl Future<Output = ()> + Send;
+
+    /// Tests sampling parameters - expects output to respect the token limit.
+    fn test_generation_parameters(&self) -> impl Future<Output = ()> + Send;
+
+    /// Tests streaming generation - expects text deltas followed by usage.
+    fn test_streaming_generation(&self) -> impl Future<Output = ()> + Send;
+
+    /// Tests streaming tool calling - expects argument fragments to reassemble.
+    fn test_streaming_tool_calling(&self) -> impl Future<Output = ()> + Send;
+
+    /// Tests prompt caching - expects a repeated cached prefix to be read from cache.
+    fn test_prompt_caching(&self) -> impl Future<Output = ()> + Send;
 }
 
 impl LlmTestExt for Llm {
@@ -130,6 +144,7 @@ impl LlmTestExt for Llm {
         let tool_calls = extract_tool_calls(&response);
 
         assert!(!tool_calls.is_empty(), "should have at least one tool call");
+        assert_eq!(response.stop_reason, Some(StopReason::ToolUse));
         assert_eq!(
             tool_calls[0].function.name, "get_weather",
             "response should call get_weather function"
@@ -201,4 +216,115 @@ impl LlmTestExt for Llm {
 
         assert!(result.is_err(), "should fail with invalid model");
     }
+
+    async fn test_generation_parameters(&self) {
+        let response = self
+            .builder()
+            .temperature(0.0)
+            .max_output_tokens(16)
+            .user("Write a long essay about the history of the Roman Empire.")
+            .generate()
+            .await
+            .expect("generation should succeed");
+
+        let output_tokens = response
+            .usage
+            .output_tokens
+            .expect("usage should report output tokens");
+        assert!(
+            output_tokens <= 16,
+            "output should respect max_output_tokens: {output_tokens}"
+        );
+        assert_eq!(response.stop_reason, Some(StopReason::MaxTokens));
+        assert!(response.is_truncated());
+    }
+
+    async fn test_streaming_generation(&self) {
+        let mut stream = self
+            .builder()
+            .user("Count from 1 to 5, separated by spaces.")
+            .stream()
+            .await
+            .expect("stream should start");
+
+        let mut accumulator = StreamAccumulator::new();
+        let mut text_deltas = 0;
+        let mut saw_usage = false;
+
+        while let Some(event) = stream.next().await {
+            let event = event.expect("stream event should succeed");
+            match &event {
+                StreamEvent::TextDelta { .. } => text_deltas += 1,
+                StreamEvent::Usage(_) => saw_usage = true,
+                _ => {}
+            }
+            accumulator.push(event).expect("stream event should apply");
+        }
+
+        assert!(text_deltas > 0, "should receive at least one text delta");
+        assert!(saw_usage, "should receive a final usage event");
+
+        let response = accumulator.finish().expect("stream should reassemble");
+        assert!(
+            response.text().contains('5'),
+            "response should contain '5': {}",
+            response.text()
+        );
+        assert!(
+            response
+                .usage
+                .output_tokens
+                .is_some_and(|tokens| tokens > 0)
+        );
+    }
+
+    async fn test_streaming_tool_calling(&self) {
+        let stream = self
+            .builder()
+            .with_definitions(vec![weather_tool()])
+            .require_tool()
+            .user("What's the weather like in Tokyo?")
+            .stream()
+            .await
+            .expect("stream should start");
+
+        let response = StreamAccumulator::collect(stream)
+            .await
+            .expect("stream should reassemble");
+
+        let tool_calls = extract_tool_calls(&response);
+        assert!(!tool_calls.is_empty(), "should have at least one tool call");
+        assert_eq!(tool_calls[0].function.name, "get_weather");
+        assert!(
+            tool_calls[0].function.arguments.get("location").is_some(),
+            "Tool call should have location argument: {:?}",
+            tool_calls[0].function.arguments
+        );
+    }
+
+    async fn test_prompt_caching(&self) {
+        // Providers only cache prefixes above a minimum length (1024-4096 tokens).
+        let system =
+            "You are a meticulous assistant. Answer concisely and accurately. ".repeat(600);
+
+        let generate = || {
+            self.builder()
+                .system(system.clone())
+                .cache_system()
+                .user("Say hello.")
+                .generate()
+        };
+
+        generate().await.expect("first generation should succeed");
+        let response = generate().await.expect("second generation should succeed");
+
+        assert!(
+            response
+                .usage
+                .cache_read_input_tokens
+                .is_some_and(|tokens| tokens > 0),
+            "second request should read the cached prefix: {:?}",
+            response.usage
+        );
+    }
 }
@@ -73,3 +73,27 @@ async fn test_invalid_model_error() {
 async fn test_image_input() {
     get_llm(MODEL).test_image_input().await;
 }
+
+#[tokio::test]
+#[ignore = "requires OPENAI_API_KEY"]
+async fn test_streaming_generation() {
+    get_llm(MODEL).test_streaming_generation().await;
+}
+
+#[tokio::test]
+#[ignore = "requires OPENAI_API_KEY"]
+async fn test_streaming_tool_calling() {
+    get_llm(MODEL).test_streaming_tool_calling().await;
+}
+
+#[tokio::test]
+#[ignore = "requires OPENAI_API_KEY"]
+async fn test_generation_parameters() {
+    get_llm(MODEL).test_generation_parameters().await;
+}
+
+#[tokio::test]
+#[ignore = "requires OPENAI_API_KEY"]
+async fn test_prompt_caching() {
+    get_llm(MODEL).test_prompt_caching().await;
+}
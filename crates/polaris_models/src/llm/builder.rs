@@ -16,6 +16,7 @@
 //! let response = llm
 //!     .builder()
 //!     .system("You are helpful")
+//!     .temperature(0.2)
 //!     .user("What's the weather?")
 //!     .generate()
 //!     .await?;
@@ -25,7 +26,8 @@
 
 use super::error::{ExtractionError, GenerationError};
 use super::model::Llm;
-use super::types::{LlmRequest, LlmResponse, Message, ToolChoice, ToolDefinition};
+use super::stream::LlmStream;
+use super::types::{CacheControl, LlmRequest, LlmResponse, Message, ToolChoice, ToolDefinition};
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use std::marker::PhantomData;
@@ -44,14 +46,22 @@ pub struct Ready {
 ///
 /// Created via [`Llm::builder()`]. Uses typestate to enforce that at least one
 /// message is added before sending. Terminal methods ([`generate()`](Self::generate),
-/// [`generate_structured()`](Self::generate_structured)) are only available in
-/// the [`Ready`] state.
+/// [`generate_structured()`](Self::generate_structured), [`stream()`](Self::stream))
+/// are only available in the [`Ready`] state.
 pub struct LlmRequestBuilder<'a, S = Empty> {
     llm: &'a Llm,
     tools: Vec<ToolDefinition>,
     system: Option<String>,
+    system_cache_control: Option<CacheControl>,
+    tools_cache_control: Option<CacheControl>,
     messages: Vec<Message>,
     tool_choice: Option<ToolChoice>,
+    temperature: Option<f32>,
+    top_p: Option<f32>,
+    top_k: Option<u32>,
+    max_output_tokens: Option<u32>,
+    stop_sequences: Option<Vec<String>>,
+    seed: Option<u64>,
     _state: PhantomData<S>,
 }
 
@@ -82,6 +92,25 @@ impl<'a, S> LlmRequestBuilder<'a, S> {
         self
     }
 
+    /// Marks the system prompt as a prompt caching breakpoint.
+    ///
+    /// See [`CacheControl`] for how providers treat the marker.
+    #[must_use]
+    pub fn cache_system(mut self) -> Self {
+        self.system_cache_control = Some(CacheControl::Ephemeral);
+        self
+    }
+
+    /// Marks the tool definitions as a prompt caching breakpoint.
+    ///
+    /// The marker is placed on the last definition when the request is built,
+    /// so it also covers tools added after this call.
+    #[must_use]
+    pub fn cache_tools(mut self) -> Self {
+        self.tools_cache_control = Some(CacheControl::Ephemeral);
+        self
+    }
+
     /// Sets how the model should choose tools.
     #[must_use]
     pub fn tool_choice(mut self, choice: ToolChoice) -> Self {
@@ -115,6 +144,52 @@ impl<'a, S> LlmRequestBuilder<'a, S> {
         self.tool_choice = Some(ToolChoice::None);
         self
     }
+
+    /// Sets the sampling temperature.
+    #[must_use]
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets the nucleus sampling threshold.
+    #[must_use]
+    pub fn top_p(mut self, top_p: f32) -> Self {
+        self.top_p = Some(top_p);
+        self
+    }
+
+    /// Sets the top-k sampling cutoff.
+    #[must_use]
+    pub fn top_k(mut self, top_k: u32) -> Self {
+        self.top_k = Some(top_k);
+        self
+    }
+
+    /// Sets the maximum number of tokens to generate.
+    #[must_use]
+    pub fn max_output_tokens(mut self, max_output_tokens: u32) -> Self {
+        self.max_output_tokens = Some(max_output_tokens);
+        self
+    }
+
+    /// Adds a stop sequence.
+    ///
+    /// Can be called multiple times; sequences accumulate.
+    #[must_use]
+    pub fn stop_sequence(mut self, sequence: impl Into<String>) -> Self {
+        self.stop_sequences
+            .get_or_insert_with(Vec::new)
+            .push(sequence.into());
+        self
+    }
+
+    /// Sets the seed for deterministic sampling.
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
 }
 
 // ─────────────────────
@@ -128,8 +203,16 @@ impl<'a, S> LlmRequestBuilder<'a, S> {
             llm: self.llm,
             tools: self.tools,
             system: self.system,
+            system_cache_control: self.system_cache_control,
+            tools_cache_control: self.tools_cache_control,
             messages: self.messages,
             tool_choice: self.tool_choice,
+            temperature: self.temperature,
+            top_p: self.top_p,
+            top_k: self.top_k,
+            max_output_tokens: self.max_output_tokens,
+            stop_sequences: self.stop_sequences,
+            seed: self.seed,
             _state: PhantomData,
         }
     }
@@ -166,8 +249,18 @@ impl<'a, S> LlmRequestBuilder<'a, S> {
 // ─────────────────────
 
 impl<'a> LlmRequestBuilder<'a, Ready> {
-    /// Builds the [`LlmRequest`] from the accumulated state.
-    fn build(self) -> (&'a Llm, LlmRequest) {
+    /// Builds the [`LlmRequest`] from the accumulated state without sending it.
+    ///
+    /// Returns the target model alongside the request, for callers that drive
+    /// generation themselves (e.g. multi-step tool loops).
+    #[must_use]
+    pub fn build(mut self) -> (&'a Llm, LlmRequest) {
+        if let Some(cache_control) = self.tools_cache_control
+            && let Some(last) = self.tools.last_mut()
+        {
+            last.cache_control = Some(cache_control);
+        }
+
         let tools = if self.tools.is_empty() {
             None
         } else {
@@ -176,10 +269,17 @@ impl<'a> LlmRequestBuilder<'a, Ready> {
 
         let request = LlmRequest {
             system: self.system,
+            system_cache_control: self.system_cache_control,
             messages: self.messages,
             tools,
             tool_choice: self.tool_choice,
             output_schema: None,
+            temperature: self.temperature,
+            top_p: self.top_p,
+            top_k: self.top_k,
+            max_output_tokens: self.max_output_tokens,
+            stop_sequences: self.stop_sequences,
+            seed: self.seed,
         };
 
         (self.llm, request)
@@ -195,6 +295,17 @@ impl<'a> LlmRequestBuilder<'a, Ready> {
         llm.generate(request).await
     }
 
+    /// Sends the generation request and streams the response incrementally.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GenerationError`] if the request cannot be started. Errors
+    /// that occur mid-stream are yielded as stream items.
+    pub async fn stream(self) -> Result<LlmStream, GenerationError> {
+        let (llm, request) = self.build();
+        llm.generate_stream(request).await
+    }
+
     /// Sends the request and extracts a typed value from the response.
     ///
     /// Automatically injects the JSON schema for `T` into the request
@@ -224,8 +335,16 @@ impl<'a> LlmRequestBuilder<'a, Empty> {
             llm,
             tools: Vec::new(),
             system: None,
+            system_cache_control: None,
+            tools_cache_control: None,
             messages: Vec::new(),
             tool_choice: None,
+            temperature: None,
+            top_p: None,
+            top_k: None,
+            max_output_tokens: None,
+            stop_sequences: None,
+            seed: None,
             _state: PhantomData,
         }
     }
@@ -0,0 +1,792 @@
+//! Provider wrapper recording requests and responses to a cassette file.
+//!
+//! A [`CassetteProvider`] in record mode forwards every request to a real
+//! provider and stores the exchange on disk. In replay mode it serves the
+//! stored responses without a network connection, so tests of whole agents
+//! run deterministically in CI.
+
+use super::error::GenerationError;
+use super::provider::LlmProvider;
+use super::stream::{LlmStream, StreamAccumulator, response_events};
+use super::types::{LlmRequest, LlmResponse};
+use async_trait::async_trait;
+use futures::StreamExt;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Text that [`CassetteProvider::with_secret`] substitutes for secrets.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A hook that rewrites a serialized request or response before it is
+/// hashed and stored.
+type Redaction = Arc<dyn Fn(&mut Value) + Send + Sync>;
+
+/// One recorded exchange.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    /// Hash of the model and the redacted request.
+    key: String,
+    model: String,
+    request: Value,
+    /// The redacted response, or the error of a failed request.
+    response: Result<Value, RecordedError>,
+}
+
+/// A [`GenerationError`] as stored in a cassette.
+///
+/// Keeps the variant and its status or retry-after hint, so that a replayed
+/// error classifies as the original did. Error sources are not kept.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum RecordedError {
+    Http {
+        message: String,
+    },
+    Json {
+        message: String,
+    },
+    Auth {
+        message: String,
+    },
+    RateLimited {
+        retry_after: Option<Duration>,
+    },
+    InvalidRequest {
+        message: String,
+    },
+    InvalidResponse {
+        message: String,
+    },
+    UnsupportedContent {
+        message: String,
+    },
+    UnsupportedParameter {
+        message: String,
+    },
+    Refusal {
+        message: String,
+    },
+    Replay {
+        message: String,
+    },
+    Rejected {
+        message: String,
+    },
+    NoTargetAvailable,
+    Provider {
+        status: Option<u16>,
+        message: String,
+    },
+}
+
+impl From<&GenerationError> for RecordedError {
+    fn from(err: &GenerationError) -> Self {
+        match err {
+            GenerationError::Http(message) => Self::Http {
+                message: message.clone(),
+            },
+            GenerationError::Json(err) => Self::Json {
+                message: err.to_string(),
+            },
+            GenerationError::Auth(message) => Self::Auth {
+                message: message.clone(),
+            },
+            GenerationError::RateLimited { retry_after } => Self::RateLimited {
+                retry_after: *retry_after,
+            },
+            GenerationError::InvalidRequest(message) => Self::InvalidRequest {
+                message: message.clone(),
+            },
+            GenerationError::InvalidResponse(message) => Self::InvalidResponse {
+                message: message.clone(),
+            },
+            GenerationError::UnsupportedContent(message) => Self::UnsupportedContent {
+                message: message.clone(),
+            },
+            GenerationError::UnsupportedParameter(message) => Self::UnsupportedParameter {
+                message: message.clone(),
+            },
+            GenerationError::Refusal(message) => Self::Refusal {
+                message: message.clone(),
+            },
+            GenerationError::Replay(message) => Self::Replay {
+                message: message.clone(),
+            },
+            GenerationError::Rejected(err) => Self::Rejected {
+                message: err.to_string(),
+            },
+            GenerationError::NoTargetAvailable => Self::NoTargetAvailable,
+            GenerationError::Provider {
+                status, message, ..
+            } => Self::Provider {
+                status: *status,
+                message: message.clone(),
+            },
+        }
+    }
+}
+
+impl From<RecordedError> for GenerationError {
+    fn from(err: RecordedError) -> Self {
+        match err {
+            RecordedError::Http { message } => Self::Http(message),
+            RecordedError::Json { message } => {
+                Self::Json(<serde_json::Error as serde::de::Error>::custom(message))
+            }
+            RecordedError::Auth { message } => Self::Auth(message),
+            RecordedError::RateLimited { retry_after } => Self::RateLimited { retry_after },
+            RecordedError::InvalidRequest { message } => Self::InvalidRequest(message),
+            RecordedError::InvalidResponse { message } => Self::InvalidResponse(message),
+            RecordedError::UnsupportedContent { message } => Self::UnsupportedContent(message),
+            RecordedError::UnsupportedParameter { message } => Self::UnsupportedParameter(message),
+            RecordedError::Refusal { message } => Self::Refusal(message),
+            RecordedError::Replay { message } => Self::Replay(message),
+            RecordedError::Rejected { message } => Self::Rejected(message.into()),
+            RecordedError::NoTargetAvailable => Self::NoTargetAvailable,
+            RecordedError::Provider { status, message } => Self::Provider {
+                status,
+                message,
+                source: None,
+            },
+        }
+    }
+}
+
+/// Contents of a cassette file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+/// A cassette being recorded, shared with the streams it records.
+struct Recording {
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+impl Recording {
+    /// Appends an exchange to the cassette and rewrites the file.
+    fn store(
+        &self,
+        redactions: &[Redaction],
+        key: String,
+        model: &str,
+        request: Value,
+        response: Result<&LlmResponse, &GenerationError>,
+    ) -> Result<(), GenerationError> {
+        let response = match response {
+            Ok(response) => {
+                let mut response = serde_json::to_value(response)?;
+                redact(redactions, &mut response);
+                Ok(response)
+            }
+            Err(err) => {
+                let mut err = serde_json::to_value(RecordedError::from(err))?;
+                redact(redactions, &mut err);
+                Err(serde_json::from_value(err)?)
+            }
+        };
+
+        let mut cassette = self.cassette.lock();
+        cassette.interactions.push(Interaction {
+            key,
+            model: model.to_owned(),
+            request,
+            response,
+        });
+        let contents = serde_json::to_string_pretty(&*cassette)?;
+        save(&self.path, &contents).map_err(|err| GenerationError::Provider {
+            status: None,
+            message: format!("failed to write cassette {}", self.path.display()),
+            source: Some(Box::new(err)),
+        })
+    }
+}
+
+/// Whether a [`CassetteProvider`] records or replays.
+enum Mode {
+    /// Forwards requests to the provider and appends them to the cassette.
+    Record {
+        provider: Arc<dyn LlmProvider>,
+        recording: Arc<Recording>,
+    },
+    /// Serves recorded responses, in recording order for repeated requests.
+    Replay {
+        responses: Mutex<HashMap<String, VecDeque<Result<Value, RecordedError>>>>,
+    },
+}
+
+/// A provider that records exchanges with another provider to a file, or
+/// replays them from one.
+///
+/// Each request is normalized to JSON, passed through the redaction hooks,
+/// and keyed by a stable hash of the model name and the redacted request.
+/// Register the provider under the name of the provider it stands in for, so
+/// the model identifiers used by agents stay the same:
+///
+/// ```no_run
+/// # use polaris_models::ModelRegistry;
+/// # use polaris_models::llm::{CassetteProvider, LlmProvider};
+/// # use std::sync::Arc;
+/// # fn example(registry: &mut ModelRegistry, anthropic: Arc<dyn LlmProvider>) {
+/// let path = "tests/cassettes/triage.json";
+/// let cassette = if std::env::var_os("RECORD_CASSETTES").is_some() {
+///     CassetteProvider::record(path, anthropic)
+/// } else {
+///     CassetteProvider::replay(path).unwrap()
+/// };
+/// let cassette = cassette.with_secret(std::env::var("CUSTOMER_EMAIL").unwrap_or_default());
+/// registry.register_llm_provider("anthropic", Arc::new(cassette));
+/// # }
+/// ```
+///
+/// # Recording
+///
+/// [`record`](Self::record) starts an empty cassette and rewrites the file
+/// after every exchange, so a test that fails halfway keeps what it
+/// recorded. Failed requests are stored with their error's variant, status
+/// and message. A stream is stored once it has been read to the end.
+///
+/// # Replaying
+///
+/// [`replay`](Self::replay) loads the file up front. A request is answered
+/// with the next unplayed response recorded for its key; a request with no
+/// response left fails with [`GenerationError::Replay`] naming its key and
+/// model. Recorded failures are returned as the same [`GenerationError`]
+/// variant with the original status and message, so they
+/// [classify](GenerationError::class) as they did when recorded; only the
+/// underlying error source is lost. Streams are replayed as the events of
+/// the recorded response.
+///
+/// # Redaction
+///
+/// Redaction hooks run on requests and responses in both modes, before the
+/// request is hashed, so configure the same hooks for recording and
+/// replaying. A request containing a secret then matches its recording
+/// even when the secret differs between runs. Use
+/// [`with_secret`](Self::with_secret) to mask a known value, or
+/// [`with_redaction`](Self::with_redaction) for anything else.
+pub struct CassetteProvider {
+    path: PathBuf,
+    mode: Mode,
+    redactions: Vec<Redaction>,
+}
+
+impl fmt::Debug for CassetteProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = match self.mode {
+            Mode::Record { .. } => "record",
+            Mode::Replay { .. } => "replay",
+        };
+        f.debug_struct("CassetteProvider")
+            .field("path", &self.path)
+            .field("mode", &mode)
+            .field("redactions", &self.redactions.len())
+            .finish()
+    }
+}
+
+impl CassetteProvider {
+    /// Creates a provider forwarding to `provider` and recording to `path`.
+    ///
+    /// The file is replaced once the first exchange is recorded.
+    #[must_use]
+    pub fn record(path: impl Into<PathBuf>, provider: Arc<dyn LlmProvider>) -> Self {
+        let path = path.into();
+        Self {
+            mode: Mode::Record {
+                provider,
+                recording: Arc::new(Recording {
+                    path: path.clone(),
+                    cassette: Mutex::new(Cassette::default()),
+                }),
+            },
+            path,
+            redactions: Vec::new(),
+        }
+    }
+
+    /// Creates a provider replaying the cassette at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CassetteError`] if the file cannot be read or parsed.
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self, CassetteError> {
+        let path = path.into();
+        let contents = std::fs::read_to_string(&path).map_err(|err| CassetteError::Io {
+            path: path.clone(),
+            source: err,
+        })?;
+        let cassette: Cassette =
+            serde_json::from_str(&contents).map_err(|err| CassetteError::Json {
+                path: path.clone(),
+                source: err,
+            })?;
+
+        let mut responses: HashMap<String, VecDeque<_>> = HashMap::new();
+        for interaction in cassette.interactions {
+            responses
+                .entry(interaction.key)
+                .or_default()
+                .push_back(interaction.response);
+        }
+        Ok(Self {
+            path,
+            mode: Mode::Replay {
+                responses: Mutex::new(responses),
+            },
+            redactions: Vec::new(),
+        })
+    }
+
+    /// Adds a hook that rewrites every serialized request and response.
+    ///
+    /// Hooks run in the order they were added.
+    #[must_use]
+    pub fn with_redaction(
+        mut self,
+        redaction: impl Fn(&mut Value) + Send + Sync + 'static,
+    ) -> Self {
+        self.redactions.push(Arc::new(redaction));
+        self
+    }
+
+    /// Replaces every occurrence of `secret` in string values with
+    /// [`REDACTED`].
+    ///
+    /// Empty secrets are ignored.
+    #[must_use]
+    pub fn with_secret(self, secret: impl Into<String>) -> Self {
+        let secret = secret.into();
+        if secret.is_empty() {
+            return self;
+        }
+        self.with_redaction(move |value| replace_strings(value, &secret))
+    }
+
+    /// Returns the path of the cassette file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether the provider records rather than replays.
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        matches!(self.mode, Mode::Record { .. })
+    }
+
+    /// Returns the number of recorded responses not yet replayed.
+    ///
+    /// Always zero in record mode. A test can assert on it to check that an
+    /// agent made every request the cassette expects.
+    #[must_use]
+    pub fn unplayed(&self) -> usize {
+        match &self.mode {
+            Mode::Record { .. } => 0,
+            Mode::Replay { responses } => responses.lock().values().map(VecDeque::len).sum(),
+        }
+    }
+
+    /// Serializes and redacts `request`, returning it with its key.
+    fn normalize(
+        &self,
+        model: &str,
+        request: &LlmRequest,
+    ) -> Result<(String, Value), GenerationError> {
+        let mut request = serde_json::to_value(request)?;
+        redact(&self.redactions, &mut request);
+        let key = format!("{:016x}", fnv1a(&serde_json::to_vec(&(model, &request))?));
+        Ok((key, request))
+    }
+
+    /// Takes the next recorded response for `key`.
+    fn replayed(
+        responses: &Mutex<HashMap<String, VecDeque<Result<Value, RecordedError>>>>,
+        key: &str,
+        model: &str,
+        path: &Path,
+    ) -> Result<LlmResponse, GenerationError> {
+        let response = responses
+            .lock()
+            .get_mut(key)
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| {
+                GenerationError::Replay(format!(
+                    "no recorded response for request {key} to '{model}' in {}",
+                    path.display()
+                ))
+            })?;
+        match response {
+            Ok(response) => Ok(serde_json::from_value(response)?),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for CassetteProvider {
+    async fn generate(
+        &self,
+        model: &str,
+        request: LlmRequest,
+    ) -> Result<LlmResponse, GenerationError> {
+        let (key, normalized) = self.normalize(model, &request)?;
+        match &self.mode {
+            Mode::Replay { responses } => Self::replayed(responses, &key, model, &self.path),
+            Mode::Record {
+                provider,
+                recording,
+            } => {
+                let response = provider.generate(model, request).await;
+                recording.store(&self.redactions, key, model, normalized, response.as_ref())?;
+                response
+            }
+        }
+    }
+
+    async fn generate_stream(
+        &self,
+        model: &str,
+        request: LlmRequest,
+    ) -> Result<LlmStream, GenerationError> {
+        let Mode::Record {
+            provider,
+            recording,
+        } = &self.mode
+        else {
+            let response = self.generate(model, request).await?;
+            let events = response_events(response).into_iter().map(Ok);
+            return Ok(Box::pin(futures::stream::iter(events)));
+        };
+
+        let (key, normalized) = self.normalize(model, &request)?;
+        let stream = match provider.generate_stream(model, request).await {
+            Ok(stream) => stream.fuse(),
+            Err(err) => {
+                recording.store(&self.redactions, key, model, normalized, Err(&err))?;
+                return Err(err);
+            }
+        };
+        let pending = PendingStream {
+            recording: Arc::clone(recording),
+            redactions: self.redactions.clone(),
+            key,
+            model: model.to_owned(),
+            request: normalized,
+            accumulator: StreamAccumulator::new(),
+        };
+        Ok(Box::pin(futures::stream::unfold(
+            (stream, Some(pending)),
+            |(mut stream, mut pending)| async move {
+                let item = match (stream.next().await, pending.take()) {
+                    (Some(Ok(event)), Some(mut stream_pending)) => {
+                        match stream_pending.accumulator.push(event.clone()) {
+                            Ok(()) => pending = Some(stream_pending),
+                            Err(err) => {
+                                let _ = stream_pending.store(Err(&err));
+                            }
+                        }
+                        Some(Ok(event))
+                    }
+                    (Some(Err(err)), Some(stream_pending)) => {
+                        // The stream's own error takes precedence over a failure to save it.
+                        let _ = stream_pending.store(Err(&err));
+                        Some(Err(err))
+                    }
+                    (None, Some(mut stream_pending)) => {
+                        let accumulator = std::mem::take(&mut stream_pending.accumulator);
+                        stream_pending
+                            .store(accumulator.finish().as_ref())
+                            .err()
+                            .map(Err)
+                    }
+                    (item, None) => item,
+                };
+                item.map(|item| (item, (stream, pending)))
+            },
+        )))
+    }
+}
+
+/// A stream being recorded, stored once it ends.
+struct PendingStream {
+    recording: Arc<Recording>,
+    redactions: Vec<Redaction>,
+    key: String,
+    model: String,
+    request: Value,
+    accumulator: StreamAccumulator,
+}
+
+impl PendingStream {
+    fn store(
+        self,
+        response: Result<&LlmResponse, &GenerationError>,
+    ) -> Result<(), GenerationError> {
+        self.recording.store(
+            &self.redactions,
+            self.key,
+            &self.model,
+            self.request,
+            response,
+        )
+    }
+}
+
+/// A cassette file that could not be loaded.
+#[derive(Debug, thiserror::Error)]
+pub enum CassetteError {
+    /// The file could not be read.
+    #[error("failed to read cassette {}: {source}", path.display())]
+    Io {
+        /// The cassette path.
+        path: PathBuf,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+
+    /// The file is not a valid cassette.
+    #[error("invalid cassette {}: {source}", path.display())]
+    Json {
+        /// The cassette path.
+        path: PathBuf,
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+}
+
+/// Runs every redaction hook on `value`.
+fn redact(redactions: &[Redaction], value: &mut Value) {
+    for redaction in redactions {
+        redaction(value);
+    }
+}
+
+/// Writes `contents` to `path`, creating parent directories.
+fn save(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, contents)
+}
+
+/// Replaces `secret` with [`REDACTED`] in every string within `value`.
+fn replace_strings(value: &mut Value, secret: &str) {
+    match value {
+        Value::String(text) if text.contains(secret) => *text = text.replace(secret, REDACTED),
+        Value::Array(items) => {
+            for item in items {
+                replace_strings(item, secret);
+            }
+        }
+        Value::Object(fields) => {
+            for field in fields.values_mut() {
+                replace_strings(field, secret);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 64-bit FNV-1a hash, stable across platforms and Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelRegistry;
+    use crate::llm::{AssistantBlock, ErrorClass, Message, TextBlock, Usage};
+
+    /// Numbers its answers, failing on requests for models `broken` and
+    /// `busy`.
+    #[derive(Default)]
+    struct Counting {
+        calls: Mutex<u32>,
+    }
+
+    #[async_trait]
+    impl LlmProvider for Counting {
+        async fn generate(
+            &self,
+            model: &str,
+            request: LlmRequest,
+        ) -> Result<LlmResponse, GenerationError> {
+            if model == "broken" {
+                return Err(GenerationError::InvalidRequest("no such model".into()));
+            }
+            if model == "busy" {
+                return Err(GenerationError::Provider {
+                    status: Some(429),
+                    message: "slow down".into(),
+                    source: None,
+                });
+            }
+            let mut calls = self.calls.lock();
+            *calls += 1;
+            let question = serde_json::to_string(&request.messages).unwrap();
+            Ok(LlmResponse {
+                content: vec![AssistantBlock::Text(TextBlock::new(format!(
+                    "answer {calls} to {question}"
+                )))],
+                usage: Usage::default(),
+                stop_reason: None,
+            })
+        }
+    }
+
+    fn ask(text: &str) -> LlmRequest {
+        LlmRequest {
+            messages: vec![Message::user(text)],
+            ..LlmRequest::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_recorded_responses_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassette.json");
+        let provider = Arc::new(Counting::default());
+
+        let recorder = CassetteProvider::record(&path, provider.clone());
+        let mut recorded = Vec::new();
+        for text in ["hello", "hello", "bye"] {
+            recorded.push(recorder.generate("model", ask(text)).await.unwrap().text());
+        }
+
+        let mut registry = ModelRegistry::new();
+        registry.register_llm_provider("mock", Arc::new(CassetteProvider::replay(&path).unwrap()));
+        let llm = registry.llm("mock/model").unwrap();
+        let mut replayed = Vec::new();
+        for text in ["hello", "bye", "hello"] {
+            replayed.push(llm.generate(ask(text)).await.unwrap().text());
+        }
+
+        assert_eq!(replayed, [&*recorded[0], &*recorded[2], &*recorded[1]]);
+        assert_eq!(*provider.calls.lock(), 3);
+    }
+
+    #[tokio::test]
+    async fn unmatched_requests_fail() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassette.json");
+        let recorder = CassetteProvider::record(&path, Arc::new(Counting::default()));
+        recorder.generate("model", ask("hello")).await.unwrap();
+
+        let replay = CassetteProvider::replay(&path).unwrap();
+        assert_eq!(replay.unplayed(), 1);
+        for (model, text) in [("model", "goodbye"), ("other", "hello")] {
+            let err = replay.generate(model, ask(text)).await.unwrap_err();
+            assert!(
+                matches!(&err, GenerationError::Replay(message) if message.contains("no recorded response")),
+                "{err}"
+            );
+        }
+
+        replay.generate("model", ask("hello")).await.unwrap();
+        let err = replay.generate("model", ask("hello")).await.unwrap_err();
+        assert!(matches!(err, GenerationError::Replay(_)), "{err}");
+        assert_eq!(replay.unplayed(), 0);
+    }
+
+    #[tokio::test]
+    async fn replays_recorded_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassette.json");
+        let recorder = CassetteProvider::record(&path, Arc::new(Counting::default()));
+        recorder.generate("broken", ask("hello")).await.unwrap_err();
+
+        let replay = CassetteProvider::replay(&path).unwrap();
+        let err = replay.generate("broken", ask("hello")).await.unwrap_err();
+        assert!(
+            matches!(&err, GenerationError::InvalidRequest(message) if message == "no such model"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn replayed_rate_limit_classifies_as_rate_limited() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassette.json");
+        let recorder = CassetteProvider::record(&path, Arc::new(Counting::default()));
+        recorder.generate("busy", ask("hello")).await.unwrap_err();
+
+        let replay = CassetteProvider::replay(&path).unwrap();
+        let err = replay.generate("busy", ask("hello")).await.unwrap_err();
+        assert_eq!(err.class(), ErrorClass::RateLimited);
+        assert!(
+            matches!(&err, GenerationError::Provider { status: Some(429), message, .. } if message == "slow down"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn redacts_secrets_before_matching_and_storing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassette.json");
+        let recorder = CassetteProvider::record(&path, Arc::new(Counting::default()))
+            .with_secret("sk-recorded");
+        let recorded = recorder
+            .generate("model", ask("my key is sk-recorded"))
+            .await
+            .unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("sk-recorded"));
+        assert!(contents.contains(REDACTED));
+
+        let replay = CassetteProvider::replay(&path)
+            .unwrap()
+            .with_secret("sk-replayed");
+        let replayed = replay
+            .generate("model", ask("my key is sk-replayed"))
+            .await
+            .unwrap();
+        assert_eq!(
+            replayed.text(),
+            recorded.text().replace("sk-recorded", REDACTED)
+        );
+    }
+
+    #[tokio::test]
+    async fn records_and_replays_streams() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassette.json");
+        let recorder = CassetteProvider::record(&path, Arc::new(Counting::default()));
+        let mut accumulator = StreamAccumulator::new();
+        let mut stream = recorder
+            .generate_stream("model", ask("hello"))
+            .await
+            .unwrap();
+        while let Some(event) = stream.next().await {
+            accumulator.push(event.unwrap()).unwrap();
+        }
+        let recorded = accumulator.finish().unwrap();
+
+        let replay = CassetteProvider::replay(&path).unwrap();
+        let mut accumulator = StreamAccumulator::new();
+        let mut stream = replay.generate_stream("model", ask("hello")).await.unwrap();
+        while let Some(event) = stream.next().await {
+            accumulator.push(event.unwrap()).unwrap();
+        }
+        assert_eq!(accumulator.finish().unwrap().text(), recorded.text());
+    }
+
+    #[test]
+    fn missing_cassette_fails_to_load() {
+        let err = CassetteProvider::replay("does/not/exist.json").unwrap_err();
+        assert!(matches!(err, CassetteError::Io { .. }), "{err}");
+    }
+}
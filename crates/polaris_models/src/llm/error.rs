@@ -56,10 +56,29 @@ pub enum GenerationError {
     #[error("unsupported content: {0}")]
     UnsupportedContent(String),
 
+    /// Request sets a generation parameter that the provider cannot honor.
+    #[error("unsupported parameter: {0}")]
+    UnsupportedParameter(String),
+
     /// The model refused to fulfill the request (e.g. content policy).
     #[error("model refused the request: {0}")]
     Refusal(String),
 
+    /// A call replayed from an execution trace failed when recorded, or the
+    /// trace holds no matching call.
+    #[error("replayed call failed: {0}")]
+    Replay(String),
+
+    /// A [`UsageObserver`](super::UsageObserver) rejected the request before
+    /// it was sent, for example because a budget is spent.
+    #[error("request rejected: {0}")]
+    Rejected(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    /// A [`RoutingProvider`](super::RoutingProvider) skipped every target
+    /// because each target's circuit is open.
+    #[error("no target available: every circuit is open")]
+    NoTargetAvailable,
+
     /// Error returned by the model provider.
     #[error("provider error: {message}")]
     Provider {
@@ -72,3 +91,62 @@ pub enum GenerationError {
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
 }
+
+impl GenerationError {
+    /// Classifies the error by how a caller should react to it.
+    ///
+    /// Provider errors are classified by their HTTP status: 429 is
+    /// [`RateLimited`](ErrorClass::RateLimited), 401 and 403 are
+    /// [`Auth`](ErrorClass::Auth), other 4xx statuses are
+    /// [`InvalidRequest`](ErrorClass::InvalidRequest), and 5xx or missing
+    /// statuses are [`Server`](ErrorClass::Server).
+    /// [`NoTargetAvailable`](Self::NoTargetAvailable) is a server error, so
+    /// that an enclosing router fails over and retry policies back off.
+    #[must_use]
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            Self::Http(_) => ErrorClass::Transport,
+            Self::RateLimited { .. } => ErrorClass::RateLimited,
+            Self::Auth(_) => ErrorClass::Auth,
+            Self::InvalidRequest(_) => ErrorClass::InvalidRequest,
+            Self::UnsupportedContent(_) | Self::UnsupportedParameter(_) => ErrorClass::Unsupported,
+            Self::Json(_) | Self::InvalidResponse(_) => ErrorClass::InvalidResponse,
+            Self::Refusal(_) => ErrorClass::Refusal,
+            Self::Replay(_) => ErrorClass::Replay,
+            Self::Rejected(_) => ErrorClass::Rejected,
+            Self::NoTargetAvailable => ErrorClass::Server,
+            Self::Provider { status, .. } => match status {
+                Some(429) => ErrorClass::RateLimited,
+                Some(401 | 403) => ErrorClass::Auth,
+                Some(400..=499) => ErrorClass::InvalidRequest,
+                _ => ErrorClass::Server,
+            },
+        }
+    }
+}
+
+/// Broad category of a [`GenerationError`], returned by
+/// [`GenerationError::class`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    /// The request did not reach the provider or its response was cut off.
+    Transport,
+    /// The provider is rate limiting the caller.
+    RateLimited,
+    /// The provider failed to serve a valid request.
+    Server,
+    /// The credentials were rejected.
+    Auth,
+    /// The provider rejected the request as malformed.
+    InvalidRequest,
+    /// The request uses content or parameters the provider cannot handle.
+    Unsupported,
+    /// The provider's response could not be understood.
+    InvalidResponse,
+    /// The model declined to answer.
+    Refusal,
+    /// A replayed call failed or was missing from the trace.
+    Replay,
+    /// The request was rejected before being sent.
+    Rejected,
+}
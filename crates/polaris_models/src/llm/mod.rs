@@ -5,21 +5,43 @@
 //!
 //! - Text generation with tool calling
 //! - Structured outputs
+//! - Streaming generation
+//! - Prompt caching breakpoints
 //! - Multi-modal inputs (images, audio, documents)
+//! - Failover and weighted routing across providers
+//! - Retries with backoff for transient failures
+//! - Client-side rate limits per provider and model
+//! - Usage observers for accounting and budgets
+//! - Recording and replaying provider responses for offline tests
 
 mod builder;
+mod cassette;
 mod error;
 mod model;
+mod observer;
 mod provider;
+pub(crate) mod rate_limit;
+mod recording;
+mod retry;
+mod routing;
+mod stream;
 mod types;
 
 pub use builder::{Empty, LlmRequestBuilder, Ready};
-pub use error::{ExtractionError, GenerationError};
+pub use cassette::{CassetteError, CassetteProvider, REDACTED};
+pub use error::{ErrorClass, ExtractionError, GenerationError};
 pub use model::Llm;
+pub use observer::UsageObserver;
 pub use provider::LlmProvider;
+pub use rate_limit::{RateLimit, RateLimitError};
+pub use retry::RetryPolicy;
+pub use routing::{
+    CircuitBreaker, CircuitState, Routing, RoutingError, RoutingProvider, Target, TargetHealth,
+};
+pub use stream::{LlmStream, StreamAccumulator, StreamEvent};
 pub use types::{
-    AssistantBlock, AudioBlock, AudioMediaType, DocumentBlock, DocumentMediaType, DocumentSource,
-    ImageBlock, ImageMediaType, LlmRequest, LlmResponse, Message, ReasoningBlock, TextBlock,
-    ToolCall, ToolChoice, ToolDefinition, ToolFunction, ToolResult, ToolResultContent,
-    ToolResultStatus, Usage, UserBlock,
+    AssistantBlock, AudioBlock, AudioMediaType, CacheControl, DocumentBlock, DocumentMediaType,
+    DocumentSource, ImageBlock, ImageMediaType, LlmRequest, LlmResponse, MEDIA_TOKEN_ESTIMATE,
+    Message, ReasoningBlock, StopReason, TextBlock, ToolCall, ToolChoice, ToolDefinition,
+    ToolFunction, ToolResult, ToolResultContent, ToolResultStatus, Usage, UserBlock,
 };
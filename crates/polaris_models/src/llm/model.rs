@@ -2,8 +2,14 @@
 
 use super::builder::LlmRequestBuilder;
 use super::error::{ExtractionError, GenerationError};
+use super::observer::{self, UsageObserver};
 use super::provider::LlmProvider;
-use super::types::{LlmRequest, LlmResponse};
+use super::rate_limit::{self, RateLimitPermit, RateLimiter};
+use super::recording;
+use super::retry::RetryPolicy;
+use super::stream::{LlmStream, StreamEvent};
+use super::types::{LlmRequest, LlmResponse, Usage};
+use futures::StreamExt;
 use schemars::{JsonSchema, schema_for};
 use serde::de::DeserializeOwned;
 use std::sync::Arc;
@@ -15,22 +21,122 @@ use std::sync::Arc;
 pub struct Llm {
     provider: Arc<dyn LlmProvider>,
     model: String,
+    retry: Option<RetryPolicy>,
+    limiters: Vec<Arc<RateLimiter>>,
+    /// `"provider/model"` identifier reported to observers.
+    model_id: String,
+    observers: Vec<Arc<dyn UsageObserver>>,
 }
 
 impl Llm {
     /// Creates a new LLM handle from provider and model name.
     #[must_use]
     pub(crate) fn new(provider: Arc<dyn LlmProvider>, model: String) -> Self {
-        Self { provider, model }
+        Self {
+            provider,
+            retry: None,
+            limiters: Vec::new(),
+            model_id: model.clone(),
+            observers: Vec::new(),
+            model,
+        }
+    }
+
+    /// Reports requests to `observers` under `model_id`.
+    pub(crate) fn with_observers(
+        mut self,
+        model_id: String,
+        observers: Vec<Arc<dyn UsageObserver>>,
+    ) -> Self {
+        self.model_id = model_id;
+        self.observers = observers;
+        self
+    }
+
+    /// Applies the rate limiters configured for this handle's provider and
+    /// model.
+    pub(crate) fn with_limiters(mut self, limiters: Vec<Arc<RateLimiter>>) -> Self {
+        self.limiters = limiters;
+        self
+    }
+
+    /// Retries failed requests according to `policy`.
+    ///
+    /// Replaces any policy set through
+    /// [`ModelRegistry::set_retry_policy`](crate::ModelRegistry::set_retry_policy).
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Disables retries for this handle.
+    #[must_use]
+    pub fn without_retry(mut self) -> Self {
+        self.retry = None;
+        self
+    }
+
+    /// Returns the retry policy applied to requests, if any.
+    #[must_use]
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry.as_ref()
     }
 
     /// Sends a generation request to the model.
     ///
+    /// Inside a graph run that records a trace, the call is recorded. Inside
+    /// a run that replays one, the recorded response is returned instead.
+    /// Failed attempts are retried according to the
+    /// [retry policy](Self::with_retry); only the final outcome is recorded.
+    /// [Usage observers](super::UsageObserver) may reject the request before
+    /// it is sent.
+    ///
     /// # Errors
     ///
     /// Returns a [`GenerationError`] if the request fails.
     pub async fn generate(&self, request: LlmRequest) -> Result<LlmResponse, GenerationError> {
-        self.provider.generate(&self.model, request).await
+        if let Some(response) = recording::replayed(&self.model, &request) {
+            return response;
+        }
+        observer::admit(&self.observers, &self.model_id)?;
+        let Some(recorded) = recording::recorded_request(&request) else {
+            return self.call(request).await;
+        };
+
+        let response = self.call(request).await;
+        recording::record(&self.model, recorded, response.as_ref());
+        response
+    }
+
+    /// Sends a generation request and streams the response incrementally.
+    ///
+    /// Use [`StreamAccumulator`](super::StreamAccumulator) to reassemble the
+    /// events into a complete [`LlmResponse`]. Traces are recorded and
+    /// replayed as for [`generate`](Self::generate); a recorded stream is
+    /// only stored once it has been read to the end. Only failures to start
+    /// the stream are retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GenerationError`] if the request cannot be started. Errors
+    /// that occur mid-stream are yielded as stream items.
+    pub async fn generate_stream(&self, request: LlmRequest) -> Result<LlmStream, GenerationError> {
+        if let Some(stream) = recording::replayed_stream(&self.model, &request) {
+            return stream;
+        }
+        observer::admit(&self.observers, &self.model_id)?;
+        let Some(recorded) = recording::recorded_request(&request) else {
+            return self.call_stream(request).await;
+        };
+
+        match self.call_stream(request).await {
+            Ok(stream) => Ok(recording::recording_stream(&self.model, recorded, stream)),
+            Err(err) => {
+                recording::record(&self.model, recorded, Err(&err));
+                Err(err)
+            }
+        }
     }
 
     /// Sends a generation request with structured output.
@@ -68,6 +174,94 @@ impl Llm {
         Ok(serde_json::from_str(&text)?)
     }
 
+    /// Sends `request` to the provider, retrying under the retry policy.
+    pub(crate) async fn call(&self, request: LlmRequest) -> Result<LlmResponse, GenerationError> {
+        match &self.retry {
+            Some(policy) => {
+                policy
+                    .run(&self.model, || self.attempt(request.clone()))
+                    .await
+            }
+            None => self.attempt(request).await,
+        }
+    }
+
+    /// Starts a stream for `request`, retrying under the retry policy.
+    pub(crate) async fn call_stream(
+        &self,
+        request: LlmRequest,
+    ) -> Result<LlmStream, GenerationError> {
+        match &self.retry {
+            Some(policy) => {
+                policy
+                    .run(&self.model, || self.attempt_stream(request.clone()))
+                    .await
+            }
+            None => self.attempt_stream(request).await,
+        }
+    }
+
+    /// Sends `request` to the provider once rate limits allow.
+    ///
+    /// The reserved tokens are refunded if the request fails.
+    async fn attempt(&self, request: LlmRequest) -> Result<LlmResponse, GenerationError> {
+        let mut permits = self.acquire(&request).await;
+        let response = match self.provider.generate(&self.model, request).await {
+            Ok(response) => response,
+            Err(err) => {
+                permits.iter_mut().for_each(RateLimitPermit::refund);
+                return Err(err);
+            }
+        };
+        for permit in &mut permits {
+            permit.reconcile(&response.usage);
+        }
+        self.observe(&response.usage);
+        Ok(response)
+    }
+
+    /// Starts a stream for `request` once rate limits allow.
+    ///
+    /// The stream keeps its in-flight slots until it is dropped. The reserved
+    /// tokens are refunded if the stream cannot be started.
+    async fn attempt_stream(&self, request: LlmRequest) -> Result<LlmStream, GenerationError> {
+        let mut permits = self.acquire(&request).await;
+        let stream = match self.provider.generate_stream(&self.model, request).await {
+            Ok(stream) => stream,
+            Err(err) => {
+                permits.iter_mut().for_each(RateLimitPermit::refund);
+                return Err(err);
+            }
+        };
+        if permits.is_empty() && self.observers.is_empty() {
+            return Ok(stream);
+        }
+        let llm = self.clone();
+        Ok(Box::pin(stream.inspect(move |event| {
+            if let Ok(StreamEvent::Usage(usage)) = event {
+                for permit in &mut permits {
+                    permit.reconcile(usage);
+                }
+                llm.observe(usage);
+            }
+        })))
+    }
+
+    /// Waits for every rate limiter to admit `request`.
+    async fn acquire(&self, request: &LlmRequest) -> Vec<RateLimitPermit> {
+        if self.limiters.is_empty() {
+            return Vec::new();
+        }
+        rate_limit::acquire(&self.limiters, request.estimated_input_tokens()).await
+    }
+
+    /// Reports `usage` to every observer.
+    fn observe(&self, usage: &Usage) {
+        for observer in &self.observers {
+            observer.record(&self.model_id, usage);
+        }
+    }
+
     /// Creates a builder for a single-shot LLM request.
     ///
     /// The builder accumulates messages, tool definitions, and options,
@@ -0,0 +1,145 @@
+//! Hooks observing the requests sent through [`Llm`](super::Llm) handles.
+
+use super::error::GenerationError;
+use super::types::Usage;
+use std::sync::Arc;
+
+/// Observes the requests sent through [`Llm`](super::Llm) handles, for
+/// accounting and budgets.
+///
+/// Registered with
+/// [`ModelRegistry::add_usage_observer`](crate::ModelRegistry::add_usage_observer);
+/// every handle created by the registry afterwards reports to it. Calls
+/// replayed from an execution trace never reach the provider and are not
+/// reported.
+///
+/// Observers are called on the task making the request, so they can read
+/// task-scoped context such as
+/// [`current_system`](polaris_graph::executor::current_system) to attribute
+/// usage.
+///
+/// # Example
+///
+/// ```
+/// # use polaris_models::ModelRegistry;
+/// # use polaris_models::llm::{Usage, UsageObserver};
+/// # use std::sync::Arc;
+/// # use std::sync::atomic::{AtomicU64, Ordering};
+/// #[derive(Default)]
+/// struct TokenCounter(AtomicU64);
+///
+/// impl UsageObserver for TokenCounter {
+///     fn record(&self, _model_id: &str, usage: &Usage) {
+///         let tokens = usage.input_tokens.unwrap_or(0) + usage.output_tokens.unwrap_or(0);
+///         self.0.fetch_add(tokens, Ordering::Relaxed);
+///     }
+/// }
+///
+/// # fn example(registry: &mut ModelRegistry) {
+/// registry.add_usage_observer(Arc::new(TokenCounter::default()));
+/// # }
+/// ```
+pub trait UsageObserver: Send + Sync + 'static {
+    /// Called before a request to `model_id` (`"provider/model"`) is sent,
+    /// once per request rather than per retry.
+    ///
+    /// Returning an error fails the request with
+    /// [`GenerationError::Rejected`] without contacting the provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason the request is rejected.
+    fn admit(&self, model_id: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let _ = model_id;
+        Ok(())
+    }
+
+    /// Called with the usage the provider reported for a request to
+    /// `model_id`.
+    ///
+    /// Only successful requests are reported. Streams report once their
+    /// usage event is read.
+    fn record(&self, model_id: &str, usage: &Usage);
+}
+
+/// Asks every observer to admit a request to `model_id`.
+pub(crate) fn admit(
+    observers: &[Arc<dyn UsageObserver>],
+    model_id: &str,
+) -> Result<(), GenerationError> {
+    for observer in observers {
+        observer
+            .admit(model_id)
+            .map_err(GenerationError::Rejected)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelRegistry;
+    use crate::llm::{AssistantBlock, LlmProvider, LlmRequest, LlmResponse, TextBlock};
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+
+    struct Echo;
+
+    #[async_trait]
+    impl LlmProvider for Echo {
+        async fn generate(
+            &self,
+            _model: &str,
+            _request: LlmRequest,
+        ) -> Result<LlmResponse, GenerationError> {
+            Ok(LlmResponse {
+                content: vec![AssistantBlock::Text(TextBlock::new("ok"))],
+                usage: Usage {
+                    output_tokens: Some(3),
+                    ..Usage::default()
+                },
+                stop_reason: None,
+            })
+        }
+    }
+
+    /// Records reported usage and rejects requests once `limit` is reached.
+    struct Meter {
+        limit: usize,
+        seen: Mutex<Vec<(String, Usage)>>,
+    }
+
+    impl UsageObserver for Meter {
+        fn admit(&self, _model_id: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            if self.seen.lock().len() >= self.limit {
+                return Err("limit reached".into());
+            }
+            Ok(())
+        }
+
+        fn record(&self, model_id: &str, usage: &Usage) {
+            self.seen.lock().push((model_id.to_owned(), usage.clone()));
+        }
+    }
+
+    #[tokio::test]
+    async fn observers_see_usage_and_can_reject_requests() {
+        let meter = Arc::new(Meter {
+            limit: 1,
+            seen: Mutex::new(Vec::new()),
+        });
+        let mut registry = ModelRegistry::new();
+        registry.register_llm_provider("mock", Arc::new(Echo));
+        registry.add_usage_observer(meter.clone());
+        let llm = registry.llm("mock/model").unwrap();
+
+        llm.generate(LlmRequest::default()).await.unwrap();
+        let err = llm.generate(LlmRequest::default()).await.unwrap_err();
+
+        assert!(matches!(err, GenerationError::Rejected(_)), "{err}");
+        let seen = meter.seen.lock();
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].0, "mock/model");
+        assert_eq!(seen[0].1.output_tokens, Some(3));
+    }
+}
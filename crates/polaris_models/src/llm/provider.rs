@@ -1,12 +1,17 @@
 //! The [`LlmProvider`] trait for LLM model providers.
 
 use super::error::GenerationError;
+use super::stream::{LlmStream, response_events};
 use super::types::{LlmRequest, LlmResponse};
 use async_trait::async_trait;
 
 /// Trait implemented by LLM providers for text generation.
 ///
 /// Provider plugins implement this trait to handle generation requests.
+///
+/// Only [`generate`](Self::generate) is required. Providers with native
+/// streaming support should also override [`generate_stream`](Self::generate_stream);
+/// otherwise it falls back to a single non-streaming call.
 #[async_trait]
 pub trait LlmProvider: Send + Sync + 'static {
     /// Sends a generation request to the provider.
@@ -20,4 +25,23 @@ pub trait LlmProvider: Send + Sync + 'static {
         model: &str,
         request: LlmRequest,
     ) -> Result<LlmResponse, GenerationError>;
+
+    /// Sends a generation request and streams the response incrementally.
+    ///
+    /// The default implementation calls [`generate`](Self::generate) and
+    /// replays the complete response as a sequence of [`StreamEvent`](super::StreamEvent)s.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model name on which to perform generation
+    /// * `request` - The generation request
+    async fn generate_stream(
+        &self,
+        model: &str,
+        request: LlmRequest,
+    ) -> Result<LlmStream, GenerationError> {
+        let response = self.generate(model, request).await?;
+        let events = response_events(response).into_iter().map(Ok);
+        Ok(Box::pin(futures::stream::iter(events)))
+    }
 }
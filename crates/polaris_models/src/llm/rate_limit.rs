@@ -0,0 +1,575 @@
+//! Client-side rate limits for generation requests.
+
+use super::types::Usage;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+/// Client-side limits on the requests sent to a provider or model.
+///
+/// Set with [`ModelRegistry::set_rate_limit`](crate::ModelRegistry::set_rate_limit).
+/// Requests per minute and tokens per minute are enforced with token buckets
+/// that refill continuously, so a full minute's quota is available as a
+/// burst. A request reserves its
+/// [estimated input tokens](super::LlmRequest::estimated_input_tokens) before
+/// it is sent; once the provider reports [`Usage`], the bucket is charged for
+/// the actual input and output tokens instead. If the request fails, the
+/// reserved tokens are returned to the bucket. Max in-flight caps the
+/// requests running at once, counting a stream as in flight until it is
+/// dropped.
+///
+/// A request over any limit waits until it can proceed. It first waits until
+/// the buckets of every limit that applies, its provider's and its model's,
+/// can admit it at once, and only then queues for in-flight slots, so that it
+/// does not hold a slot while waiting for quota. The wait is an ordinary
+/// `await`, so a graph timeout or cancellation that drops the system also
+/// drops the queued request without consuming quota.
+///
+/// Every limit must be greater than zero.
+///
+/// # Example
+///
+/// ```
+/// # use polaris_models::ModelRegistry;
+/// # use polaris_models::llm::RateLimit;
+/// # fn example(registry: &mut ModelRegistry) -> Result<(), Box<dyn std::error::Error>> {
+/// registry.set_rate_limit(
+///     "anthropic",
+///     RateLimit::new()
+///         .with_requests_per_minute(50)?
+///         .with_tokens_per_minute(40_000)?
+///         .with_max_in_flight(8)?,
+/// );
+/// registry.set_rate_limit(
+///     "anthropic/claude-opus-4-1",
+///     RateLimit::new().with_requests_per_minute(10)?,
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    requests_per_minute: Option<u32>,
+    tokens_per_minute: Option<u32>,
+    max_in_flight: Option<usize>,
+}
+
+impl RateLimit {
+    /// Creates a limit that allows everything until configured.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the requests started per minute.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RateLimitError::ZeroRequestsPerMinute`] if `requests` is 0.
+    pub fn with_requests_per_minute(mut self, requests: u32) -> Result<Self, RateLimitError> {
+        if requests == 0 {
+            return Err(RateLimitError::ZeroRequestsPerMinute);
+        }
+        self.requests_per_minute = Some(requests);
+        Ok(self)
+    }
+
+    /// Limits the input and output tokens used per minute.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RateLimitError::ZeroTokensPerMinute`] if `tokens` is 0.
+    pub fn with_tokens_per_minute(mut self, tokens: u32) -> Result<Self, RateLimitError> {
+        if tokens == 0 {
+            return Err(RateLimitError::ZeroTokensPerMinute);
+        }
+        self.tokens_per_minute = Some(tokens);
+        Ok(self)
+    }
+
+    /// Limits the requests running at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RateLimitError::ZeroMaxInFlight`] if `requests` is 0.
+    pub fn with_max_in_flight(mut self, requests: usize) -> Result<Self, RateLimitError> {
+        if requests == 0 {
+            return Err(RateLimitError::ZeroMaxInFlight);
+        }
+        self.max_in_flight = Some(requests);
+        Ok(self)
+    }
+
+    /// Returns the requests-per-minute limit, if any.
+    #[must_use]
+    pub fn requests_per_minute(&self) -> Option<u32> {
+        self.requests_per_minute
+    }
+
+    /// Returns the tokens-per-minute limit, if any.
+    #[must_use]
+    pub fn tokens_per_minute(&self) -> Option<u32> {
+        self.tokens_per_minute
+    }
+
+    /// Returns the in-flight limit, if any.
+    #[must_use]
+    pub fn max_in_flight(&self) -> Option<usize> {
+        self.max_in_flight
+    }
+}
+
+/// Error configuring a [`RateLimit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum RateLimitError {
+    /// A requests-per-minute limit of 0 would never admit a request.
+    #[error("requests per minute must be greater than zero")]
+    ZeroRequestsPerMinute,
+    /// A tokens-per-minute limit of 0 would never admit a request.
+    #[error("tokens per minute must be greater than zero")]
+    ZeroTokensPerMinute,
+    /// An in-flight limit of 0 would never admit a request.
+    #[error("max in flight must be greater than zero")]
+    ZeroMaxInFlight,
+}
+
+/// A token bucket holding up to a minute's quota.
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    available: f64,
+    refilled_at: Instant,
+}
+
+impl Bucket {
+    fn new(per_minute: u32) -> Self {
+        Self {
+            capacity: f64::from(per_minute),
+            available: f64::from(per_minute),
+            refilled_at: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        self.available = (self.available + elapsed * self.capacity / 60.0).min(self.capacity);
+        self.refilled_at = now;
+    }
+
+    /// Returns how long until `amount` is available, capping `amount` at the
+    /// capacity so that oversized requests still run on a full bucket.
+    fn wait_for(&self, amount: f64) -> Duration {
+        let missing = amount.min(self.capacity) - self.available;
+        if missing <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64(missing * 60.0 / self.capacity)
+    }
+
+    /// Returns `amount` to the bucket.
+    fn credit(&mut self, amount: f64) {
+        self.available = (self.available + amount).min(self.capacity);
+    }
+}
+
+/// Buckets of a limiter, locked together so a request takes from both at
+/// once.
+#[derive(Debug)]
+struct Buckets {
+    requests: Option<Bucket>,
+    tokens: Option<Bucket>,
+}
+
+impl Buckets {
+    /// Returns how long until a request reserving `tokens` can be admitted.
+    fn wait_for(&mut self, now: Instant, tokens: f64) -> Duration {
+        let mut wait = Duration::ZERO;
+        if let Some(bucket) = &mut self.requests {
+            bucket.refill(now);
+            wait = wait.max(bucket.wait_for(1.0));
+        }
+        if let Some(bucket) = &mut self.tokens {
+            bucket.refill(now);
+            wait = wait.max(bucket.wait_for(tokens));
+        }
+        wait
+    }
+
+    /// Takes a request reserving `tokens` from the buckets.
+    fn take(&mut self, tokens: f64) {
+        if let Some(bucket) = &mut self.requests {
+            bucket.available -= 1.0;
+        }
+        if let Some(bucket) = &mut self.tokens {
+            bucket.available -= tokens;
+        }
+    }
+}
+
+/// Enforces a [`RateLimit`] shared by every handle it applies to.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    scope: String,
+    buckets: Mutex<Buckets>,
+    in_flight: Option<Arc<Semaphore>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(scope: String, limit: RateLimit) -> Self {
+        Self {
+            scope,
+            buckets: Mutex::new(Buckets {
+                requests: limit.requests_per_minute.map(Bucket::new),
+                tokens: limit.tokens_per_minute.map(Bucket::new),
+            }),
+            in_flight: limit
+                .max_in_flight
+                .map(|requests| Arc::new(Semaphore::new(requests))),
+        }
+    }
+
+    /// Charges the token bucket for `actual` tokens in place of `reserved`.
+    fn reconcile(&self, reserved: u64, actual: u64) {
+        let mut buckets = self.buckets.lock();
+        if let Some(bucket) = &mut buckets.tokens {
+            let delta = actual as f64 - reserved as f64;
+            // Overspending leaves the bucket in debt, delaying later requests.
+            bucket.available = (bucket.available - delta).min(bucket.capacity);
+        }
+    }
+
+    /// Returns a request slot, if `request` is set, and `tokens` to the
+    /// buckets.
+    fn credit(&self, request: bool, tokens: u64) {
+        let mut buckets = self.buckets.lock();
+        if request && let Some(bucket) = &mut buckets.requests {
+            bucket.credit(1.0);
+        }
+        if let Some(bucket) = &mut buckets.tokens {
+            bucket.credit(tokens as f64);
+        }
+    }
+}
+
+/// Waits until every limiter in `limiters` admits a request reserving
+/// `tokens`.
+///
+/// The buckets of all limiters are charged together once each can admit the
+/// request, and in-flight slots are taken only afterwards. Limiters are
+/// locked in the order given, which is always provider before model.
+pub(crate) async fn acquire(limiters: &[Arc<RateLimiter>], tokens: u64) -> Vec<RateLimitPermit> {
+    let amount = tokens as f64;
+    loop {
+        let (wait, scope) = {
+            let mut buckets: Vec<_> = limiters
+                .iter()
+                .map(|limiter| limiter.buckets.lock())
+                .collect();
+            let now = Instant::now();
+            let mut wait = Duration::ZERO;
+            let mut scope = None;
+            for (limiter, buckets) in limiters.iter().zip(&mut buckets) {
+                let limiter_wait = buckets.wait_for(now, amount);
+                if limiter_wait > wait {
+                    wait = limiter_wait;
+                    scope = Some(&limiter.scope);
+                }
+            }
+            if wait.is_zero() {
+                for buckets in &mut buckets {
+                    buckets.take(amount);
+                }
+            }
+            (wait, scope)
+        };
+        let Some(scope) = scope else {
+            break;
+        };
+        tracing::debug!(
+            scope = %scope,
+            wait_ms = u64::try_from(wait.as_millis()).unwrap_or(u64::MAX),
+            "LLM request waiting for rate limit"
+        );
+        tokio::time::sleep(wait).await;
+    }
+
+    // Until every slot is taken, dropping the permits returns their quota.
+    let mut permits: Vec<RateLimitPermit> = limiters
+        .iter()
+        .map(|limiter| RateLimitPermit {
+            limiter: Arc::clone(limiter),
+            reserved: tokens,
+            admitted: false,
+            _in_flight: None,
+        })
+        .collect();
+    for permit in &mut permits {
+        if let Some(semaphore) = &permit.limiter.in_flight {
+            permit._in_flight = Some(
+                Arc::clone(semaphore)
+                    .acquire_owned()
+                    .await
+                    .expect("rate limit semaphore is never closed"),
+            );
+        }
+    }
+    for permit in &mut permits {
+        permit.admitted = true;
+    }
+    permits
+}
+
+/// Admission of one request through a [`RateLimiter`].
+///
+/// Holds the in-flight slot until dropped.
+#[derive(Debug)]
+pub(crate) struct RateLimitPermit {
+    limiter: Arc<RateLimiter>,
+    reserved: u64,
+    /// Whether the request was admitted; a permit dropped before then
+    /// returns its request slot and tokens.
+    admitted: bool,
+    _in_flight: Option<OwnedSemaphorePermit>,
+}
+
+impl RateLimitPermit {
+    /// Replaces the token reservation with the usage the provider reported.
+    pub(crate) fn reconcile(&mut self, usage: &Usage) {
+        let actual = usage
+            .total_tokens
+            .or(match (usage.input_tokens, usage.output_tokens) {
+                (None, None) => None,
+                (input, output) => Some(input.unwrap_or(0) + output.unwrap_or(0)),
+            });
+        if let Some(actual) = actual {
+            self.limiter.reconcile(self.reserved, actual);
+            self.reserved = actual;
+        }
+    }
+
+    /// Returns the reserved tokens of a request that failed.
+    pub(crate) fn refund(&mut self) {
+        self.limiter.credit(false, self.reserved);
+        self.reserved = 0;
+    }
+}
+
+impl Drop for RateLimitPermit {
+    fn drop(&mut self) {
+        if !self.admitted {
+            self.limiter.credit(true, self.reserved);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ModelRegistry;
+    use crate::llm::{
+        AssistantBlock, GenerationError, Llm, LlmProvider, LlmRequest, LlmResponse, Message,
+        TextBlock,
+    };
+    use async_trait::async_trait;
+
+    /// Answers after `delay`, reporting `tokens` of usage.
+    struct Slow {
+        delay: Duration,
+        tokens: u64,
+    }
+
+    /// Fails every request.
+    struct Failing;
+
+    #[async_trait]
+    impl LlmProvider for Failing {
+        async fn generate(
+            &self,
+            _model: &str,
+            _request: LlmRequest,
+        ) -> Result<LlmResponse, GenerationError> {
+            Err(GenerationError::Http("connection reset".into()))
+        }
+    }
+
+    #[async_trait]
+    impl LlmProvider for Slow {
+        async fn generate(
+            &self,
+            _model: &str,
+            _request: LlmRequest,
+        ) -> Result<LlmResponse, GenerationError> {
+            tokio::time::sleep(self.delay).await;
+            Ok(LlmResponse {
+                content: vec![AssistantBlock::Text(TextBlock::new("ok"))],
+                usage: Usage {
+                    total_tokens: Some(self.tokens),
+                    ..Usage::default()
+                },
+                stop_reason: None,
+            })
+        }
+    }
+
+    fn llm(provider: Slow, scope: &str, limit: RateLimit) -> Llm {
+        let mut registry = ModelRegistry::new();
+        registry.register_llm_provider("mock", Arc::new(provider));
+        registry.set_rate_limit(scope, limit);
+        registry.llm("mock/model").unwrap()
+    }
+
+    fn request() -> LlmRequest {
+        LlmRequest {
+            messages: vec![Message::user("0123456789abcdef")],
+            ..LlmRequest::default()
+        }
+    }
+
+    #[test]
+    fn estimates_input_tokens() {
+        assert_eq!(request().estimated_input_tokens(), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn requests_per_minute_delays_excess_requests() {
+        let provider = Slow {
+            delay: Duration::ZERO,
+            tokens: 1,
+        };
+        let llm = llm(
+            provider,
+            "mock",
+            RateLimit::new().with_requests_per_minute(2).unwrap(),
+        );
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            llm.generate(request()).await.unwrap();
+        }
+        assert_eq!(start.elapsed(), Duration::from_secs(30));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tokens_per_minute_reconciles_reported_usage() {
+        let provider = Slow {
+            delay: Duration::ZERO,
+            tokens: 600,
+        };
+        let llm = llm(
+            provider,
+            "mock/model",
+            RateLimit::new().with_tokens_per_minute(1_000).unwrap(),
+        );
+
+        let start = Instant::now();
+        llm.generate(request()).await.unwrap();
+        llm.generate(request()).await.unwrap();
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        // 1,200 tokens were used, so the bucket owes 200 plus the estimate.
+        llm.generate(request()).await.unwrap();
+        assert_eq!(start.elapsed(), Duration::from_millis(12_240));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn max_in_flight_queues_concurrent_requests() {
+        let provider = Slow {
+            delay: Duration::from_secs(10),
+            tokens: 1,
+        };
+        let llm = llm(
+            provider,
+            "mock",
+            RateLimit::new().with_max_in_flight(1).unwrap(),
+        );
+
+        let start = Instant::now();
+        let (first, second) = tokio::join!(llm.generate(request()), llm.generate(request()));
+        first.unwrap();
+        second.unwrap();
+        assert_eq!(start.elapsed(), Duration::from_secs(20));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn dropped_queued_request_releases_its_place() {
+        let provider = Slow {
+            delay: Duration::ZERO,
+            tokens: 1,
+        };
+        let llm = llm(
+            provider,
+            "mock",
+            RateLimit::new().with_requests_per_minute(1).unwrap(),
+        );
+        llm.generate(request()).await.unwrap();
+
+        // A timeout drops the queued request before it is admitted.
+        let queued = tokio::time::timeout(Duration::from_secs(5), llm.generate(request())).await;
+        assert!(queued.is_err());
+
+        let start = Instant::now();
+        llm.generate(request()).await.unwrap();
+        assert_eq!(start.elapsed(), Duration::from_secs(55));
+    }
+
+    #[test]
+    fn zero_limits_are_rejected() {
+        assert_eq!(
+            RateLimit::new().with_requests_per_minute(0),
+            Err(RateLimitError::ZeroRequestsPerMinute)
+        );
+        assert_eq!(
+            RateLimit::new().with_tokens_per_minute(0),
+            Err(RateLimitError::ZeroTokensPerMinute)
+        );
+        assert_eq!(
+            RateLimit::new().with_max_in_flight(0),
+            Err(RateLimitError::ZeroMaxInFlight)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_request_refunds_reserved_tokens() {
+        let mut registry = ModelRegistry::new();
+        registry.register_llm_provider("mock", Arc::new(Failing));
+        registry.set_rate_limit("mock", RateLimit::new().with_tokens_per_minute(4).unwrap());
+        let llm = registry.llm("mock/model").unwrap();
+
+        let start = Instant::now();
+        llm.generate(request()).await.unwrap_err();
+        llm.generate(request()).await.unwrap_err();
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_for_quota_before_taking_in_flight_slot() {
+        let mut registry = ModelRegistry::new();
+        registry.register_llm_provider(
+            "mock",
+            Arc::new(Slow {
+                delay: Duration::from_secs(10),
+                tokens: 1,
+            }),
+        );
+        registry.set_rate_limit("mock", RateLimit::new().with_max_in_flight(1).unwrap());
+        registry.set_rate_limit(
+            "mock/limited",
+            RateLimit::new().with_requests_per_minute(1).unwrap(),
+        );
+        let limited = registry.llm("mock/limited").unwrap();
+        let other = registry.llm("mock/other").unwrap();
+        limited.generate(request()).await.unwrap();
+
+        // The limited request waits for its model's quota without holding the
+        // provider's only in-flight slot.
+        let start = Instant::now();
+        let (queued, elapsed) = tokio::join!(limited.generate(request()), async {
+            other.generate(request()).await.unwrap();
+            start.elapsed()
+        });
+        queued.unwrap();
+        assert_eq!(elapsed, Duration::from_secs(10));
+    }
+}
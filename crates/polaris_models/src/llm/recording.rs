@@ -0,0 +1,194 @@
+//! Recording and replaying LLM calls in graph execution traces.
+//!
+//! Calls made while a graph run records a trace are stored as
+//! [`RecordedCall`]s of kind [`CALL_KIND`]. Calls made while a run replays a
+//! trace are served from it instead of reaching the provider. See
+//! [`polaris_graph::trace`].
+
+use super::error::GenerationError;
+use super::stream::{LlmStream, StreamAccumulator, response_events};
+use super::types::{LlmRequest, LlmResponse};
+use futures::StreamExt;
+use polaris_graph::trace::{self, RecordedCall, TraceError};
+
+/// Kind of the [`RecordedCall`]s made by [`Llm`](super::Llm).
+pub(crate) const CALL_KIND: &str = "llm";
+
+/// Takes the recorded call to `model` made with `request` if the current run
+/// is a replay.
+pub(crate) fn replayed(
+    model: &str,
+    request: &LlmRequest,
+) -> Option<Result<LlmResponse, GenerationError>> {
+    if !trace::is_replaying() {
+        return None;
+    }
+    let request = serde_json::to_value(request).unwrap_or_default();
+    trace::replay_call(CALL_KIND, model, &request).map(response_of)
+}
+
+/// Takes the recorded call to `model` made with `request` as a stream if the
+/// current run is a replay.
+pub(crate) fn replayed_stream(
+    model: &str,
+    request: &LlmRequest,
+) -> Option<Result<LlmStream, GenerationError>> {
+    replayed(model, request).map(|response| {
+        let events = response_events(response?).into_iter().map(Ok);
+        Ok(Box::pin(futures::stream::iter(events)) as LlmStream)
+    })
+}
+
+/// Returns the serialized request if the current run records a trace.
+pub(crate) fn recorded_request(request: &LlmRequest) -> Option<serde_json::Value> {
+    trace::is_recording().then(|| serde_json::to_value(request).unwrap_or_default())
+}
+
+/// Records a call to `model` made with the serialized `request`.
+pub(crate) fn record(
+    model: &str,
+    request: serde_json::Value,
+    response: Result<&LlmResponse, &GenerationError>,
+) {
+    trace::record_call(RecordedCall {
+        kind: CALL_KIND.to_owned(),
+        target: model.to_owned(),
+        request,
+        response: response
+            .map(|response| serde_json::to_value(response).unwrap_or_default())
+            .map_err(ToString::to_string),
+    });
+}
+
+/// Wraps `stream` to record the call once the stream is exhausted.
+///
+/// The call is recorded with the reassembled response, or with the first
+/// error the stream yields. Streams dropped before they end are not
+/// recorded.
+pub(crate) fn recording_stream(
+    model: &str,
+    request: serde_json::Value,
+    stream: LlmStream,
+) -> LlmStream {
+    let state = (
+        stream,
+        Some((model.to_owned(), request, StreamAccumulator::new())),
+    );
+    Box::pin(futures::stream::unfold(
+        state,
+        |(mut stream, mut pending)| async move {
+            let item = stream.next().await;
+            match (&item, pending.take()) {
+                (Some(Ok(event)), Some((model, request, mut accumulator))) => {
+                    match accumulator.push(event.clone()) {
+                        Ok(()) => pending = Some((model, request, accumulator)),
+                        Err(err) => record(&model, request, Err(&err)),
+                    }
+                }
+                (Some(Err(err)), Some((model, request, _))) => record(&model, request, Err(err)),
+                (None, Some((model, request, accumulator))) => {
+                    record(&model, request, accumulator.finish().as_ref());
+                }
+                (_, None) => {}
+            }
+            item.map(|item| (item, (stream, pending)))
+        },
+    ))
+}
+
+/// Converts a replayed call into the response it recorded.
+fn response_of(call: Result<RecordedCall, TraceError>) -> Result<LlmResponse, GenerationError> {
+    let call = call.map_err(|err| GenerationError::Replay(err.to_string()))?;
+    match call.response {
+        Ok(response) => Ok(serde_json::from_value(response)?),
+        Err(message) => Err(GenerationError::Replay(message)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ModelRegistry;
+    use crate::llm::{
+        AssistantBlock, Llm, LlmProvider, LlmRequest, LlmResponse, Message, TextBlock,
+    };
+    use crate::llm::{GenerationError, Usage};
+    use async_trait::async_trait;
+    use polaris_graph::trace::{Replay, TraceRecorder};
+    use polaris_graph::{ExecutionError, Graph, GraphExecutor, RunOptions};
+    use polaris_system::param::{Res, SystemContext};
+    use polaris_system::resource::LocalResource;
+    use polaris_system::system;
+    use polaris_system::system::SystemError;
+    use std::sync::Arc;
+
+    /// Answers with the text of the last message.
+    struct Echo;
+
+    #[async_trait]
+    impl LlmProvider for Echo {
+        async fn generate(
+            &self,
+            _model: &str,
+            request: LlmRequest,
+        ) -> Result<LlmResponse, GenerationError> {
+            let question = serde_json::to_string(&request.messages).unwrap();
+            Ok(LlmResponse {
+                content: vec![AssistantBlock::Text(TextBlock::new(question))],
+                usage: Usage::default(),
+                stop_reason: None,
+            })
+        }
+    }
+
+    /// The model and question asked by `ask`.
+    struct Question(Llm, &'static str);
+
+    impl LocalResource for Question {}
+
+    #[system]
+    async fn ask(question: Res<Question>) -> Result<String, SystemError> {
+        let request = LlmRequest {
+            messages: vec![Message::user(question.1)],
+            ..LlmRequest::default()
+        };
+        question
+            .0
+            .generate(request)
+            .await
+            .map(|response| response.text())
+            .map_err(|err| SystemError::ExecutionError(err.to_string()))
+    }
+
+    async fn run(text: &'static str, options: RunOptions) -> Result<String, ExecutionError> {
+        let mut registry = ModelRegistry::new();
+        registry.register_llm_provider("echo", Arc::new(Echo));
+        let mut graph = Graph::new();
+        graph.add_system(ask);
+        let mut ctx = SystemContext::new();
+        ctx.insert(Question(registry.llm("echo/model").unwrap(), text));
+        GraphExecutor::new()
+            .execute_with(&graph, &mut ctx, None, options)
+            .await?;
+        Ok(ctx.get_output::<String>().unwrap().clone())
+    }
+
+    #[tokio::test]
+    async fn replayed_calls_match_the_recorded_request() {
+        let recorder = TraceRecorder::new();
+        let recorded = run("hello", RunOptions::new().with_recorder(recorder.clone()))
+            .await
+            .unwrap();
+        let trace = recorder.take();
+        assert_eq!(trace.calls(super::CALL_KIND).count(), 1);
+
+        let options = RunOptions::new().with_replay(Replay::new(trace.clone()));
+        assert_eq!(run("hello", options).await.unwrap(), recorded);
+
+        let options = RunOptions::new().with_replay(Replay::new(trace));
+        let result = run("goodbye", options).await;
+        assert!(
+            matches!(&result, Err(ExecutionError::SystemError(message)) if message.contains("not recorded")),
+            "expected divergence, got {result:?}"
+        );
+    }
+}
@@ -0,0 +1,338 @@
+//! Automatic retries for transient generation failures.
+
+use super::error::{ErrorClass, GenerationError};
+use std::future::Future;
+use std::time::Duration;
+
+/// Error classes that are never retried, whatever the policy says.
+const NEVER_RETRIED: [ErrorClass; 3] = [
+    ErrorClass::Auth,
+    ErrorClass::InvalidRequest,
+    ErrorClass::Refusal,
+];
+
+/// Error classes retried by default.
+const DEFAULT_RETRY_ON: [ErrorClass; 3] = [
+    ErrorClass::Transport,
+    ErrorClass::RateLimited,
+    ErrorClass::Server,
+];
+
+/// Retry policy for generation requests made through an [`Llm`](super::Llm).
+///
+/// Failed requests whose [error class](GenerationError::class) is retryable
+/// are sent again, up to [`max_retries`](Self::max_retries) times. By default
+/// transport errors, rate limits and server errors (including overload
+/// responses) are retried. [`Auth`](ErrorClass::Auth),
+/// [`InvalidRequest`](ErrorClass::InvalidRequest) and
+/// [`Refusal`](ErrorClass::Refusal) errors are never retried.
+///
+/// When a rate-limited response carries a retry-after hint, the policy waits
+/// exactly that long; if the hint exceeds [`with_max_delay`](Self::with_max_delay),
+/// the error is returned instead. Otherwise the delay grows exponentially from
+/// the initial delay, capped at the maximum delay, and with jitter enabled is
+/// drawn uniformly between half and all of it.
+///
+/// Each retry emits a `tracing` warning with the model, attempt number, error
+/// and delay.
+///
+/// # Example
+///
+/// ```
+/// # use polaris_models::llm::{Llm, RetryPolicy};
+/// # use std::time::Duration;
+/// # fn example(llm: Llm) -> Llm {
+/// llm.with_retry(
+///     RetryPolicy::new(5)
+///         .with_initial_delay(Duration::from_millis(200))
+///         .with_max_delay(Duration::from_secs(10)),
+/// )
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retry_on: Vec<ErrorClass>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_on: DEFAULT_RETRY_ON.to_vec(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates the default policy with `max_retries` retries after the first
+    /// attempt.
+    #[must_use]
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the delay before the first retry. Defaults to 500ms.
+    #[must_use]
+    pub fn with_initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// Sets the longest delay between attempts, which also bounds the
+    /// retry-after hints that are honored. Defaults to 30 seconds.
+    #[must_use]
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enables or disables jitter on backoff delays. Enabled by default.
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the error classes that are retried, replacing the defaults.
+    ///
+    /// [`Auth`](ErrorClass::Auth), [`InvalidRequest`](ErrorClass::InvalidRequest)
+    /// and [`Refusal`](ErrorClass::Refusal) are ignored.
+    #[must_use]
+    pub fn with_retry_on(mut self, classes: impl IntoIterator<Item = ErrorClass>) -> Self {
+        self.retry_on = classes
+            .into_iter()
+            .filter(|class| !NEVER_RETRIED.contains(class))
+            .collect();
+        self
+    }
+
+    /// Returns the maximum number of retries after the first attempt.
+    #[must_use]
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns how long to wait before retry number `retry` (0-indexed) of a
+    /// request that failed with `err`, or `None` if it should not be retried.
+    #[must_use]
+    pub fn delay_for(&self, retry: u32, err: &GenerationError) -> Option<Duration> {
+        if retry >= self.max_retries || !self.retry_on.contains(&err.class()) {
+            return None;
+        }
+        if let GenerationError::RateLimited {
+            retry_after: Some(retry_after),
+        } = err
+        {
+            return (*retry_after <= self.max_delay).then_some(*retry_after);
+        }
+
+        let backoff = 2u32
+            .checked_pow(retry)
+            .map_or(self.max_delay, |factor| {
+                self.initial_delay.saturating_mul(factor)
+            })
+            .min(self.max_delay);
+        if !self.jitter {
+            return Some(backoff);
+        }
+        let half = backoff / 2;
+        Some(half + (backoff - half).mul_f64(fastrand::f64()))
+    }
+
+    /// Runs `call` until it succeeds, fails with an error that is not
+    /// retried, or runs out of retries.
+    pub(crate) async fn run<T, F, Fut>(
+        &self,
+        model: &str,
+        mut call: F,
+    ) -> Result<T, GenerationError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, GenerationError>>,
+    {
+        let mut retry = 0;
+        loop {
+            let err = match call().await {
+                Ok(value) => return Ok(value),
+                Err(err) => err,
+            };
+            let Some(delay) = self.delay_for(retry, &err) else {
+                if retry > 0 {
+                    tracing::warn!(
+                        model,
+                        attempts = retry + 1,
+                        error = %err,
+                        "LLM generation failed after retries"
+                    );
+                }
+                return Err(err);
+            };
+            retry += 1;
+            tracing::warn!(
+                model,
+                attempt = retry,
+                error = %err,
+                delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+                "retrying LLM generation"
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::{AssistantBlock, Llm, LlmProvider, LlmRequest, LlmResponse, TextBlock, Usage};
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+    use std::collections::VecDeque;
+    use std::sync::Arc;
+
+    /// Fails with the queued errors, then answers.
+    struct Flaky {
+        errors: Mutex<VecDeque<GenerationError>>,
+        calls: Mutex<u32>,
+    }
+
+    impl Flaky {
+        fn new(errors: impl IntoIterator<Item = GenerationError>) -> Arc<Self> {
+            Arc::new(Self {
+                errors: Mutex::new(errors.into_iter().collect()),
+                calls: Mutex::new(0),
+            })
+        }
+
+        fn calls(&self) -> u32 {
+            *self.calls.lock()
+        }
+    }
+
+    #[async_trait]
+    impl LlmProvider for Flaky {
+        async fn generate(
+            &self,
+            _model: &str,
+            _request: LlmRequest,
+        ) -> Result<LlmResponse, GenerationError> {
+            *self.calls.lock() += 1;
+            if let Some(err) = self.errors.lock().pop_front() {
+                return Err(err);
+            }
+            Ok(LlmResponse {
+                content: vec![AssistantBlock::Text(TextBlock::new("ok"))],
+                usage: Usage::default(),
+                stop_reason: None,
+            })
+        }
+    }
+
+    fn overloaded() -> GenerationError {
+        GenerationError::Provider {
+            status: Some(529),
+            message: "overloaded".into(),
+            source: None,
+        }
+    }
+
+    fn llm(provider: Arc<Flaky>, policy: RetryPolicy) -> Llm {
+        Llm::new(provider, "model".into()).with_retry(policy)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_transient_errors_with_backoff() {
+        let provider = Flaky::new([GenerationError::Http("reset".into()), overloaded()]);
+        let policy = RetryPolicy::new(3)
+            .with_initial_delay(Duration::from_secs(1))
+            .with_jitter(false);
+        let llm = llm(provider.clone(), policy);
+
+        let start = tokio::time::Instant::now();
+        let response = llm.generate(LlmRequest::default()).await.unwrap();
+
+        assert_eq!(response.text(), "ok");
+        assert_eq!(provider.calls(), 3);
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn honors_retry_after_hint() {
+        let provider = Flaky::new([GenerationError::RateLimited {
+            retry_after: Some(Duration::from_secs(7)),
+        }]);
+        let llm = llm(provider.clone(), RetryPolicy::default());
+
+        let start = tokio::time::Instant::now();
+        llm.generate(LlmRequest::default()).await.unwrap();
+
+        assert_eq!(start.elapsed(), Duration::from_secs(7));
+        assert_eq!(provider.calls(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn gives_up_when_retry_after_exceeds_max_delay() {
+        let provider = Flaky::new([GenerationError::RateLimited {
+            retry_after: Some(Duration::from_secs(120)),
+        }]);
+        let llm = llm(provider.clone(), RetryPolicy::default());
+
+        let err = llm.generate(LlmRequest::default()).await.unwrap_err();
+        assert_eq!(err.class(), ErrorClass::RateLimited);
+        assert_eq!(provider.calls(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stops_after_max_retries() {
+        let provider = Flaky::new((0..5).map(|_| overloaded()));
+        let llm = llm(provider.clone(), RetryPolicy::new(2));
+
+        let err = llm.generate(LlmRequest::default()).await.unwrap_err();
+        assert_eq!(err.class(), ErrorClass::Server);
+        assert_eq!(provider.calls(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn never_retries_auth_invalid_request_or_refusal() {
+        let policy = RetryPolicy::new(3).with_retry_on([
+            ErrorClass::Auth,
+            ErrorClass::InvalidRequest,
+            ErrorClass::Refusal,
+            ErrorClass::Server,
+        ]);
+        for err in [
+            GenerationError::Auth("bad key".into()),
+            GenerationError::InvalidRequest("bad".into()),
+            GenerationError::Refusal("no".into()),
+        ] {
+            let provider = Flaky::new([err]);
+            let llm = llm(provider.clone(), policy.clone());
+
+            llm.generate(LlmRequest::default()).await.unwrap_err();
+            assert_eq!(provider.calls(), 1);
+        }
+    }
+
+    #[test]
+    fn backoff_is_capped_and_jittered() {
+        let policy = RetryPolicy::new(10)
+            .with_initial_delay(Duration::from_secs(1))
+            .with_max_delay(Duration::from_secs(8));
+
+        for retry in 0..10 {
+            let expected = Duration::from_secs(1 << retry.min(3));
+            let delay = policy.delay_for(retry, &overloaded()).unwrap();
+            assert!(delay >= expected / 2 && delay <= expected, "{delay:?}");
+        }
+        assert_eq!(policy.delay_for(10, &overloaded()), None);
+    }
+}
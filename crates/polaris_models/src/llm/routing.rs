@@ -0,0 +1,709 @@
+//! Composite provider that routes requests across several targets.
+//!
+//! A [`RoutingProvider`] is registered in the [`ModelRegistry`] like any
+//! other provider and forwards each request to one of its [`Target`]s. When a
+//! target fails with an error whose [`ErrorClass`] calls for failover, the
+//! request moves on to the next target. Each target has a circuit breaker
+//! that takes it out of rotation after repeated failures.
+//!
+//! Targets resolved from the [`ModelRegistry`] wait for the registry's rate
+//! limits. Retries are left to the handle the router is used through, which
+//! retries the whole failover sequence.
+
+use super::error::{ErrorClass, GenerationError};
+use super::model::Llm;
+use super::provider::LlmProvider;
+use super::stream::LlmStream;
+use super::types::{LlmRequest, LlmResponse};
+use crate::ModelRegistry;
+use crate::error::CreateModelError;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A provider and model that a [`RoutingProvider`] sends requests to.
+#[derive(Clone)]
+pub struct Target {
+    id: String,
+    llm: Llm,
+    weight: u32,
+}
+
+impl Target {
+    /// Creates a target for `model` on `provider`, identified as
+    /// `"{provider_name}/{model}"`.
+    ///
+    /// Requests are sent to `provider` directly, without retries or rate
+    /// limits. Use [`resolve`](Self::resolve) to apply the registry's.
+    #[must_use]
+    pub fn new(
+        provider_name: &str,
+        provider: Arc<dyn LlmProvider>,
+        model: impl Into<String>,
+    ) -> Self {
+        let model = model.into();
+        Self {
+            id: format!("{provider_name}/{model}"),
+            llm: Llm::new(provider, model),
+            weight: 1,
+        }
+    }
+
+    /// Creates a target from a `"provider/model"` identifier, looking the
+    /// provider up in `registry`.
+    ///
+    /// Requests to the target wait for the
+    /// [rate limits](ModelRegistry::set_rate_limit) of its provider and
+    /// model. They are not retried on the target: the
+    /// [retry policy](ModelRegistry::set_retry_policy) and usage observers
+    /// apply to the handle the routing provider is used through, so that a
+    /// request is neither retried nor counted twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `model_id` is malformed or names a provider that
+    /// is not registered.
+    pub fn resolve(registry: &ModelRegistry, model_id: &str) -> Result<Self, CreateModelError> {
+        let llm = registry
+            .llm(model_id)?
+            .without_retry()
+            .with_observers(model_id.to_owned(), Vec::new());
+        Ok(Self {
+            id: model_id.to_owned(),
+            llm,
+            weight: 1,
+        })
+    }
+
+    /// Sets the target's share of requests under [`Routing::Weighted`].
+    ///
+    /// Defaults to 1. A target with weight 0 is only used for failover.
+    #[must_use]
+    pub fn weight(mut self, weight: u32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Returns the target's `"provider/model"` identifier.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl std::fmt::Debug for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Target")
+            .field("id", &self.id)
+            .field("weight", &self.weight)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How a [`RoutingProvider`] picks the first target for a request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Routing {
+    /// Every request starts with the first available target in order.
+    #[default]
+    Priority,
+    /// Requests are spread across targets in proportion to their
+    /// [weights](Target::weight). Failover continues with the remaining
+    /// targets in order.
+    Weighted,
+}
+
+/// Circuit breaker settings applied to each target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreaker {
+    /// Consecutive failures after which the circuit opens.
+    pub failure_threshold: u32,
+    /// How long an open circuit keeps the target out of rotation before a
+    /// trial request is let through.
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// State of a target's circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// The target receives requests.
+    Closed,
+    /// The target failed repeatedly and is skipped until the cooldown ends.
+    Open,
+    /// The cooldown has ended; the next request to reach the target is a
+    /// trial that closes the circuit on success and reopens it on failure.
+    HalfOpen,
+}
+
+/// Health of a target, returned by [`RoutingProvider::health`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetHealth {
+    /// The target's `"provider/model"` identifier.
+    pub id: String,
+    /// The state of its circuit breaker.
+    pub state: CircuitState,
+    /// Failures since its last successful request.
+    pub consecutive_failures: u32,
+}
+
+/// Error creating a [`RoutingProvider`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RoutingError {
+    /// No targets were given.
+    #[error("routing provider requires at least one target")]
+    NoTargets,
+}
+
+/// Error classes that fail over to the next target by default.
+const DEFAULT_FAILOVER: [ErrorClass; 3] = [
+    ErrorClass::Transport,
+    ErrorClass::RateLimited,
+    ErrorClass::Server,
+];
+
+/// An [`LlmProvider`] that forwards requests to an ordered list of targets,
+/// failing over between them.
+///
+/// A request is sent to one target at a time. If it fails with an error whose
+/// [class](GenerationError::class) is in the failover set, the failure counts
+/// against the target's circuit breaker and the request is retried on the next
+/// target; any other outcome is returned to the caller. By default, transport
+/// errors, rate limits and server errors fail over. When every target fails,
+/// the last error is returned. When every target is skipped because its
+/// circuit is open, the request fails with
+/// [`GenerationError::NoTargetAvailable`].
+///
+/// Targets whose circuit is open are skipped. Once the cooldown ends, a single
+/// trial request is let through; other requests keep skipping the target until
+/// the trial completes or another cooldown passes.
+///
+/// The model requested from the routing provider itself is ignored: each
+/// target names its own model.
+///
+/// Streaming requests fail over only if the stream cannot be started. Errors
+/// yielded mid-stream are passed to the caller.
+///
+/// # Example
+///
+/// ```
+/// # use polaris_models::ModelRegistry;
+/// # use polaris_models::llm::{CircuitBreaker, Routing, RoutingProvider, Target};
+/// # use std::sync::Arc;
+/// # use std::time::Duration;
+/// # fn example(registry: &mut ModelRegistry) -> Result<(), Box<dyn std::error::Error>> {
+/// let router = RoutingProvider::new([
+///     Target::resolve(registry, "anthropic/claude-sonnet-4-5")?.weight(3),
+///     Target::resolve(registry, "openai/gpt-4o")?,
+/// ])?
+/// .with_routing(Routing::Weighted)
+/// .with_circuit_breaker(CircuitBreaker {
+///     failure_threshold: 3,
+///     cooldown: Duration::from_secs(60),
+/// });
+///
+/// registry.try_register_llm_provider("resilient", Arc::new(router))?;
+/// // Agents then use `registry.llm("resilient/chat")`.
+/// # Ok(())
+/// # }
+/// ```
+pub struct RoutingProvider {
+    targets: Vec<Target>,
+    routing: Routing,
+    failover_on: Vec<ErrorClass>,
+    breaker: CircuitBreaker,
+    health: Mutex<Vec<Health>>,
+}
+
+/// Mutable routing state of a target.
+#[derive(Debug, Default)]
+struct Health {
+    consecutive_failures: u32,
+    /// When the circuit last opened or let a trial request through.
+    opened_at: Option<Instant>,
+    /// Running weight for smooth weighted round-robin.
+    current_weight: i64,
+}
+
+impl Health {
+    fn state(&self, breaker: &CircuitBreaker, now: Instant) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if now.duration_since(opened_at) < breaker.cooldown => {
+                CircuitState::Open
+            }
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+}
+
+impl RoutingProvider {
+    /// Creates a provider that routes across `targets` in
+    /// [priority](Routing::Priority) order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RoutingError::NoTargets`] if `targets` is empty.
+    pub fn new(targets: impl IntoIterator<Item = Target>) -> Result<Self, RoutingError> {
+        let targets: Vec<Target> = targets.into_iter().collect();
+        if targets.is_empty() {
+            return Err(RoutingError::NoTargets);
+        }
+        let health = targets.iter().map(|_| Health::default()).collect();
+        Ok(Self {
+            targets,
+            routing: Routing::default(),
+            failover_on: DEFAULT_FAILOVER.to_vec(),
+            breaker: CircuitBreaker::default(),
+            health: Mutex::new(health),
+        })
+    }
+
+    /// Sets how the first target of each request is picked.
+    #[must_use]
+    pub fn with_routing(mut self, routing: Routing) -> Self {
+        self.routing = routing;
+        self
+    }
+
+    /// Sets the error classes that fail over to the next target, replacing
+    /// the defaults.
+    #[must_use]
+    pub fn with_failover_on(mut self, classes: impl IntoIterator<Item = ErrorClass>) -> Self {
+        self.failover_on = classes.into_iter().collect();
+        self
+    }
+
+    /// Sets the circuit breaker applied to each target.
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.breaker = breaker;
+        self
+    }
+
+    /// Returns the targets, in failover order.
+    #[must_use]
+    pub fn targets(&self) -> &[Target] {
+        &self.targets
+    }
+
+    /// Returns the current health of each target, in failover order.
+    #[must_use]
+    pub fn health(&self) -> Vec<TargetHealth> {
+        let now = Instant::now();
+        let health = self.health.lock();
+        self.targets
+            .iter()
+            .zip(health.iter())
+            .map(|(target, health)| TargetHealth {
+                id: target.id.clone(),
+                state: health.state(&self.breaker, now),
+                consecutive_failures: health.consecutive_failures,
+            })
+            .collect()
+    }
+
+    /// Returns the order in which targets are tried for the next request.
+    fn order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.targets.len()).collect();
+        if self.routing == Routing::Weighted
+            && let Some(first) = self.pick_weighted()
+        {
+            order.retain(|&index| index != first);
+            order.insert(0, first);
+        }
+        order
+    }
+
+    /// Picks a target by smooth weighted round-robin among those whose
+    /// circuit is not open.
+    fn pick_weighted(&self) -> Option<usize> {
+        let now = Instant::now();
+        let mut health = self.health.lock();
+        let mut total = 0;
+        let mut best: Option<usize> = None;
+        for (index, target) in self.targets.iter().enumerate() {
+            let state = health[index].state(&self.breaker, now);
+            if target.weight == 0 || state == CircuitState::Open {
+                continue;
+            }
+            total += i64::from(target.weight);
+            health[index].current_weight += i64::from(target.weight);
+            if best.is_none_or(|best| health[index].current_weight > health[best].current_weight) {
+                best = Some(index);
+            }
+        }
+        if let Some(best) = best {
+            health[best].current_weight -= total;
+        }
+        best
+    }
+
+    /// Returns whether a request may be sent to the target, letting a trial
+    /// request through a half-open circuit.
+    fn admit(&self, index: usize) -> bool {
+        let now = Instant::now();
+        let mut health = self.health.lock();
+        let health = &mut health[index];
+        match health.state(&self.breaker, now) {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => {
+                // Keep other requests away until the trial completes.
+                health.opened_at = Some(now);
+                true
+            }
+        }
+    }
+
+    /// Records the outcome of a request sent to the target.
+    fn record(&self, index: usize, failed: bool) {
+        let mut health = self.health.lock();
+        let health = &mut health[index];
+        if !failed {
+            health.consecutive_failures = 0;
+            health.opened_at = None;
+            return;
+        }
+        health.consecutive_failures += 1;
+        if health.opened_at.is_some()
+            || health.consecutive_failures >= self.breaker.failure_threshold
+        {
+            health.opened_at = Some(Instant::now());
+        }
+    }
+
+    /// Sends `request` to each available target in turn until one succeeds
+    /// or fails with an error that does not fail over.
+    async fn route<T, F, Fut>(&self, request: LlmRequest, call: F) -> Result<T, GenerationError>
+    where
+        F: Fn(usize, LlmRequest) -> Fut,
+        Fut: Future<Output = Result<T, GenerationError>>,
+    {
+        let mut last_err = None;
+        for index in self.order() {
+            if !self.admit(index) {
+                continue;
+            }
+            match call(index, request.clone()).await {
+                Err(err) if self.failover_on.contains(&err.class()) => {
+                    self.record(index, true);
+                    last_err = Some(err);
+                }
+                result => {
+                    self.record(index, false);
+                    return result;
+                }
+            }
+        }
+        Err(last_err.unwrap_or(GenerationError::NoTargetAvailable))
+    }
+}
+
+impl std::fmt::Debug for RoutingProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RoutingProvider")
+            .field("targets", &self.targets)
+            .field("routing", &self.routing)
+            .field("failover_on", &self.failover_on)
+            .field("breaker", &self.breaker)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl LlmProvider for RoutingProvider {
+    async fn generate(
+        &self,
+        _model: &str,
+        request: LlmRequest,
+    ) -> Result<LlmResponse, GenerationError> {
+        self.route(request, |index, request| {
+            self.targets[index].llm.call(request)
+        })
+        .await
+    }
+
+    async fn generate_stream(
+        &self,
+        _model: &str,
+        request: LlmRequest,
+    ) -> Result<LlmStream, GenerationError> {
+        self.route(request, |index, request| {
+            self.targets[index].llm.call_stream(request)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::{AssistantBlock, RetryPolicy, TextBlock, Usage};
+    use std::collections::VecDeque;
+
+    /// Replies with its name, or with the queued errors first.
+    struct Scripted {
+        name: &'static str,
+        errors: Mutex<VecDeque<GenerationError>>,
+        calls: Mutex<u32>,
+    }
+
+    impl Scripted {
+        fn new(name: &'static str, errors: impl IntoIterator<Item = GenerationError>) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                errors: Mutex::new(errors.into_iter().collect()),
+                calls: Mutex::new(0),
+            })
+        }
+
+        fn calls(&self) -> u32 {
+            *self.calls.lock()
+        }
+    }
+
+    #[async_trait]
+    impl LlmProvider for Scripted {
+        async fn generate(
+            &self,
+            model: &str,
+            _request: LlmRequest,
+        ) -> Result<LlmResponse, GenerationError> {
+            *self.calls.lock() += 1;
+            if let Some(err) = self.errors.lock().pop_front() {
+                return Err(err);
+            }
+            Ok(LlmResponse {
+                content: vec![AssistantBlock::Text(TextBlock::new(format!(
+                    "{}/{model}",
+                    self.name
+                )))],
+                usage: Usage::default(),
+                stop_reason: None,
+            })
+        }
+    }
+
+    fn rate_limited() -> GenerationError {
+        GenerationError::RateLimited { retry_after: None }
+    }
+
+    fn server_error() -> GenerationError {
+        GenerationError::Provider {
+            status: Some(503),
+            message: "overloaded".into(),
+            source: None,
+        }
+    }
+
+    async fn reply(router: &RoutingProvider) -> Result<String, GenerationError> {
+        let response = router.generate("ignored", LlmRequest::default()).await?;
+        Ok(response.text())
+    }
+
+    #[test]
+    fn errors_are_classified() {
+        assert_eq!(rate_limited().class(), ErrorClass::RateLimited);
+        assert_eq!(server_error().class(), ErrorClass::Server);
+        let not_found = GenerationError::Provider {
+            status: Some(404),
+            message: String::new(),
+            source: None,
+        };
+        assert_eq!(not_found.class(), ErrorClass::InvalidRequest);
+        assert_eq!(
+            GenerationError::Http("reset".into()).class(),
+            ErrorClass::Transport
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_over_on_rate_limit_and_server_errors() {
+        let primary = Scripted::new("primary", [rate_limited(), server_error()]);
+        let backup = Scripted::new("backup", []);
+        let router = RoutingProvider::new([
+            Target::new("primary", primary.clone(), "large"),
+            Target::new("backup", backup.clone(), "small"),
+        ])
+        .unwrap();
+
+        assert_eq!(reply(&router).await.unwrap(), "backup/small");
+        assert_eq!(reply(&router).await.unwrap(), "backup/small");
+        assert_eq!(reply(&router).await.unwrap(), "primary/large");
+        assert_eq!(primary.calls(), 3);
+        assert_eq!(backup.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn registry_retry_policy_applies_once_around_failover() {
+        let primary = Scripted::new("primary", [server_error(), server_error()]);
+        let backup = Scripted::new("backup", [server_error()]);
+        let mut registry = ModelRegistry::new();
+        registry.register_llm_provider("primary", primary.clone());
+        registry.register_llm_provider("backup", backup.clone());
+        registry.set_retry_policy(
+            RetryPolicy::new(2)
+                .with_initial_delay(Duration::ZERO)
+                .with_jitter(false),
+        );
+        let router = RoutingProvider::new([
+            Target::resolve(&registry, "primary/large").unwrap(),
+            Target::resolve(&registry, "backup/small").unwrap(),
+        ])
+        .unwrap();
+        registry.register_llm_provider("router", Arc::new(router));
+
+        // The first pass fails on both targets and is retried once; the
+        // targets themselves do not retry.
+        let llm = registry.llm("router/chat").unwrap();
+        let response = llm.generate(LlmRequest::default()).await.unwrap();
+        assert_eq!(response.text(), "backup/small");
+        assert_eq!(primary.calls(), 2);
+        assert_eq!(backup.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn returns_errors_that_do_not_fail_over() {
+        let primary = Scripted::new("primary", [GenerationError::Auth("bad key".into())]);
+        let backup = Scripted::new("backup", []);
+        let router = RoutingProvider::new([
+            Target::new("primary", primary, "large"),
+            Target::new("backup", backup.clone(), "small"),
+        ])
+        .unwrap();
+
+        let err = reply(&router).await.unwrap_err();
+        assert_eq!(err.class(), ErrorClass::Auth);
+        assert_eq!(backup.calls(), 0);
+
+        // Auth failures fail over once configured to.
+        let primary = Scripted::new("primary", [GenerationError::Auth("bad key".into())]);
+        let router = RoutingProvider::new([
+            Target::new("primary", primary, "large"),
+            Target::new("backup", backup, "small"),
+        ])
+        .unwrap()
+        .with_failover_on([ErrorClass::Auth]);
+        assert_eq!(reply(&router).await.unwrap(), "backup/small");
+    }
+
+    #[tokio::test]
+    async fn returns_last_error_when_every_target_fails() {
+        let router = RoutingProvider::new([
+            Target::new(
+                "primary",
+                Scripted::new("primary", [server_error()]),
+                "large",
+            ),
+            Target::new("backup", Scripted::new("backup", [rate_limited()]), "small"),
+        ])
+        .unwrap();
+
+        let err = reply(&router).await.unwrap_err();
+        assert!(matches!(err, GenerationError::RateLimited { .. }));
+    }
+
+    #[tokio::test]
+    async fn open_circuit_skips_target_until_cooldown() {
+        let primary = Scripted::new("primary", [server_error(), server_error()]);
+        let backup = Scripted::new("backup", []);
+        let router = RoutingProvider::new([
+            Target::new("primary", primary.clone(), "large"),
+            Target::new("backup", backup, "small"),
+        ])
+        .unwrap()
+        .with_circuit_breaker(CircuitBreaker {
+            failure_threshold: 2,
+            cooldown: Duration::from_secs(3600),
+        });
+
+        reply(&router).await.unwrap();
+        assert_eq!(router.health()[0].state, CircuitState::Closed);
+        reply(&router).await.unwrap();
+        assert_eq!(
+            router.health()[0],
+            TargetHealth {
+                id: "primary/large".into(),
+                state: CircuitState::Open,
+                consecutive_failures: 2,
+            }
+        );
+
+        assert_eq!(reply(&router).await.unwrap(), "backup/small");
+        assert_eq!(primary.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn half_open_trial_closes_circuit_on_success() {
+        let primary = Scripted::new("primary", [server_error()]);
+        let router = RoutingProvider::new([
+            Target::new("primary", primary, "large"),
+            Target::new("backup", Scripted::new("backup", []), "small"),
+        ])
+        .unwrap()
+        .with_circuit_breaker(CircuitBreaker {
+            failure_threshold: 1,
+            cooldown: Duration::ZERO,
+        });
+
+        assert_eq!(reply(&router).await.unwrap(), "backup/small");
+        assert_eq!(router.health()[0].state, CircuitState::HalfOpen);
+
+        assert_eq!(reply(&router).await.unwrap(), "primary/large");
+        assert_eq!(router.health()[0].state, CircuitState::Closed);
+        assert_eq!(router.health()[0].consecutive_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn every_circuit_open_fails_fast() {
+        let router = RoutingProvider::new([Target::new(
+            "primary",
+            Scripted::new("primary", [server_error()]),
+            "large",
+        )])
+        .unwrap()
+        .with_circuit_breaker(CircuitBreaker {
+            failure_threshold: 1,
+            cooldown: Duration::from_secs(3600),
+        });
+
+        reply(&router).await.unwrap_err();
+        let err = reply(&router).await.unwrap_err();
+        assert!(matches!(err, GenerationError::NoTargetAvailable), "{err}");
+    }
+
+    #[test]
+    fn requires_a_target() {
+        let err = RoutingProvider::new([]).unwrap_err();
+        assert_eq!(err, RoutingError::NoTargets);
+    }
+
+    #[tokio::test]
+    async fn weighted_routing_spreads_requests() {
+        let heavy = Scripted::new("heavy", []);
+        let light = Scripted::new("light", []);
+        let router = RoutingProvider::new([
+            Target::new("heavy", heavy.clone(), "m").weight(3),
+            Target::new("light", light.clone(), "m"),
+            Target::new("spare", Scripted::new("spare", []), "m").weight(0),
+        ])
+        .unwrap()
+        .with_routing(Routing::Weighted);
+
+        for _ in 0..8 {
+            reply(&router).await.unwrap();
+        }
+        assert_eq!(heavy.calls(), 6);
+        assert_eq!(light.calls(), 2);
+    }
+}
@@ -0,0 +1,558 @@
+//! Streaming types for incremental LLM generation.
+//!
+//! Providers that support streaming emit a sequence of [`StreamEvent`]s as the
+//! model produces output. Events carry the index of the content block they
+//! belong to, so interleaved text, reasoning, and tool call fragments can be
+//! reassembled into a complete [`LlmResponse`] with a [`StreamAccumulator`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use polaris_models::llm::{Llm, StreamEvent};
+//!
+//! # async fn example(llm: Llm) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut stream = llm.builder().user("Tell me a story").stream().await?;
+//!
+//! while let Some(event) = stream.next().await {
+//!     if let StreamEvent::TextDelta { text, .. } = event? {
+//!         print!("{text}");
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::error::GenerationError;
+use super::types::{
+    AssistantBlock, LlmResponse, ReasoningBlock, StopReason, TextBlock, ToolCall, ToolFunction,
+    Usage,
+};
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::pin::Pin;
+
+/// A boxed stream of [`StreamEvent`]s returned by streaming generation.
+pub type LlmStream = Pin<Box<dyn Stream<Item = Result<StreamEvent, GenerationError>> + Send>>;
+
+/// An incremental event emitted during streaming generation.
+///
+/// The `index` field identifies the content block the event belongs to. Blocks
+/// are ordered by index in the final response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StreamEvent {
+    /// A fragment of assistant text.
+    TextDelta {
+        /// Index of the content block.
+        index: usize,
+        /// The text fragment.
+        text: String,
+    },
+    /// The start of a reasoning block.
+    ///
+    /// Providers that do not assign reasoning IDs may omit this event and
+    /// emit [`ReasoningDelta`](Self::ReasoningDelta) directly.
+    ReasoningStart {
+        /// Index of the content block.
+        index: usize,
+        /// Provider-assigned reasoning ID, if any.
+        id: Option<String>,
+    },
+    /// A fragment of model reasoning.
+    ReasoningDelta {
+        /// Index of the content block.
+        index: usize,
+        /// The reasoning fragment.
+        text: String,
+    },
+    /// The signature of a reasoning block.
+    ReasoningSignature {
+        /// Index of the content block.
+        index: usize,
+        /// The signature fragment.
+        signature: String,
+    },
+    /// The start of a tool call.
+    ToolCallStart {
+        /// Index of the content block.
+        index: usize,
+        /// Provider-assigned tool call ID.
+        id: String,
+        /// Provider-specific call ID, if any.
+        call_id: Option<String>,
+        /// Name of the function being called.
+        name: String,
+    },
+    /// A fragment of a tool call's JSON-encoded arguments.
+    ToolCallDelta {
+        /// Index of the content block.
+        index: usize,
+        /// The argument fragment.
+        arguments: String,
+    },
+    /// The reason the model stopped generating.
+    ///
+    /// Emitted at most once, after all content events.
+    Stop {
+        /// The normalized stop reason.
+        reason: StopReason,
+    },
+    /// Final token usage for the generation.
+    ///
+    /// Emitted once, after all content events.
+    Usage(Usage),
+}
+
+// ─────────────────────
+// Accumulator
+// ─────────────────────
+
+/// A content block under construction.
+#[derive(Debug)]
+enum PartialBlock {
+    Text(String),
+    Reasoning {
+        id: Option<String>,
+        text: String,
+        signature: Option<String>,
+    },
+    ToolCall {
+        id: String,
+        call_id: Option<String>,
+        name: String,
+        arguments: String,
+    },
+}
+
+/// Reassembles [`StreamEvent`]s into a complete [`LlmResponse`].
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use polaris_models::llm::{Llm, StreamAccumulator, StreamEvent};
+///
+/// # async fn example(llm: Llm) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut stream = llm.builder().user("Hello").stream().await?;
+/// let mut accumulator = StreamAccumulator::new();
+///
+/// while let Some(event) = stream.next().await {
+///     let event = event?;
+///     if let StreamEvent::TextDelta { text, .. } = &event {
+///         print!("{text}");
+///     }
+///     accumulator.push(event)?;
+/// }
+///
+/// let response = accumulator.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct StreamAccumulator {
+    blocks: BTreeMap<usize, PartialBlock>,
+    usage: Usage,
+    stop_reason: Option<StopReason>,
+}
+
+impl StreamAccumulator {
+    /// Creates an empty accumulator.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consumes a stream to completion and returns the assembled response.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error yielded by the stream, or a
+    /// [`GenerationError::InvalidResponse`] if the events do not form a valid
+    /// response.
+    pub async fn collect(mut stream: LlmStream) -> Result<LlmResponse, GenerationError> {
+        let mut accumulator = Self::new();
+        while let Some(event) = stream.next().await {
+            accumulator.push(event?)?;
+        }
+        accumulator.finish()
+    }
+
+    /// Applies a single event to the accumulated state.
+    ///
+    /// Events of different blocks may be interleaved and arrive in any
+    /// order of index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GenerationError::InvalidResponse`] if the event belongs to a
+    /// block of another type, or is a tool call fragment arriving before the
+    /// start of its call.
+    pub fn push(&mut self, event: StreamEvent) -> Result<(), GenerationError> {
+        match event {
+            StreamEvent::TextDelta { index, text } => {
+                match self
+                    .blocks
+                    .entry(index)
+                    .or_insert_with(|| PartialBlock::Text(String::new()))
+                {
+                    PartialBlock::Text(buffer) => buffer.push_str(&text),
+                    block => return Err(mismatch(index, "text", block)),
+                }
+            }
+            StreamEvent::ReasoningStart { index, id } => match self.reasoning_entry(index) {
+                PartialBlock::Reasoning { id: existing, .. } => *existing = id,
+                block => return Err(mismatch(index, "reasoning", block)),
+            },
+            StreamEvent::ReasoningDelta { index, text } => match self.reasoning_entry(index) {
+                PartialBlock::Reasoning { text: buffer, .. } => buffer.push_str(&text),
+                block => return Err(mismatch(index, "reasoning", block)),
+            },
+            StreamEvent::ReasoningSignature { index, signature } => {
+                match self.reasoning_entry(index) {
+                    PartialBlock::Reasoning {
+                        signature: existing,
+                        ..
+                    } => existing
+                        .get_or_insert_with(String::new)
+                        .push_str(&signature),
+                    block => return Err(mismatch(index, "reasoning", block)),
+                }
+            }
+            StreamEvent::ToolCallStart {
+                index,
+                id,
+                call_id,
+                name,
+            } => {
+                if let Some(block) = self.blocks.get(&index) {
+                    return Err(mismatch(index, "tool call start", block));
+                }
+                self.blocks.insert(
+                    index,
+                    PartialBlock::ToolCall {
+                        id,
+                        call_id,
+                        name,
+                        arguments: String::new(),
+                    },
+                );
+            }
+            StreamEvent::ToolCallDelta { index, arguments } => match self.blocks.get_mut(&index) {
+                Some(PartialBlock::ToolCall {
+                    arguments: buffer, ..
+                }) => buffer.push_str(&arguments),
+                Some(block) => return Err(mismatch(index, "tool call", block)),
+                None => {
+                    return Err(GenerationError::InvalidResponse(format!(
+                        "tool call arguments for block {index} arrived before the call started"
+                    )));
+                }
+            },
+            StreamEvent::Stop { reason } => self.stop_reason = Some(reason),
+            StreamEvent::Usage(usage) => self.usage = usage,
+        }
+        Ok(())
+    }
+
+    /// Returns the text accumulated so far across all text blocks.
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.blocks
+            .values()
+            .filter_map(|block| match block {
+                PartialBlock::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Finalizes the accumulated events into a response.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GenerationError::InvalidResponse`] if a tool call's
+    /// accumulated arguments are not valid JSON.
+    pub fn finish(self) -> Result<LlmResponse, GenerationError> {
+        let content = self
+            .blocks
+            .into_values()
+            .map(|block| match block {
+                PartialBlock::Text(text) => Ok(AssistantBlock::Text(TextBlock::new(text))),
+                PartialBlock::Reasoning {
+                    id,
+                    text,
+                    signature,
+                } => Ok(AssistantBlock::Reasoning(ReasoningBlock {
+                    id,
+                    reasoning: vec![text],
+                    signature,
+                })),
+                PartialBlock::ToolCall {
+                    id,
+                    call_id,
+                    name,
+                    arguments,
+                } => {
+                    let arguments = if arguments.trim().is_empty() {
+                        serde_json::Value::Object(serde_json::Map::new())
+                    } else {
+                        serde_json::from_str(&arguments).map_err(|err| {
+                            GenerationError::InvalidResponse(format!(
+                                "tool call '{name}' has invalid JSON arguments: {err}"
+                            ))
+                        })?
+                    };
+                    Ok(AssistantBlock::ToolCall(ToolCall {
+                        id,
+                        call_id,
+                        function: ToolFunction { name, arguments },
+                        signature: None,
+                        additional_params: None,
+                    }))
+                }
+            })
+            .collect::<Result<Vec<_>, GenerationError>>()?;
+
+        Ok(LlmResponse {
+            content,
+            usage: self.usage,
+            stop_reason: self.stop_reason,
+        })
+    }
+
+    /// Returns the reasoning block at `index`, creating it if absent.
+    fn reasoning_entry(&mut self, index: usize) -> &mut PartialBlock {
+        self.blocks
+            .entry(index)
+            .or_insert_with(|| PartialBlock::Reasoning {
+                id: None,
+                text: String::new(),
+                signature: None,
+            })
+    }
+}
+
+impl PartialBlock {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Text(_) => "text",
+            Self::Reasoning { .. } => "reasoning",
+            Self::ToolCall { .. } => "tool call",
+        }
+    }
+}
+
+/// Error for an event of kind `event` arriving for a block of another type.
+fn mismatch(index: usize, event: &str, block: &PartialBlock) -> GenerationError {
+    GenerationError::InvalidResponse(format!(
+        "{event} event for block {index}, which is a {} block",
+        block.kind()
+    ))
+}
+
+// ─────────────────────
+// Fallback conversion
+// ─────────────────────
+
+/// Converts a complete response into the equivalent sequence of stream events.
+///
+/// Used by the default [`LlmProvider::generate_stream`](super::LlmProvider::generate_stream)
+/// implementation for providers without native streaming support.
+pub(crate) fn response_events(response: LlmResponse) -> Vec<StreamEvent> {
+    let mut events = Vec::with_capacity(response.content.len() + 2);
+
+    for (index, block) in response.content.into_iter().enumerate() {
+        match block {
+            AssistantBlock::Text(block) => events.push(StreamEvent::TextDelta {
+                index,
+                text: block.text,
+            }),
+            AssistantBlock::Reasoning(block) => {
+                events.push(StreamEvent::ReasoningStart {
+                    index,
+                    id: block.id,
+                });
+                events.push(StreamEvent::ReasoningDelta {
+                    index,
+                    text: block.reasoning.join("\n"),
+                });
+                if let Some(signature) = block.signature {
+                    events.push(StreamEvent::ReasoningSignature { index, signature });
+                }
+            }
+            AssistantBlock::ToolCall(call) => {
+                events.push(StreamEvent::ToolCallStart {
+                    index,
+                    id: call.id,
+                    call_id: call.call_id,
+                    name: call.function.name,
+                });
+                events.push(StreamEvent::ToolCallDelta {
+                    index,
+                    arguments: call.function.arguments.to_string(),
+                });
+            }
+        }
+    }
+
+    if let Some(reason) = response.stop_reason {
+        events.push(StreamEvent::Stop { reason });
+    }
+    events.push(StreamEvent::Usage(response.usage));
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accumulate(events: Vec<StreamEvent>) -> Result<LlmResponse, GenerationError> {
+        let mut accumulator = StreamAccumulator::new();
+        for event in events {
+            accumulator.push(event)?;
+        }
+        accumulator.finish()
+    }
+
+    #[test]
+    fn interleaved_blocks_are_ordered_by_index() {
+        let response = accumulate(vec![
+            StreamEvent::ToolCallStart {
+                index: 2,
+                id: "call_1".into(),
+                call_id: None,
+                name: "get_weather".into(),
+            },
+            StreamEvent::TextDelta {
+                index: 1,
+                text: "Let me ".into(),
+            },
+            StreamEvent::ToolCallDelta {
+                index: 2,
+                arguments: r#"{"loca"#.into(),
+            },
+            StreamEvent::ReasoningDelta {
+                index: 0,
+                text: "The user wants weather.".into(),
+            },
+            StreamEvent::TextDelta {
+                index: 1,
+                text: "check.".into(),
+            },
+            StreamEvent::ToolCallDelta {
+                index: 2,
+                arguments: r#"tion":"Tokyo"}"#.into(),
+            },
+            StreamEvent::ReasoningSignature {
+                index: 0,
+                signature: "sig".into(),
+            },
+            StreamEvent::Stop {
+                reason: StopReason::ToolUse,
+            },
+        ])
+        .unwrap();
+
+        assert_eq!(response.content.len(), 3);
+        let AssistantBlock::Reasoning(reasoning) = &response.content[0] else {
+            panic!("expected reasoning, got {:?}", response.content[0]);
+        };
+        assert_eq!(reasoning.reasoning, vec!["The user wants weather."]);
+        assert_eq!(reasoning.signature.as_deref(), Some("sig"));
+        assert_eq!(response.text(), "Let me check.");
+        let AssistantBlock::ToolCall(call) = &response.content[2] else {
+            panic!("expected tool call, got {:?}", response.content[2]);
+        };
+        assert_eq!(call.function.arguments["location"], "Tokyo");
+        assert_eq!(response.stop_reason, Some(StopReason::ToolUse));
+    }
+
+    #[test]
+    fn event_for_a_block_of_another_type_is_an_error() {
+        let err = accumulate(vec![
+            StreamEvent::TextDelta {
+                index: 0,
+                text: "hi".into(),
+            },
+            StreamEvent::ToolCallDelta {
+                index: 0,
+                arguments: "{}".into(),
+            },
+        ])
+        .unwrap_err();
+        assert!(matches!(err, GenerationError::InvalidResponse(_)), "{err}");
+
+        let err = accumulate(vec![
+            StreamEvent::ReasoningDelta {
+                index: 0,
+                text: "thinking".into(),
+            },
+            StreamEvent::TextDelta {
+                index: 0,
+                text: "hi".into(),
+            },
+        ])
+        .unwrap_err();
+        assert!(matches!(err, GenerationError::InvalidResponse(_)), "{err}");
+    }
+
+    #[test]
+    fn tool_call_arguments_before_the_call_start_are_an_error() {
+        let err = accumulate(vec![StreamEvent::ToolCallDelta {
+            index: 0,
+            arguments: "{}".into(),
+        }])
+        .unwrap_err();
+        assert!(matches!(err, GenerationError::InvalidResponse(_)), "{err}");
+    }
+
+    #[test]
+    fn invalid_tool_call_arguments_fail_to_finish() {
+        let err = accumulate(vec![
+            StreamEvent::ToolCallStart {
+                index: 0,
+                id: "call_1".into(),
+                call_id: None,
+                name: "get_weather".into(),
+            },
+            StreamEvent::ToolCallDelta {
+                index: 0,
+                arguments: r#"{"location":"#.into(),
+            },
+        ])
+        .unwrap_err();
+        assert!(matches!(err, GenerationError::InvalidResponse(_)), "{err}");
+    }
+
+    #[test]
+    fn response_events_round_trip() {
+        let response = LlmResponse {
+            content: vec![
+                AssistantBlock::Text(TextBlock::new("hello")),
+                AssistantBlock::ToolCall(ToolCall {
+                    id: "call_1".into(),
+                    call_id: None,
+                    function: ToolFunction {
+                        name: "get_weather".into(),
+                        arguments: serde_json::json!({ "location": "Tokyo" }),
+                    },
+                    signature: None,
+                    additional_params: None,
+                }),
+            ],
+            usage: Usage {
+                output_tokens: Some(7),
+                ..Usage::default()
+            },
+            stop_reason: Some(StopReason::ToolUse),
+        };
+
+        let rebuilt = accumulate(response_events(response.clone())).unwrap();
+        assert_eq!(
+            serde_json::to_value(rebuilt).unwrap(),
+            serde_json::to_value(response).unwrap()
+        );
+    }
+}
@@ -12,6 +12,10 @@ use serde_json::Value;
 pub struct LlmRequest {
     /// System prompt for the model.
     pub system: Option<String>,
+    /// Cache breakpoint placed after the system prompt.
+    ///
+    /// Ignored when [`system`](Self::system) is `None`.
+    pub system_cache_control: Option<CacheControl>,
     /// The messages to send to the model.
     pub messages: Vec<Message>,
     /// Available tools the model can call.
@@ -23,6 +27,26 @@ pub struct LlmRequest {
     /// When provided, the model will generate output conforming to this schema.
     /// This is set automatically by `Llm::generate_structured()`.
     pub output_schema: Option<Value>,
+    /// Sampling temperature.
+    ///
+    /// Higher values produce more varied output. The valid range is provider-specific.
+    pub temperature: Option<f32>,
+    /// Nucleus sampling threshold.
+    ///
+    /// Only tokens within the top `top_p` probability mass are considered.
+    pub top_p: Option<f32>,
+    /// Top-k sampling cutoff.
+    ///
+    /// Only the `top_k` most likely tokens are considered at each step.
+    pub top_k: Option<u32>,
+    /// Maximum number of tokens to generate.
+    ///
+    /// Providers apply their own default when unset.
+    pub max_output_tokens: Option<u32>,
+    /// Sequences that stop generation when produced.
+    pub stop_sequences: Option<Vec<String>>,
+    /// Seed for deterministic sampling, where supported.
+    pub seed: Option<u64>,
 }
 
 impl LlmRequest {
@@ -38,8 +62,62 @@ impl LlmRequest {
                 .any(|b| matches!(b, AssistantBlock::ToolCall(_))),
         })
     }
+
+    /// Returns a rough estimate of the input tokens this request uses.
+    ///
+    /// Text is counted at four characters per token, and each image, audio
+    /// clip or document at a flat [`MEDIA_TOKEN_ESTIMATE`]. The estimate is
+    /// meant for budgeting before a request is sent; the provider's
+    /// [`Usage`] is authoritative.
+    #[must_use]
+    pub fn estimated_input_tokens(&self) -> u64 {
+        let mut chars = self.system.as_ref().map_or(0, String::len);
+        let mut media = 0;
+        for message in &self.messages {
+            match message {
+                Message::User { content } => {
+                    for block in content {
+                        match block {
+                            UserBlock::Text(text) => chars += text.text.len(),
+                            UserBlock::ToolResult(result) => match &result.content {
+                                ToolResultContent::Text(text) => chars += text.len(),
+                                ToolResultContent::Image(_) => media += 1,
+                            },
+                            UserBlock::Image(_) | UserBlock::Audio(_) | UserBlock::Document(_) => {
+                                media += 1;
+                            }
+                        }
+                    }
+                }
+                Message::Assistant { content, .. } => {
+                    for block in content {
+                        chars += match block {
+                            AssistantBlock::Text(text) => text.text.len(),
+                            AssistantBlock::ToolCall(call) => {
+                                call.function.name.len() + call.function.arguments.to_string().len()
+                            }
+                            AssistantBlock::Reasoning(reasoning) => {
+                                reasoning.reasoning.iter().map(String::len).sum()
+                            }
+                        };
+                    }
+                }
+            }
+        }
+        for tool in self.tools.iter().flatten() {
+            chars += tool.name.len() + tool.description.len() + tool.parameters.to_string().len();
+        }
+        if let Some(schema) = &self.output_schema {
+            chars += schema.to_string().len();
+        }
+        (chars as u64).div_ceil(4) + media * MEDIA_TOKEN_ESTIMATE
+    }
 }
 
+/// Tokens assumed per image, audio clip or document by
+/// [`LlmRequest::estimated_input_tokens`].
+pub const MEDIA_TOKEN_ESTIMATE: u64 = 1_600;
+
 /// A generation response from a model.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LlmResponse {
@@ -47,6 +125,10 @@ pub struct LlmResponse {
     pub content: Vec<AssistantBlock>,
     /// Token usage information.
     pub usage: Usage,
+    /// Why the model stopped generating.
+    ///
+    /// `None` if the provider did not report a stop reason.
+    pub stop_reason: Option<StopReason>,
 }
 
 impl LlmResponse {
@@ -77,6 +159,14 @@ impl LlmResponse {
             .any(|block| matches!(block, AssistantBlock::ToolCall(_)))
     }
 
+    /// Returns `true` if generation was cut off by the output token limit.
+    ///
+    /// Agent loops can use this to request a continuation of a truncated answer.
+    #[must_use]
+    pub fn is_truncated(&self) -> bool {
+        self.stop_reason == Some(StopReason::MaxTokens)
+    }
+
     /// Returns all tool calls contained in the response.
     #[must_use]
     pub fn tool_calls(&self) -> Vec<&ToolCall> {
@@ -99,6 +189,38 @@ pub struct Usage {
     pub output_tokens: Option<u64>,
     /// Total tokens (input + output).
     pub total_tokens: Option<u64>,
+    /// Number of input tokens read from the provider's prompt cache.
+    ///
+    /// Not included in `input_tokens`.
+    pub cache_read_input_tokens: Option<u64>,
+    /// Number of input tokens written to the provider's prompt cache.
+    ///
+    /// Not included in `input_tokens`.
+    pub cache_creation_input_tokens: Option<u64>,
+    /// Number of output tokens spent on model reasoning.
+    ///
+    /// Included in `output_tokens` by providers that report it separately.
+    pub reasoning_tokens: Option<u64>,
+}
+
+/// The reason a model stopped generating, normalized across providers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StopReason {
+    /// The model finished its turn naturally.
+    EndTurn,
+    /// Generation hit the output token limit.
+    MaxTokens,
+    /// The model produced one of the requested stop sequences.
+    StopSequence,
+    /// The model stopped to call one or more tools.
+    ToolUse,
+    /// Output was blocked or cut off by a content filter or guardrail.
+    ContentFilter,
+    /// The provider paused a long-running turn; send the response back to continue.
+    Paused,
+    /// A provider-specific reason with no normalized equivalent.
+    Other,
 }
 
 // ─────────────────────
@@ -130,7 +252,7 @@ impl Message {
     #[must_use]
     pub fn user(text: impl Into<String>) -> Self {
         Self::User {
-            content: vec![UserBlock::Text(TextBlock { text: text.into() })],
+            content: vec![UserBlock::Text(TextBlock::new(text))],
         }
     }
 
@@ -139,7 +261,7 @@ impl Message {
     pub fn assistant(text: impl Into<String>) -> Self {
         Self::Assistant {
             id: None,
-            content: vec![AssistantBlock::Text(TextBlock { text: text.into() })],
+            content: vec![AssistantBlock::Text(TextBlock::new(text))],
         }
     }
 
@@ -157,7 +279,7 @@ impl Message {
     pub fn assistant_with_id(id: impl Into<String>, text: impl Into<String>) -> Self {
         Self::Assistant {
             id: Some(id.into()),
-            content: vec![AssistantBlock::Text(TextBlock { text: text.into() })],
+            content: vec![AssistantBlock::Text(TextBlock::new(text))],
         }
     }
 
@@ -220,22 +342,57 @@ impl Message {
 pub struct TextBlock {
     /// The text content.
     pub text: String,
+    /// Cache breakpoint placed after this block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl TextBlock {
+    /// Creates a text block without a cache breakpoint.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            cache_control: None,
+        }
+    }
+
+    /// Marks this block as a cache breakpoint.
+    #[must_use]
+    pub fn with_cache_control(mut self, cache_control: CacheControl) -> Self {
+        self.cache_control = Some(cache_control);
+        self
+    }
 }
 
 impl From<String> for TextBlock {
     fn from(text: String) -> Self {
-        Self { text }
+        Self::new(text)
     }
 }
 
 impl From<&str> for TextBlock {
     fn from(text: &str) -> Self {
-        Self {
-            text: text.to_string(),
-        }
+        Self::new(text)
     }
 }
 
+/// A prompt caching breakpoint.
+///
+/// Marks the end of a reusable prompt prefix: the request content up to and
+/// including the marked item may be cached and reused by later requests.
+/// Providers with explicit prompt caching (Anthropic, Bedrock) translate the
+/// marker; providers that cache automatically ignore it.
+///
+/// Cache hits are reported in [`Usage::cache_read_input_tokens`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheControl {
+    /// Cache for the provider's default, short-lived duration.
+    #[default]
+    Ephemeral,
+}
+
 /// Content that can appear in a user message.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -256,7 +413,7 @@ impl UserBlock {
     /// Creates a text content block.
     #[must_use]
     pub fn text(text: impl Into<String>) -> Self {
-        Self::Text(TextBlock { text: text.into() })
+        Self::Text(TextBlock::new(text))
     }
 
     /// Creates an image content block from base64-encoded data.
@@ -363,7 +520,7 @@ impl AssistantBlock {
     /// Creates a text content block.
     #[must_use]
     pub fn text(text: impl Into<String>) -> Self {
-        Self::Text(TextBlock { text: text.into() })
+        Self::Text(TextBlock::new(text))
     }
 
     /// Creates a tool call content block.
@@ -512,6 +669,21 @@ pub struct ToolDefinition {
     /// }
     /// ```
     pub parameters: Value,
+    /// Cache breakpoint placed after this definition.
+    ///
+    /// Tool definitions precede the system prompt and messages in the cached
+    /// prefix, so marking the last definition caches the whole tool list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl ToolDefinition {
+    /// Marks this definition as a cache breakpoint.
+    #[must_use]
+    pub fn with_cache_control(mut self, cache_control: CacheControl) -> Self {
+        self.cache_control = Some(cache_control);
+        self
+    }
 }
 
 /// Controls how the model should select tools.
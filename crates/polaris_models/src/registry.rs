@@ -1,7 +1,9 @@
 //! Model provider registry.
 
 use crate::error::CreateModelError;
-use crate::llm::{Llm, LlmProvider};
+use crate::llm::rate_limit::RateLimiter;
+use crate::llm::{Llm, LlmProvider, RateLimit, RetryPolicy, UsageObserver};
+use polaris_system::plugin::RegistrationConflict;
 use polaris_system::resource::GlobalResource;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -20,7 +22,7 @@ use std::sync::Arc;
 /// after the `ready()` phase.
 ///
 /// ```
-/// # use polaris_system::plugin::{Plugin, PluginId, Version};
+/// # use polaris_system::plugin::{Dependency, Plugin, PluginError, Version};
 /// # use polaris_system::server::Server;
 /// # use polaris_models::{ModelRegistry, ModelsPlugin};
 /// # use polaris_models::llm::{LlmProvider, LlmRequest, LlmResponse, GenerationError};
@@ -43,18 +45,25 @@ use std::sync::Arc;
 ///    const ID: &'static str = "my_provider";
 ///    const VERSION: Version = Version::new(0, 0, 1);
 ///
-///     fn dependencies(&self) -> Vec<PluginId> {
-///         vec![PluginId::of::<ModelsPlugin>()]
+///     fn dependencies(&self) -> Vec<Dependency> {
+///         vec![Dependency::of::<ModelsPlugin>()]
 ///     }
 ///
 ///     fn build(&self, server: &mut Server) {
+///         if let Err(err) = self.try_build(server) {
+///             panic!("{err}");
+///         }
+///     }
+///
+///     fn try_build(&self, server: &mut Server) -> Result<(), PluginError> {
 ///         let provider = MyProvider::new(/* ... */);
 ///
 ///         let mut registry = server
 ///             .get_resource_mut::<ModelRegistry>()
-///             .expect("ModelsPlugin must be added before provider plugins");
+///             .ok_or_else(|| PluginError::other("ModelsPlugin must be added before provider plugins"))?;
 ///
-///         registry.register_llm_provider("my_provider", Arc::new(provider));
+///         registry.try_register_llm_provider("my_provider", Arc::new(provider))?;
+///         Ok(())
 ///     }
 /// }
 /// ```
@@ -62,12 +71,21 @@ use std::sync::Arc;
 pub struct ModelRegistry {
     // Maps provider names to implementations.
     llm_providers: HashMap<String, Arc<dyn LlmProvider>>,
+    // Retry policy applied to created handles.
+    retry_policy: Option<RetryPolicy>,
+    // Maps provider names and "provider/model" identifiers to rate limiters.
+    rate_limiters: HashMap<String, Arc<RateLimiter>>,
+    // Observers reported to by created handles.
+    usage_observers: Vec<Arc<dyn UsageObserver>>,
 }
 
 impl std::fmt::Debug for ModelRegistry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ModelRegistry")
             .field("llm_providers", &self.llm_provider_names())
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limiters", &self.rate_limiters.keys())
+            .field("usage_observers", &self.usage_observers.len())
             .finish()
     }
 }
@@ -80,6 +98,9 @@ impl ModelRegistry {
     pub fn new() -> Self {
         Self {
             llm_providers: HashMap::new(),
+            retry_policy: None,
+            rate_limiters: HashMap::new(),
+            usage_observers: Vec::new(),
         }
     }
 
@@ -89,12 +110,80 @@ impl ModelRegistry {
     ///
     /// * `model_id` - Identifier in `"provider/model"` format (e.g., `"openai/gpt-4o"`)
     ///
+    /// The handle retries failed requests under the registry's
+    /// [retry policy](Self::set_retry_policy), if one is set, and waits for
+    /// the [rate limits](Self::set_rate_limit) of its provider and model.
+    /// Its requests are reported to the registry's
+    /// [usage observers](Self::add_usage_observer).
+    ///
     /// # Errors
     ///
     /// Returns an error if the `model_id` structure is invalid or the provider is not registered.
     pub fn llm(&self, model_id: impl AsRef<str>) -> Result<Llm, CreateModelError> {
         let model_id = model_id.as_ref();
+        let (provider, model_name) = self.resolve_llm(model_id)?;
+        let provider_name = &model_id[..model_id.len() - model_name.len() - 1];
+        let limiters = [provider_name, model_id]
+            .iter()
+            .filter_map(|scope| self.rate_limiters.get(*scope).cloned())
+            .collect();
+        let llm = Llm::new(provider, model_name.to_string())
+            .with_limiters(limiters)
+            .with_observers(model_id.to_string(), self.usage_observers.clone());
+        Ok(match &self.retry_policy {
+            Some(policy) => llm.with_retry(policy.clone()),
+            None => llm,
+        })
+    }
+
+    /// Sets the retry policy applied to every handle created by
+    /// [`llm()`](Self::llm).
+    ///
+    /// Like provider registration, this may only be called while the
+    /// registry is mutable during the `build()` phase. Individual handles can
+    /// override it with [`Llm::with_retry`] or [`Llm::without_retry`].
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = Some(policy);
+    }
+
+    /// Sets client-side rate limits for a provider or a single model.
+    ///
+    /// `scope` is either a provider name (e.g. `"anthropic"`), limiting all
+    /// of its models together, or a `"provider/model"` identifier. A request
+    /// must satisfy both the provider's and the model's limits. Every handle
+    /// for the scope shares the same limits, across sessions and parallel
+    /// branches. Setting a scope again replaces its limits for handles
+    /// created afterwards.
+    ///
+    /// Like provider registration, this may only be called while the
+    /// registry is mutable during the `build()` phase. See [`RateLimit`].
+    pub fn set_rate_limit(&mut self, scope: impl Into<String>, limit: RateLimit) {
+        let scope = scope.into();
+        let limiter = Arc::new(RateLimiter::new(scope.clone(), limit));
+        self.rate_limiters.insert(scope, limiter);
+    }
+
+    /// Adds an observer that every handle created by [`llm()`](Self::llm)
+    /// reports its requests to.
+    ///
+    /// Like provider registration, this may only be called while the
+    /// registry is mutable during the `build()` phase. See
+    /// [`UsageObserver`].
+    pub fn add_usage_observer(&mut self, observer: Arc<dyn UsageObserver>) {
+        self.usage_observers.push(observer);
+    }
 
+    /// Returns the retry policy applied to created handles, if any.
+    #[must_use]
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Splits a `"provider/model"` identifier and looks up the provider.
+    pub(crate) fn resolve_llm<'a>(
+        &self,
+        model_id: &'a str,
+    ) -> Result<(Arc<dyn LlmProvider>, &'a str), CreateModelError> {
         let (provider_name, model_name) = model_id
             .split_once('/')
             .ok_or_else(|| CreateModelError::InvalidModelId(model_id.to_string()))?;
@@ -103,7 +192,7 @@ impl ModelRegistry {
             .get_llm_provider(provider_name)
             .ok_or_else(|| CreateModelError::UnknownProvider(provider_name.to_string()))?;
 
-        Ok(Llm::new(provider, model_name.to_string()))
+        Ok((provider, model_name))
     }
 
     /// Registers an LLM provider.
@@ -120,19 +209,43 @@ impl ModelRegistry {
     ///
     /// # Panics
     ///
-    /// Panics if a provider with the same name is already registered.
+    /// Panics if a provider with the same name is already registered. Use
+    /// [`try_register_llm_provider`](Self::try_register_llm_provider) to
+    /// handle the conflict instead.
     pub fn register_llm_provider<P: LlmProvider>(
         &mut self,
         name: impl Into<String>,
         provider: Arc<P>,
     ) {
+        if let Err(err) = self.try_register_llm_provider(name, provider) {
+            panic!("{err}");
+        }
+    }
+
+    /// Registers an LLM provider, rejecting a name that is already
+    /// registered.
+    ///
+    /// Provider plugins call this from
+    /// [`Plugin::try_build`](polaris_system::plugin::Plugin::try_build) so
+    /// that a conflict aborts startup with a
+    /// [`BuildError`](polaris_system::plugin::BuildError).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RegistrationConflict`] if a provider with the same name is
+    /// already registered.
+    pub fn try_register_llm_provider<P: LlmProvider>(
+        &mut self,
+        name: impl Into<String>,
+        provider: Arc<P>,
+    ) -> Result<(), RegistrationConflict> {
         let name = name.into();
-        assert!(
-            !self.llm_providers.contains_key(&name),
-            "LLM provider '{name}' is already registered"
-        );
+        if self.llm_providers.contains_key(&name) {
+            return Err(RegistrationConflict::new("LLM provider", name));
+        }
         self.llm_providers
             .insert(name, provider as Arc<dyn LlmProvider>);
+        Ok(())
     }
 
     /// Returns a provider by name.
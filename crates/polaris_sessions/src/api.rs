@@ -7,18 +7,28 @@
 use crate::error::SessionError;
 use crate::info::SessionInfo;
 use crate::store::{AgentTypeId, ResourceEntry, SessionData, SessionId, SessionStore};
+use crate::turn::TurnOutcome;
+use crate::usage::UsageAPI;
 use hashbrown::HashMap;
 use parking_lot::RwLock;
 use polaris_agent::Agent;
 use polaris_core_plugins::persistence::{PersistenceAPI, PersistencePlugin, ResourceSerializer};
 use polaris_graph::hooks::HooksAPI;
-use polaris_graph::{ExecutionResult, Graph, GraphExecutor};
+use polaris_graph::trace::TraceAPI;
+use polaris_graph::{
+    CancellationToken, CheckpointSink, ExecutionCursor, ExecutionError, Graph, GraphExecutor,
+    Interrupt, RunOptions,
+};
 use polaris_system::api::API;
 use polaris_system::param::SystemContext;
-use polaris_system::plugin::{Plugin, PluginId, Version};
+use polaris_system::plugin::{Dependency, Plugin, Version};
+use polaris_system::resource::Output;
 use polaris_system::server::Server;
+use polaris_system::system::BoxFuture;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Checkpoint (internal)
@@ -30,6 +40,38 @@ struct Checkpoint {
     data: SessionData,
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Node checkpoints (internal)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Persists the execution cursor and session resources after each node.
+struct StoreCheckpoint {
+    store: Arc<dyn SessionStore>,
+    id: SessionId,
+    serializers: Vec<Arc<dyn ResourceSerializer>>,
+    agent_type: AgentTypeId,
+    turn_number: u32,
+}
+
+impl CheckpointSink for StoreCheckpoint {
+    fn save<'a>(
+        &'a self,
+        cursor: &'a ExecutionCursor,
+        ctx: &'a SystemContext<'_>,
+    ) -> BoxFuture<'a, Result<(), ExecutionError>> {
+        Box::pin(async move {
+            let mut data =
+                serialize_context(&self.serializers, self.agent_type, self.turn_number, ctx)
+                    .map_err(|err| ExecutionError::CheckpointFailed(err.to_string()))?;
+            data.cursor = Some(cursor.clone());
+            self.store
+                .save(&self.id, &data)
+                .await
+                .map_err(|err| ExecutionError::CheckpointFailed(err.to_string()))
+        })
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // SessionState (internal)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -45,9 +87,60 @@ struct SessionState {
     executor: GraphExecutor,
     agent_type: AgentTypeId,
     turn_number: AtomicU32,
+    /// Whether the store holds a turn suspended at an interrupt node.
+    awaiting_input: AtomicBool,
     checkpoints: parking_lot::Mutex<Vec<Checkpoint>>,
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// TurnTracker (internal)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Counts the turns in flight so that shutdown can wait for them.
+#[derive(Default)]
+struct TurnTracker {
+    active: AtomicUsize,
+    /// Set once shutdown begins; no new turns start afterwards.
+    closed: AtomicBool,
+    idle: tokio::sync::Notify,
+}
+
+impl TurnTracker {
+    /// Registers a turn, or returns `None` if shutdown has begun.
+    fn start(&self) -> Option<TurnGuard<'_>> {
+        // Counting before checking `closed` means `drain` either sees this
+        // turn or this turn sees `closed`.
+        self.active.fetch_add(1, Ordering::SeqCst);
+        let guard = TurnGuard(self);
+        (!self.closed.load(Ordering::SeqCst)).then_some(guard)
+    }
+
+    /// Stops new turns from starting and waits for the running ones.
+    async fn drain(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        loop {
+            let idle = self.idle.notified();
+            tokio::pin!(idle);
+            idle.as_mut().enable();
+            if self.active.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+            idle.await;
+        }
+    }
+}
+
+/// Marks a turn as finished when dropped.
+struct TurnGuard<'a>(&'a TurnTracker);
+
+impl Drop for TurnGuard<'_> {
+    fn drop(&mut self) {
+        if self.0.active.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // SessionsAPI
 // ─────────────────────────────────────────────────────────────────────────────
@@ -66,6 +159,37 @@ struct SessionState {
 /// [`SessionsPlugin::without_auto_checkpoint`] if unwanted. Checkpoints
 /// are stored in memory and are not persisted to the backing store.
 ///
+/// # Node Checkpoints
+///
+/// When enabled via [`SessionsPlugin::with_node_checkpoints`], the session's
+/// resources are saved to the [`SessionStore`] after every completed node,
+/// together with an [`ExecutionCursor`] marking where the turn stopped. If a
+/// turn fails or the process exits mid-turn,
+/// [`resume_turn`](Self::resume_turn) continues it from the last completed
+/// node instead of the graph entry. A turn that completes saves the session
+/// once more without a cursor. System outputs are saved in the cursor and
+/// restored on resume only for types registered with
+/// [`SessionsPlugin::with_checkpoint_output`].
+///
+/// # Interrupts
+///
+/// A turn whose graph reaches an interrupt node (see
+/// [`Graph::add_interrupt`]) returns [`TurnOutcome::AwaitingInput`] with the
+/// node's request instead of blocking. The session's resources, the cursor
+/// and the request are saved to the [`SessionStore`] and the context lock is
+/// released. [`resume_with_input`](Self::resume_with_input) stores the
+/// response as an output and continues the turn after the interrupt node.
+/// Until then, new turns fail with [`SessionError::AwaitingInput`];
+/// [`cancel_pending_input`](Self::cancel_pending_input) abandons the
+/// suspended turn instead.
+///
+/// # Shutdown
+///
+/// [`SessionsPlugin`] registers a shutdown hook with the server's run loop
+/// (see [`Server::serve`]). Once shutdown begins, new turns fail with
+/// [`SessionError::ShuttingDown`] and the loop waits for the turns already
+/// running before plugins are cleaned up.
+///
 /// # Interior Mutability
 ///
 /// All methods take `&self` and use internal locks for thread safety.
@@ -75,6 +199,10 @@ pub struct SessionsAPI {
     agents: RwLock<HashMap<AgentTypeId, Arc<dyn Agent>>>,
     sessions: RwLock<HashMap<SessionId, Arc<SessionState>>>,
     auto_checkpoint: AtomicBool,
+    node_checkpoints: AtomicBool,
+    /// Registers the output types saved in cursors on a turn's run options.
+    checkpoint_outputs: RwLock<Vec<fn(RunOptions) -> RunOptions>>,
+    turns: Arc<TurnTracker>,
 }
 
 impl API for SessionsAPI {}
@@ -82,7 +210,7 @@ impl API for SessionsAPI {}
 impl SessionsAPI {
     /// Creates a new sessions API with the given store backend.
     ///
-    /// Auto-checkpoint is enabled by default.
+    /// Auto-checkpoint is enabled by default; node checkpoints are disabled.
     pub fn new(store: Arc<dyn SessionStore>) -> Self {
         Self {
             store,
@@ -90,6 +218,9 @@ impl SessionsAPI {
             agents: RwLock::new(HashMap::new()),
             sessions: RwLock::new(HashMap::new()),
             auto_checkpoint: AtomicBool::new(true),
+            node_checkpoints: AtomicBool::new(false),
+            checkpoint_outputs: RwLock::new(Vec::new()),
+            turns: Arc::new(TurnTracker::default()),
         }
     }
 
@@ -105,6 +236,19 @@ impl SessionsAPI {
         self.auto_checkpoint.store(enabled, Ordering::Relaxed);
     }
 
+    /// Sets whether node checkpoints are persisted to the store during turns.
+    pub fn set_node_checkpoints(&self, enabled: bool) {
+        self.node_checkpoints.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Saves outputs of type `T` in execution cursors and restores them when
+    /// a turn is resumed.
+    pub fn add_checkpoint_output<T: Output + Serialize + DeserializeOwned>(&self) {
+        self.checkpoint_outputs
+            .write()
+            .push(RunOptions::with_checkpoint_output::<T>);
+    }
+
     // ─────────────────────────────────────────────────────────────────────
     // Agent registration
     // ─────────────────────────────────────────────────────────────────────
@@ -220,6 +364,7 @@ impl SessionsAPI {
             executor,
             agent_type: *agent_type,
             turn_number: AtomicU32::new(0),
+            awaiting_input: AtomicBool::new(false),
             checkpoints: parking_lot::Mutex::new(Vec::new()),
         });
 
@@ -236,15 +381,23 @@ impl SessionsAPI {
     /// context state after the turn completes. This does not block the
     /// return of the execution result.
     ///
+    /// If the graph stops at an interrupt node, the turn is saved to the
+    /// store and [`TurnOutcome::AwaitingInput`] is returned; the turn number
+    /// is not advanced until the turn completes.
+    ///
     /// # Errors
     ///
     /// Returns [`SessionError::SessionNotFound`] if the session does not exist,
-    /// or [`SessionError::Execution`] if the graph execution fails.
+    /// [`SessionError::ShuttingDown`] if the server is shutting down,
+    /// [`SessionError::AwaitingInput`] if a turn is waiting for input,
+    /// [`SessionError::BudgetExceeded`] if the session or its tenant has spent
+    /// its [budget](crate::usage), or [`SessionError::Execution`] if the graph
+    /// execution fails.
     pub async fn process_turn(
         &self,
         server: &Server,
         id: &SessionId,
-    ) -> Result<ExecutionResult, SessionError> {
+    ) -> Result<TurnOutcome, SessionError> {
         self.process_turn_with(server, id, |_| {}).await
     }
 
@@ -261,16 +414,49 @@ impl SessionsAPI {
     /// # Errors
     ///
     /// Returns [`SessionError::SessionNotFound`] if the session does not exist,
-    /// or [`SessionError::Execution`] if the graph execution fails.
+    /// [`SessionError::ShuttingDown`] if the server is shutting down,
+    /// [`SessionError::AwaitingInput`] if a turn is waiting for input, or
+    /// [`SessionError::Execution`] if the graph execution fails.
     pub async fn process_turn_with(
         &self,
         server: &Server,
         id: &SessionId,
         setup: impl FnOnce(&mut SystemContext<'static>),
-    ) -> Result<ExecutionResult, SessionError> {
+    ) -> Result<TurnOutcome, SessionError> {
+        self.process_turn_with_cancellation(server, id, &CancellationToken::new(), setup)
+            .await
+    }
+
+    /// Executes a single turn for the session that can be stopped through a
+    /// [`CancellationToken`].
+    ///
+    /// Behaves like [`process_turn_with`](Self::process_turn_with), but the
+    /// graph is executed with
+    /// [`GraphExecutor::execute_with_cancellation`]. A cancelled turn fires
+    /// `OnGraphCancelled` hooks, does not advance the turn number, and is not
+    /// checkpointed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SessionError::SessionNotFound`] if the session does not exist,
+    /// [`SessionError::ShuttingDown`] if the server is shutting down,
+    /// [`SessionError::AwaitingInput`] if a turn is waiting for input, or
+    /// [`SessionError::Execution`] if the graph execution fails or is
+    /// cancelled ([`ExecutionError::Cancelled`](polaris_graph::ExecutionError::Cancelled)).
+    pub async fn process_turn_with_cancellation(
+        &self,
+        server: &Server,
+        id: &SessionId,
+        cancel: &CancellationToken,
+        setup: impl FnOnce(&mut SystemContext<'static>),
+    ) -> Result<TurnOutcome, SessionError> {
+        let _turn = self.turns.start().ok_or(SessionError::ShuttingDown)?;
         let state = self.get_state(id)?;
 
         let mut ctx = state.ctx.lock().await;
+        if state.awaiting_input.load(Ordering::Acquire) {
+            return Err(SessionError::AwaitingInput(id.clone()));
+        }
         let turn = state.turn_number.load(Ordering::Acquire);
 
         // Inject session metadata.
@@ -281,38 +467,172 @@ impl SessionsAPI {
 
         setup(&mut ctx);
 
-        let hooks = server.api::<HooksAPI>();
-        let result = state
-            .executor
-            .execute(&state.graph, &mut ctx, hooks)
-            .await?;
+        self.run_turn(
+            server,
+            id,
+            &state,
+            &mut ctx,
+            turn,
+            RunOptions::new().with_cancellation(cancel.clone()),
+        )
+        .await
+    }
 
-        state.turn_number.store(turn + 1, Ordering::Release);
+    /// Resumes a turn that stopped mid-graph from its last node checkpoint.
+    ///
+    /// See [`resume_turn_with`](Self::resume_turn_with). A turn waiting for
+    /// input is not run; its request is returned again as
+    /// [`TurnOutcome::AwaitingInput`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SessionError::NoPendingTurn`] if the store holds no cursor
+    /// for the session, or any error of
+    /// [`process_turn`](Self::process_turn).
+    pub async fn resume_turn(
+        &self,
+        server: &Server,
+        id: &SessionId,
+    ) -> Result<TurnOutcome, SessionError> {
+        self.resume_turn_with(server, id, |_| {}).await
+    }
 
-        // Auto-checkpoint: serialize while we still hold the lock
-        // TODO @localminimum: look into doing this in a background task
-        // to avoid any potential latency impact on the turn result.
-        // Get profiling data to see if this is actually a problem
-        // worth optimizing.
-        if self.auto_checkpoint.load(Ordering::Relaxed) {
-            let serializers = self.serializers.read().clone();
-            match serialize_context(&serializers, state.agent_type, turn, &ctx) {
-                Ok(data) => {
-                    state.checkpoints.lock().push(Checkpoint {
-                        turn_number: turn,
-                        data,
-                    });
-                }
-                Err(err) => {
-                    tracing::warn!(
-                        session = %id,
-                        "auto-checkpoint failed: {err}"
-                    );
-                }
-            }
+    /// Answers a turn waiting at an interrupt node and continues it.
+    ///
+    /// Loads the suspended turn from the store, restores its resources into
+    /// the live context, and resumes at the interrupt node with `response`,
+    /// which is stored as the node's response output. The session must be
+    /// live; after a restart, call [`resume_session`](Self::resume_session)
+    /// first. [`SessionInfo`] is injected before execution.
+    ///
+    /// The continued turn may reach another interrupt and return
+    /// [`TurnOutcome::AwaitingInput`] again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SessionError::NoPendingInput`] if no turn is waiting for
+    /// input, [`SessionError::InvalidInput`] if `response` cannot be
+    /// serialized, or any error of [`process_turn`](Self::process_turn),
+    /// including [`ExecutionError::InterruptFailed`] if the response does not
+    /// match the node's response type.
+    pub async fn resume_with_input<T: Serialize>(
+        &self,
+        server: &Server,
+        id: &SessionId,
+        response: &T,
+    ) -> Result<TurnOutcome, SessionError> {
+        let response = serde_json::to_value(response).map_err(SessionError::InvalidInput)?;
+        self.resume_from_store(server, id, Some(response), |_| {})
+            .await
+    }
+
+    /// Abandons the turn waiting for input, so that the next turn can start.
+    ///
+    /// The suspended turn's cursor and request are removed from the store.
+    /// Resources it changed before reaching the interrupt keep their values,
+    /// and the turn number is not advanced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SessionError::SessionNotFound`] if the session does not
+    /// exist, [`SessionError::NoPendingInput`] if no turn is waiting for
+    /// input, or a persistence error if the session cannot be saved.
+    pub async fn cancel_pending_input(&self, id: &SessionId) -> Result<(), SessionError> {
+        let state = self.get_state(id)?;
+        let ctx = state.ctx.lock().await;
+        if !state.awaiting_input.load(Ordering::Acquire) {
+            return Err(SessionError::NoPendingInput(id.clone()));
         }
+        let turn = state.turn_number.load(Ordering::Acquire);
+        let serializers = self.serializers.read().clone();
+        let data = serialize_context(&serializers, state.agent_type, turn, &ctx)?;
+        self.store.save(id, &data).await?;
+        state.awaiting_input.store(false, Ordering::Release);
+        Ok(())
+    }
 
-        Ok(result)
+    /// Resumes a turn that stopped mid-graph with a setup closure.
+    ///
+    /// Loads the last node checkpoint from the store, restores its resources
+    /// into the live context, and continues the turn from the recorded
+    /// [`ExecutionCursor`] rather than the graph entry. The session must be
+    /// live; after a restart, call [`resume_session`](Self::resume_session)
+    /// first. As with [`process_turn_with`](Self::process_turn_with),
+    /// [`SessionInfo`] is injected and `setup` is called before execution.
+    ///
+    /// Requires node checkpoints to be enabled via
+    /// [`SessionsPlugin::with_node_checkpoints`]. A turn waiting for input
+    /// is not run; its request is returned again as
+    /// [`TurnOutcome::AwaitingInput`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SessionError::SessionNotFound`] if the session does not
+    /// exist, [`SessionError::NoPendingTurn`] if the store holds no cursor
+    /// for the session, [`SessionError::ShuttingDown`] if the server is
+    /// shutting down, or [`SessionError::Execution`] if the cursor does not
+    /// match the session's graph or the resumed execution fails.
+    pub async fn resume_turn_with(
+        &self,
+        server: &Server,
+        id: &SessionId,
+        setup: impl FnOnce(&mut SystemContext<'static>),
+    ) -> Result<TurnOutcome, SessionError> {
+        self.resume_from_store(server, id, None, setup).await
+    }
+
+    /// Continues the turn recorded in the store, answering its interrupt
+    /// with `response` if given.
+    async fn resume_from_store(
+        &self,
+        server: &Server,
+        id: &SessionId,
+        response: Option<serde_json::Value>,
+        setup: impl FnOnce(&mut SystemContext<'static>),
+    ) -> Result<TurnOutcome, SessionError> {
+        let _turn = self.turns.start().ok_or(SessionError::ShuttingDown)?;
+        let state = self.get_state(id)?;
+        let mut data = self
+            .store
+            .load(id)
+            .await?
+            .ok_or_else(|| SessionError::SessionNotFound(id.clone()))?;
+
+        let mut options = RunOptions::new();
+        match (data.pending_input.take(), response) {
+            (Some(request), None) => return Ok(TurnOutcome::AwaitingInput(request)),
+            (None, Some(_)) => return Err(SessionError::NoPendingInput(id.clone())),
+            (Some(_), Some(response)) => options = options.with_interrupt_response(response),
+            (None, None) => {}
+        }
+        let cursor = data
+            .cursor
+            .take()
+            .ok_or_else(|| SessionError::NoPendingTurn(id.clone()))?;
+
+        let mut ctx = state.ctx.lock().await;
+        let serializers = self.serializers.read().clone();
+        deserialize_into_context(&serializers, &data, &mut ctx)?;
+
+        let turn = data.turn_number;
+        state.turn_number.store(turn, Ordering::Release);
+
+        ctx.insert(SessionInfo {
+            session_id: id.clone(),
+            turn_number: turn,
+        });
+
+        setup(&mut ctx);
+
+        self.run_turn(
+            server,
+            id,
+            &state,
+            &mut ctx,
+            turn,
+            options.resume_from(cursor),
+        )
+        .await
     }
 
     // ─────────────────────────────────────────────────────────────────────
@@ -483,6 +803,7 @@ impl SessionsAPI {
             executor,
             agent_type,
             turn_number: AtomicU32::new(data.turn_number),
+            awaiting_input: AtomicBool::new(data.pending_input.is_some()),
             checkpoints: parking_lot::Mutex::new(Vec::new()),
         });
 
@@ -563,6 +884,136 @@ impl SessionsAPI {
     // Helpers
     // ─────────────────────────────────────────────────────────────────────
 
+    /// Executes the session graph for `turn` and records the outcome.
+    ///
+    /// Registers the checkpointed output types on `options`, adds the node
+    /// checkpoint sink when enabled, and a trace
+    /// recorder when [`TracePlugin`](polaris_graph::TracePlugin) is added.
+    /// The trace is saved as `{id}-{turn}` whatever the outcome, so a turn
+    /// that is run again overwrites the trace of its earlier run. When
+    /// [`UsagePlugin`](crate::UsagePlugin) is added, the turn is refused if
+    /// the session's budget is spent. The graph runs with the session set as
+    /// [current](SessionInfo::current) so its requests are attributed to it.
+    /// On success, advances the turn number, takes the auto-checkpoint, and
+    /// clears any persisted cursor. If the graph stops at an interrupt node,
+    /// saves the turn with its cursor and request instead.
+    async fn run_turn(
+        &self,
+        server: &Server,
+        id: &SessionId,
+        state: &SessionState,
+        ctx: &mut SystemContext<'static>,
+        turn: u32,
+        mut options: RunOptions,
+    ) -> Result<TurnOutcome, SessionError> {
+        if let Some(usage) = server.api::<UsageAPI>() {
+            usage.check_budget(id)?;
+        }
+
+        for register in self.checkpoint_outputs.read().iter() {
+            options = register(options);
+        }
+
+        let node_checkpoints = self.node_checkpoints.load(Ordering::Relaxed);
+        if node_checkpoints {
+            options = options.with_checkpoints(Arc::new(StoreCheckpoint {
+                store: Arc::clone(&self.store),
+                id: id.clone(),
+                serializers: self.serializers.read().clone(),
+                agent_type: state.agent_type,
+                turn_number: turn,
+            }));
+        }
+
+        let trace = server.api::<TraceAPI>();
+        let recorder = trace.map(TraceAPI::recorder);
+        if let Some(recorder) = &recorder {
+            options = options.with_recorder(recorder.clone());
+        }
+
+        let hooks = server.api::<HooksAPI>();
+        let info = SessionInfo {
+            session_id: id.clone(),
+            turn_number: turn,
+        };
+        let result = info
+            .scope(
+                state
+                    .executor
+                    .execute_with(&state.graph, ctx, hooks, options),
+            )
+            .await;
+
+        if let (Some(trace), Some(recorder)) = (trace, recorder)
+            && let Err(err) = trace.save(&format!("{id}-{turn}"), &recorder.take())
+        {
+            tracing::warn!(session = %id, turn, "failed to save trace: {err}");
+        }
+
+        let result = match result {
+            Ok(result) => result,
+            Err(ExecutionError::Interrupted(interrupt)) => {
+                return self.suspend_turn(id, state, ctx, turn, *interrupt).await;
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        state.turn_number.store(turn + 1, Ordering::Release);
+
+        // Auto-checkpoint: serialize while we still hold the lock
+        // TODO @localminimum: look into doing this in a background task
+        // to avoid any potential latency impact on the turn result.
+        // Get profiling data to see if this is actually a problem
+        // worth optimizing.
+        if self.auto_checkpoint.load(Ordering::Relaxed) {
+            let serializers = self.serializers.read().clone();
+            match serialize_context(&serializers, state.agent_type, turn, ctx) {
+                Ok(data) => {
+                    state.checkpoints.lock().push(Checkpoint {
+                        turn_number: turn,
+                        data,
+                    });
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        session = %id,
+                        "auto-checkpoint failed: {err}"
+                    );
+                }
+            }
+        }
+
+        // Overwrite the last node checkpoint or interrupt so the completed
+        // turn is not resumed again.
+        if state.awaiting_input.swap(false, Ordering::AcqRel) || node_checkpoints {
+            let serializers = self.serializers.read().clone();
+            let data = serialize_context(&serializers, state.agent_type, turn + 1, ctx)?;
+            self.store.save(id, &data).await?;
+        }
+
+        Ok(TurnOutcome::Completed(result))
+    }
+
+    /// Saves a turn stopped at an interrupt node so it can be resumed with
+    /// the response.
+    async fn suspend_turn(
+        &self,
+        id: &SessionId,
+        state: &SessionState,
+        ctx: &SystemContext<'static>,
+        turn: u32,
+        interrupt: Interrupt,
+    ) -> Result<TurnOutcome, SessionError> {
+        let serializers = self.serializers.read().clone();
+        let mut data = serialize_context(&serializers, state.agent_type, turn, ctx)?;
+        data.cursor = Some(interrupt.cursor);
+        data.pending_input = Some(interrupt.request.clone());
+        self.store.save(id, &data).await?;
+        state.awaiting_input.store(true, Ordering::Release);
+
+        Ok(TurnOutcome::AwaitingInput(interrupt.request))
+    }
+
     /// Looks up a live session by ID.
     fn get_state(&self, id: &SessionId) -> Result<Arc<SessionState>, SessionError> {
         self.sessions
@@ -599,6 +1050,8 @@ fn serialize_context(
         agent_type: agent_type.as_str().to_owned(),
         turn_number,
         resources,
+        cursor: None,
+        pending_input: None,
     })
 }
 
@@ -634,9 +1087,17 @@ fn deserialize_into_context(
 /// By default, a background checkpoint is created after every successful
 /// [`process_turn`](SessionsAPI::process_turn). Call
 /// [`without_auto_checkpoint`](Self::without_auto_checkpoint) to disable.
+///
+/// # Node Checkpoints
+///
+/// Call [`with_node_checkpoints`](Self::with_node_checkpoints) to persist the
+/// session after every completed node so interrupted turns can be continued
+/// with [`SessionsAPI::resume_turn`].
 pub struct SessionsPlugin {
     store: Arc<dyn SessionStore>,
     auto_checkpoint: bool,
+    node_checkpoints: bool,
+    checkpoint_outputs: Vec<fn(&SessionsAPI)>,
 }
 
 impl SessionsPlugin {
@@ -647,6 +1108,8 @@ impl SessionsPlugin {
         Self {
             store,
             auto_checkpoint: true,
+            node_checkpoints: false,
+            checkpoint_outputs: Vec::new(),
         }
     }
 
@@ -656,6 +1119,28 @@ impl SessionsPlugin {
         self.auto_checkpoint = false;
         self
     }
+
+    /// Persists the session to the store after every completed node.
+    ///
+    /// Each save includes the execution cursor of the running turn, which
+    /// [`SessionsAPI::resume_turn`] uses to continue an interrupted turn.
+    #[must_use]
+    pub fn with_node_checkpoints(mut self) -> Self {
+        self.node_checkpoints = true;
+        self
+    }
+
+    /// Saves outputs of type `T` in the cursor of node checkpoints and
+    /// interrupted turns, and restores them when the turn is resumed.
+    ///
+    /// Outputs of unregistered types are lost when a turn is resumed, so
+    /// nodes after the cursor cannot read them.
+    #[must_use]
+    pub fn with_checkpoint_output<T: Output + Serialize + DeserializeOwned>(mut self) -> Self {
+        self.checkpoint_outputs
+            .push(SessionsAPI::add_checkpoint_output::<T>);
+        self
+    }
 }
 
 impl Plugin for SessionsPlugin {
@@ -665,7 +1150,16 @@ impl Plugin for SessionsPlugin {
     fn build(&self, server: &mut Server) {
         let api = SessionsAPI::new(Arc::clone(&self.store));
         api.set_auto_checkpoint(self.auto_checkpoint);
+        api.set_node_checkpoints(self.node_checkpoints);
+        for register in &self.checkpoint_outputs {
+            register(&api);
+        }
+        let turns = Arc::clone(&api.turns);
         server.insert_api(api);
+        server.on_shutdown(move || {
+            let turns = Arc::clone(&turns);
+            async move { turns.drain().await }
+        });
     }
 
     fn ready(&self, server: &mut Server) {
@@ -678,7 +1172,7 @@ impl Plugin for SessionsPlugin {
         sessions.set_serializers(persistence.serializers());
     }
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<PersistencePlugin>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<PersistencePlugin>()]
     }
 }
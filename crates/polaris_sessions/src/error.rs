@@ -1,6 +1,7 @@
 //! Error types for session operations.
 
 use crate::store::SessionId;
+use crate::usage::BudgetExceeded;
 use polaris_agent::SetupError;
 use polaris_graph::ValidationResult;
 
@@ -27,6 +28,32 @@ pub enum SessionError {
     #[error("agent not found: {0}")]
     AgentNotFound(String),
 
+    /// The session has no interrupted turn recorded in the store.
+    #[error("no interrupted turn to resume: {0}")]
+    NoPendingTurn(SessionId),
+
+    /// The session has no turn waiting for input.
+    #[error("no turn awaiting input: {0}")]
+    NoPendingInput(SessionId),
+
+    /// The session has a turn waiting for input, so a new turn cannot start
+    /// until it is resumed or its input is cancelled.
+    #[error("turn awaiting input: {0}")]
+    AwaitingInput(SessionId),
+
+    /// The response to an interrupt could not be serialized.
+    #[error("invalid input: {0}")]
+    InvalidInput(#[source] serde_json::Error),
+
+    /// The server is shutting down and no longer starts turns.
+    #[error("server is shutting down")]
+    ShuttingDown,
+
+    /// The session or its tenant has spent its budget, so the turn was not
+    /// started.
+    #[error("budget exceeded: {0}")]
+    BudgetExceeded(#[from] BudgetExceeded),
+
     /// No checkpoint exists for the given turn number.
     #[error("turn not found: {0}")]
     TurnNotFound(u32),
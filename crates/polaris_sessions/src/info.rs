@@ -2,6 +2,12 @@
 
 use crate::store::SessionId;
 use polaris_system::resource::LocalResource;
+use std::future::Future;
+
+tokio::task_local! {
+    /// Session and turn being executed on the current task.
+    static CURRENT: SessionInfo;
+}
 
 /// Metadata about the current session, injected into the context
 /// at the start of each [`process_turn`](crate::api::SessionsAPI::process_turn).
@@ -17,3 +23,22 @@ pub struct SessionInfo {
 }
 
 impl LocalResource for SessionInfo {}
+
+impl SessionInfo {
+    /// Returns the session and turn being executed on the current task, if
+    /// any.
+    ///
+    /// Set while a turn's graph runs, so code without access to the context,
+    /// such as a [`UsageObserver`](polaris_models::llm::UsageObserver), can
+    /// attribute its work to the session. Returns `None` outside a turn and
+    /// in tasks spawned by a system.
+    #[must_use]
+    pub fn current() -> Option<SessionInfo> {
+        CURRENT.try_with(Clone::clone).ok()
+    }
+
+    /// Runs `future` with this session set as the current one.
+    pub(crate) async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT.scope(self, future).await
+    }
+}
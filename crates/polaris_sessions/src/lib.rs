@@ -3,7 +3,8 @@
 //! This crate provides server-managed sessions that own live
 //! [`SystemContext`](polaris_system::param::SystemContext) instances in memory.
 //! Sessions handle context creation, graph execution, checkpointing, and
-//! persistence through the [`SessionsAPI`].
+//! persistence through the [`SessionsAPI`]. The [`UsagePlugin`] accounts
+//! the tokens and cost of their LLM requests.
 //!
 //! # Quick Start
 //!
@@ -31,12 +32,16 @@ pub mod api;
 pub mod error;
 pub mod info;
 pub mod store;
+pub mod turn;
+pub mod usage;
 
 pub use api::{SessionsAPI, SessionsPlugin};
 pub use error::SessionError;
 pub use info::SessionInfo;
 pub use store::memory::InMemoryStore;
 pub use store::{AgentTypeId, ResourceEntry, SessionData, SessionId, SessionStore};
+pub use turn::TurnOutcome;
+pub use usage::{UsageAPI, UsageLedger, UsagePlugin};
 
 #[cfg(feature = "file-store")]
 pub use store::file::FileStore;
@@ -50,6 +55,10 @@ pub mod prelude {
     pub use crate::info::SessionInfo;
     pub use crate::store::memory::InMemoryStore;
     pub use crate::store::{AgentTypeId, ResourceEntry, SessionData, SessionId, SessionStore};
+    pub use crate::turn::TurnOutcome;
+    pub use crate::usage::{
+        BudgetExceeded, ModelPrice, PriceTable, UsageAPI, UsageLedger, UsagePlugin, UsageTotals,
+    };
 
     #[cfg(feature = "file-store")]
     pub use crate::store::file::FileStore;
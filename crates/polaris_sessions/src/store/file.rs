@@ -272,6 +272,8 @@ mod tests {
             agent_type: "TestAgent".into(),
             turn_number: 0,
             resources: vec![],
+            cursor: None,
+            pending_input: None,
         };
 
         store.save(&id, &data).await.unwrap();
@@ -305,6 +307,8 @@ mod tests {
             agent_type: "TestAgent".into(),
             turn_number: 0,
             resources: vec![],
+            cursor: None,
+            pending_input: None,
         };
 
         // Forward slash
@@ -335,6 +339,8 @@ mod tests {
             agent_type: "TestAgent".into(),
             turn_number: 0,
             resources: vec![],
+            cursor: None,
+            pending_input: None,
         };
 
         store
@@ -362,6 +368,8 @@ mod tests {
             agent_type: "TestAgent".into(),
             turn_number: 0,
             resources: vec![],
+            cursor: None,
+            pending_input: None,
         };
 
         // One real session
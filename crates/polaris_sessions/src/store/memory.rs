@@ -63,6 +63,8 @@ mod tests {
             agent_type: "TestAgent".into(),
             turn_number: 0,
             resources: vec![],
+            cursor: None,
+            pending_input: None,
         };
 
         store.save(&id, &data).await.unwrap();
@@ -87,6 +89,8 @@ mod tests {
             agent_type: "TestAgent".into(),
             turn_number: 0,
             resources: vec![],
+            cursor: None,
+            pending_input: None,
         };
 
         store.save(&id, &data).await.unwrap();
@@ -102,6 +106,8 @@ mod tests {
             agent_type: "TestAgent".into(),
             turn_number: 0,
             resources: vec![],
+            cursor: None,
+            pending_input: None,
         };
 
         let id1 = SessionId::from_string("a");
@@ -9,6 +9,7 @@ pub mod memory;
 pub mod file;
 
 use crate::error::SessionError;
+use polaris_graph::{ExecutionCursor, InterruptRequest};
 use polaris_system::system::BoxFuture;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -112,6 +113,16 @@ pub struct SessionData {
     pub turn_number: u32,
     /// Serialized resources from the session context.
     pub resources: Vec<ResourceEntry>,
+    /// Position of a turn interrupted mid-graph, recorded by node checkpoints.
+    ///
+    /// `None` when the session was saved between turns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<ExecutionCursor>,
+    /// Request of a turn suspended at an interrupt node, waiting for input.
+    ///
+    /// When set, `cursor` points at the interrupt node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_input: Option<InterruptRequest>,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
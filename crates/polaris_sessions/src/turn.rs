@@ -0,0 +1,39 @@
+//! Outcome of processing a session turn.
+
+use polaris_graph::{ExecutionResult, InterruptRequest};
+
+/// How a turn ended.
+///
+/// Returned by [`SessionsAPI::process_turn`](crate::api::SessionsAPI::process_turn)
+/// and the methods that resume a turn.
+#[derive(Debug)]
+pub enum TurnOutcome {
+    /// The graph ran to completion.
+    Completed(ExecutionResult),
+    /// The graph stopped at an interrupt node and is waiting for input.
+    ///
+    /// The turn is persisted to the session store and continues once the
+    /// response is passed to
+    /// [`SessionsAPI::resume_with_input`](crate::api::SessionsAPI::resume_with_input).
+    AwaitingInput(InterruptRequest),
+}
+
+impl TurnOutcome {
+    /// Returns the execution result if the turn completed.
+    #[must_use]
+    pub fn completed(self) -> Option<ExecutionResult> {
+        match self {
+            TurnOutcome::Completed(result) => Some(result),
+            TurnOutcome::AwaitingInput(_) => None,
+        }
+    }
+
+    /// Returns the pending request if the turn is waiting for input.
+    #[must_use]
+    pub fn awaiting_input(&self) -> Option<&InterruptRequest> {
+        match self {
+            TurnOutcome::Completed(_) => None,
+            TurnOutcome::AwaitingInput(request) => Some(request),
+        }
+    }
+}
@@ -0,0 +1,561 @@
+//! Token and cost accounting for LLM requests, with optional budgets.
+//!
+//! [`UsagePlugin`] observes every request made through a handle from the
+//! [`ModelRegistry`] and aggregates the reported [`Usage`] into a
+//! [`UsageLedger`]: per model, per session, and within a session per graph
+//! node and per turn. A [`PriceTable`] turns token counts into cost.
+//!
+//! The ledger is a global resource that systems read with
+//! `Res<UsageLedger>`. Server code reaches it through [`UsageAPI`], which
+//! also assigns sessions to tenants for tenant budgets.
+//!
+//! # Budgets
+//!
+//! With a session or tenant budget configured, a session whose spend
+//! reaches its budget, or whose tenant's spend reaches the tenant budget,
+//! is stopped:
+//!
+//! - [`SessionsAPI`](crate::SessionsAPI) refuses to start its turns with
+//!   [`SessionError::BudgetExceeded`](crate::SessionError::BudgetExceeded).
+//! - LLM requests made during a turn fail with
+//!   [`GenerationError::Rejected`](polaris_models::llm::GenerationError::Rejected)
+//!   wrapping a [`BudgetExceeded`], which fails the system making them.
+//!
+//! Budgets are checked before each request, so requests already in flight
+//! when a budget is reached still complete and are counted.
+//!
+//! # Example
+//!
+//! ```
+//! # use polaris_system::server::Server;
+//! # use polaris_models::ModelsPlugin;
+//! use polaris_sessions::usage::{ModelPrice, PriceTable, UsageAPI, UsagePlugin};
+//!
+//! let prices = PriceTable::new()
+//!     .with_price("anthropic/claude-sonnet-4-5", ModelPrice::new(3.0, 15.0))
+//!     .with_price("openai", ModelPrice::new(2.5, 10.0));
+//!
+//! let mut server = Server::new();
+//! server
+//!     .add_plugins(ModelsPlugin)
+//!     .add_plugins(
+//!         UsagePlugin::new(prices)
+//!             .with_session_budget(5.0)
+//!             .with_tenant_budget("acme", 100.0),
+//!     );
+//! server.run();
+//!
+//! let usage = server.api::<UsageAPI>().unwrap();
+//! println!("spent ${:.2}", usage.ledger().total().cost);
+//! ```
+
+use crate::info::SessionInfo;
+use crate::store::SessionId;
+use hashbrown::HashMap;
+use parking_lot::RwLock;
+use polaris_graph::executor::current_system;
+use polaris_graph::node::NodeId;
+use polaris_models::llm::{Usage, UsageObserver};
+use polaris_models::{ModelRegistry, ModelsPlugin};
+use polaris_system::api::API;
+use polaris_system::plugin::{Dependency, Plugin, PluginError, Version};
+use polaris_system::resource::GlobalResource;
+use polaris_system::server::Server;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Prices
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Price of a model in USD per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPrice {
+    /// Price of input tokens.
+    pub input: f64,
+    /// Price of output tokens.
+    pub output: f64,
+    /// Price of input tokens read from the prompt cache.
+    pub cache_read: f64,
+    /// Price of input tokens written to the prompt cache.
+    pub cache_write: f64,
+}
+
+impl ModelPrice {
+    /// Creates a price for input and output tokens, charging cache reads and
+    /// writes as input tokens.
+    #[must_use]
+    pub fn new(input: f64, output: f64) -> Self {
+        Self {
+            input,
+            output,
+            cache_read: input,
+            cache_write: input,
+        }
+    }
+
+    /// Sets the price of tokens read from the prompt cache.
+    #[must_use]
+    pub fn with_cache_read(mut self, cache_read: f64) -> Self {
+        self.cache_read = cache_read;
+        self
+    }
+
+    /// Sets the price of tokens written to the prompt cache.
+    #[must_use]
+    pub fn with_cache_write(mut self, cache_write: f64) -> Self {
+        self.cache_write = cache_write;
+        self
+    }
+
+    /// Returns the cost of `usage` in USD.
+    ///
+    /// Cache tokens are not part of `input_tokens` and are charged at the
+    /// cache prices on top of them.
+    #[must_use]
+    pub fn cost(&self, usage: &Usage) -> f64 {
+        let tokens = |count: Option<u64>| count.unwrap_or(0) as f64 / 1_000_000.0;
+        tokens(usage.input_tokens) * self.input
+            + tokens(usage.output_tokens) * self.output
+            + tokens(usage.cache_read_input_tokens) * self.cache_read
+            + tokens(usage.cache_creation_input_tokens) * self.cache_write
+    }
+}
+
+/// Prices of the models in use.
+///
+/// Keys are `"provider/model"` identifiers or provider names; a model
+/// without its own price uses its provider's. Requests to unpriced models
+/// are counted at no cost.
+#[derive(Debug, Clone, Default)]
+pub struct PriceTable {
+    prices: HashMap<String, ModelPrice>,
+}
+
+impl PriceTable {
+    /// Creates an empty price table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the price of a `"provider/model"` identifier or of every model
+    /// of a provider.
+    #[must_use]
+    pub fn with_price(mut self, key: impl Into<String>, price: ModelPrice) -> Self {
+        self.prices.insert(key.into(), price);
+        self
+    }
+
+    /// Returns the price of `model_id`, falling back to its provider's.
+    #[must_use]
+    pub fn price(&self, model_id: &str) -> Option<&ModelPrice> {
+        self.prices.get(model_id).or_else(|| {
+            let (provider, _) = model_id.split_once('/')?;
+            self.prices.get(provider)
+        })
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Totals
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Accumulated usage of a set of requests.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UsageTotals {
+    /// Number of requests.
+    pub requests: u64,
+    /// Input tokens.
+    pub input_tokens: u64,
+    /// Output tokens.
+    pub output_tokens: u64,
+    /// Input tokens read from the prompt cache.
+    pub cache_read_input_tokens: u64,
+    /// Input tokens written to the prompt cache.
+    pub cache_creation_input_tokens: u64,
+    /// Cost in USD according to the [`PriceTable`].
+    pub cost: f64,
+}
+
+impl UsageTotals {
+    /// Returns the input and output tokens together.
+    #[must_use]
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+
+    fn add(&mut self, usage: &Usage, cost: f64) {
+        self.requests += 1;
+        self.input_tokens += usage.input_tokens.unwrap_or(0);
+        self.output_tokens += usage.output_tokens.unwrap_or(0);
+        self.cache_read_input_tokens += usage.cache_read_input_tokens.unwrap_or(0);
+        self.cache_creation_input_tokens += usage.cache_creation_input_tokens.unwrap_or(0);
+        self.cost += cost;
+    }
+}
+
+/// Usage of one session, broken down by model, graph node and turn.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionUsage {
+    /// Usage of the whole session.
+    pub total: UsageTotals,
+    /// Usage per `"provider/model"` identifier.
+    pub models: HashMap<String, UsageTotals>,
+    /// Usage per system node making the requests.
+    pub nodes: HashMap<NodeId, NodeUsage>,
+    /// Usage per turn number.
+    pub turns: BTreeMap<u32, UsageTotals>,
+}
+
+/// Usage of one system node within a session.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NodeUsage {
+    /// Name of the node's system, for display. Several nodes may run the
+    /// same system.
+    pub name: &'static str,
+    /// Usage of the node's requests.
+    pub usage: UsageTotals,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Budgets
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// What a budget applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BudgetScope {
+    /// A single session.
+    Session(SessionId),
+    /// Every session assigned to a tenant.
+    Tenant(String),
+}
+
+impl fmt::Display for BudgetScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Session(id) => write!(f, "session '{id}'"),
+            Self::Tenant(tenant) => write!(f, "tenant '{tenant}'"),
+        }
+    }
+}
+
+/// A session or tenant has spent its budget.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("{scope} has spent ${spent:.4} of its ${limit:.4} budget")]
+pub struct BudgetExceeded {
+    /// The session or tenant over budget.
+    pub scope: BudgetScope,
+    /// The budget in USD.
+    pub limit: f64,
+    /// The amount spent in USD.
+    pub spent: f64,
+}
+
+/// Budgets in USD, and the tenants they are checked against.
+#[derive(Debug, Clone, Default)]
+struct Budgets {
+    session: Option<f64>,
+    tenants: HashMap<String, f64>,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Ledger
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Default)]
+struct LedgerState {
+    total: UsageTotals,
+    models: HashMap<String, UsageTotals>,
+    sessions: HashMap<SessionId, SessionUsage>,
+    tenants: HashMap<String, UsageTotals>,
+    session_tenants: HashMap<SessionId, String>,
+}
+
+#[derive(Debug)]
+struct LedgerInner {
+    prices: PriceTable,
+    budgets: Budgets,
+    state: RwLock<LedgerState>,
+}
+
+/// Usage and cost of the LLM requests made on the server.
+///
+/// Inserted as a global resource by [`UsagePlugin`]. Clones share the same
+/// totals. Requests made outside a session turn count towards the server
+/// and model totals only, and requests made outside a system node are not
+/// attributed to a node.
+#[derive(Debug, Clone)]
+pub struct UsageLedger {
+    inner: Arc<LedgerInner>,
+}
+
+impl GlobalResource for UsageLedger {}
+
+impl UsageLedger {
+    fn new(prices: PriceTable, budgets: Budgets) -> Self {
+        Self {
+            inner: Arc::new(LedgerInner {
+                prices,
+                budgets,
+                state: RwLock::new(LedgerState::default()),
+            }),
+        }
+    }
+
+    /// Returns the price table costs are computed with.
+    #[must_use]
+    pub fn prices(&self) -> &PriceTable {
+        &self.inner.prices
+    }
+
+    /// Returns the usage of every request.
+    #[must_use]
+    pub fn total(&self) -> UsageTotals {
+        self.inner.state.read().total
+    }
+
+    /// Returns the usage of a `"provider/model"` identifier.
+    #[must_use]
+    pub fn model(&self, model_id: &str) -> UsageTotals {
+        let state = self.inner.state.read();
+        state.models.get(model_id).copied().unwrap_or_default()
+    }
+
+    /// Returns the usage of every model, keyed by `"provider/model"`.
+    #[must_use]
+    pub fn models(&self) -> HashMap<String, UsageTotals> {
+        self.inner.state.read().models.clone()
+    }
+
+    /// Returns the usage of a session, or `None` if it made no requests.
+    #[must_use]
+    pub fn session(&self, id: &SessionId) -> Option<SessionUsage> {
+        self.inner.state.read().sessions.get(id).cloned()
+    }
+
+    /// Returns the usage of the sessions assigned to `tenant`.
+    #[must_use]
+    pub fn tenant(&self, tenant: &str) -> UsageTotals {
+        let state = self.inner.state.read();
+        state.tenants.get(tenant).copied().unwrap_or_default()
+    }
+
+    /// Adds `usage` of a request to `model_id`, attributing it to the
+    /// current session and node.
+    fn record(&self, model_id: &str, usage: &Usage) {
+        let cost = self
+            .inner
+            .prices
+            .price(model_id)
+            .map_or(0.0, |price| price.cost(usage));
+        let mut state = self.inner.state.write();
+        let state = &mut *state;
+        state.total.add(usage, cost);
+        state
+            .models
+            .entry_ref(model_id)
+            .or_default()
+            .add(usage, cost);
+
+        let Some(info) = SessionInfo::current() else {
+            return;
+        };
+        if let Some(tenant) = state.session_tenants.get(&info.session_id) {
+            state
+                .tenants
+                .entry_ref(tenant.as_str())
+                .or_default()
+                .add(usage, cost);
+        }
+        let session = state.sessions.entry(info.session_id).or_default();
+        session.total.add(usage, cost);
+        session
+            .models
+            .entry_ref(model_id)
+            .or_default()
+            .add(usage, cost);
+        session
+            .turns
+            .entry(info.turn_number)
+            .or_default()
+            .add(usage, cost);
+        if let Some(system) = current_system() {
+            session
+                .nodes
+                .entry(system.node_id)
+                .or_insert_with(|| NodeUsage {
+                    name: system.name,
+                    usage: UsageTotals::default(),
+                })
+                .usage
+                .add(usage, cost);
+        }
+    }
+
+    /// Checks the budgets of session `id` and its tenant.
+    fn check(&self, id: &SessionId) -> Result<(), BudgetExceeded> {
+        let budgets = &self.inner.budgets;
+        let state = self.inner.state.read();
+        if let Some(limit) = budgets.session {
+            let spent = state.sessions.get(id).map_or(0.0, |usage| usage.total.cost);
+            if spent >= limit {
+                return Err(BudgetExceeded {
+                    scope: BudgetScope::Session(id.clone()),
+                    limit,
+                    spent,
+                });
+            }
+        }
+        if let Some(tenant) = state.session_tenants.get(id)
+            && let Some(&limit) = budgets.tenants.get(tenant)
+        {
+            let spent = state.tenants.get(tenant).map_or(0.0, |usage| usage.cost);
+            if spent >= limit {
+                return Err(BudgetExceeded {
+                    scope: BudgetScope::Tenant(tenant.clone()),
+                    limit,
+                    spent,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reports the requests of every model handle to the ledger.
+struct LedgerObserver(UsageLedger);
+
+impl UsageObserver for LedgerObserver {
+    fn admit(&self, _model_id: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        match SessionInfo::current() {
+            Some(info) => self.0.check(&info.session_id).map_err(Into::into),
+            None => Ok(()),
+        }
+    }
+
+    fn record(&self, model_id: &str, usage: &Usage) {
+        self.0.record(model_id, usage);
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// UsageAPI
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// API for querying usage and managing tenants.
+///
+/// Inserted by [`UsagePlugin`]. [`SessionsAPI`](crate::SessionsAPI) uses it
+/// to check budgets before each turn.
+pub struct UsageAPI {
+    ledger: UsageLedger,
+}
+
+impl API for UsageAPI {}
+
+impl UsageAPI {
+    /// Returns the ledger holding the totals.
+    #[must_use]
+    pub fn ledger(&self) -> &UsageLedger {
+        &self.ledger
+    }
+
+    /// Assigns session `id` to `tenant`, replacing any earlier assignment.
+    ///
+    /// Only requests made after the assignment count towards the tenant.
+    pub fn assign_tenant(&self, id: &SessionId, tenant: impl Into<String>) {
+        let mut state = self.ledger.inner.state.write();
+        state.session_tenants.insert(id.clone(), tenant.into());
+    }
+
+    /// Returns the tenant session `id` is assigned to, if any.
+    #[must_use]
+    pub fn tenant_of(&self, id: &SessionId) -> Option<String> {
+        self.ledger
+            .inner
+            .state
+            .read()
+            .session_tenants
+            .get(id)
+            .cloned()
+    }
+
+    /// Checks that neither session `id` nor its tenant has spent its budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BudgetExceeded`] for the first budget that is spent.
+    pub fn check_budget(&self, id: &SessionId) -> Result<(), BudgetExceeded> {
+        self.ledger.check(id)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// UsagePlugin
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Plugin accounting the tokens and cost of LLM requests.
+///
+/// Registers a [`UsageObserver`] with the [`ModelRegistry`], and inserts the
+/// [`UsageLedger`] global resource and the [`UsageAPI`]. Requires
+/// [`ModelsPlugin`]. See the [module documentation](self) for budgets.
+#[derive(Debug, Clone, Default)]
+pub struct UsagePlugin {
+    prices: PriceTable,
+    budgets: Budgets,
+}
+
+impl UsagePlugin {
+    /// Creates a plugin computing costs with `prices`, without budgets.
+    #[must_use]
+    pub fn new(prices: PriceTable) -> Self {
+        Self {
+            prices,
+            budgets: Budgets::default(),
+        }
+    }
+
+    /// Limits the spend of every session to `limit` USD.
+    #[must_use]
+    pub fn with_session_budget(mut self, limit: f64) -> Self {
+        self.budgets.session = Some(limit);
+        self
+    }
+
+    /// Limits the spend of the sessions assigned to `tenant` to `limit` USD
+    /// in total.
+    ///
+    /// Sessions are assigned with [`UsageAPI::assign_tenant`].
+    #[must_use]
+    pub fn with_tenant_budget(mut self, tenant: impl Into<String>, limit: f64) -> Self {
+        self.budgets.tenants.insert(tenant.into(), limit);
+        self
+    }
+}
+
+impl Plugin for UsagePlugin {
+    const ID: &'static str = "polaris::usage";
+    const VERSION: Version = Version::new(0, 0, 1);
+
+    fn build(&self, server: &mut Server) {
+        if let Err(err) = self.try_build(server) {
+            panic!("{err}");
+        }
+    }
+
+    fn try_build(&self, server: &mut Server) -> Result<(), PluginError> {
+        let ledger = UsageLedger::new(self.prices.clone(), self.budgets.clone());
+        server
+            .get_resource_mut::<ModelRegistry>()
+            .ok_or_else(|| PluginError::other("UsagePlugin requires ModelsPlugin"))?
+            .add_usage_observer(Arc::new(LedgerObserver(ledger.clone())));
+        server.insert_global(ledger.clone());
+        server.insert_api(UsageAPI { ledger });
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ModelsPlugin>()]
+    }
+}
@@ -1,18 +1,30 @@
 //! Integration tests for [`SessionsAPI`].
 //!
 //! Verifies session lifecycle: multi-turn execution, checkpoint/rollback,
-//! save/resume, and session isolation.
+//! save/resume, session isolation, resuming interrupted turns, turns
+//! waiting for input, turn traces, draining turns on shutdown, and usage
+//! accounting with budgets.
 
+use async_trait::async_trait;
 use polaris_agent::Agent;
 use polaris_core_plugins::persistence::{PersistenceAPI, PersistencePlugin, Storable};
 use polaris_graph::graph::Graph;
+use polaris_graph::trace::{TraceAPI, TracePlugin};
+use polaris_graph::{CancellationToken, ExecutionError};
+use polaris_models::llm::{
+    AssistantBlock, GenerationError, LlmProvider, LlmRequest, LlmResponse, TextBlock, Usage,
+};
+use polaris_models::{ModelRegistry, ModelsPlugin};
 use polaris_sessions::store::memory::InMemoryStore;
 use polaris_sessions::store::{AgentTypeId, SessionId, SessionStore};
-use polaris_sessions::{SessionError, SessionsAPI, SessionsPlugin};
-use polaris_system::param::ResMut;
+use polaris_sessions::usage::{BudgetScope, ModelPrice, PriceTable, UsageAPI, UsagePlugin};
+use polaris_sessions::{SessionError, SessionsAPI, SessionsPlugin, TurnOutcome};
+use polaris_system::param::{Out, Res, ResMut};
+use polaris_system::plugin::{Dependency, Plugin, PluginError, Version};
 use polaris_system::resource::LocalResource;
 use polaris_system::server::Server;
 use polaris_system::system;
+use polaris_system::system::SystemError;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -46,10 +58,16 @@ impl Agent for CounterAgent {
 
 /// Builds a server with persistence + sessions (auto-checkpoint disabled).
 fn test_server(store: Arc<InMemoryStore>) -> Server {
+    test_server_with(store, |_| {})
+}
+
+/// Like [`test_server`], with extra plugins added by `add_plugins`.
+fn test_server_with(store: Arc<InMemoryStore>, add_plugins: impl FnOnce(&mut Server)) -> Server {
     let mut server = Server::new();
     server
         .add_plugins(PersistencePlugin)
         .add_plugins(SessionsPlugin::new(store).without_auto_checkpoint());
+    add_plugins(&mut server);
     server.finish();
 
     let persistence = server.api::<PersistenceAPI>().unwrap();
@@ -58,6 +76,144 @@ fn test_server(store: Arc<InMemoryStore>) -> Server {
     let sessions = server.api::<SessionsAPI>().unwrap();
     sessions.set_serializers(persistence.serializers());
     sessions.register_agent(CounterAgent).unwrap();
+    sessions.register_agent(ApprovalAgent).unwrap();
+
+    server
+}
+
+/// Request raised by [`ApprovalAgent`].
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Proposal {
+    step: u32,
+}
+
+/// Response expected by [`ApprovalAgent`].
+#[derive(Deserialize)]
+struct Approval {
+    step: u32,
+}
+
+#[system]
+async fn propose(counter: Res<Counter>) -> Proposal {
+    Proposal {
+        step: counter.value,
+    }
+}
+
+#[system]
+async fn apply(approval: Out<Approval>, mut counter: ResMut<Counter>) {
+    counter.value += approval.step;
+}
+
+/// Increments the counter, then waits for approval before applying a step.
+struct ApprovalAgent;
+
+impl Agent for ApprovalAgent {
+    fn build(&self, graph: &mut Graph) {
+        graph
+            .add_system(increment)
+            .add_system(propose)
+            .add_interrupt::<Proposal, Approval>("approve")
+            .add_system(apply);
+    }
+
+    fn name(&self) -> &'static str {
+        "ApprovalAgent"
+    }
+}
+
+#[system]
+async fn scale(proposal: Out<Proposal>, approval: Out<Approval>, mut counter: ResMut<Counter>) {
+    counter.value += proposal.step * approval.step;
+}
+
+/// Proposes a step, waits for approval, then reads both the proposal and
+/// the approval.
+struct ReviewAgent;
+
+impl Agent for ReviewAgent {
+    fn build(&self, graph: &mut Graph) {
+        graph
+            .add_system(propose)
+            .add_interrupt::<Proposal, Approval>("review")
+            .add_system(scale);
+    }
+
+    fn name(&self) -> &'static str {
+        "ReviewAgent"
+    }
+}
+
+/// Non-persisted switch that makes [`guard`] fail while set.
+struct Tripwire(bool);
+impl LocalResource for Tripwire {}
+
+#[system]
+async fn guard(tripwire: Res<Tripwire>) -> Result<(), SystemError> {
+    if tripwire.0 {
+        return Err(SystemError::ExecutionError("tripwire set".into()));
+    }
+    Ok(())
+}
+
+/// Increments the counter on both sides of a [`guard`].
+struct GuardedAgent;
+
+impl Agent for GuardedAgent {
+    fn build(&self, graph: &mut Graph) {
+        graph.add_system(increment);
+        graph.add_system(guard);
+        graph.add_system(increment);
+    }
+
+    fn name(&self) -> &'static str {
+        "GuardedAgent"
+    }
+}
+
+/// Non-persisted signals that let a test hold a turn inside [`hold`].
+#[derive(Default)]
+struct Gate {
+    entered: Arc<tokio::sync::Notify>,
+    release: Arc<tokio::sync::Notify>,
+}
+impl LocalResource for Gate {}
+
+#[system]
+async fn hold(gate: Res<Gate>) {
+    gate.entered.notify_one();
+    gate.release.notified().await;
+}
+
+/// Waits in [`hold`] until the test releases the turn.
+struct GateAgent;
+
+impl Agent for GateAgent {
+    fn build(&self, graph: &mut Graph) {
+        graph.add_system(hold);
+    }
+
+    fn name(&self) -> &'static str {
+        "GateAgent"
+    }
+}
+
+/// Builds a server with node checkpoints enabled and [`GuardedAgent`] registered.
+fn node_checkpoint_server(store: Arc<InMemoryStore>) -> Server {
+    let mut server = Server::new();
+    server.add_plugins(PersistencePlugin).add_plugins(
+        SessionsPlugin::new(store)
+            .without_auto_checkpoint()
+            .with_node_checkpoints(),
+    );
+    server.finish();
+
+    let persistence = server.api::<PersistenceAPI>().unwrap();
+    persistence.register::<Counter>("test");
+
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    sessions.set_serializers(persistence.serializers());
+    sessions.register_agent(GuardedAgent).unwrap();
 
     server
 }
@@ -83,6 +239,134 @@ async fn read_counter(store: &InMemoryStore, id: &SessionId) -> u32 {
     counter.value
 }
 
+/// Answers every request with 1,000 input and 500 output tokens.
+struct FixedUsageLlm;
+
+#[async_trait]
+impl LlmProvider for FixedUsageLlm {
+    async fn generate(
+        &self,
+        _model: &str,
+        _request: LlmRequest,
+    ) -> Result<LlmResponse, GenerationError> {
+        Ok(LlmResponse {
+            content: vec![AssistantBlock::Text(TextBlock::new("ok"))],
+            usage: Usage {
+                input_tokens: Some(1_000),
+                output_tokens: Some(500),
+                ..Usage::default()
+            },
+            stop_reason: None,
+        })
+    }
+}
+
+/// Registers [`FixedUsageLlm`] as the `mock` provider.
+struct MockModelsPlugin;
+
+impl Plugin for MockModelsPlugin {
+    const ID: &'static str = "test::mock_models";
+    const VERSION: Version = Version::new(0, 0, 1);
+
+    fn build(&self, server: &mut Server) {
+        if let Err(err) = self.try_build(server) {
+            panic!("{err}");
+        }
+    }
+
+    fn try_build(&self, server: &mut Server) -> Result<(), PluginError> {
+        server
+            .get_resource_mut::<ModelRegistry>()
+            .ok_or_else(|| PluginError::other("ModelsPlugin missing"))?
+            .try_register_llm_provider("mock", Arc::new(FixedUsageLlm))?;
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ModelsPlugin>()]
+    }
+}
+
+/// Sends one request to the `mock` provider.
+async fn generate(registry: &ModelRegistry) -> Result<(), SystemError> {
+    let llm = registry
+        .llm("mock/model")
+        .map_err(|err| SystemError::ExecutionError(err.to_string()))?;
+    llm.builder()
+        .user("hello")
+        .generate()
+        .await
+        .map_err(|err| SystemError::ExecutionError(err.to_string()))?;
+    Ok(())
+}
+
+#[system]
+async fn plan(registry: Res<ModelRegistry>) -> Result<(), SystemError> {
+    generate(&registry).await
+}
+
+#[system]
+async fn answer(registry: Res<ModelRegistry>) -> Result<(), SystemError> {
+    generate(&registry).await
+}
+
+/// Makes two LLM requests per turn, from different nodes.
+struct ChatAgent;
+
+impl Agent for ChatAgent {
+    fn build(&self, graph: &mut Graph) {
+        graph.add_system(plan).add_system(answer);
+    }
+
+    fn name(&self) -> &'static str {
+        "ChatAgent"
+    }
+}
+
+/// Makes its two LLM requests per turn from two nodes running the same
+/// system.
+struct RepeatAgent;
+
+impl Agent for RepeatAgent {
+    fn build(&self, graph: &mut Graph) {
+        graph.add_system(answer).add_system(answer);
+    }
+
+    fn name(&self) -> &'static str {
+        "RepeatAgent"
+    }
+}
+
+/// Builds a server accounting usage with `usage`, at $1 per million input
+/// tokens and $2 per million output tokens, so each request costs $0.002 and each turn $0.004.
+fn usage_server(usage: UsagePlugin) -> Server {
+    let server = test_server_with(Arc::new(InMemoryStore::new()), |server| {
+        server
+            .add_plugins(ModelsPlugin)
+            .add_plugins(MockModelsPlugin)
+            .add_plugins(usage);
+    });
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    sessions.register_agent(ChatAgent).unwrap();
+    sessions.register_agent(RepeatAgent).unwrap();
+    server
+}
+
+fn prices() -> PriceTable {
+    PriceTable::new().with_price("mock", ModelPrice::new(1.0, 2.0))
+}
+
+fn create_chat_session(server: &Server, id: &SessionId) {
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    sessions
+        .create_session(server, id, &AgentTypeId::from_name("ChatAgent"))
+        .unwrap();
+}
+
+fn assert_cost(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -105,6 +389,51 @@ async fn multi_turn_and_save() {
     assert_eq!(read_counter(&store, &id).await, 3);
 }
 
+/// Step size provided as an output by each turn's setup.
+struct Step(u32);
+
+#[system]
+async fn advance(step: Out<Step>, mut counter: ResMut<Counter>) {
+    counter.value += step.0;
+}
+
+/// Advances the counter by a step the caller provides.
+struct StepAgent;
+
+impl Agent for StepAgent {
+    fn build(&self, graph: &mut Graph) {
+        graph.add_system(advance);
+    }
+
+    fn name(&self) -> &'static str {
+        "StepAgent"
+    }
+}
+
+/// Agents may read outputs their graph does not produce, such as outputs
+/// inserted by turn setup.
+#[tokio::test]
+async fn agent_reading_setup_outputs_registers() {
+    let store = Arc::new(InMemoryStore::new());
+    let server = test_server(Arc::clone(&store));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    sessions.register_agent(StepAgent).unwrap();
+
+    let id = SessionId::new();
+    sessions
+        .create_session_with(&server, &id, &AgentTypeId::from_name("StepAgent"), |ctx| {
+            ctx.insert(Counter::default());
+        })
+        .unwrap();
+    sessions
+        .process_turn_with(&server, &id, |ctx| ctx.insert_output(Step(5)))
+        .await
+        .unwrap();
+
+    sessions.save_session(&id).await.unwrap();
+    assert_eq!(read_counter(&store, &id).await, 5);
+}
+
 /// Checkpoint captures state; rollback restores it.
 #[tokio::test]
 async fn checkpoint_and_rollback() {
@@ -211,3 +540,445 @@ async fn unregistered_agent_errors() {
         .unwrap_err();
     assert!(matches!(err, SessionError::AgentNotFound(_)));
 }
+
+/// A cancelled turn returns an error and leaves the turn number unchanged.
+#[tokio::test]
+async fn cancelled_turn_does_not_advance() {
+    let store = Arc::new(InMemoryStore::new());
+    let server = test_server(Arc::clone(&store));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+
+    create_test_session(&server, &id);
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let err = sessions
+        .process_turn_with_cancellation(&server, &id, &token, |_| {})
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        SessionError::Execution(ExecutionError::Cancelled)
+    ));
+
+    sessions.process_turn(&server, &id).await.unwrap();
+    assert_eq!(sessions.checkpoint(&id).await.unwrap(), 1);
+
+    sessions.save_session(&id).await.unwrap();
+    assert_eq!(read_counter(&store, &id).await, 1);
+}
+
+#[tokio::test]
+async fn resume_turn_continues_from_last_node() {
+    let store = Arc::new(InMemoryStore::new());
+    let server = node_checkpoint_server(Arc::clone(&store));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+
+    sessions
+        .create_session_with(
+            &server,
+            &id,
+            &AgentTypeId::from_name("GuardedAgent"),
+            |ctx| {
+                ctx.insert(Counter::default());
+            },
+        )
+        .unwrap();
+
+    let result = sessions
+        .process_turn_with(&server, &id, |ctx| ctx.insert(Tripwire(true)))
+        .await;
+    assert!(matches!(result, Err(SessionError::Execution(_))));
+
+    // The first increment was checkpointed along with the failing guard.
+    let data = store.load(&id).await.unwrap().unwrap();
+    assert!(data.cursor.is_some());
+    assert_eq!(data.turn_number, 0);
+    assert_eq!(read_counter(&store, &id).await, 1);
+
+    sessions
+        .resume_turn_with(&server, &id, |ctx| ctx.insert(Tripwire(false)))
+        .await
+        .unwrap();
+
+    // Restarting from the entry would have produced 3.
+    let data = store.load(&id).await.unwrap().unwrap();
+    assert!(
+        data.cursor.is_none(),
+        "completed turn should clear the cursor"
+    );
+    assert_eq!(data.turn_number, 1);
+    assert_eq!(read_counter(&store, &id).await, 2);
+}
+
+#[tokio::test]
+async fn resume_turn_without_pending_turn_errors() {
+    let store = Arc::new(InMemoryStore::new());
+    let server = node_checkpoint_server(Arc::clone(&store));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+
+    sessions
+        .create_session_with(
+            &server,
+            &id,
+            &AgentTypeId::from_name("GuardedAgent"),
+            |ctx| {
+                ctx.insert(Counter::default());
+                ctx.insert(Tripwire(false));
+            },
+        )
+        .unwrap();
+    sessions.process_turn(&server, &id).await.unwrap();
+
+    let err = sessions.resume_turn(&server, &id).await.unwrap_err();
+    assert!(matches!(err, SessionError::NoPendingTurn(_)));
+}
+
+/// Outputs produced before an interrupt are restored when the turn resumes
+/// if their type is registered for checkpoints.
+#[tokio::test]
+async fn interrupted_turn_restores_checkpointed_outputs() {
+    let store = Arc::new(InMemoryStore::new());
+    let server = test_server(Arc::clone(&store));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    sessions.add_checkpoint_output::<Proposal>();
+    sessions.register_agent(ReviewAgent).unwrap();
+    let id = SessionId::new();
+
+    sessions
+        .create_session_with(
+            &server,
+            &id,
+            &AgentTypeId::from_name("ReviewAgent"),
+            |ctx| {
+                ctx.insert(Counter { value: 5 });
+            },
+        )
+        .unwrap();
+
+    let outcome = sessions.process_turn(&server, &id).await.unwrap();
+    assert!(outcome.awaiting_input().is_some());
+    let data = store.load(&id).await.unwrap().unwrap();
+    assert_eq!(data.cursor.unwrap().outputs().len(), 1);
+
+    let outcome = sessions
+        .resume_with_input(&server, &id, &serde_json::json!({ "step": 2 }))
+        .await
+        .unwrap();
+    assert!(matches!(outcome, TurnOutcome::Completed(_)));
+    assert_eq!(read_counter(&store, &id).await, 15);
+}
+
+/// A turn reaching an interrupt is persisted with its request and resumed
+/// with the response.
+#[tokio::test]
+async fn interrupted_turn_resumes_with_input() {
+    let store = Arc::new(InMemoryStore::new());
+    let server = test_server(Arc::clone(&store));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+
+    sessions
+        .create_session_with(
+            &server,
+            &id,
+            &AgentTypeId::from_name("ApprovalAgent"),
+            |ctx| {
+                ctx.insert(Counter::default());
+            },
+        )
+        .unwrap();
+
+    let outcome = sessions.process_turn(&server, &id).await.unwrap();
+    let request = outcome.awaiting_input().expect("turn should await input");
+    assert_eq!(request.node_name, "approve");
+    assert_eq!(request.decode::<Proposal>().unwrap(), Proposal { step: 1 });
+
+    // The suspended turn is persisted without advancing the turn number.
+    let data = store.load(&id).await.unwrap().unwrap();
+    assert!(data.cursor.is_some());
+    assert_eq!(data.pending_input.as_ref(), Some(request));
+    assert_eq!(data.turn_number, 0);
+    assert_eq!(read_counter(&store, &id).await, 1);
+
+    // Resuming without input returns the same request.
+    let again = sessions.resume_turn(&server, &id).await.unwrap();
+    assert_eq!(again.awaiting_input(), Some(request));
+
+    let outcome = sessions
+        .resume_with_input(&server, &id, &serde_json::json!({ "step": 10 }))
+        .await
+        .unwrap();
+    assert!(matches!(outcome, TurnOutcome::Completed(_)));
+
+    let data = store.load(&id).await.unwrap().unwrap();
+    assert!(data.cursor.is_none());
+    assert!(data.pending_input.is_none());
+    assert_eq!(data.turn_number, 1);
+    assert_eq!(read_counter(&store, &id).await, 11);
+}
+
+/// A new turn is refused while one is waiting for input, until the pending
+/// input is cancelled.
+#[tokio::test]
+async fn process_turn_while_awaiting_input_errors() {
+    let store = Arc::new(InMemoryStore::new());
+    let server = test_server(Arc::clone(&store));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+
+    sessions
+        .create_session_with(
+            &server,
+            &id,
+            &AgentTypeId::from_name("ApprovalAgent"),
+            |ctx| {
+                ctx.insert(Counter::default());
+            },
+        )
+        .unwrap();
+    let outcome = sessions.process_turn(&server, &id).await.unwrap();
+    assert!(outcome.awaiting_input().is_some());
+
+    let err = sessions.process_turn(&server, &id).await.unwrap_err();
+    assert!(matches!(err, SessionError::AwaitingInput(_)), "{err}");
+    // The suspended turn is still stored.
+    let data = store.load(&id).await.unwrap().unwrap();
+    assert!(data.cursor.is_some());
+    assert!(data.pending_input.is_some());
+
+    sessions.cancel_pending_input(&id).await.unwrap();
+    let data = store.load(&id).await.unwrap().unwrap();
+    assert!(data.cursor.is_none());
+    assert!(data.pending_input.is_none());
+    let err = sessions.cancel_pending_input(&id).await.unwrap_err();
+    assert!(matches!(err, SessionError::NoPendingInput(_)), "{err}");
+
+    let outcome = sessions.process_turn(&server, &id).await.unwrap();
+    assert!(outcome.awaiting_input().is_some());
+}
+
+#[tokio::test]
+async fn resume_with_input_without_pending_input_errors() {
+    let store = Arc::new(InMemoryStore::new());
+    let server = test_server(Arc::clone(&store));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+
+    create_test_session(&server, &id);
+    sessions.process_turn(&server, &id).await.unwrap();
+    sessions.save_session(&id).await.unwrap();
+
+    let err = sessions
+        .resume_with_input(&server, &id, &serde_json::json!({ "step": 1 }))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, SessionError::NoPendingInput(_)));
+}
+
+/// With `TracePlugin`, every turn saves its trace under the session ID and
+/// turn number.
+#[tokio::test]
+async fn trace_plugin_saves_each_turn() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = test_server_with(Arc::new(InMemoryStore::new()), |server| {
+        server.add_plugins(TracePlugin::new(dir.path()));
+    });
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+
+    create_test_session(&server, &id);
+    sessions.process_turn(&server, &id).await.unwrap();
+    sessions.process_turn(&server, &id).await.unwrap();
+
+    let traces = server.api::<TraceAPI>().unwrap();
+    for turn in 0..2 {
+        let trace = traces.load(&format!("{id}-{turn}")).unwrap();
+        assert_eq!(trace.path().collect::<Vec<_>>(), ["increment"]);
+    }
+}
+
+/// When the run loop shuts down, running turns finish before it returns and
+/// new turns are rejected.
+#[tokio::test]
+async fn shutdown_drains_running_turns() {
+    let server = test_server(Arc::new(InMemoryStore::new()));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    sessions.register_agent(GateAgent).unwrap();
+
+    let gate = Gate::default();
+    let entered = Arc::clone(&gate.entered);
+    let release = Arc::clone(&gate.release);
+    let held = SessionId::new();
+    sessions
+        .create_session_with(
+            &server,
+            &held,
+            &AgentTypeId::from_name("GateAgent"),
+            |ctx| {
+                ctx.insert(gate);
+            },
+        )
+        .unwrap();
+    let other = SessionId::new();
+    create_test_session(&server, &other);
+
+    // Shutdown begins once the held turn is running.
+    let (report, turn, ()) = tokio::join!(
+        server.serve(entered.notified()),
+        sessions.process_turn(&server, &held),
+        async {
+            loop {
+                match sessions.process_turn(&server, &other).await {
+                    Err(SessionError::ShuttingDown) => break,
+                    result => {
+                        result.unwrap();
+                        tokio::task::yield_now().await;
+                    }
+                }
+            }
+            release.notify_one();
+        },
+    );
+
+    assert!(report.drained);
+    assert!(matches!(turn.unwrap(), TurnOutcome::Completed(_)));
+}
+
+/// Usage is aggregated per model, session, node and turn, and priced from
+/// the provider's entry in the price table.
+#[tokio::test]
+async fn usage_is_aggregated_per_model_session_node_and_turn() {
+    let server = usage_server(UsagePlugin::new(prices()));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+
+    create_chat_session(&server, &id);
+    sessions.process_turn(&server, &id).await.unwrap();
+    sessions.process_turn(&server, &id).await.unwrap();
+
+    let ledger = server.api::<UsageAPI>().unwrap().ledger();
+    let total = ledger.total();
+    assert_eq!(total.requests, 4);
+    assert_eq!(total.total_tokens(), 6_000);
+    assert_cost(total.cost, 0.008);
+    assert_eq!(ledger.model("mock/model"), total);
+
+    let session = ledger.session(&id).unwrap();
+    assert_eq!(session.total, total);
+    let mut nodes: Vec<_> = session
+        .nodes
+        .values()
+        .map(|node| (node.name, node.usage.requests))
+        .collect();
+    nodes.sort_unstable();
+    assert_eq!(nodes, [("answer", 2), ("plan", 2)]);
+    assert_eq!(session.turns.keys().copied().collect::<Vec<_>>(), [0, 1]);
+    assert_cost(session.turns[&1].cost, 0.004);
+}
+
+/// Cached input tokens are charged only at the cache read price. A prompt
+/// of 1,000 tokens with 400 cached, as the `openai` provider reports it, is
+/// 600 input tokens and 400 cache reads.
+#[test]
+fn cached_openai_usage_is_charged_once() {
+    let price = ModelPrice::new(1.0, 2.0).with_cache_read(0.1);
+    let usage = Usage {
+        input_tokens: Some(600),
+        output_tokens: Some(500),
+        cache_read_input_tokens: Some(400),
+        ..Usage::default()
+    };
+
+    assert_cost(price.cost(&usage), 0.001_64);
+}
+
+/// Nodes running the same system are accounted separately.
+#[tokio::test]
+async fn usage_is_aggregated_per_node_not_per_system() {
+    let server = usage_server(UsagePlugin::new(prices()));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+
+    sessions
+        .create_session(&server, &id, &AgentTypeId::from_name("RepeatAgent"))
+        .unwrap();
+    sessions.process_turn(&server, &id).await.unwrap();
+
+    let session = server
+        .api::<UsageAPI>()
+        .unwrap()
+        .ledger()
+        .session(&id)
+        .unwrap();
+    assert_eq!(session.nodes.len(), 2);
+    for node in session.nodes.values() {
+        assert_eq!(node.name, "answer");
+        assert_eq!(node.usage.requests, 1);
+    }
+}
+
+/// Once a session's spend reaches its budget, its next turn is refused.
+#[tokio::test]
+async fn session_budget_refuses_turns() {
+    let server = usage_server(UsagePlugin::new(prices()).with_session_budget(0.004));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+    let other = SessionId::new();
+
+    create_chat_session(&server, &id);
+    create_chat_session(&server, &other);
+    sessions.process_turn(&server, &id).await.unwrap();
+
+    let err = sessions.process_turn(&server, &id).await.unwrap_err();
+    let SessionError::BudgetExceeded(exceeded) = err else {
+        panic!("expected BudgetExceeded, got {err}");
+    };
+    assert_eq!(exceeded.scope, BudgetScope::Session(id.clone()));
+    assert_cost(exceeded.spent, 0.004);
+
+    // Other sessions have budgets of their own.
+    sessions.process_turn(&server, &other).await.unwrap();
+}
+
+/// A budget reached mid-turn rejects the turn's remaining requests.
+#[tokio::test]
+async fn session_budget_rejects_requests_mid_turn() {
+    let server = usage_server(UsagePlugin::new(prices()).with_session_budget(0.001));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let id = SessionId::new();
+
+    create_chat_session(&server, &id);
+    let err = sessions.process_turn(&server, &id).await.unwrap_err();
+
+    assert!(matches!(err, SessionError::Execution(_)), "{err}");
+    assert!(err.to_string().contains("request rejected"), "{err}");
+    let session = server.api::<UsageAPI>().unwrap().ledger().session(&id);
+    assert_eq!(session.unwrap().total.requests, 1);
+}
+
+/// A tenant budget is shared by every session assigned to the tenant.
+#[tokio::test]
+async fn tenant_budget_spans_sessions() {
+    let server = usage_server(UsagePlugin::new(prices()).with_tenant_budget("acme", 0.008));
+    let sessions = server.api::<SessionsAPI>().unwrap();
+    let usage = server.api::<UsageAPI>().unwrap();
+    let first = SessionId::new();
+    let second = SessionId::new();
+
+    for id in [&first, &second] {
+        create_chat_session(&server, id);
+        usage.assign_tenant(id, "acme");
+        sessions.process_turn(&server, id).await.unwrap();
+    }
+    assert_cost(usage.ledger().tenant("acme").cost, 0.008);
+
+    let err = sessions.process_turn(&server, &first).await.unwrap_err();
+    let SessionError::BudgetExceeded(exceeded) = err else {
+        panic!("expected BudgetExceeded, got {err}");
+    };
+    assert_eq!(exceeded.scope, BudgetScope::Tenant("acme".into()));
+}
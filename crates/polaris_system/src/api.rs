@@ -23,7 +23,7 @@
 ///
 /// ```
 /// use polaris_system::api::API;
-/// use polaris_system::plugin::{Plugin, PluginId, Version};
+/// use polaris_system::plugin::{Dependency, Plugin, Version};
 /// use polaris_system::server::Server;
 ///
 /// struct MyAPI;
@@ -48,8 +48,8 @@
 ///     const ID: &'static str = "consumer";
 ///     const VERSION: Version = Version::new(0, 0, 1);
 ///
-///     fn dependencies(&self) -> Vec<PluginId> {
-///         vec![PluginId::of::<MyAPIPlugin>()]
+///     fn dependencies(&self) -> Vec<Dependency> {
+///         vec![Dependency::of::<MyAPIPlugin>()]
 ///     }
 ///
 ///     fn build(&self, _server: &mut Server) {}
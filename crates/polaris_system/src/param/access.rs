@@ -48,6 +48,11 @@ pub struct Access {
     /// Global resources are read-only across all contexts, so they
     /// only conflict within the same context for write access.
     pub is_global: bool,
+    /// Whether the parameter tolerates the type being absent.
+    ///
+    /// Optional accesses (e.g. `Option<Out<T>>`) still take part in conflict
+    /// detection, but validation does not require the type to be present.
+    pub optional: bool,
 }
 
 impl Access {
@@ -59,6 +64,7 @@ impl Access {
             type_name: type_name::<T>(),
             mode: AccessMode::Read,
             is_global: false,
+            optional: false,
         }
     }
 
@@ -70,6 +76,7 @@ impl Access {
             type_name: type_name::<T>(),
             mode: AccessMode::Write,
             is_global: false,
+            optional: false,
         }
     }
 
@@ -79,6 +86,13 @@ impl Access {
         self.is_global = true;
         self
     }
+
+    /// Marks this access as optional.
+    #[must_use]
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
 }
 
 /// Aggregated access patterns for a system or parameter.
@@ -17,6 +17,14 @@
 //! | [`Out<T>`] | Read-only | Current context | Read the return value of a preceding system |
 //! | [`ErrOut<T>`] | Read-only | Current context | Read error context from a failed system on an
 //! error edge. The system must be reachable through an error edge from a fallible system |
+//! | [`ResOrDefault<T>`] | Read-only | Walks context hierarchy | Read a resource, or its [`Default`] when absent |
+//!
+//! `Option<Res<T>>`, `Option<ResMut<T>>` and `Option<Out<T>>` resolve to
+//! `None` when the value is absent instead of failing, so a system can adapt
+//! to whichever plugins a deployment includes. Optional parameters and
+//! [`ResOrDefault<T>`] still declare their access for conflict detection, but
+//! are marked [`optional`](Access::optional) so validation does not require
+//! the type to be present.
 //!
 //! # Conflict Detection
 //!
@@ -268,6 +276,22 @@ impl<'parent> SystemContext<'parent> {
         }
     }
 
+    /// Creates a root context that shares this context's globals but none of
+    /// its local resources, ancestors, or outputs.
+    ///
+    /// Unlike [`child`](Self::child), the returned context cannot read
+    /// resources from this context. Use it to run work in isolation and pass
+    /// state in and out explicitly.
+    #[must_use]
+    pub fn isolated(&self) -> SystemContext<'static> {
+        SystemContext {
+            parent: None,
+            globals: self.globals.clone(),
+            resources: Resources::new(),
+            outputs: Outputs::new(),
+        }
+    }
+
     /// Inserts a local resource into this context's scope.
     ///
     /// This resource will shadow any resource of the same type in parent scopes
@@ -297,6 +321,13 @@ impl<'parent> SystemContext<'parent> {
         self.resources.insert_boxed(type_id, resource);
     }
 
+    /// Removes a resource from this context's scope and returns it.
+    ///
+    /// Parent scopes and globals are not affected.
+    pub fn remove_resource<R: Resource>(&mut self) -> Option<R> {
+        self.resources.remove::<R>()
+    }
+
     // ─────────────────────────────────────────────────────────────────────
     // Resource methods (hierarchical lookup)
     // ─────────────────────────────────────────────────────────────────────
@@ -790,7 +821,124 @@ impl<'a, T: Output> SystemParam for Option<Out<'a, T>> {
     }
 
     fn access() -> SystemAccess {
-        <Out<'a, T> as SystemParam>::access()
+        let mut access = SystemAccess::new();
+        access.outputs.push(Access::read::<T>().optional());
+        access
+    }
+}
+
+/// Optional shared access to a resource.
+///
+/// Returns `None` if the resource doesn't exist instead of erroring.
+impl<'a, T: Resource> SystemParam for Option<Res<'a, T>> {
+    type Item<'w> = Option<Res<'w, T>>;
+
+    fn fetch<'w>(ctx: &'w SystemContext<'_>) -> Result<Self::Item<'w>, ParamError> {
+        match <Res<'a, T> as SystemParam>::fetch(ctx) {
+            Ok(res) => Ok(Some(res)),
+            Err(ParamError::ResourceNotFound(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn access() -> SystemAccess {
+        let mut access = SystemAccess::new();
+        access.resources.push(Access::read::<T>().optional());
+        access
+    }
+}
+
+/// Optional mutable access to a local resource.
+///
+/// Returns `None` if the resource doesn't exist in the current scope instead
+/// of erroring.
+impl<'a, T: LocalResource> SystemParam for Option<ResMut<'a, T>> {
+    type Item<'w> = Option<ResMut<'w, T>>;
+
+    fn fetch<'w>(ctx: &'w SystemContext<'_>) -> Result<Self::Item<'w>, ParamError> {
+        match <ResMut<'a, T> as SystemParam>::fetch(ctx) {
+            Ok(res) => Ok(Some(res)),
+            Err(ParamError::ResourceNotFound(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn access() -> SystemAccess {
+        let mut access = SystemAccess::new();
+        access.resources.push(Access::write::<T>().optional());
+        access
+    }
+}
+
+/// Shared access to a resource, falling back to its [`Default`] value.
+///
+/// `ResOrDefault<T>` resolves `T` like [`Res<T>`], but yields
+/// `T::default()` instead of failing when no scope holds the resource. Use it
+/// for configuration provided by optional plugins.
+///
+/// Implements [`Deref<Target = T>`](core::ops::Deref).
+///
+/// # Example
+///
+/// ```
+/// # use polaris_system::param::ResOrDefault;
+/// # use polaris_system::resource::GlobalResource;
+/// # use polaris_system::system;
+/// #[derive(Default)]
+/// struct TraceConfig {
+///     verbose: bool,
+/// }
+/// impl GlobalResource for TraceConfig {}
+///
+/// #[system]
+/// async fn report(config: ResOrDefault<TraceConfig>) -> String {
+///     if config.verbose { "verbose".into() } else { "quiet".into() }
+/// }
+/// ```
+pub struct ResOrDefault<'w, T: Resource + Default> {
+    inner: MaybeDefault<'w, T>,
+}
+
+enum MaybeDefault<'w, T: Resource> {
+    Found(ResourceRef<'w, T>),
+    Default(T),
+}
+
+impl<'w, T: Resource + Default> ResOrDefault<'w, T> {
+    /// Returns `true` if the resource was absent and the default is used.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        matches!(self.inner, MaybeDefault::Default(_))
+    }
+}
+
+impl<'w, T: Resource + Default> std::ops::Deref for ResOrDefault<'w, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        match &self.inner {
+            MaybeDefault::Found(inner) => inner,
+            MaybeDefault::Default(value) => value,
+        }
+    }
+}
+
+impl<'a, T: Resource + Default> SystemParam for ResOrDefault<'a, T> {
+    type Item<'w> = ResOrDefault<'w, T>;
+
+    fn fetch<'w>(ctx: &'w SystemContext<'_>) -> Result<Self::Item<'w>, ParamError> {
+        let inner = match ctx.get_resource::<T>() {
+            Ok(inner) => MaybeDefault::Found(inner),
+            Err(ParamError::ResourceNotFound(_)) => MaybeDefault::Default(T::default()),
+            Err(err) => return Err(err),
+        };
+        Ok(ResOrDefault { inner })
+    }
+
+    fn access() -> SystemAccess {
+        let mut access = SystemAccess::new();
+        access.resources.push(Access::read::<T>().optional());
+        access
     }
 }
 
@@ -830,7 +978,7 @@ mod tests {
     use super::*;
     use crate::resource::LocalResource;
 
-    #[derive(Debug, PartialEq)]
+    #[derive(Debug, Default, PartialEq)]
     struct Counter {
         value: i32,
     }
@@ -908,6 +1056,54 @@ mod tests {
         assert!(matches!(result, Err(ParamError::ResourceNotFound(_))));
     }
 
+    #[test]
+    fn optional_res_returns_none() {
+        let ctx = SystemContext::new();
+
+        assert!(Option::<Res<Counter>>::fetch(&ctx).unwrap().is_none());
+        assert!(Option::<ResMut<Counter>>::fetch(&ctx).unwrap().is_none());
+    }
+
+    #[test]
+    fn optional_res_returns_some() {
+        let ctx = SystemContext::new().with(Counter { value: 3 });
+        {
+            let mut res = Option::<ResMut<Counter>>::fetch(&ctx).unwrap().unwrap();
+            res.value += 1;
+        }
+
+        let res = Option::<Res<Counter>>::fetch(&ctx).unwrap();
+        assert_eq!(res.unwrap().value, 4);
+    }
+
+    #[test]
+    fn optional_res_reports_borrow_conflict() {
+        let ctx = SystemContext::new().with(Counter { value: 3 });
+        let _res_mut = ResMut::<Counter>::fetch(&ctx).unwrap();
+
+        let result = Option::<Res<Counter>>::fetch(&ctx);
+        assert!(matches!(result, Err(ParamError::BorrowConflict(_))));
+    }
+
+    #[test]
+    fn res_or_default_falls_back_to_default() {
+        let ctx = SystemContext::new();
+
+        let res = ResOrDefault::<Counter>::fetch(&ctx).unwrap();
+        assert!(res.is_default());
+        assert_eq!(res.value, 0);
+    }
+
+    #[test]
+    fn res_or_default_reads_resource() {
+        let parent = SystemContext::new().with(Counter { value: 7 });
+        let child = parent.child();
+
+        let res = ResOrDefault::<Counter>::fetch(&child).unwrap();
+        assert!(!res.is_default());
+        assert_eq!(res.value, 7);
+    }
+
     #[test]
     fn tuple_param_fetch() {
         let ctx = SystemContext::new()
@@ -992,6 +1188,32 @@ mod tests {
         assert_eq!(level2.get_resource::<Config>().unwrap().name, "level1");
     }
 
+    #[test]
+    fn isolated_context_hides_local_resources() {
+        let mut parent = SystemContext::new().with(Counter { value: 1 });
+        parent.insert_output(7_i32);
+        let isolated = parent.isolated();
+
+        assert!(!isolated.contains_resource::<Counter>());
+        assert!(!isolated.contains_output::<i32>());
+        assert!(isolated.parent().is_none());
+    }
+
+    #[test]
+    fn remove_resource_takes_local_only() {
+        let mut parent = SystemContext::new().with(Counter { value: 1 });
+        assert_eq!(
+            parent.remove_resource::<Counter>(),
+            Some(Counter { value: 1 })
+        );
+        assert!(!parent.contains_resource::<Counter>());
+
+        let parent = SystemContext::new().with(Counter { value: 2 });
+        let mut child = parent.child();
+        assert_eq!(child.remove_resource::<Counter>(), None);
+        assert!(child.contains_resource::<Counter>());
+    }
+
     // ─────────────────────────────────────────────────────────────────────
     // Output tests
     // ─────────────────────────────────────────────────────────────────────
@@ -1318,6 +1540,32 @@ mod tests {
         assert_eq!(access.outputs.len(), 1);
         assert_eq!(access.outputs[0].mode, AccessMode::Read);
         assert!(access.outputs[0].type_name.contains("ReasoningResult"));
+        assert!(!access.outputs[0].optional);
+    }
+
+    #[test]
+    fn optional_out_declares_optional_output_access() {
+        let access = <Option<Out<ReasoningResult>>>::access();
+        assert_eq!(access.outputs.len(), 1);
+        assert_eq!(access.outputs[0].mode, AccessMode::Read);
+        assert!(access.outputs[0].optional);
+    }
+
+    #[test]
+    fn optional_res_declares_optional_resource_access() {
+        let read = <Option<Res<Counter>>>::access();
+        assert_eq!(read.resources[0].mode, AccessMode::Read);
+        assert!(read.resources[0].optional);
+
+        let write = <Option<ResMut<Counter>>>::access();
+        assert_eq!(write.resources[0].mode, AccessMode::Write);
+        assert!(write.resources[0].optional);
+
+        let default = <ResOrDefault<Counter>>::access();
+        assert_eq!(default.resources[0].mode, AccessMode::Read);
+        assert!(default.resources[0].optional);
+
+        assert!(!<Res<Counter>>::access().resources[0].optional);
     }
 
     #[test]
@@ -0,0 +1,208 @@
+//! Errors raised while building plugins.
+
+use super::{PluginId, Version};
+
+/// Boxed error returned by a plugin that failed to build.
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A registration rejected because its name is already taken.
+///
+/// Returned by registries such as a tool or model provider registry, and
+/// reported by [`Server::try_finish`](crate::server::Server::try_finish) as
+/// [`BuildError::Conflict`] when a plugin's build fails with it.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{kind} '{name}' is already registered")]
+pub struct RegistrationConflict {
+    /// What was being registered (e.g. `"tool"`).
+    pub kind: &'static str,
+    /// The name that is already taken.
+    pub name: String,
+}
+
+impl RegistrationConflict {
+    /// Creates a conflict for `name` in a registry of `kind`.
+    #[must_use]
+    pub fn new(kind: &'static str, name: impl Into<String>) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+        }
+    }
+}
+
+/// Errors returned by [`Plugin::try_build`](super::Plugin::try_build) and
+/// [`Plugin::try_ready`](super::Plugin::try_ready).
+#[derive(Debug, thiserror::Error)]
+pub enum PluginError {
+    /// A registration conflicted with an earlier one.
+    #[error(transparent)]
+    Conflict(#[from] RegistrationConflict),
+
+    /// Any other failure, such as invalid configuration or a missing
+    /// credential.
+    #[error(transparent)]
+    Other(BoxError),
+}
+
+impl PluginError {
+    /// Wraps an error or message as [`PluginError::Other`].
+    pub fn other(err: impl Into<BoxError>) -> Self {
+        Self::Other(err.into())
+    }
+}
+
+/// Lifecycle phase in which a plugin failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildPhase {
+    /// [`Plugin::build`](super::Plugin::build).
+    Build,
+    /// [`Plugin::ready`](super::Plugin::ready).
+    Ready,
+}
+
+impl std::fmt::Display for BuildPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Build => f.write_str("build"),
+            Self::Ready => f.write_str("ready"),
+        }
+    }
+}
+
+/// A dependency declared by a plugin that was never added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingDependency {
+    /// The plugin declaring the dependency.
+    pub plugin: PluginId,
+    /// The plugin it requires.
+    pub dependency: PluginId,
+}
+
+/// A dependency whose added version does not meet the declared requirement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionMismatch {
+    /// The plugin declaring the dependency.
+    pub plugin: PluginId,
+    /// The plugin it requires.
+    pub dependency: PluginId,
+    /// The declared version requirement.
+    pub requirement: String,
+    /// The version of the dependency that was added.
+    pub found: Version,
+}
+
+/// Errors returned by [`Server::try_finish`](crate::server::Server::try_finish).
+#[derive(Debug, thiserror::Error)]
+pub enum BuildError {
+    /// The server has already been built.
+    #[error("Server::finish() was already called. Cannot build twice.")]
+    AlreadyBuilt,
+
+    /// Plugins require plugins that were not added.
+    #[error("{}", missing_message(.0))]
+    MissingDependencies(Vec<MissingDependency>),
+
+    /// Added plugins do not meet the version requirements of the plugins
+    /// depending on them.
+    #[error("{}", mismatch_message(.0))]
+    IncompatibleVersions(Vec<VersionMismatch>),
+
+    /// A plugin declared a version requirement that does not parse.
+    #[error(
+        "Plugin '{plugin}' declares an invalid version requirement '{requirement}' for '{dependency}': {reason}"
+    )]
+    InvalidVersionRequirement {
+        /// The plugin declaring the dependency.
+        plugin: PluginId,
+        /// The plugin it requires.
+        dependency: PluginId,
+        /// The requirement as declared.
+        requirement: String,
+        /// Why it does not parse.
+        reason: String,
+    },
+
+    /// Plugins depend on each other in a cycle.
+    ///
+    /// Each plugin in the path requires the next; the last entry repeats
+    /// the first.
+    #[error(
+        "Circular dependency detected among plugins: {}. Break the cycle by extracting shared functionality into a separate plugin.",
+        cycle_message(.0)
+    )]
+    CircularDependency(Vec<PluginId>),
+
+    /// A plugin's registration conflicted with an earlier one.
+    #[error("plugin '{plugin}' failed during {phase}: {conflict}")]
+    Conflict {
+        /// The plugin whose registration was rejected.
+        plugin: PluginId,
+        /// The phase in which it failed.
+        phase: BuildPhase,
+        /// The rejected registration.
+        conflict: RegistrationConflict,
+    },
+
+    /// A plugin failed to build or become ready.
+    #[error("plugin '{plugin}' failed during {phase}: {source}")]
+    Plugin {
+        /// The plugin that failed.
+        plugin: PluginId,
+        /// The phase in which it failed.
+        phase: BuildPhase,
+        /// The plugin's error.
+        source: BoxError,
+    },
+}
+
+impl BuildError {
+    /// Attributes an error returned by `plugin` during `phase`.
+    pub(crate) fn from_plugin(plugin: PluginId, phase: BuildPhase, err: PluginError) -> Self {
+        match err {
+            PluginError::Conflict(conflict) => Self::Conflict {
+                plugin,
+                phase,
+                conflict,
+            },
+            PluginError::Other(source) => Self::Plugin {
+                plugin,
+                phase,
+                source,
+            },
+        }
+    }
+}
+
+fn missing_message(missing: &[MissingDependency]) -> String {
+    missing
+        .iter()
+        .map(|missing| {
+            format!(
+                "Plugin '{}' requires '{}' which was not added. Add {} before {}, or use a plugin group that includes it.",
+                missing.plugin, missing.dependency, missing.dependency, missing.plugin
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn mismatch_message(mismatched: &[VersionMismatch]) -> String {
+    mismatched
+        .iter()
+        .map(|mismatch| {
+            format!(
+                "Plugin '{}' requires '{}' {}, but version {} was added.",
+                mismatch.plugin, mismatch.dependency, mismatch.requirement, mismatch.found
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn cycle_message(cycle: &[PluginId]) -> String {
+    cycle
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
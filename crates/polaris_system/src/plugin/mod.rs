@@ -7,7 +7,7 @@
 //! # Example
 //!
 //! ```
-//! use polaris_system::plugin::{Plugin, PluginId, Version};
+//! use polaris_system::plugin::{Dependency, Plugin, Version};
 //! use polaris_system::server::Server;
 //!
 //! struct MyPlugin {
@@ -35,8 +35,8 @@
 //!         });
 //!     }
 //!
-//!     fn dependencies(&self) -> Vec<PluginId> {
-//!         vec![PluginId::of::<TracingPlugin>()]
+//!     fn dependencies(&self) -> Vec<Dependency> {
+//!         vec![Dependency::of::<TracingPlugin>()]
 //!     }
 //! }
 //!
@@ -46,9 +46,13 @@
 //!     .run();
 //! ```
 
+mod error;
 mod schedule;
 
 use crate::server::Server;
+pub use error::{
+    BuildError, BuildPhase, MissingDependency, PluginError, RegistrationConflict, VersionMismatch,
+};
 pub use schedule::{IntoScheduleIds, Schedule, ScheduleId};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -155,6 +159,113 @@ impl std::fmt::Display for PluginId {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Dependency
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A plugin's dependency on another plugin, returned from
+/// [`Plugin::dependencies`].
+///
+/// By default the dependency must be added, in any version. A version
+/// requirement restricts the accepted versions of the dependency, and an
+/// optional dependency only orders the two plugins when the dependency is
+/// present. Both are checked when the server is built.
+///
+/// # Example
+///
+/// ```
+/// # use polaris_system::plugin::{Dependency, Plugin, Version};
+/// # use polaris_system::server::Server;
+/// # struct ModelsPlugin;
+/// # impl Plugin for ModelsPlugin {
+/// #     const ID: &'static str = "models";
+/// #     const VERSION: Version = Version::new(0, 2, 4);
+/// #     fn build(&self, _: &mut Server) {}
+/// # }
+/// # struct TracingPlugin;
+/// # impl Plugin for TracingPlugin {
+/// #     const ID: &'static str = "tracing";
+/// #     const VERSION: Version = Version::new(0, 0, 1);
+/// #     fn build(&self, _: &mut Server) {}
+/// # }
+/// let dependencies = vec![
+///     // Any 0.2 release of ModelsPlugin must be added.
+///     Dependency::of::<ModelsPlugin>().version(">=0.2, <0.3"),
+///     // TracingPlugin is built first if it was added.
+///     Dependency::of::<TracingPlugin>().optional(),
+/// ];
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    id: PluginId,
+    requirement: Option<String>,
+    optional: bool,
+}
+
+impl Dependency {
+    /// Creates a required dependency on the plugin with the given ID.
+    #[must_use]
+    pub fn new(id: PluginId) -> Self {
+        Self {
+            id,
+            requirement: None,
+            optional: false,
+        }
+    }
+
+    /// Creates a required dependency on plugin `P`.
+    #[must_use]
+    pub fn of<P: Plugin>() -> Self {
+        Self::new(PluginId::of::<P>())
+    }
+
+    /// Restricts the accepted versions of the dependency.
+    ///
+    /// `requirement` uses Cargo's syntax, such as `"0.2"`, `"^1.4"` or
+    /// `">=0.2, <0.3"`. A requirement that does not parse is reported when
+    /// the server is built.
+    #[must_use]
+    pub fn version(mut self, requirement: impl Into<String>) -> Self {
+        self.requirement = Some(requirement.into());
+        self
+    }
+
+    /// Makes the dependency optional.
+    ///
+    /// An optional dependency that was not added is ignored. When it is
+    /// added, it is built before the depending plugin and its version
+    /// requirement is checked.
+    #[must_use]
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Returns the ID of the plugin depended on.
+    #[must_use]
+    pub fn id(&self) -> &PluginId {
+        &self.id
+    }
+
+    /// Returns the version requirement, if any.
+    #[must_use]
+    pub fn requirement(&self) -> Option<&str> {
+        self.requirement.as_deref()
+    }
+
+    /// Returns whether the dependency is optional.
+    #[must_use]
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+}
+
+impl From<PluginId> for Dependency {
+    fn from(id: PluginId) -> Self {
+        Self::new(id)
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Plugin Trait
 // ─────────────────────────────────────────────────────────────────────────────
@@ -171,7 +282,7 @@ impl std::fmt::Display for PluginId {
 /// # Example
 ///
 /// ```
-/// # use polaris_system::plugin::{Plugin, PluginId, Version};
+/// # use polaris_system::plugin::{Dependency, Plugin, Version};
 /// # use polaris_system::server::Server;
 /// # use polaris_system::resource::{Resource, GlobalResource};
 /// # use std::time::Duration;
@@ -213,8 +324,8 @@ impl std::fmt::Display for PluginId {
 ///         }
 ///     }
 ///
-///     fn dependencies(&self) -> Vec<PluginId> {
-///         vec![PluginId::of::<TracingPlugin>()]
+///     fn dependencies(&self) -> Vec<Dependency> {
+///         vec![Dependency::of::<TracingPlugin>()]
 ///     }
 /// }
 /// ```
@@ -227,6 +338,8 @@ pub trait Plugin: Send + Sync + 'static {
 
     /// Configures the server. Called once when the plugin is added.
     ///
+    /// Every plugin implements `build()`.
+    ///
     /// Use this to:
     /// - Register resources with initial values
     /// - Add sub-plugins via `server.add_plugins()`
@@ -235,8 +348,27 @@ pub trait Plugin: Send + Sync + 'static {
     ///
     /// Keep `build()` lightweight. Heavy initialization should be deferred
     /// to `ready()` or done in systems.
+    ///
+    /// Plugins whose setup can fail also override
+    /// [`try_build()`](Self::try_build), which the server calls in place of
+    /// `build()`, and implement `build()` by panicking on its error.
     fn build(&self, server: &mut Server);
 
+    /// Fallible version of [`build()`](Self::build), which the server calls.
+    ///
+    /// The default calls `build()`; override it only if setup can fail. Return an error to abort startup, for
+    /// example on invalid configuration or a conflicting registration;
+    /// [`Server::try_finish`] reports it as a
+    /// [`BuildError`] naming this plugin.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError`] if the plugin cannot be built.
+    fn try_build(&self, server: &mut Server) -> Result<(), PluginError> {
+        self.build(server);
+        Ok(())
+    }
+
     /// Called after all plugins have been built and the server is ready to run.
     ///
     /// Use this for:
@@ -245,6 +377,18 @@ pub trait Plugin: Send + Sync + 'static {
     /// - Establishing connections (databases, APIs, etc.)
     fn ready(&self, _server: &mut Server) {}
 
+    /// Fallible version of [`ready()`](Self::ready), which the server calls.
+    ///
+    /// The default calls `ready()`. See [`try_build()`](Self::try_build).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError`] if the plugin cannot become ready.
+    fn try_ready(&self, server: &mut Server) -> Result<(), PluginError> {
+        self.ready(server);
+        Ok(())
+    }
+
     /// Called when a schedule this plugin registered for is triggered.
     ///
     /// The `schedule` parameter indicates which schedule triggered this update,
@@ -253,7 +397,9 @@ pub trait Plugin: Send + Sync + 'static {
     ///
     /// Most logic should live in systems, not here. Reserve `update()` for
     /// plugin-level housekeeping (buffer flushing, health checks, etc.).
-    fn update(&self, _server: &mut Server, _schedule: ScheduleId) {}
+    /// The server is shared so that schedules can tick while it is in use;
+    /// keep per-plugin state in resources with interior mutability.
+    fn update(&self, _server: &Server, _schedule: ScheduleId) {}
 
     /// Called when the server is shutting down.
     ///
@@ -294,7 +440,7 @@ pub trait Plugin: Send + Sync + 'static {
     ///         ]
     ///     }
     ///
-    ///     fn update(&self, _server: &mut Server, schedule: ScheduleId) {
+    ///     fn update(&self, _server: &Server, schedule: ScheduleId) {
     ///         // Called when PostAgentRun or PreTurn schedules trigger
     ///     }
     /// }
@@ -303,14 +449,18 @@ pub trait Plugin: Send + Sync + 'static {
         Vec::new()
     }
 
-    /// Declares plugins that must be added before this one.
+    /// Declares plugins that must be built before this one.
+    ///
+    /// Each [`Dependency`] may restrict the accepted versions of the plugin
+    /// it names, or be optional.
     ///
-    /// The server will panic if dependencies are not satisfied when `run()` is called.
+    /// The server will panic if dependencies are not satisfied when `run()` is called;
+    /// [`Server::try_finish`] returns [`BuildError::MissingDependencies`] instead.
     ///
     /// # Example
     ///
     /// ```
-    /// # use polaris_system::plugin::{Plugin, PluginId, Version};
+    /// # use polaris_system::plugin::{Dependency, Plugin, Version};
     /// # use polaris_system::server::Server;
     /// struct TracingPlugin;
     ///
@@ -327,12 +477,12 @@ pub trait Plugin: Send + Sync + 'static {
     ///     const VERSION: Version = Version::new(0, 0, 1);
     ///     fn build(&self, _: &mut Server) {}
     ///
-    ///     fn dependencies(&self) -> Vec<PluginId> {
-    ///         vec![PluginId::of::<TracingPlugin>()]
+    ///     fn dependencies(&self) -> Vec<Dependency> {
+    ///         vec![Dependency::of::<TracingPlugin>()]
     ///     }
     /// }
     /// ```
-    fn dependencies(&self) -> Vec<PluginId> {
+    fn dependencies(&self) -> Vec<Dependency> {
         Vec::new()
     }
 }
@@ -353,20 +503,19 @@ pub(crate) trait DynPlugin: Send + Sync + 'static {
     /// Returns this plugin's [`PluginId`].
     fn id(&self) -> PluginId;
     /// Returns this plugin's [`Version`].
-    #[expect(dead_code, reason = "reserved for future use by server introspection")]
     fn version(&self) -> Version;
-    /// See [`Plugin::build`].
-    fn build(&self, server: &mut Server);
-    /// See [`Plugin::ready`].
-    fn ready(&self, server: &mut Server);
+    /// See [`Plugin::try_build`].
+    fn try_build(&self, server: &mut Server) -> Result<(), PluginError>;
+    /// See [`Plugin::try_ready`].
+    fn try_ready(&self, server: &mut Server) -> Result<(), PluginError>;
     /// See [`Plugin::update`].
-    fn update(&self, server: &mut Server, schedule: ScheduleId);
+    fn update(&self, server: &Server, schedule: ScheduleId);
     /// See [`Plugin::cleanup`].
     fn cleanup(&self, server: &mut Server);
     /// See [`Plugin::tick_schedules`].
     fn tick_schedules(&self) -> Vec<ScheduleId>;
     /// See [`Plugin::dependencies`].
-    fn dependencies(&self) -> Vec<PluginId>;
+    fn dependencies(&self) -> Vec<Dependency>;
 }
 
 impl<T: Plugin> DynPlugin for T {
@@ -376,13 +525,13 @@ impl<T: Plugin> DynPlugin for T {
     fn version(&self) -> Version {
         T::VERSION
     }
-    fn build(&self, server: &mut Server) {
-        Plugin::build(self, server);
+    fn try_build(&self, server: &mut Server) -> Result<(), PluginError> {
+        Plugin::try_build(self, server)
     }
-    fn ready(&self, server: &mut Server) {
-        Plugin::ready(self, server);
+    fn try_ready(&self, server: &mut Server) -> Result<(), PluginError> {
+        Plugin::try_ready(self, server)
     }
-    fn update(&self, server: &mut Server, schedule: ScheduleId) {
+    fn update(&self, server: &Server, schedule: ScheduleId) {
         Plugin::update(self, server, schedule);
     }
     fn cleanup(&self, server: &mut Server) {
@@ -391,7 +540,7 @@ impl<T: Plugin> DynPlugin for T {
     fn tick_schedules(&self) -> Vec<ScheduleId> {
         Plugin::tick_schedules(self)
     }
-    fn dependencies(&self) -> Vec<PluginId> {
+    fn dependencies(&self) -> Vec<Dependency> {
         Plugin::dependencies(self)
     }
 }
@@ -670,8 +819,8 @@ mod tests {
         const ID: &'static str = "test::plugin_b";
         const VERSION: Version = Version::new(0, 0, 1);
         fn build(&self, _server: &mut Server) {}
-        fn dependencies(&self) -> Vec<PluginId> {
-            vec![PluginId::of::<PluginA>()]
+        fn dependencies(&self) -> Vec<Dependency> {
+            vec![Dependency::of::<PluginA>()]
         }
     }
 
@@ -718,7 +867,7 @@ mod tests {
     fn plugin_with_dependencies() {
         let deps = Plugin::dependencies(&PluginB);
         assert_eq!(deps.len(), 1);
-        assert_eq!(deps[0], PluginId::of::<PluginA>());
+        assert_eq!(deps[0], Dependency::of::<PluginA>());
     }
 
     #[test]
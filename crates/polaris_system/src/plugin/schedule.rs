@@ -43,7 +43,7 @@ use variadics_please::all_tuples;
 ///         vec![ScheduleId::of::<PostAgentRun>()]
 ///     }
 ///
-///     fn update(&self, _server: &mut Server, _schedule: ScheduleId) {
+///     fn update(&self, _server: &Server, _schedule: ScheduleId) {
 ///         // called when the executor runs server.tick::<PostAgentRun>()
 ///     }
 /// }
@@ -139,8 +139,8 @@ macro_rules! impl_into_schedule_ids_for_tuple {
     };
 }
 
-// Generate implementations for tuples from 2 to 16 elements
-all_tuples!(impl_into_schedule_ids_for_tuple, 2, 16, S);
+// Generate implementations for tuples from 2 to 24 elements
+all_tuples!(impl_into_schedule_ids_for_tuple, 2, 24, S);
 
 #[cfg(test)]
 mod tests {
@@ -178,6 +178,32 @@ impl Outputs {
         })
     }
 
+    /// Calls `f` with the output that has the given `TypeId`, if present and
+    /// not currently being written.
+    ///
+    /// This is used by the executor to serialize outputs whose concrete type
+    /// is not known at compile time (e.g., when saving a checkpoint).
+    pub fn read_by_type_id<R>(
+        &self,
+        type_id: TypeId,
+        f: impl FnOnce(&(dyn Any + Send + Sync)) -> R,
+    ) -> Option<R> {
+        let entry = self.storage.get(&OutputId(type_id))?;
+        let guard = entry.try_read()?;
+        Some(f(&**guard))
+    }
+
+    /// Removes an output and returns it, if present.
+    ///
+    /// This is used by the executor to gather values of one type from
+    /// several child contexts (parallel branches) before merging.
+    pub fn remove<T: Output>(&mut self) -> Option<T> {
+        self.storage
+            .remove(&OutputId::of::<T>())
+            .and_then(|entry| entry.data.into_inner().downcast::<T>().ok())
+            .map(|boxed| *boxed)
+    }
+
     /// Clears all outputs.
     ///
     /// Called by the executor between agent runs to reset ephemeral state.
@@ -373,6 +399,24 @@ mod tests {
         assert!(!outputs.contains_by_type_id(tool_id));
     }
 
+    #[test]
+    fn read_by_type_id() {
+        let mut outputs = Outputs::new();
+        outputs.insert(ToolResult { value: 7 });
+
+        let value = outputs.read_by_type_id(TypeId::of::<ToolResult>(), |output| {
+            output
+                .downcast_ref::<ToolResult>()
+                .map(|result| result.value)
+        });
+        assert_eq!(value, Some(Some(7)));
+        assert!(
+            outputs
+                .read_by_type_id(TypeId::of::<ReasoningResult>(), |_| ())
+                .is_none()
+        );
+    }
+
     #[test]
     fn output_ref_raii_releases_on_drop() {
         let mut outputs = Outputs::new();
@@ -418,6 +462,19 @@ mod tests {
     // merge_from tests
     // ─────────────────────────────────────────────────────────────────────
 
+    #[test]
+    fn remove_returns_and_drops_output() {
+        let mut outputs = Outputs::new();
+        outputs.insert(ToolResult { value: 7 });
+
+        assert_eq!(
+            outputs.remove::<ToolResult>(),
+            Some(ToolResult { value: 7 })
+        );
+        assert!(!outputs.contains::<ToolResult>());
+        assert_eq!(outputs.remove::<ToolResult>(), None);
+    }
+
     #[test]
     fn merge_from_overwrites_existing() {
         let mut target = Outputs::new();
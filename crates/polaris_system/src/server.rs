@@ -53,18 +53,57 @@
 //! 1. **Dependency Resolution** - Validate and topologically sort plugins
 //! 2. **Build Phase** - Call `plugin.build()` in dependency order
 //! 3. **Ready Phase** - Call `plugin.ready()` in dependency order
-//! 4. **Run Loop** - Execute systems and call `plugin.update()` (Layer 2)
+//! 4. **Run Loop** - Call `plugin.update()` when schedules tick
 //! 5. **Cleanup Phase** - Call `plugin.cleanup()` in reverse order
+//!
+//! # Run Loop
+//!
+//! [`Server::run_until`] builds the server if needed, then ticks schedules
+//! registered with [`Server::tick_every`] on their timers and schedules sent
+//! through a [`ScheduleTrigger`], until the shutdown future completes. With
+//! [signal handling](Server::set_signal_handling) enabled, SIGINT and
+//! SIGTERM stop the loop too. It then waits for the hooks registered with
+//! [`Server::on_shutdown`] to drain in-flight work, up to the
+//! [shutdown timeout](Server::set_shutdown_timeout), and runs cleanup.
+//!
+//! ```no_run
+//! # use polaris_system::server::Server;
+//! # use polaris_system::plugin::Schedule;
+//! # use std::time::Duration;
+//! # struct Flush;
+//! # impl Schedule for Flush {}
+//! # async fn run() {
+//! let mut server = Server::new();
+//! server.tick_every::<Flush>(Duration::from_secs(5));
+//! server.set_signal_handling(true);
+//!
+//! let report = server.run_until(std::future::pending()).await;
+//! println!("stopped by {:?}", report.reason);
+//! # }
+//! ```
+//!
+//! Applications that do their own work against the server while schedules
+//! tick, such as serving session turns, call [`Server::finish`], run that
+//! work alongside [`Server::serve`], and call [`Server::cleanup`] once both
+//! have returned.
 
 use crate::api::API;
 use crate::param::SystemContext;
-use crate::plugin::{DynPlugin, Plugin, PluginId, Plugins, Schedule, ScheduleId};
+use crate::plugin::{
+    BuildError, BuildPhase, DynPlugin, MissingDependency, Plugin, PluginId, Plugins, Schedule,
+    ScheduleId, VersionMismatch,
+};
 use crate::resource::{
     GlobalResource, LocalResource, Resource, ResourceRef, ResourceRefMut, Resources,
 };
+use crate::system::BoxFuture;
 use hashbrown::{HashMap, HashSet};
 use std::any::TypeId;
 use std::sync::Arc;
+use std::task::Poll;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Server
@@ -79,6 +118,12 @@ type LocalFactory = Box<dyn Fn() -> BoxedResource + Send + Sync>;
 /// Type-erased API for dynamic storage.
 type BoxedAPI = Box<dyn std::any::Any + Send + Sync>;
 
+/// Hook that starts draining in-flight work when the run loop shuts down.
+type ShutdownHook = Box<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// How long the run loop waits for shutdown hooks by default.
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Represents the build state of the server.
 ///
 /// The server progresses through these states linearly:
@@ -111,7 +156,6 @@ pub struct Server {
     /// We keep this separate from `global` for mutable access to resources not
     /// accessible to systems via `Res<T>` and `ResMut<T>`. This is useful
     /// for plugins that need mutable server-wide state.
-    /// Note: This is safe because Plugins' `update()` calls are not run concurrently.
     resources: Resources,
 
     /// Factories for creating per-context local resources.
@@ -143,10 +187,38 @@ pub struct Server {
     /// Built during `finish()` from plugin `tick_schedules()`.
     schedule_registry: HashMap<ScheduleId, Vec<usize>>,
 
+    /// Schedules ticked by the run loop at a fixed period.
+    ///
+    /// Registered via [`tick_every()`](Self::tick_every).
+    timers: Vec<(ScheduleId, Duration)>,
+
+    /// Sending half of the trigger channel, cloned into [`ScheduleTrigger`]s.
+    trigger_sender: mpsc::UnboundedSender<ScheduleId>,
+
+    /// Receiving half of the trigger channel, held by the running loop.
+    trigger_receiver: tokio::sync::Mutex<mpsc::UnboundedReceiver<ScheduleId>>,
+
+    /// Hooks awaited by the run loop before it returns.
+    ///
+    /// Registered via [`on_shutdown()`](Self::on_shutdown).
+    shutdown_hooks: Vec<ShutdownHook>,
+
+    /// How long the run loop waits for shutdown hooks.
+    shutdown_timeout: Duration,
+    /// Whether the run loop stops on SIGINT and SIGTERM.
+    handle_signals: bool,
+
     /// The current build state of the server.
     ///
     /// Progresses linearly: `NotStarted` → `Building` → `Built`.
     build_state: BuildState,
+
+    /// First failure of a plugin added and built during the build phase.
+    ///
+    /// Such plugins are built inside another plugin's `build()`, so the
+    /// error is held here until [`try_finish()`](Self::try_finish) can
+    /// return it.
+    nested_build_error: Option<BuildError>,
 }
 
 /// Internal entry for a registered plugin.
@@ -172,6 +244,7 @@ impl Server {
     /// The server starts with no plugins and no resources.
     #[must_use]
     pub fn new() -> Self {
+        let (trigger_sender, trigger_receiver) = mpsc::unbounded_channel();
         Self {
             global: Arc::new(Resources::new()),
             resources: Resources::new(),
@@ -181,7 +254,14 @@ impl Server {
             built_plugins: Vec::new(),
             plugin_ids: HashSet::new(),
             schedule_registry: HashMap::new(),
+            timers: Vec::new(),
+            trigger_sender,
+            trigger_receiver: tokio::sync::Mutex::new(trigger_receiver),
+            shutdown_hooks: Vec::new(),
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            handle_signals: false,
             build_state: BuildState::NotStarted,
+            nested_build_error: None,
         }
     }
 
@@ -245,8 +325,13 @@ impl Server {
         // If we're in the build phase, the plugin is built immediately
         if self.build_state == BuildState::Building {
             // Build immediately and add to built list
-            entry.plugin.build(self);
-            self.built_plugins.push(entry);
+            match entry.plugin.try_build(self) {
+                Ok(()) => self.built_plugins.push(entry),
+                Err(err) => {
+                    let err = BuildError::from_plugin(entry.id, BuildPhase::Build, err);
+                    self.nested_build_error.get_or_insert(err);
+                }
+            }
         } else {
             // Queue for later
             self.pending_plugins.push(entry);
@@ -558,6 +643,8 @@ impl Server {
     ///
     /// Typically called by Layer 2 (`polaris_agent`) in response to agent
     /// execution events (e.g. after an agent run or between conversation turns).
+    /// The [run loop](Self::run_until) also ticks schedules on timers and
+    /// triggers.
     ///
     /// # Example
     ///
@@ -569,10 +656,10 @@ impl Server {
     /// impl Schedule for PostAgentRun {}
     ///
     /// // Layer 2 executor triggers the tick:
-    /// # let mut server = Server::new();
+    /// # let server = Server::new();
     /// server.tick::<PostAgentRun>();
     /// ```
-    pub fn tick<S: Schedule + 'static>(&mut self) {
+    pub fn tick<S: Schedule + 'static>(&self) {
         self.tick_schedule(S::schedule_id());
     }
 
@@ -580,24 +667,49 @@ impl Server {
     ///
     /// Plugins are ticked in dependency order.
     /// This is the non-generic version of [`tick()`](Self::tick).
-    pub fn tick_schedule(&mut self, schedule: ScheduleId) {
+    pub fn tick_schedule(&self, schedule: ScheduleId) {
         let Some(plugin_indices) = self.schedule_registry.get(&schedule) else {
             return;
         };
 
-        // Clone indices to avoid borrow conflict with &mut self passed to update()
-        let indices: Vec<usize> = plugin_indices.clone();
+        for &idx in plugin_indices {
+            self.built_plugins[idx].plugin.update(self, schedule);
+        }
+    }
 
-        for idx in indices {
-            let plugin_ptr = &self.built_plugins[idx].plugin as *const Box<dyn DynPlugin>;
-            // SAFETY: built_plugins cannot be modified during this loop:
-            // - It's a private field, inaccessible to plugin code
-            // - add_plugins() during update goes to pending_plugins (build_state is Built)
-            // - finish() during update panics (build_state is not NotStarted)
-            // The pointer remains valid throughout the loop.
-            unsafe {
-                (*plugin_ptr).update(self, schedule);
-            }
+    /// Registers schedule `S` to be ticked every `period` by the
+    /// [run loop](Self::run_until).
+    ///
+    /// The first tick happens one `period` after the loop starts. Ticks
+    /// missed while the loop was busy are skipped rather than replayed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn tick_every<S: Schedule + 'static>(&mut self, period: Duration) {
+        self.tick_schedule_every(S::schedule_id(), period);
+    }
+
+    /// Registers a schedule to be ticked every `period` by the run loop.
+    ///
+    /// This is the non-generic version of [`tick_every()`](Self::tick_every).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn tick_schedule_every(&mut self, schedule: ScheduleId, period: Duration) {
+        assert!(!period.is_zero(), "schedule tick period must be non-zero");
+        self.timers.push((schedule, period));
+    }
+
+    /// Returns a handle that asks the run loop to tick schedules.
+    ///
+    /// Ticks requested while no loop is running are performed, in order,
+    /// once one starts.
+    #[must_use]
+    pub fn schedule_trigger(&self) -> ScheduleTrigger {
+        ScheduleTrigger {
+            sender: self.trigger_sender.clone(),
         }
     }
 
@@ -613,24 +725,65 @@ impl Server {
     /// 3. Calls `build()` on each plugin in order
     /// 4. Calls `ready()` on each plugin in order
     ///
+    /// See [`try_finish()`](Self::try_finish) for a version that returns
+    /// errors instead.
+    ///
     /// # Panics
     ///
     /// - If a plugin's dependency is not satisfied
     /// - If there is a circular dependency between plugins
+    /// - If a plugin fails to build or become ready
     /// - If called more than once
     pub fn finish(&mut self) {
+        if let Err(err) = self.try_finish() {
+            panic!("{err}");
+        }
+    }
+
+    /// Builds all plugins and prepares the server for execution, returning
+    /// an error instead of panicking.
+    ///
+    /// Performs the same steps as [`finish()`](Self::finish), calling
+    /// [`Plugin::try_build`] and [`Plugin::try_ready`].
+    ///
+    /// Dependency errors are detected before any plugin is built; the server
+    /// is left unchanged, so the missing plugins can be added and the build
+    /// retried. If a plugin fails to build or become ready, the plugins
+    /// already built are cleaned up in reverse order and the server cannot
+    /// be built again.
+    ///
+    /// # Errors
+    ///
+    /// - [`BuildError::AlreadyBuilt`] if the server was already built
+    /// - [`BuildError::MissingDependencies`] listing every dependency that
+    ///   was not added
+    /// - [`BuildError::CircularDependency`] with the path of a cycle
+    /// - [`BuildError::Conflict`] if a plugin's registration conflicted with
+    ///   an earlier one
+    /// - [`BuildError::Plugin`] if a plugin failed for another reason
+    pub fn try_finish(&mut self) -> Result<(), BuildError> {
         if self.build_state != BuildState::NotStarted {
-            panic!("Server::finish() was already called. Cannot build twice.");
+            return Err(BuildError::AlreadyBuilt);
         }
 
         // Phase 1: Sort plugins by dependencies
-        let sorted_plugins = self.sort_plugins_by_dependencies();
+        let sorted_plugins = self.sort_plugins_by_dependencies()?;
 
         // Phase 2: Build all plugins in sorted order
         self.build_state = BuildState::Building;
         for entry in sorted_plugins {
-            entry.plugin.build(self);
-            self.built_plugins.push(entry);
+            let result = entry.plugin.try_build(self);
+            let failed = match result {
+                Ok(()) => {
+                    self.built_plugins.push(entry);
+                    self.nested_build_error.take()
+                }
+                Err(err) => Some(BuildError::from_plugin(entry.id, BuildPhase::Build, err)),
+            };
+            if let Some(err) = failed {
+                self.cleanup();
+                return Err(err);
+            }
         }
 
         // Phase 3: Ready all plugins in sorted order
@@ -638,12 +791,15 @@ impl Server {
         for i in 0..self.built_plugins.len() {
             // SAFETY: We're using index-based access to avoid borrow conflicts
             // The plugin is borrowed immutably, and we pass &mut self to ready()
-            let plugin_ptr = &self.built_plugins[i].plugin as *const Box<dyn DynPlugin>;
+            let plugin_ptr = std::ptr::from_ref(&self.built_plugins[i].plugin);
             // SAFETY: We don't modify built_plugins during this loop, and the
             // pointer remains valid. The plugin's ready() may add resources but
             // shouldn't modify built_plugins.
-            unsafe {
-                (*plugin_ptr).ready(self);
+            let result = unsafe { (*plugin_ptr).try_ready(self) };
+            if let Err(err) = result {
+                let plugin = self.built_plugins[i].id.clone();
+                self.cleanup();
+                return Err(BuildError::from_plugin(plugin, BuildPhase::Ready, err));
             }
         }
 
@@ -651,6 +807,7 @@ impl Server {
         self.build_schedule_registry();
 
         self.build_state = BuildState::Built;
+        Ok(())
     }
 
     /// Builds the schedule registry from plugin `tick_schedules()` declarations.
@@ -671,17 +828,17 @@ impl Server {
         }
     }
 
-    /// Runs the server lifecycle.
+    /// Builds the server and returns.
     ///
-    /// This is a convenience method that calls `finish()` and then returns.
-    /// The full run loop with `update()` calls will be added in Layer 2.
+    /// This is a convenience method that calls `finish()`. Use
+    /// [`run_until()`](Self::run_until) to also tick schedules until
+    /// shutdown.
     ///
     /// # Panics
     ///
     /// Same as [`finish()`](Self::finish).
     pub fn run(&mut self) {
         self.finish();
-        // Run loop will be added in Layer 2
     }
 
     /// Runs build and ready phases, then returns.
@@ -722,7 +879,7 @@ impl Server {
     pub fn cleanup(&mut self) {
         // Cleanup in reverse order (dependents before dependencies)
         for i in (0..self.built_plugins.len()).rev() {
-            let plugin_ptr = &self.built_plugins[i].plugin as *const Box<dyn DynPlugin>;
+            let plugin_ptr = std::ptr::from_ref(&self.built_plugins[i].plugin);
             // SAFETY: Same as ready() - we don't modify built_plugins during cleanup
             unsafe {
                 (*plugin_ptr).cleanup(self);
@@ -731,20 +888,160 @@ impl Server {
     }
 
     // ─────────────────────────────────────────────────────────────────────────
-    // Internal: Dependency Resolution
+    // Run Loop
     // ─────────────────────────────────────────────────────────────────────────
 
-    /// Sorts pending plugins by dependencies using topological sort.
+    /// Registers a hook that drains in-flight work when the run loop shuts
+    /// down.
+    ///
+    /// Each hook is called once shutdown begins, and the loop waits for the
+    /// returned futures, which run concurrently, before cleanup, up to the
+    /// [shutdown timeout](Self::set_shutdown_timeout). Plugins use this to
+    /// stop accepting new work and wait for work already started.
+    pub fn on_shutdown<F, Fut>(&mut self, hook: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.shutdown_hooks
+            .push(Box::new(move || Box::pin(hook()) as BoxFuture<'static, ()>));
+    }
+
+    /// Sets how long the run loop waits for shutdown hooks before cleanup.
     ///
-    /// Returns the sorted list of plugins.
+    /// Defaults to 30 seconds.
+    pub fn set_shutdown_timeout(&mut self, timeout: Duration) {
+        self.shutdown_timeout = timeout;
+    }
+
+    /// Sets whether the run loop also stops when the process receives
+    /// SIGINT, or SIGTERM on Unix.
+    ///
+    /// Disabled by default, so that the server does not replace signal
+    /// handlers the application installed itself. Applications that handle
+    /// signals themselves pass their own shutdown future instead.
+    pub fn set_signal_handling(&mut self, enabled: bool) {
+        self.handle_signals = enabled;
+    }
+
+    /// Runs the server until `shutdown` completes, or the process is asked to
+    /// stop if [signal handling](Self::set_signal_handling) is enabled, then
+    /// cleans up.
+    ///
+    /// Builds the server first if [`finish()`](Self::finish) has not been
+    /// called, then runs [`serve()`](Self::serve) and
+    /// [`cleanup()`](Self::cleanup).
     ///
     /// # Panics
     ///
-    /// - If a plugin's dependency is not found
-    /// - If there is a circular dependency
-    fn sort_plugins_by_dependencies(&mut self) -> Vec<PluginEntry> {
+    /// Same as [`finish()`](Self::finish) and [`serve()`](Self::serve).
+    pub async fn run_until(&mut self, shutdown: impl Future<Output = ()>) -> ShutdownReport {
+        if self.build_state == BuildState::NotStarted {
+            self.finish();
+        }
+        let report = self.serve(shutdown).await;
+        self.cleanup();
+        report
+    }
+
+    /// Ticks schedules until `shutdown` completes, or the process receives
+    /// SIGINT or SIGTERM if [signal handling](Self::set_signal_handling) is
+    /// enabled, then drains in-flight work.
+    ///
+    /// Schedules registered with [`tick_every()`](Self::tick_every) are
+    /// ticked on their timers and schedules sent through a
+    /// [`ScheduleTrigger`] as they arrive. On shutdown, ticking stops and the
+    /// hooks registered with [`on_shutdown()`](Self::on_shutdown) are
+    /// awaited together up to the shutdown timeout. Plugins are not cleaned up;
+    /// [`run_until()`](Self::run_until) does that.
+    ///
+    /// Takes `&self` so that the application can use the server, for
+    /// example to run session turns, while the loop runs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another call to `serve()` on this server is running.
+    pub async fn serve(&self, shutdown: impl Future<Output = ()>) -> ShutdownReport {
+        let mut triggers = self
+            .trigger_receiver
+            .try_lock()
+            .expect("Server::serve() is already running");
+
+        let start = Instant::now();
+        let mut timers: Vec<(ScheduleId, Duration, Instant)> = self
+            .timers
+            .iter()
+            .map(|&(schedule, period)| (schedule, period, start + period))
+            .collect();
+
+        let handle_signals = self.handle_signals;
+        let signal = async move {
+            if handle_signals {
+                shutdown_signal().await
+            } else {
+                std::future::pending().await
+            }
+        };
+        tokio::pin!(shutdown, signal);
+
+        let reason = loop {
+            let next = timers.iter().map(|&(_, _, due)| due).min();
+            let timer = async {
+                match next {
+                    Some(due) => tokio::time::sleep_until(due).await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            tokio::select! {
+                biased;
+                () = &mut shutdown => break ShutdownReason::Requested,
+                reason = &mut signal => break reason,
+                Some(schedule) = triggers.recv() => self.tick_schedule(schedule),
+                () = timer => {
+                    let now = Instant::now();
+                    for (schedule, period, due) in &mut timers {
+                        if *due > now {
+                            continue;
+                        }
+                        self.tick_schedule(*schedule);
+                        *due += *period;
+                        if *due <= now {
+                            *due = now + *period;
+                        }
+                    }
+                }
+            }
+        };
+
+        let mut hooks: Vec<_> = self.shutdown_hooks.iter().map(|hook| hook()).collect();
+        let drain = std::future::poll_fn(|cx| {
+            hooks.retain_mut(|hook| hook.as_mut().poll(cx).is_pending());
+            if hooks.is_empty() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        });
+        let drained = tokio::time::timeout(self.shutdown_timeout, drain)
+            .await
+            .is_ok();
+
+        ShutdownReport { reason, drained }
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Internal: Dependency Resolution
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Sorts pending plugins by dependencies using topological sort.
+    ///
+    /// Returns the sorted list of plugins, or an error without taking any
+    /// plugins if a dependency is missing or there is a circular
+    /// dependency.
+    fn sort_plugins_by_dependencies(&mut self) -> Result<Vec<PluginEntry>, BuildError> {
         if self.pending_plugins.is_empty() {
-            return Vec::new();
+            return Ok(Vec::new());
         }
 
         // Build a map of plugin id -> index for dependency lookup
@@ -757,28 +1054,64 @@ impl Server {
         let n = self.pending_plugins.len();
         let mut in_degree = vec![0usize; n];
         let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut dependencies: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut missing = Vec::new();
+        let mut mismatched = Vec::new();
 
         for (i, entry) in self.pending_plugins.iter().enumerate() {
-            for dep_id in entry.plugin.dependencies() {
-                // Find the dependency in pending plugins
-                if let Some(&dep_idx) = id_to_index.get(&dep_id) {
+            for dependency in entry.plugin.dependencies() {
+                let dep_id = dependency.id();
+                // Find the dependency in pending plugins, then in built ones
+                let found = if let Some(&dep_idx) = id_to_index.get(dep_id) {
                     // dep_idx must be built before i
                     dependents[dep_idx].push(i);
+                    dependencies[i].push(dep_idx);
                     in_degree[i] += 1;
+                    &self.pending_plugins[dep_idx]
+                } else if let Some(built) = self.built_plugins.iter().find(|p| &p.id == dep_id) {
+                    // Dependency already built, no need to track
+                    built
                 } else {
-                    // Check if already built
-                    if !self.built_plugins.iter().any(|p| p.id == dep_id) {
-                        panic!(
-                            "Plugin '{}' requires '{}' which was not added.\n\
-                             Add {} before {}, or use a plugin group that includes it.",
-                            entry.id, dep_id, dep_id, entry.id
-                        );
+                    if !dependency.is_optional() {
+                        missing.push(MissingDependency {
+                            plugin: entry.id.clone(),
+                            dependency: dep_id.clone(),
+                        });
                     }
-                    // Dependency already built, no need to track
+                    continue;
+                };
+
+                let Some(requirement) = dependency.requirement() else {
+                    continue;
+                };
+                let parsed = semver::VersionReq::parse(requirement).map_err(|err| {
+                    BuildError::InvalidVersionRequirement {
+                        plugin: entry.id.clone(),
+                        dependency: dep_id.clone(),
+                        requirement: requirement.to_owned(),
+                        reason: err.to_string(),
+                    }
+                })?;
+                let version = found.plugin.version();
+                let semver = semver::Version::new(version.major, version.minor, version.patch);
+                if !parsed.matches(&semver) {
+                    mismatched.push(VersionMismatch {
+                        plugin: entry.id.clone(),
+                        dependency: dep_id.clone(),
+                        requirement: requirement.to_owned(),
+                        found: version,
+                    });
                 }
             }
         }
 
+        if !missing.is_empty() {
+            return Err(BuildError::MissingDependencies(missing));
+        }
+        if !mismatched.is_empty() {
+            return Err(BuildError::IncompatibleVersions(mismatched));
+        }
+
         // Kahn's algorithm for topological sort
         let mut queue: Vec<usize> = Vec::new();
         for (i, &deg) in in_degree.iter().enumerate() {
@@ -802,19 +1135,27 @@ impl Server {
 
         // Check for cycle
         if sorted_indices.len() != n {
-            // Find plugins involved in cycle
-            let in_cycle: Vec<String> = in_degree
+            // Every unsorted plugin has an unsorted dependency, so following
+            // those from any of them must revisit a plugin.
+            let mut path: Vec<usize> = Vec::new();
+            let mut current = (0..n)
+                .find(|&i| in_degree[i] > 0)
+                .expect("an unsorted plugin should exist");
+            while !path.contains(&current) {
+                path.push(current);
+                current = dependencies[current]
+                    .iter()
+                    .copied()
+                    .find(|&dep| in_degree[dep] > 0)
+                    .expect("an unsorted plugin should have an unsorted dependency");
+            }
+            let start = path.iter().position(|&i| i == current).unwrap_or(0);
+            let cycle = path[start..]
                 .iter()
-                .enumerate()
-                .filter(|(_, deg)| **deg > 0)
-                .map(|(i, _)| self.pending_plugins[i].id.to_string())
+                .chain(std::iter::once(&current))
+                .map(|&i| self.pending_plugins[i].id.clone())
                 .collect();
-
-            panic!(
-                "Circular dependency detected among plugins: {:?}\n\
-                 Break the cycle by extracting shared functionality into a separate plugin.",
-                in_cycle
-            );
+            return Err(BuildError::CircularDependency(cycle));
         }
 
         // Extract plugins in sorted order
@@ -835,6 +1176,93 @@ impl Server {
             result[new_pos] = Some(entry);
         }
 
-        result.into_iter().flatten().collect()
+        Ok(result.into_iter().flatten().collect())
+    }
+}
+
+/// Waits for SIGINT, or SIGTERM on Unix.
+///
+/// Signals whose handler cannot be installed are never reported.
+async fn shutdown_signal() -> ShutdownReason {
+    let interrupt = async {
+        if tokio::signal::ctrl_c().await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = interrupt => ShutdownReason::Interrupt,
+        () = terminate => ShutdownReason::Terminate,
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Run Loop Types
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Handle that asks a server's run loop to tick schedules.
+///
+/// Created by [`Server::schedule_trigger`]. Cheap to clone and usable from
+/// any thread or task, so external events (a webhook, a file watcher) can
+/// drive schedules.
+#[derive(Clone)]
+pub struct ScheduleTrigger {
+    sender: mpsc::UnboundedSender<ScheduleId>,
+}
+
+impl ScheduleTrigger {
+    /// Asks the run loop to tick schedule `S`.
+    pub fn trigger<S: Schedule + 'static>(&self) {
+        self.trigger_schedule(S::schedule_id());
     }
+
+    /// Asks the run loop to tick the given schedule.
+    ///
+    /// This is the non-generic version of [`trigger()`](Self::trigger).
+    /// Does nothing if the server has been dropped.
+    pub fn trigger_schedule(&self, schedule: ScheduleId) {
+        // The server owns the receiver, so sending only fails once it is gone.
+        let _ = self.sender.send(schedule);
+    }
+}
+
+impl std::fmt::Debug for ScheduleTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScheduleTrigger").finish_non_exhaustive()
+    }
+}
+
+/// Why a run loop stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownReason {
+    /// The shutdown future passed to the loop completed.
+    Requested,
+    /// The process received SIGINT (Ctrl+C).
+    Interrupt,
+    /// The process received SIGTERM.
+    Terminate,
+}
+
+/// Summary of how a run loop stopped.
+///
+/// Returned by [`Server::run_until`] and [`Server::serve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// What stopped the loop.
+    pub reason: ShutdownReason,
+    /// Whether every shutdown hook finished before the timeout.
+    pub drained: bool,
 }
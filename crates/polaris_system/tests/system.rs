@@ -3,7 +3,7 @@
 //! These tests cover basic server functionality, plugin lifecycle, resource management, and API interactions.
 //! These tests have been moved directly from the `polaris_system/src/server.rs` file.
 
-use polaris_system::plugin::{Plugin, PluginGroup, PluginId, Version};
+use polaris_system::plugin::{Dependency, Plugin, PluginGroup, PluginId, Version};
 use polaris_system::prelude::*;
 use std::sync::atomic::AtomicBool;
 
@@ -45,8 +45,8 @@ impl Plugin for PluginB {
         }
     }
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<PluginA>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<PluginA>()]
     }
 }
 
@@ -60,8 +60,8 @@ impl Plugin for PluginC {
         }
     }
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<PluginB>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<PluginB>()]
     }
 }
 
@@ -254,8 +254,8 @@ fn circular_dependency_panics() {
         const ID: &'static str = "test::cycle_a";
         const VERSION: Version = Version::new(0, 0, 1);
         fn build(&self, _server: &mut Server) {}
-        fn dependencies(&self) -> Vec<PluginId> {
-            vec![PluginId::of::<CycleB>()]
+        fn dependencies(&self) -> Vec<Dependency> {
+            vec![Dependency::of::<CycleB>()]
         }
     }
 
@@ -264,8 +264,8 @@ fn circular_dependency_panics() {
         const ID: &'static str = "test::cycle_b";
         const VERSION: Version = Version::new(0, 0, 1);
         fn build(&self, _server: &mut Server) {}
-        fn dependencies(&self) -> Vec<PluginId> {
-            vec![PluginId::of::<CycleA>()]
+        fn dependencies(&self) -> Vec<Dependency> {
+            vec![Dependency::of::<CycleA>()]
         }
     }
 
@@ -284,6 +284,345 @@ fn double_finish_panics() {
     server.finish(); // Should panic
 }
 
+#[test]
+fn try_finish_reports_every_missing_dependency() {
+    struct NeedsBoth;
+    impl Plugin for NeedsBoth {
+        const ID: &'static str = "test::needs_both";
+        const VERSION: Version = Version::new(0, 0, 1);
+        fn build(&self, _server: &mut Server) {}
+        fn dependencies(&self) -> Vec<Dependency> {
+            vec![Dependency::of::<PluginA>(), Dependency::of::<PluginC>()]
+        }
+    }
+
+    let mut server = Server::new();
+    server.add_plugins(NeedsBoth);
+    server.add_plugins(PluginB);
+
+    let Err(BuildError::MissingDependencies(missing)) = server.try_finish() else {
+        panic!("expected missing dependencies");
+    };
+    let missing: Vec<(String, String)> = missing
+        .iter()
+        .map(|missing| (missing.plugin.to_string(), missing.dependency.to_string()))
+        .collect();
+    assert_eq!(
+        missing,
+        [
+            ("test::needs_both".into(), "test::plugin_a".into()),
+            ("test::needs_both".into(), "test::plugin_c".into()),
+            ("test::plugin_b".into(), "test::plugin_a".into()),
+        ]
+    );
+
+    // Nothing was built, so the missing plugins can still be added.
+    server.add_plugins(PluginA).add_plugins(PluginC);
+    server.try_finish().unwrap();
+    assert!(server.contains_resource::<TestResource>());
+}
+
+#[test]
+fn try_finish_reports_cycle_path() {
+    struct First;
+    impl Plugin for First {
+        const ID: &'static str = "test::first";
+        const VERSION: Version = Version::new(0, 0, 1);
+        fn build(&self, _server: &mut Server) {}
+        fn dependencies(&self) -> Vec<Dependency> {
+            vec![Dependency::of::<Second>()]
+        }
+    }
+
+    struct Second;
+    impl Plugin for Second {
+        const ID: &'static str = "test::second";
+        const VERSION: Version = Version::new(0, 0, 1);
+        fn build(&self, _server: &mut Server) {}
+        fn dependencies(&self) -> Vec<Dependency> {
+            vec![Dependency::of::<PluginA>(), Dependency::of::<Third>()]
+        }
+    }
+
+    struct Third;
+    impl Plugin for Third {
+        const ID: &'static str = "test::third";
+        const VERSION: Version = Version::new(0, 0, 1);
+        fn build(&self, _server: &mut Server) {}
+        fn dependencies(&self) -> Vec<Dependency> {
+            vec![Dependency::of::<First>()]
+        }
+    }
+
+    let mut server = Server::new();
+    server
+        .add_plugins(PluginA)
+        .add_plugins(First)
+        .add_plugins(Second)
+        .add_plugins(Third);
+
+    let err = server.try_finish().unwrap_err();
+    let BuildError::CircularDependency(cycle) = &err else {
+        panic!("expected a circular dependency, got {err}");
+    };
+    let cycle: Vec<String> = cycle.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        cycle,
+        ["test::first", "test::second", "test::third", "test::first"]
+    );
+    assert!(
+        err.to_string()
+            .contains("test::first -> test::second -> test::third -> test::first")
+    );
+}
+
+#[test]
+fn try_finish_twice_errors() {
+    let mut server = Server::new();
+    server.add_plugins(PluginA);
+    server.try_finish().unwrap();
+
+    assert!(matches!(server.try_finish(), Err(BuildError::AlreadyBuilt)));
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Dependency Constraint Tests
+// ─────────────────────────────────────────────────────────────────────────
+
+struct ModelsV2;
+impl Plugin for ModelsV2 {
+    const ID: &'static str = "test::models";
+    const VERSION: Version = Version::new(0, 2, 4);
+    fn build(&self, server: &mut Server) {
+        server.insert_resource(TestResource { value: 2 });
+    }
+}
+
+/// Depends on `test::models` with the given requirement.
+struct ModelsUser(&'static str);
+impl Plugin for ModelsUser {
+    const ID: &'static str = "test::models_user";
+    const VERSION: Version = Version::new(1, 0, 0);
+    fn build(&self, server: &mut Server) {
+        // Runs after ModelsV2 has inserted the resource.
+        server.get_resource_mut::<TestResource>().unwrap().value *= 10;
+    }
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ModelsV2>().version(self.0)]
+    }
+}
+
+#[test]
+fn dependency_version_requirement_satisfied() {
+    let mut server = Server::new();
+    server
+        .add_plugins(ModelsUser(">=0.2, <0.3"))
+        .add_plugins(ModelsV2);
+    server.try_finish().unwrap();
+
+    assert_eq!(server.get_resource::<TestResource>().unwrap().value, 20);
+}
+
+#[test]
+fn dependency_version_mismatch_names_plugins() {
+    let mut server = Server::new();
+    server.add_plugins(ModelsUser("^0.3")).add_plugins(ModelsV2);
+
+    let err = server.try_finish().unwrap_err();
+    let BuildError::IncompatibleVersions(mismatched) = &err else {
+        panic!("expected incompatible versions, got {err}");
+    };
+    assert_eq!(
+        mismatched,
+        &[VersionMismatch {
+            plugin: PluginId::of::<ModelsUser>(),
+            dependency: PluginId::of::<ModelsV2>(),
+            requirement: "^0.3".into(),
+            found: Version::new(0, 2, 4),
+        }]
+    );
+    assert_eq!(
+        err.to_string(),
+        "Plugin 'test::models_user' requires 'test::models' ^0.3, but version 0.2.4 was added."
+    );
+    // Nothing was built.
+    assert!(!server.contains_resource::<TestResource>());
+}
+
+#[test]
+fn invalid_version_requirement_is_reported() {
+    let mut server = Server::new();
+    server
+        .add_plugins(ModelsUser("not a version"))
+        .add_plugins(ModelsV2);
+
+    let err = server.try_finish().unwrap_err();
+    assert!(matches!(
+        &err,
+        BuildError::InvalidVersionRequirement { plugin, requirement, .. }
+            if plugin == &PluginId::of::<ModelsUser>() && requirement == "not a version"
+    ));
+}
+
+#[test]
+#[should_panic(expected = "but version 0.2.4 was added")]
+fn finish_panics_on_version_mismatch() {
+    let mut server = Server::new();
+    server.add_plugins(ModelsUser("1")).add_plugins(ModelsV2);
+    server.finish();
+}
+
+/// Optionally depends on `test::models`, recording the value it saw.
+struct OptionalModelsUser {
+    seen: std::sync::Arc<std::sync::atomic::AtomicI32>,
+}
+impl Plugin for OptionalModelsUser {
+    const ID: &'static str = "test::optional_models_user";
+    const VERSION: Version = Version::new(0, 0, 1);
+    fn build(&self, server: &mut Server) {
+        let value = server
+            .get_resource::<TestResource>()
+            .map_or(-1, |res| res.value);
+        self.seen.store(value, std::sync::atomic::Ordering::SeqCst);
+    }
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ModelsV2>().version("0.2").optional()]
+    }
+}
+
+#[test]
+fn optional_dependency_absent_is_ignored() {
+    let seen = std::sync::Arc::new(std::sync::atomic::AtomicI32::new(0));
+    let mut server = Server::new();
+    server.add_plugins(OptionalModelsUser { seen: seen.clone() });
+    server.try_finish().unwrap();
+
+    assert_eq!(seen.load(std::sync::atomic::Ordering::SeqCst), -1);
+}
+
+#[test]
+fn optional_dependency_present_is_built_first() {
+    let seen = std::sync::Arc::new(std::sync::atomic::AtomicI32::new(0));
+    let mut server = Server::new();
+    server
+        .add_plugins(OptionalModelsUser { seen: seen.clone() })
+        .add_plugins(ModelsV2);
+    server.try_finish().unwrap();
+
+    assert_eq!(seen.load(std::sync::atomic::Ordering::SeqCst), 2);
+}
+
+/// Fails in the phase given by `in_ready`, after recording that it ran.
+struct FailingPlugin {
+    in_ready: bool,
+}
+
+impl Plugin for FailingPlugin {
+    const ID: &'static str = "test::failing";
+    const VERSION: Version = Version::new(0, 0, 1);
+
+    fn build(&self, server: &mut Server) {
+        if let Err(err) = self.try_build(server) {
+            panic!("{err}");
+        }
+    }
+
+    fn try_build(&self, _server: &mut Server) -> Result<(), PluginError> {
+        if self.in_ready {
+            return Ok(());
+        }
+        Err(PluginError::other("API key is empty"))
+    }
+
+    fn try_ready(&self, _server: &mut Server) -> Result<(), PluginError> {
+        if self.in_ready {
+            return Err(RegistrationConflict::new("tool", "search").into());
+        }
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<CleanupPlugin>()]
+    }
+}
+
+#[test]
+fn try_finish_reports_failed_build_and_cleans_up() {
+    let mut server = Server::new();
+    server
+        .add_plugins(CleanupPlugin)
+        .add_plugins(FailingPlugin { in_ready: false });
+
+    let err = server.try_finish().unwrap_err();
+    let BuildError::Plugin { plugin, phase, .. } = &err else {
+        panic!("expected a plugin error, got {err}");
+    };
+    assert_eq!(plugin, &PluginId::of::<FailingPlugin>());
+    assert_eq!(*phase, BuildPhase::Build);
+    assert_eq!(
+        err.to_string(),
+        "plugin 'test::failing' failed during build: API key is empty"
+    );
+    // CleanupPlugin was built before the failure, so it was cleaned up.
+    assert!(!server.contains_resource::<TestResource>());
+}
+
+#[test]
+fn try_finish_reports_registration_conflict_in_ready() {
+    let mut server = Server::new();
+    server
+        .add_plugins(CleanupPlugin)
+        .add_plugins(FailingPlugin { in_ready: true });
+
+    let err = server.try_finish().unwrap_err();
+    let BuildError::Conflict {
+        plugin,
+        phase,
+        conflict,
+    } = &err
+    else {
+        panic!("expected a conflict, got {err}");
+    };
+    assert_eq!(plugin, &PluginId::of::<FailingPlugin>());
+    assert_eq!(*phase, BuildPhase::Ready);
+    assert_eq!(conflict, &RegistrationConflict::new("tool", "search"));
+    assert!(!server.contains_resource::<TestResource>());
+}
+
+#[test]
+#[should_panic(expected = "failed during build")]
+fn finish_panics_on_failed_build() {
+    let mut server = Server::new();
+    server
+        .add_plugins(CleanupPlugin)
+        .add_plugins(FailingPlugin { in_ready: false });
+    server.finish();
+}
+
+#[test]
+fn try_finish_reports_failed_sub_plugin() {
+    struct FailingParent;
+    impl Plugin for FailingParent {
+        const ID: &'static str = "test::failing_parent";
+        const VERSION: Version = Version::new(0, 0, 1);
+        fn build(&self, server: &mut Server) {
+            server
+                .add_plugins(CleanupPlugin)
+                .add_plugins(FailingPlugin { in_ready: false });
+        }
+    }
+
+    let mut server = Server::new();
+    server.add_plugins(FailingParent);
+
+    let err = server.try_finish().unwrap_err();
+    assert!(matches!(
+        &err,
+        BuildError::Plugin { plugin, .. } if plugin == &PluginId::of::<FailingPlugin>()
+    ));
+    assert!(!server.contains_resource::<TestResource>());
+}
+
 #[test]
 fn sub_plugin_added_during_build() {
     struct ParentPlugin;
@@ -360,8 +699,8 @@ fn cleanup_in_reverse_order() {
             let n = self.order.fetch_add(1, Ordering::SeqCst);
             self.my_order.store(n, Ordering::SeqCst);
         }
-        fn dependencies(&self) -> Vec<PluginId> {
-            vec![PluginId::of::<OrderedCleanupA>()]
+        fn dependencies(&self) -> Vec<Dependency> {
+            vec![Dependency::of::<OrderedCleanupA>()]
         }
     }
 
@@ -645,7 +984,7 @@ fn plugin_registers_for_schedule_gets_ticked() {
             vec![ScheduleId::of::<TestScheduleA>()]
         }
 
-        fn update(&self, _server: &mut Server, _schedule: ScheduleId) {
+        fn update(&self, _server: &Server, _schedule: ScheduleId) {
             self.count.fetch_add(1, Ordering::SeqCst);
         }
     }
@@ -686,7 +1025,7 @@ fn plugin_not_registered_for_schedule_not_ticked() {
             vec![ScheduleId::of::<TestScheduleA>()]
         }
 
-        fn update(&self, _server: &mut Server, _schedule: ScheduleId) {
+        fn update(&self, _server: &Server, _schedule: ScheduleId) {
             self.count.fetch_add(1, Ordering::SeqCst);
         }
     }
@@ -729,7 +1068,7 @@ fn multiple_plugins_same_schedule_all_ticked_in_order() {
             vec![ScheduleId::of::<TestScheduleA>()]
         }
 
-        fn update(&self, _server: &mut Server, _schedule: ScheduleId) {
+        fn update(&self, _server: &Server, _schedule: ScheduleId) {
             let n = self.order.fetch_add(1, Ordering::SeqCst);
             self.my_order.store(n, Ordering::SeqCst);
         }
@@ -749,13 +1088,13 @@ fn multiple_plugins_same_schedule_all_ticked_in_order() {
             vec![ScheduleId::of::<TestScheduleA>()]
         }
 
-        fn update(&self, _server: &mut Server, _schedule: ScheduleId) {
+        fn update(&self, _server: &Server, _schedule: ScheduleId) {
             let n = self.order.fetch_add(1, Ordering::SeqCst);
             self.my_order.store(n, Ordering::SeqCst);
         }
 
-        fn dependencies(&self) -> Vec<PluginId> {
-            vec![PluginId::of::<FirstPlugin>()]
+        fn dependencies(&self) -> Vec<Dependency> {
+            vec![Dependency::of::<FirstPlugin>()]
         }
     }
 
@@ -803,7 +1142,7 @@ fn plugin_multiple_schedules() {
             ]
         }
 
-        fn update(&self, _server: &mut Server, schedule: ScheduleId) {
+        fn update(&self, _server: &Server, schedule: ScheduleId) {
             if schedule == ScheduleId::of::<TestScheduleA>() {
                 self.count_a.fetch_add(1, Ordering::SeqCst);
             } else if schedule == ScheduleId::of::<TestScheduleB>() {
@@ -860,7 +1199,7 @@ fn schedule_passed_to_update_matches_triggered() {
             vec![ScheduleId::of::<TestScheduleA>()]
         }
 
-        fn update(&self, _server: &mut Server, schedule: ScheduleId) {
+        fn update(&self, _server: &Server, schedule: ScheduleId) {
             if schedule == ScheduleId::of::<TestScheduleA>() {
                 self.correct.store(true, Ordering::SeqCst);
             }
@@ -1020,8 +1359,8 @@ fn plugin_accesses_api_in_ready() {
             }
         }
 
-        fn dependencies(&self) -> Vec<PluginId> {
-            vec![PluginId::of::<APIProviderPlugin>()]
+        fn dependencies(&self) -> Vec<Dependency> {
+            vec![Dependency::of::<APIProviderPlugin>()]
         }
     }
 
@@ -1213,7 +1552,7 @@ fn tick_schedule_uses_schedule_id_directly() {
             vec![ScheduleId::of::<TestScheduleA>()]
         }
 
-        fn update(&self, _server: &mut Server, _schedule: ScheduleId) {
+        fn update(&self, _server: &Server, _schedule: ScheduleId) {
             self.count.fetch_add(1, Ordering::SeqCst);
         }
     }
@@ -1254,7 +1593,7 @@ fn update_receives_correct_schedule_id() {
             ]
         }
 
-        fn update(&self, _server: &mut Server, schedule: ScheduleId) {
+        fn update(&self, _server: &Server, schedule: ScheduleId) {
             self.received.lock().push(schedule);
         }
     }
@@ -1294,7 +1633,7 @@ fn plugins_with_no_tick_schedules_never_updated() {
 
         // Default tick_schedules() returns empty vec
 
-        fn update(&self, _server: &mut Server, _schedule: ScheduleId) {
+        fn update(&self, _server: &Server, _schedule: ScheduleId) {
             // This should never be called
             self.count.fetch_add(1, Ordering::SeqCst);
         }
@@ -1313,3 +1652,175 @@ fn plugins_with_no_tick_schedules_never_updated() {
     // Plugin should never have been updated
     assert_eq!(update_count.load(Ordering::SeqCst), 0);
 }
+
+// ─────────────────────────────────────────────────────────────────────────
+// Run Loop Tests
+// ─────────────────────────────────────────────────────────────────────────
+
+/// Counts ticks of `TestScheduleA` and records whether cleanup ran.
+struct RunLoopPlugin {
+    ticks: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    cleaned_up: std::sync::Arc<AtomicBool>,
+}
+
+impl Plugin for RunLoopPlugin {
+    const ID: &'static str = "test::run_loop";
+    const VERSION: Version = Version::new(0, 0, 1);
+    fn build(&self, _server: &mut Server) {}
+
+    fn tick_schedules(&self) -> Vec<ScheduleId> {
+        vec![ScheduleId::of::<TestScheduleA>()]
+    }
+
+    fn update(&self, _server: &Server, _schedule: ScheduleId) {
+        self.ticks.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    fn cleanup(&self, _server: &mut Server) {
+        self.cleaned_up
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+fn run_loop_server() -> (
+    Server,
+    std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    std::sync::Arc<AtomicBool>,
+) {
+    let ticks = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let cleaned_up = std::sync::Arc::new(AtomicBool::new(false));
+    let mut server = Server::new();
+    server.add_plugins(RunLoopPlugin {
+        ticks: ticks.clone(),
+        cleaned_up: cleaned_up.clone(),
+    });
+    (server, ticks, cleaned_up)
+}
+
+#[tokio::test(start_paused = true)]
+async fn run_until_ticks_timer_schedules_and_cleans_up() {
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    let (mut server, ticks, cleaned_up) = run_loop_server();
+    server.tick_every::<TestScheduleA>(Duration::from_millis(10));
+
+    let report = server
+        .run_until(tokio::time::sleep(Duration::from_millis(35)))
+        .await;
+
+    assert_eq!(report.reason, ShutdownReason::Requested);
+    assert!(report.drained);
+    assert_eq!(ticks.load(Ordering::SeqCst), 3);
+    assert!(cleaned_up.load(Ordering::SeqCst));
+}
+
+#[tokio::test(start_paused = true)]
+async fn schedule_trigger_ticks_schedule() {
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    let (mut server, ticks, _) = run_loop_server();
+    let trigger = server.schedule_trigger();
+    // Queued before the loop starts.
+    trigger.trigger::<TestScheduleA>();
+    // Not registered by any plugin.
+    trigger.trigger::<TestScheduleB>();
+
+    let report = server
+        .run_until(async {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            trigger.trigger::<TestScheduleA>();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        })
+        .await;
+
+    assert_eq!(report.reason, ShutdownReason::Requested);
+    assert_eq!(ticks.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn shutdown_hooks_drain_before_cleanup() {
+    use std::sync::Arc;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    let (mut server, _, cleaned_up) = run_loop_server();
+    let drained = Arc::new(AtomicBool::new(false));
+    let hook_drained = drained.clone();
+    let hook_cleaned_up = cleaned_up.clone();
+    server.on_shutdown(move || {
+        let drained = hook_drained.clone();
+        let cleaned_up = hook_cleaned_up.clone();
+        async move {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            assert!(!cleaned_up.load(Ordering::SeqCst));
+            drained.store(true, Ordering::SeqCst);
+        }
+    });
+
+    let report = server.run_until(std::future::ready(())).await;
+
+    assert!(report.drained);
+    assert!(drained.load(Ordering::SeqCst));
+    assert!(cleaned_up.load(Ordering::SeqCst));
+}
+
+#[tokio::test(start_paused = true)]
+async fn shutdown_hooks_are_abandoned_after_timeout() {
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    let (mut server, _, cleaned_up) = run_loop_server();
+    server.set_shutdown_timeout(Duration::from_secs(5));
+    server.on_shutdown(std::future::pending::<()>);
+
+    let started = tokio::time::Instant::now();
+    let report = server.run_until(std::future::ready(())).await;
+
+    assert!(!report.drained);
+    assert_eq!(started.elapsed(), Duration::from_secs(5));
+    assert!(cleaned_up.load(Ordering::SeqCst));
+}
+
+#[tokio::test(start_paused = true)]
+async fn shutdown_hooks_run_concurrently() {
+    use std::time::Duration;
+
+    let (mut server, _, _) = run_loop_server();
+    server.set_shutdown_timeout(Duration::from_secs(5));
+    for _ in 0..3 {
+        server.on_shutdown(|| tokio::time::sleep(Duration::from_secs(3)));
+    }
+
+    let started = tokio::time::Instant::now();
+    let report = server.run_until(std::future::ready(())).await;
+
+    assert!(report.drained);
+    assert_eq!(started.elapsed(), Duration::from_secs(3));
+}
+
+#[tokio::test(start_paused = true)]
+async fn serve_shares_server_with_application() {
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    let (mut server, ticks, cleaned_up) = run_loop_server();
+    server.tick_every::<TestScheduleA>(Duration::from_millis(10));
+    server.finish();
+
+    let stop = tokio::sync::Notify::new();
+    let (report, ()) = tokio::join!(server.serve(stop.notified()), async {
+        tokio::time::sleep(Duration::from_millis(25)).await;
+        // The application can tick and read the server while it is served.
+        server.tick::<TestScheduleA>();
+        assert!(!server.contains_resource::<TestResource>());
+        stop.notify_one();
+    });
+
+    assert_eq!(report.reason, ShutdownReason::Requested);
+    assert_eq!(ticks.load(Ordering::SeqCst), 3);
+    assert!(!cleaned_up.load(Ordering::SeqCst));
+    server.cleanup();
+    assert!(cleaned_up.load(Ordering::SeqCst));
+}
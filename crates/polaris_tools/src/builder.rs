@@ -1,7 +1,9 @@
-//! Single-shot tool-use builder extensions.
+//! Tool-use builder extensions.
 //!
 //! Bridges [`Tool`] / [`Toolset`] into [`LlmRequestBuilder`] and provides
-//! [`LlmReasonExt::reason`] for single-shot LLM calls with tool definitions.
+//! [`LlmReasonExt::reason`] for single-shot LLM calls with tool definitions,
+//! and [`LlmToolLoopExt::run_tools`] for calling the model and executing its
+//! tool calls until it produces a final answer.
 //!
 //! # Example
 //!
@@ -25,12 +27,35 @@
 //! }
 //! # Ok(())
 //! # }
+//! ```
+//!
+//! # Tool loop
+//!
+//! ```no_run
+//! use polaris_tools::{LlmRequestBuilderExt, LlmToolLoopExt, ToolLoopConfig, ToolRegistry};
+//! use polaris_models::llm::Llm;
+//!
+//! # async fn example(llm: Llm, registry: &ToolRegistry) -> Result<(), Box<dyn std::error::Error>> {
+//! let transcript = llm
+//!     .builder()
+//!     .with_registry(registry)
+//!     .user("What's the weather in Paris and Tokyo?")
+//!     .run_tools(registry, ToolLoopConfig::new().max_steps(5).parallel(true))
+//!     .await?;
+//!
+//! let answer = transcript.text();
+//! # Ok(())
+//! # }
+//! ```
 
 use crate::registry::ToolRegistry;
 use crate::tool::Tool;
 use crate::toolset::Toolset;
 use core::future::Future;
-use polaris_models::llm::{GenerationError, LlmRequestBuilder, LlmResponse};
+use polaris_models::llm::{
+    GenerationError, LlmRequestBuilder, LlmResponse, Message, ToolCall, ToolChoice, ToolResult,
+    ToolResultContent, ToolResultStatus, UserBlock,
+};
 
 // ─────────────────────
 // Error
@@ -46,6 +71,19 @@ pub enum ReasonError {
     /// No tool definitions were provided before calling `reason()`.
     #[error("no tools provided — add at least one tool definition before calling reason()")]
     NoTools,
+
+    /// [`ToolLoopConfig::max_steps`] was set to zero.
+    #[error("tool loop max_steps must be at least 1")]
+    ZeroMaxSteps,
+
+    /// The model was still calling tools after the configured number of steps.
+    #[error("tool loop did not finish within {max_steps} steps")]
+    MaxStepsExceeded {
+        /// The configured step limit.
+        max_steps: usize,
+        /// The steps completed before the limit was reached.
+        transcript: Box<ToolTranscript>,
+    },
 }
 
 // ─────────────────────
@@ -109,14 +147,224 @@ impl LlmReasonExt for LlmRequestBuilder<'_, polaris_models::llm::Ready> {
     }
 }
 
+// ─────────────────────
+// Extension: run_tools() (Ready state only)
+// ─────────────────────
+
+/// Configuration for [`LlmToolLoopExt::run_tools`].
+#[derive(Debug, Clone)]
+pub struct ToolLoopConfig {
+    max_steps: usize,
+    parallel: bool,
+}
+
+impl Default for ToolLoopConfig {
+    fn default() -> Self {
+        Self {
+            max_steps: 10,
+            parallel: false,
+        }
+    }
+}
+
+impl ToolLoopConfig {
+    /// Creates a configuration with a limit of 10 steps and sequential dispatch.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of model calls.
+    ///
+    /// Must be at least 1; [`run_tools`](LlmToolLoopExt::run_tools) rejects
+    /// a limit of zero.
+    #[must_use]
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Sets whether the tool calls of a single step are executed concurrently.
+    ///
+    /// Results are reported in call order either way.
+    #[must_use]
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+}
+
+/// A single model call within a tool loop.
+#[derive(Debug, Clone)]
+pub struct ToolStep {
+    /// The model's response.
+    pub response: LlmResponse,
+    /// Results of the tool calls in [`response`](Self::response), in call order.
+    ///
+    /// Empty for the final step.
+    pub results: Vec<ToolResult>,
+}
+
+/// The record of a tool loop, returned by [`LlmToolLoopExt::run_tools`].
+#[derive(Debug, Clone)]
+pub struct ToolTranscript {
+    /// Every step of the loop, in order.
+    pub steps: Vec<ToolStep>,
+    /// The full conversation: the request's messages followed by each
+    /// assistant response and tool result message.
+    pub messages: Vec<Message>,
+}
+
+impl ToolTranscript {
+    /// Returns the last model response, if any step ran.
+    #[must_use]
+    pub fn final_response(&self) -> Option<&LlmResponse> {
+        self.steps.last().map(|step| &step.response)
+    }
+
+    /// Returns the text of the last model response.
+    ///
+    /// Returns an empty string if no step ran.
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.final_response()
+            .map(LlmResponse::text)
+            .unwrap_or_default()
+    }
+}
+
+/// Extension trait for the [`run_tools()`](LlmToolLoopExt::run_tools) method,
+/// available only when the builder is in the [`Ready`](polaris_models::llm::builder::Ready)
+/// state (has at least one message).
+pub trait LlmToolLoopExt {
+    /// Calls the model and executes its tool calls until it stops calling tools.
+    ///
+    /// Each step sends the conversation so far, executes every requested tool
+    /// through `registry`, and appends the assistant message and a single user
+    /// message holding all tool results. Tool failures do not end the loop:
+    /// they are reported back to the model as results with
+    /// [`ToolResultStatus::Error`].
+    ///
+    /// A [`ToolChoice::Required`] or [`ToolChoice::Specific`] choice applies
+    /// to the first step only; later steps use [`ToolChoice::Auto`] so the
+    /// model can answer once it has the tool results.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReasonError::NoTools`] if no tool definitions were added.
+    /// Returns [`ReasonError::ZeroMaxSteps`] if
+    /// [`ToolLoopConfig::max_steps`] is zero.
+    /// Returns [`ReasonError::Generation`] if an LLM call fails.
+    /// Returns [`ReasonError::MaxStepsExceeded`] if the model is still calling
+    /// tools after [`ToolLoopConfig::max_steps`] calls.
+    fn run_tools(
+        self,
+        registry: &ToolRegistry,
+        config: ToolLoopConfig,
+    ) -> impl Future<Output = Result<ToolTranscript, ReasonError>> + Send;
+}
+
+impl LlmToolLoopExt for LlmRequestBuilder<'_, polaris_models::llm::Ready> {
+    async fn run_tools(
+        self,
+        registry: &ToolRegistry,
+        config: ToolLoopConfig,
+    ) -> Result<ToolTranscript, ReasonError> {
+        if self.tool_count() == 0 {
+            return Err(ReasonError::NoTools);
+        }
+        if config.max_steps == 0 {
+            return Err(ReasonError::ZeroMaxSteps);
+        }
+
+        let (llm, mut request) = self.build();
+        let mut steps = Vec::new();
+
+        for _ in 0..config.max_steps {
+            let response = llm.generate(request.clone()).await?;
+            request.messages.push(Message::Assistant {
+                id: None,
+                content: response.content.clone(),
+            });
+
+            if !response.has_tool_calls() {
+                steps.push(ToolStep {
+                    response,
+                    results: Vec::new(),
+                });
+                return Ok(ToolTranscript {
+                    steps,
+                    messages: request.messages,
+                });
+            }
+
+            let calls = response.tool_calls();
+            let results = if config.parallel {
+                futures::future::join_all(calls.into_iter().map(|call| dispatch(registry, call)))
+                    .await
+            } else {
+                let mut results = Vec::with_capacity(calls.len());
+                for call in calls {
+                    results.push(dispatch(registry, call).await);
+                }
+                results
+            };
+
+            request.messages.push(Message::User {
+                content: results.iter().cloned().map(UserBlock::ToolResult).collect(),
+            });
+            steps.push(ToolStep { response, results });
+
+            // A forced tool call would otherwise repeat on every step.
+            if matches!(
+                request.tool_choice,
+                Some(ToolChoice::Required | ToolChoice::Specific(_))
+            ) {
+                request.tool_choice = Some(ToolChoice::Auto);
+            }
+        }
+
+        Err(ReasonError::MaxStepsExceeded {
+            max_steps: config.max_steps,
+            transcript: Box::new(ToolTranscript {
+                steps,
+                messages: request.messages,
+            }),
+        })
+    }
+}
+
+/// Executes a single tool call, converting failures into error results.
+async fn dispatch(registry: &ToolRegistry, call: &ToolCall) -> ToolResult {
+    let (output, status) = match registry
+        .execute(&call.function.name, &call.function.arguments)
+        .await
+    {
+        Ok(value) => (
+            value
+                .as_str()
+                .map_or_else(|| value.to_string(), String::from),
+            ToolResultStatus::Success,
+        ),
+        Err(err) => (err.to_string(), ToolResultStatus::Error),
+    };
+
+    ToolResult {
+        id: call.id.clone(),
+        call_id: call.call_id.clone(),
+        content: ToolResultContent::Text(output),
+        status,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use async_trait::async_trait;
     use polaris_models::ModelRegistry;
     use polaris_models::llm::{
-        AssistantBlock, GenerationError, Llm, LlmProvider, LlmRequest, LlmResponse, ToolCall,
-        ToolFunction, Usage,
+        AssistantBlock, GenerationError, Llm, LlmProvider, LlmRequest, LlmResponse, StopReason,
+        TextBlock, ToolCall, ToolChoice, ToolFunction, ToolResultContent, ToolResultStatus, Usage,
     };
     use serde_json::json;
     use std::future::Future;
@@ -150,6 +398,7 @@ mod tests {
                 name: self.name.to_string(),
                 description: format!("Fake {}", self.name),
                 parameters: json!({"type": "object", "properties": {}}),
+                cache_control: None,
             }
         }
 
@@ -162,6 +411,27 @@ mod tests {
         }
     }
 
+    struct FailingTool;
+
+    impl Tool for FailingTool {
+        fn definition(&self) -> polaris_models::llm::ToolDefinition {
+            polaris_models::llm::ToolDefinition {
+                name: "fail".to_string(),
+                description: "Always fails".to_string(),
+                parameters: json!({"type": "object", "properties": {}}),
+                cache_control: None,
+            }
+        }
+
+        fn execute(
+            &self,
+            _args: serde_json::Value,
+        ) -> Pin<Box<dyn Future<Output = Result<serde_json::Value, crate::ToolError>> + Send + '_>>
+        {
+            Box::pin(async { Err(crate::ToolError::execution_error("boom")) })
+        }
+    }
+
     // ── Mock Toolset ──
 
     struct FakeToolset;
@@ -189,6 +459,57 @@ mod tests {
             Ok(LlmResponse {
                 content: vec![AssistantBlock::ToolCall(make_tool_call("1", "search"))],
                 usage: Usage::default(),
+                stop_reason: Some(StopReason::ToolUse),
+            })
+        }
+    }
+
+    /// Calls `search` and `fail` until it sees tool results, then answers.
+    struct TwoStepProvider;
+
+    #[async_trait]
+    impl LlmProvider for TwoStepProvider {
+        async fn generate(
+            &self,
+            _model: &str,
+            request: LlmRequest,
+        ) -> Result<LlmResponse, GenerationError> {
+            let content = if request.contains_tool_blocks() {
+                vec![AssistantBlock::Text(TextBlock::new("done"))]
+            } else {
+                vec![
+                    AssistantBlock::ToolCall(make_tool_call("1", "search")),
+                    AssistantBlock::ToolCall(make_tool_call("2", "fail")),
+                ]
+            };
+            Ok(LlmResponse {
+                content,
+                usage: Usage::default(),
+                stop_reason: None,
+            })
+        }
+    }
+
+    /// Calls `search` whenever the request forces a tool call, otherwise answers.
+    struct ForcedChoiceProvider;
+
+    #[async_trait]
+    impl LlmProvider for ForcedChoiceProvider {
+        async fn generate(
+            &self,
+            _model: &str,
+            request: LlmRequest,
+        ) -> Result<LlmResponse, GenerationError> {
+            let content = match request.tool_choice {
+                Some(ToolChoice::Required | ToolChoice::Specific(_)) => {
+                    vec![AssistantBlock::ToolCall(make_tool_call("1", "search"))]
+                }
+                _ => vec![AssistantBlock::Text(TextBlock::new("done"))],
+            };
+            Ok(LlmResponse {
+                content,
+                usage: Usage::default(),
+                stop_reason: None,
             })
         }
     }
@@ -199,6 +520,19 @@ mod tests {
         registry.llm("mock/test").unwrap()
     }
 
+    fn two_step_llm() -> Llm {
+        let mut registry = ModelRegistry::new();
+        registry.register_llm_provider("mock", Arc::new(TwoStepProvider));
+        registry.llm("mock/test").unwrap()
+    }
+
+    fn tool_registry() -> ToolRegistry {
+        let mut registry = ToolRegistry::new();
+        registry.register(FakeTool { name: "search" });
+        registry.register(FailingTool);
+        registry
+    }
+
     // ── LlmRequestBuilderExt tests ──
 
     #[test]
@@ -239,4 +573,126 @@ mod tests {
         assert!(response.has_tool_calls());
         assert_eq!(response.tool_calls()[0].function.name, "search");
     }
+
+    // ── LlmToolLoopExt tests ──
+
+    async fn run_two_step(config: ToolLoopConfig) -> ToolTranscript {
+        let llm = two_step_llm();
+        let registry = tool_registry();
+        llm.builder()
+            .with_registry(&registry)
+            .user("Search for something")
+            .run_tools(&registry, config)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn run_tools_loops_until_final_answer() {
+        let transcript = run_two_step(ToolLoopConfig::new()).await;
+
+        assert_eq!(transcript.steps.len(), 2);
+        assert_eq!(transcript.text(), "done");
+        // user, assistant (tool calls), user (tool results), assistant (answer)
+        assert_eq!(transcript.messages.len(), 4);
+        assert!(transcript.steps[1].results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn run_tools_reports_tool_errors_as_results() {
+        let transcript = run_two_step(ToolLoopConfig::new()).await;
+        let results = &transcript.steps[0].results;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].id, "1");
+        assert!(matches!(results[0].status, ToolResultStatus::Success));
+        assert_eq!(results[1].id, "2");
+        assert!(matches!(results[1].status, ToolResultStatus::Error));
+        assert!(
+            matches!(&results[1].content, ToolResultContent::Text(text) if text.contains("boom"))
+        );
+    }
+
+    #[tokio::test]
+    async fn run_tools_parallel_preserves_call_order() {
+        let transcript = run_two_step(ToolLoopConfig::new().parallel(true)).await;
+        let ids: Vec<_> = transcript.steps[0]
+            .results
+            .iter()
+            .map(|result| result.id.as_str())
+            .collect();
+
+        assert_eq!(ids, ["1", "2"]);
+    }
+
+    #[tokio::test]
+    async fn run_tools_stops_at_max_steps() {
+        let llm = mock_llm();
+        let registry = tool_registry();
+        let result = llm
+            .builder()
+            .with_registry(&registry)
+            .user("Search forever")
+            .run_tools(&registry, ToolLoopConfig::new().max_steps(3))
+            .await;
+
+        match result {
+            Err(ReasonError::MaxStepsExceeded {
+                max_steps,
+                transcript,
+            }) => {
+                assert_eq!(max_steps, 3);
+                assert_eq!(transcript.steps.len(), 3);
+            }
+            other => panic!("expected MaxStepsExceeded, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn run_tools_forces_tool_choice_on_first_step_only() {
+        let mut models = ModelRegistry::new();
+        models.register_llm_provider("mock", Arc::new(ForcedChoiceProvider));
+        let llm = models.llm("mock/test").unwrap();
+        let registry = tool_registry();
+
+        for choice in [ToolChoice::Required, ToolChoice::Specific("search".into())] {
+            let transcript = llm
+                .builder()
+                .with_registry(&registry)
+                .tool_choice(choice)
+                .user("Search for something")
+                .run_tools(&registry, ToolLoopConfig::new().max_steps(3))
+                .await
+                .unwrap();
+
+            assert_eq!(transcript.steps.len(), 2);
+            assert_eq!(transcript.text(), "done");
+        }
+    }
+
+    #[tokio::test]
+    async fn run_tools_rejects_zero_max_steps() {
+        let llm = two_step_llm();
+        let registry = tool_registry();
+        let result = llm
+            .builder()
+            .with_registry(&registry)
+            .user("Search for something")
+            .run_tools(&registry, ToolLoopConfig::new().max_steps(0))
+            .await;
+
+        assert!(matches!(result, Err(ReasonError::ZeroMaxSteps)));
+    }
+
+    #[tokio::test]
+    async fn run_tools_returns_no_tools_error_when_empty() {
+        let llm = mock_llm();
+        let result = llm
+            .builder()
+            .user("hello")
+            .run_tools(&tool_registry(), ToolLoopConfig::new())
+            .await;
+
+        assert!(matches!(result, Err(ReasonError::NoTools)));
+    }
 }
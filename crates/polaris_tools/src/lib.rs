@@ -8,7 +8,7 @@
 //!
 //! ```
 //! use polaris_tools::{tool, ToolsPlugin, ToolRegistry, ToolError};
-//! use polaris_system::plugin::{Plugin, PluginId, Version};
+//! use polaris_system::plugin::{Dependency, Plugin, Version};
 //! use polaris_system::server::Server;
 //!
 //! let mut server = Server::new();
@@ -35,8 +35,8 @@
 //!     const ID: &'static str = "search";
 //!     const VERSION: Version = Version::new(0, 0, 1);
 //!
-//!     fn dependencies(&self) -> Vec<PluginId> {
-//!         vec![PluginId::of::<ToolsPlugin>()]
+//!     fn dependencies(&self) -> Vec<Dependency> {
+//!         vec![Dependency::of::<ToolsPlugin>()]
 //!     }
 //!
 //!     fn build(&self, server: &mut Server) {
@@ -70,7 +70,10 @@ pub mod tool;
 pub mod toolset;
 
 // Re-export core types at crate root.
-pub use builder::{LlmReasonExt, LlmRequestBuilderExt, ReasonError};
+pub use builder::{
+    LlmReasonExt, LlmRequestBuilderExt, LlmToolLoopExt, ReasonError, ToolLoopConfig, ToolStep,
+    ToolTranscript,
+};
 pub use error::ToolError;
 pub use param::{FunctionCall, FunctionParam, InputParam};
 pub use registry::{ToolRegistry, ToolsPlugin};
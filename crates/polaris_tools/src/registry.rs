@@ -12,13 +12,16 @@ use crate::tool::Tool;
 use crate::toolset::Toolset;
 use indexmap::IndexMap;
 use polaris_models::llm::ToolDefinition;
-use polaris_system::plugin::{Plugin, Version};
+use polaris_system::plugin::{Plugin, RegistrationConflict, Version};
 use polaris_system::resource::GlobalResource;
 use polaris_system::server::Server;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 
+/// Kind reported in [`RegistrationConflict`]s from the tool registry.
+const TOOL: &str = "tool";
+
 /// Registry of available tools.
 ///
 /// Stores tools by name and provides lookup, execution, and definition listing.
@@ -50,14 +53,25 @@ impl ToolRegistry {
     ///
     /// # Panics
     ///
-    /// Panics if a tool with the same name is already registered.
+    /// Panics if a tool with the same name is already registered. Use
+    /// [`try_register`](Self::try_register) to handle the conflict instead.
     pub fn register(&mut self, tool: impl Tool) {
+        if let Err(err) = self.try_register(tool) {
+            panic!("{err}");
+        }
+    }
+
+    /// Registers a tool, rejecting a name that is already registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RegistrationConflict`] if a tool with the same name is
+    /// already registered.
+    pub fn try_register(&mut self, tool: impl Tool) -> Result<(), RegistrationConflict> {
         let name = tool.definition().name;
-        assert!(
-            !self.tools.contains_key(&name),
-            "Tool '{name}' is already registered"
-        );
+        self.check_available(&name)?;
         self.tools.insert(name, Arc::new(tool));
+        Ok(())
     }
 
     /// Registers all tools from a toolset.
@@ -65,15 +79,48 @@ impl ToolRegistry {
     /// # Panics
     ///
     /// Panics if any tool name conflicts with an already-registered tool.
+    /// Use [`try_register_toolset`](Self::try_register_toolset) to handle the
+    /// conflict instead.
     pub fn register_toolset(&mut self, toolset: impl Toolset) {
-        for tool in toolset.tools() {
-            let name = tool.definition().name;
-            assert!(
-                !self.tools.contains_key(&name),
-                "Tool '{name}' is already registered"
-            );
+        if let Err(err) = self.try_register_toolset(toolset) {
+            panic!("{err}");
+        }
+    }
+
+    /// Registers all tools from a toolset, or none of them if any name is
+    /// already registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RegistrationConflict`] for the first tool whose name is
+    /// already registered or repeated within the toolset.
+    pub fn try_register_toolset(
+        &mut self,
+        toolset: impl Toolset,
+    ) -> Result<(), RegistrationConflict> {
+        let tools: Vec<(String, Box<dyn Tool>)> = toolset
+            .tools()
+            .into_iter()
+            .map(|tool| (tool.definition().name, tool))
+            .collect();
+        for (index, (name, _)) in tools.iter().enumerate() {
+            self.check_available(name)?;
+            if tools[..index].iter().any(|(earlier, _)| earlier == name) {
+                return Err(RegistrationConflict::new(TOOL, name.clone()));
+            }
+        }
+        for (name, tool) in tools {
             self.tools.insert(name, Arc::from(tool));
         }
+        Ok(())
+    }
+
+    /// Returns a conflict if `name` is already registered.
+    fn check_available(&self, name: &str) -> Result<(), RegistrationConflict> {
+        if self.tools.contains_key(name) {
+            return Err(RegistrationConflict::new(TOOL, name));
+        }
+        Ok(())
     }
 
     /// Executes a tool by name with JSON arguments.
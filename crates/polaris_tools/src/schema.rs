@@ -95,6 +95,7 @@ impl FunctionMetadata {
             name: self.name.clone(),
             description: self.description.clone().unwrap_or_default(),
             parameters: self.schema.clone(),
+            cache_control: None,
         }
     }
 
@@ -26,6 +26,7 @@ impl Tool for ManualTool {
                 },
                 "required": ["input"]
             }),
+            cache_control: None,
         }
     }
 
@@ -119,6 +120,20 @@ fn registry_duplicate_registration_panics() {
     registry.register(ManualTool);
 }
 
+#[test]
+fn registry_try_register_reports_conflict() {
+    let mut registry = ToolRegistry::new();
+    registry.try_register(ManualTool).unwrap();
+
+    let conflict = registry.try_register(ManualTool).unwrap_err();
+    assert_eq!(conflict.name, "manual_tool");
+    assert_eq!(
+        conflict.to_string(),
+        "tool 'manual_tool' is already registered"
+    );
+    assert_eq!(registry.names(), ["manual_tool"]);
+}
+
 // ─────────────────────────────────────────────────────────────────────
 // 4. ToolsPlugin lifecycle
 // ─────────────────────────────────────────────────────────────────────
@@ -417,6 +432,16 @@ impl MathTools {
     }
 }
 
+#[test]
+fn registry_try_register_toolset_reports_conflict() {
+    let mut registry = ToolRegistry::new();
+    registry.try_register_toolset(MathTools).unwrap();
+
+    let conflict = registry.try_register_toolset(MathTools).unwrap_err();
+    assert_eq!(conflict.kind, "tool");
+    assert_eq!(registry.names().len(), 2);
+}
+
 #[tokio::test]
 async fn toolset_basic() {
     let tools = MathTools.tools();
@@ -1,7 +1,7 @@
 //! Tool definitions using the `polaris_tools` framework.
 
 use crate::config::AgentConfig;
-use polaris::system::plugin::{Plugin, PluginId, Version};
+use polaris::system::plugin::{Dependency, Plugin, Version};
 use polaris::system::server::Server;
 use polaris::tools::{ToolError, ToolRegistry, ToolsPlugin, toolset};
 
@@ -101,8 +101,8 @@ impl Plugin for FileToolsPlugin {
     const ID: &'static str = "examples::file_tools";
     const VERSION: Version = Version::new(0, 0, 1);
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<ToolsPlugin>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ToolsPlugin>()]
     }
 
     fn build(&self, server: &mut Server) {
@@ -5,11 +5,11 @@ use polaris::{
         HooksAPI,
         api::BoxedHook,
         events::GraphEvent,
-        schedule::{OnGraphComplete, OnGraphFailure},
+        schedule::{OnGraphCancelled, OnGraphComplete, OnGraphFailure},
     },
     system::{
         param::SystemContext,
-        plugin::{Plugin, PluginId, ScheduleId, Version},
+        plugin::{Dependency, Plugin, ScheduleId, Version},
         resource::GlobalResource,
         server::Server,
     },
@@ -207,7 +207,7 @@ impl Plugin for SessionPlugin {
             .api::<HooksAPI>()
             .expect("HooksAPI should be present");
 
-        // Save on completion and failure
+        // Save on completion, failure, and cancellation
         Self::register_save_hook(
             hooks,
             ScheduleId::of::<OnGraphComplete>(),
@@ -220,14 +220,21 @@ impl Plugin for SessionPlugin {
             ScheduleId::of::<OnGraphFailure>(),
             "session:save_on_failure",
             self.config.clone(),
+            Arc::clone(&serializers),
+        );
+        Self::register_save_hook(
+            hooks,
+            ScheduleId::of::<OnGraphCancelled>(),
+            "session:save_on_cancel",
+            self.config.clone(),
             serializers,
         );
     }
 
-    fn dependencies(&self) -> Vec<PluginId> {
+    fn dependencies(&self) -> Vec<Dependency> {
         vec![
-            PluginId::of::<ReActPlugin>(),
-            PluginId::of::<PersistencePlugin>(),
+            Dependency::of::<ReActPlugin>(),
+            Dependency::of::<PersistencePlugin>(),
         ]
     }
 }
@@ -26,7 +26,7 @@ use polaris::models::AnthropicPlugin;
 use polaris::models::llm::{AssistantBlock, Message, UserBlock};
 use polaris::plugins::{IOMessage, InputBuffer, PersistenceAPI, PersistencePlugin};
 use polaris::sessions::{
-    AgentTypeId, FileStore, SessionId, SessionInfo, SessionsAPI, SessionsPlugin,
+    AgentTypeId, FileStore, SessionId, SessionInfo, SessionsAPI, SessionsPlugin, TurnOutcome,
 };
 use polaris::{
     graph::{DevToolsPlugin, GraphExecutor},
@@ -393,13 +393,19 @@ async fn main() {
                 tokio::task::yield_now().await;
 
                 match result {
-                    Ok(result) => {
+                    Ok(TurnOutcome::Completed(result)) => {
                         eprintln!(
                             "{STYLE_DIM}  ({} nodes, {:.2}s){STYLE_RESET}\n",
                             result.nodes_executed,
                             result.duration.as_secs_f64()
                         );
                     }
+                    Ok(TurnOutcome::AwaitingInput(request)) => {
+                        eprintln!(
+                            "{STYLE_DIM}  (awaiting input at '{}'){STYLE_RESET}\n",
+                            request.node_name
+                        );
+                    }
                     Err(err) => {
                         eprintln!("{STYLE_RED}Execution error: {err}{STYLE_RESET}\n");
                     }
@@ -1,6 +1,6 @@
 //! File tools plugin.
 
-use polaris::system::plugin::{Plugin, PluginId, Version};
+use polaris::system::plugin::{Dependency, Plugin, Version};
 use polaris::system::server::Server;
 use polaris::tools::{ToolError, ToolRegistry, ToolsPlugin, toolset};
 use std::path::PathBuf;
@@ -150,8 +150,8 @@ impl Plugin for FileToolsPlugin {
     const ID: &'static str = "examples::file_tools";
     const VERSION: Version = Version::new(0, 0, 1);
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<ToolsPlugin>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<ToolsPlugin>()]
     }
 
     fn build(&self, server: &mut Server) {
@@ -4,7 +4,7 @@
 //! communication, and [`TerminalIOPlugin`] which registers it as the [`UserIO`] local resource.
 
 use polaris::plugins::{IOContent, IOError, IOMessage, IOPlugin, IOProvider, IOSource, UserIO};
-use polaris::system::plugin::{Plugin, PluginId, Version};
+use polaris::system::plugin::{Dependency, Plugin, Version};
 use polaris::system::server::Server;
 use std::sync::Arc;
 
@@ -83,7 +83,7 @@ impl Plugin for TerminalIOPlugin {
         server.register_local(move || UserIO::new(provider.clone()));
     }
 
-    fn dependencies(&self) -> Vec<PluginId> {
-        vec![PluginId::of::<IOPlugin>()]
+    fn dependencies(&self) -> Vec<Dependency> {
+        vec![Dependency::of::<IOPlugin>()]
     }
 }